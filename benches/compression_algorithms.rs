@@ -0,0 +1,70 @@
+//! Compares `StorageConfig::compression_algorithm` settings on the write path for realistic
+//! legal-opinion-sized text: throughput via criterion's timing, and on-disk size via one
+//! `db.size_on_disk()` print per algorithm (criterion has no built-in way to report anything but
+//! time, so size is reported once outside the timed loop rather than made part of it).
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use trie_semantic_search::config::{Config, CompressionAlgorithm};
+use trie_semantic_search::storage::StorageManager;
+use uuid::Uuid;
+
+/// A few paragraphs of repetitive legal prose, long enough (~6 KB) that gzip/zstd's fixed
+/// per-call overhead doesn't dominate the comparison the way it would on a one-line string.
+fn sample_case_text() -> String {
+    "Separate educational facilities are inherently unequal. \
+     The plaintiffs contend that segregated public schools are not equal and cannot be made \
+     equal, and that hence they are deprived of the equal protection of the laws. \
+     We conclude that in the field of public education the doctrine of separate but equal \
+     has no place. "
+        .repeat(40)
+}
+
+async fn seeded_storage(algorithm: CompressionAlgorithm) -> StorageManager {
+    let mut config = Config::default();
+    config.storage.db_path = std::env::temp_dir().join(format!("compression-bench-{}", Uuid::new_v4()));
+    config.storage.compression_algorithm = algorithm;
+    StorageManager::new(config.storage).await.unwrap()
+}
+
+fn report_size_on_disk(rt: &tokio::runtime::Runtime, label: &str, storage: &StorageManager) {
+    if let Ok(stats) = rt.block_on(storage.get_stats()) {
+        println!("{label}: database size on disk after seeding = {} bytes", stats.database_size_bytes);
+    }
+}
+
+fn bench_gzip(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let storage = rt.block_on(seeded_storage(CompressionAlgorithm::Gzip));
+    let text = sample_case_text();
+
+    c.bench_function("store_case_text_gzip", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                let case_id = Uuid::new_v4();
+                storage.store_case_text(&case_id, &text, &text).await.unwrap();
+            })
+        })
+    });
+
+    report_size_on_disk(&rt, "gzip", &storage);
+}
+
+fn bench_zstd(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let storage = rt.block_on(seeded_storage(CompressionAlgorithm::Zstd));
+    let text = sample_case_text();
+
+    c.bench_function("store_case_text_zstd", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                let case_id = Uuid::new_v4();
+                storage.store_case_text(&case_id, &text, &text).await.unwrap();
+            })
+        })
+    });
+
+    report_size_on_disk(&rt, "zstd", &storage);
+}
+
+criterion_group!(benches, bench_gzip, bench_zstd);
+criterion_main!(benches);
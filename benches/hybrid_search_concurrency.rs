@@ -0,0 +1,114 @@
+//! Benchmarks `SearchEngine::search_with_params` with the trie and vector stages launched
+//! concurrently via `tokio::join!` (the default) against the old sequential,
+//! early-exit-on-a-full-lexical-page behavior (`enable_vector_short_circuit: true`), for a query
+//! that never fills `max_results` from the lexical stage alone and so always runs both stages —
+//! the case the request that introduced concurrent stage execution called out.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use trie_semantic_search::config::Config;
+use trie_semantic_search::search::{SearchEngine, SearchQuery, SearchSyntax, SortOrder};
+use trie_semantic_search::storage::StorageManager;
+use trie_semantic_search::trie::TrieIndex;
+use trie_semantic_search::vector::VectorIndex;
+use trie_semantic_search::{CaseMetadata, DocRef, Jurisdiction, SearchConfig};
+use uuid::Uuid;
+
+/// How many cases are indexed into the content trie; kept below `SearchConfig::default()`'s
+/// `max_results` of 10.
+const LEXICALLY_INDEXED_CASES: usize = 5;
+
+fn build_engine(rt: &tokio::runtime::Runtime, temp_dir: &std::path::Path, case_count: usize) -> SearchEngine {
+    rt.block_on(async {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        let mut vector_index = VectorIndex::new(config.vector.clone()).await.unwrap();
+
+        for i in 0..case_count {
+            let case_id = Uuid::new_v4();
+            let full_text = format!("appeal regarding maritime salvage rights, case number {i}");
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {i}"),
+                citation: format!("{i} citation"),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: full_text.clone(),
+                jurisdiction: Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: full_text.split_whitespace().count(),
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, &full_text, &full_text).await.unwrap();
+
+            // Only a few cases go into the content trie, so the lexical stage alone stays below
+            // `max_results` and the sequential path still runs the vector stage.
+            if i < LEXICALLY_INDEXED_CASES {
+                let tokens: Vec<(String, usize)> =
+                    full_text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+                trie_index
+                    .insert_content(&tokens, DocRef { case_id, paragraph_index: 0, char_offset: None })
+                    .unwrap();
+            }
+            vector_index
+                .add_document(DocRef { case_id, paragraph_index: 0, char_offset: None }, &full_text)
+                .await
+                .unwrap();
+        }
+
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+        vector_index.save_to_disk(temp_dir.join("vector_cache.bin")).await.unwrap();
+
+        SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap()
+    })
+}
+
+fn maritime_query(enable_vector_short_circuit: bool) -> SearchQuery {
+    SearchQuery {
+        query: "maritime salvage rights".to_string(),
+        max_results: Some(10),
+        offset: 0,
+        court_filter: None,
+        judge_filter: None,
+        date_range: None,
+        topic_filter: None,
+        syntax: SearchSyntax::Plain,
+        sort: SortOrder::Relevance,
+        profile: None,
+        config: SearchConfig { enable_vector_short_circuit, ..SearchConfig::default() },
+    }
+}
+
+fn bench_hybrid_search_stage_execution(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let temp_dir = std::env::temp_dir().join(format!("bench-hybrid-search-concurrency-{}", Uuid::new_v4()));
+    let engine = build_engine(&rt, &temp_dir, 500);
+
+    c.bench_function("hybrid_search_concurrent_trie_and_vector", |b| {
+        b.iter(|| rt.block_on(engine.search_with_params(maritime_query(false))).unwrap())
+    });
+
+    c.bench_function("hybrid_search_sequential_early_exit", |b| {
+        b.iter(|| rt.block_on(engine.search_with_params(maritime_query(true))).unwrap())
+    });
+
+    rt.block_on(tokio::fs::remove_dir_all(&temp_dir)).ok();
+}
+
+criterion_group!(benches, bench_hybrid_search_stage_execution);
+criterion_main!(benches);
@@ -0,0 +1,75 @@
+//! Benchmarks `StorageManager::get_cases_metadata` (one batched pass) against the equivalent
+//! sequence of one-at-a-time `get_case_metadata` calls for 1000 ids, to confirm batching actually
+//! saves on await-per-item overhead at the scale the request that introduced it called out.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use chrono::Utc;
+use trie_semantic_search::config::Config;
+use trie_semantic_search::storage::StorageManager;
+use trie_semantic_search::{CaseMetadata, Jurisdiction};
+use uuid::Uuid;
+
+fn fixture_metadata(case_id: Uuid, index: usize) -> CaseMetadata {
+    CaseMetadata {
+        id: case_id,
+        name: format!("United States versus Term{index}"),
+        citation: format!("{index} U.S. 1"),
+        court: "Supreme Court".to_string(),
+        decision_date: chrono::NaiveDate::from_ymd_opt(1954, 5, 17).unwrap(),
+        judges: vec!["Warren".to_string()],
+        topics: vec![],
+        full_text: "Separate educational facilities are inherently unequal.".to_string(),
+        jurisdiction: Jurisdiction::Federal,
+        citations: vec![format!("{index} U.S. 1")],
+        docket_number: Some(index.to_string()),
+        source_url: None,
+        word_count: 8,
+        ingestion_date: Utc::now(),
+        validation_warnings: vec![],
+        content_simhash: None,
+        duplicate_of: None,
+    }
+}
+
+async fn seeded_storage(count: usize) -> (StorageManager, Vec<Uuid>) {
+    let mut config = Config::default();
+    config.storage.db_path = std::env::temp_dir().join(format!("metadata-batch-bench-{}", Uuid::new_v4()));
+    let storage = StorageManager::new(config.storage).await.unwrap();
+
+    let mut ids = Vec::with_capacity(count);
+    for i in 0..count {
+        let case_id = Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id, i)).await.unwrap();
+        ids.push(case_id);
+    }
+    (storage, ids)
+}
+
+fn bench_one_at_a_time(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let (storage, ids) = rt.block_on(seeded_storage(1000));
+
+    c.bench_function("get_case_metadata_1000_one_at_a_time", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                for case_id in &ids {
+                    storage.get_case_metadata(case_id).await.unwrap();
+                }
+            })
+        })
+    });
+}
+
+fn bench_batched(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let (storage, ids) = rt.block_on(seeded_storage(1000));
+
+    c.bench_function("get_cases_metadata_1000_batched", |b| {
+        b.iter(|| {
+            rt.block_on(storage.get_cases_metadata(&ids)).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_one_at_a_time, bench_batched);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+//! Benchmarks `TrieIndex::insert_batch` against the equivalent sequence of one-at-a-time
+//! `insert_case_name` calls for 100k entries, to confirm the batch path's prefix-sharing and
+//! deferred frequency updates (see `TrieNode::insert_sorted_batch`) actually pay off at the
+//! scale the request that introduced it called out.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::path::PathBuf;
+use trie_semantic_search::config::TrieConfig;
+use trie_semantic_search::trie::{TrieEntry, TrieIndex};
+use uuid::Uuid;
+
+fn test_trie_config() -> TrieConfig {
+    TrieConfig {
+        use_fst: false,
+        index_case_names: true,
+        index_citations: true,
+        max_prefix_length: 50,
+        index_path: PathBuf::from("./data/trie_index"),
+        enable_memory_mapping: false,
+        fuzzy_short_token_length_threshold: 6,
+        fuzzy_max_edit_distance_short: 1,
+        fuzzy_max_edit_distance_long: 2,
+        wildcard_max_results: 100,
+        skip_stopword_only_ngrams: false,
+        min_token_length: 0,
+    }
+}
+
+fn case_names(count: usize) -> Vec<(String, Uuid)> {
+    (0..count)
+        .map(|i| (format!("United States versus Term{i}"), Uuid::new_v4()))
+        .collect()
+}
+
+fn bench_insert_one_at_a_time(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let names = case_names(100_000);
+
+    c.bench_function("insert_case_name_100k_one_at_a_time", |b| {
+        b.iter(|| {
+            let mut trie = rt.block_on(TrieIndex::new(test_trie_config())).unwrap();
+            for (name, case_id) in &names {
+                trie.insert_case_name(name, *case_id).unwrap();
+            }
+        })
+    });
+}
+
+fn bench_insert_batch(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let names = case_names(100_000);
+
+    c.bench_function("insert_case_name_100k_batch", |b| {
+        b.iter(|| {
+            let mut trie = rt.block_on(TrieIndex::new(test_trie_config())).unwrap();
+            let entries = names
+                .iter()
+                .map(|(name, case_id)| TrieEntry::CaseName { case_name: name.clone(), case_id: *case_id })
+                .collect();
+            trie.insert_batch(entries).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_insert_one_at_a_time, bench_insert_batch);
+criterion_main!(benches);
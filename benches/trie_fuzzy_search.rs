@@ -0,0 +1,51 @@
+//! Benchmarks `TrieIndex::search_fuzzy` against a 100k-term trie to guard the sub-50ms
+//! target called out in the request that introduced it: bounded Levenshtein traversal must
+//! stay cheap even at that scale, since every child edge at each level is a candidate the
+//! length-difference and row-min pruning in `bounded_levenshtein` need to reject quickly.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::path::PathBuf;
+use trie_semantic_search::config::TrieConfig;
+use trie_semantic_search::trie::TrieIndex;
+use uuid::Uuid;
+
+fn test_trie_config() -> TrieConfig {
+    TrieConfig {
+        use_fst: false,
+        index_case_names: true,
+        index_citations: true,
+        max_prefix_length: 50,
+        index_path: PathBuf::from("./data/trie_index"),
+        enable_memory_mapping: false,
+        fuzzy_short_token_length_threshold: 6,
+        fuzzy_max_edit_distance_short: 1,
+        fuzzy_max_edit_distance_long: 2,
+        wildcard_max_results: 100,
+        skip_stopword_only_ngrams: false,
+        min_token_length: 0,
+    }
+}
+
+fn build_trie_with_case_names(count: usize) -> TrieIndex {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut trie = rt.block_on(TrieIndex::new(test_trie_config())).unwrap();
+    for i in 0..count {
+        trie.insert_case_name(&format!("Term{i} versus State of Confusion"), Uuid::new_v4()).unwrap();
+    }
+    trie
+}
+
+fn bench_search_fuzzy(c: &mut Criterion) {
+    let trie = build_trie_with_case_names(100_000);
+
+    c.bench_function("search_fuzzy_100k_terms_one_typo", |b| {
+        b.iter(|| trie.search_fuzzy("Term99999 versos State of Confusion", None).unwrap())
+    });
+
+    c.bench_function("search_fuzzy_100k_terms_no_match", |b| {
+        b.iter(|| trie.search_fuzzy("Zzzzzzzzz nowhere near anything indexed", None).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_search_fuzzy);
+criterion_main!(benches);
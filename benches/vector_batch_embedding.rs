@@ -0,0 +1,92 @@
+//! Benchmarks `VectorIndex::add_documents` against the equivalent sequence of one-at-a-time
+//! `add_document` calls for 10k short case texts, to confirm batching per
+//! `EmbeddingModelConfig::batch_size` actually cuts down model invocations the way the request
+//! that introduced it called out.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::path::PathBuf;
+use trie_semantic_search::config::{
+    ChunkingConfig, DistanceMetric, EmbeddingModelConfig, HnswConfig, QuantizationMode, VectorConfig,
+};
+use trie_semantic_search::vector::VectorIndex;
+use trie_semantic_search::DocRef;
+use uuid::Uuid;
+
+fn test_vector_config() -> VectorConfig {
+    VectorConfig {
+        model: EmbeddingModelConfig {
+            model_path: PathBuf::from("./models/legal-bert.onnx"),
+            tokenizer_path: PathBuf::from("./models/tokenizer.json"),
+            model_type: "legal-bert".to_string(),
+            use_gpu: false,
+            batch_size: 32,
+            max_sequence_length: 512,
+            intra_op_threads: 0,
+            inter_op_threads: 0,
+            lazy_load_model: false,
+        },
+        hnsw: HnswConfig {
+            m: 16,
+            ef_construction: 200,
+            ef_search: 50,
+            max_elements: 10_000_000,
+            index_path: PathBuf::from("./data/vector_index"),
+        },
+        dimension: 768,
+        metric: DistanceMetric::Cosine,
+        cache_max_entries: 10_000,
+        cache_max_bytes: 256 * 1024 * 1024,
+        similarity_threshold: 0.5,
+        max_ann_results: 100,
+        filter_overfetch_multiplier: 3,
+        max_overfetch_multiplier: 24,
+        pending_migration: None,
+        exact_search_threshold: 0,
+        force_backend: None,
+        quantization: QuantizationMode::None,
+        chunking: ChunkingConfig {
+            chunk_size_tokens: 200,
+            overlap_tokens: 50,
+        },
+    }
+}
+
+fn documents(count: usize) -> Vec<(DocRef, String)> {
+    (0..count)
+        .map(|i| {
+            (
+                DocRef { case_id: Uuid::new_v4(), paragraph_index: 0, char_offset: None },
+                format!("This is the opinion for test case {i}. The court holds that freedom of speech applies."),
+            )
+        })
+        .collect()
+}
+
+fn bench_add_document_one_at_a_time(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let docs = documents(10_000);
+
+    c.bench_function("add_document_10k_one_at_a_time", |b| {
+        b.iter(|| {
+            let mut index = rt.block_on(VectorIndex::new(test_vector_config())).unwrap();
+            for (doc_ref, text) in &docs {
+                rt.block_on(index.add_document(doc_ref.clone(), text)).unwrap();
+            }
+        })
+    });
+}
+
+fn bench_add_documents_batched(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let docs = documents(10_000);
+
+    c.bench_function("add_documents_10k_batched", |b| {
+        b.iter(|| {
+            let mut index = rt.block_on(VectorIndex::new(test_vector_config())).unwrap();
+            rt.block_on(index.add_documents(docs.clone()));
+        })
+    });
+}
+
+criterion_group!(benches, bench_add_document_one_at_a_time, bench_add_documents_batched);
+criterion_main!(benches);
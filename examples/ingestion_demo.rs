@@ -248,7 +248,7 @@ async fn main() -> Result<()> {
     // Configure storage
     let storage_config = StorageConfig {
         db_path: db_path.clone(),
-        enable_compression: true,
+        compression_algorithm: Default::default(),
         backup_interval_hours: 24,
         max_backup_files: 5,
     };
@@ -0,0 +1,248 @@
+//! # Search Demo
+//!
+//! End-to-end demonstration of the query path: build a trie/vector snapshot from a
+//! handful of sample cases (the same way `index_build::build_snapshot` does), stand up a
+//! `SearchEngine` from that snapshot with semantic search disabled, and run a few queries
+//! against it — case-name lookup, a content phrase, a citation, and a court/date-filtered
+//! search. Snippets and highlights are printed for each result.
+//!
+//! `SearchEngine` has no in-memory constructor of its own; `SearchEngine::from_snapshot`
+//! is the public entry point for a pre-built index, so this demo writes its snapshot to a
+//! throwaway temp directory and points the engine at that, exactly like a real deployment
+//! would point it at an `index-build` output directory.
+//!
+//! `search_demo_produces_results` in `tests/search_demo.rs` runs this same logic and
+//! asserts every query returns at least one result, so a regression here fails CI.
+
+use chrono::{NaiveDate, Utc};
+use trie_semantic_search::config::Config;
+use trie_semantic_search::errors::Result;
+use trie_semantic_search::storage::StorageManager;
+use trie_semantic_search::text_processing::TextProcessor;
+use trie_semantic_search::trie::TrieIndex;
+use trie_semantic_search::vector::VectorIndex;
+use trie_semantic_search::search::SortOrder;
+use trie_semantic_search::{CaseMetadata, DocRef, Jurisdiction, SearchEngine, SearchQuery, SearchSyntax};
+use uuid::Uuid;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let snapshot_dir = tempfile::tempdir()?;
+    let engine = build_demo_engine(snapshot_dir.path()).await?;
+
+    run_query(&engine, "case-name lookup", SearchQuery {
+        query: "Miranda v. Arizona".to_string(),
+        max_results: Some(5),
+        offset: 0,
+        syntax: SearchSyntax::Plain,
+        court_filter: None,
+        judge_filter: None,
+        date_range: None,
+        topic_filter: None,
+        sort: SortOrder::Relevance,
+        profile: None,
+        config: engine_search_config(&engine),
+    }).await?;
+
+    run_query(&engine, "content phrase", SearchQuery {
+        query: "right to remain silent".to_string(),
+        max_results: Some(5),
+        offset: 0,
+        syntax: SearchSyntax::Plain,
+        court_filter: None,
+        judge_filter: None,
+        date_range: None,
+        topic_filter: None,
+        sort: SortOrder::Relevance,
+        profile: None,
+        config: engine_search_config(&engine),
+    }).await?;
+
+    run_query(&engine, "citation", SearchQuery {
+        query: "410 U.S. 113".to_string(),
+        max_results: Some(5),
+        offset: 0,
+        syntax: SearchSyntax::Plain,
+        court_filter: None,
+        judge_filter: None,
+        date_range: None,
+        topic_filter: None,
+        sort: SortOrder::Relevance,
+        profile: None,
+        config: engine_search_config(&engine),
+    }).await?;
+
+    run_query(&engine, "court + date filtered", SearchQuery {
+        query: "equal protection".to_string(),
+        max_results: Some(5),
+        offset: 0,
+        syntax: SearchSyntax::Plain,
+        court_filter: Some(vec!["Supreme Court of the United States".to_string()]),
+        judge_filter: None,
+        date_range: Some((
+            NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(1960, 12, 31).unwrap(),
+        )),
+        topic_filter: None,
+        sort: SortOrder::Relevance,
+        profile: None,
+        config: engine_search_config(&engine),
+    }).await?;
+
+    Ok(())
+}
+
+/// The `SearchConfig` this demo runs every query with: semantic search disabled, since the
+/// snapshot below has an empty vector index and there's no embedding model to query anyway.
+fn engine_search_config(_engine: &SearchEngine) -> trie_semantic_search::SearchConfig {
+    trie_semantic_search::SearchConfig { enable_semantic: false, ..Default::default() }
+}
+
+async fn run_query(engine: &SearchEngine, label: &str, query: SearchQuery) -> Result<()> {
+    println!("\n=== {label}: \"{}\" ===", query.query);
+    let outcome = engine.search_with_params(query).await?;
+    if outcome.results.is_empty() {
+        println!("  (no results)");
+    }
+    for result in &outcome.results {
+        println!(
+            "  [{:.2}] {} ({:?} via {:?})",
+            result.score, result.case_metadata.name, result.match_type, result.provenance
+        );
+        println!("      snippet: {}", result.snippet);
+        for highlight in &result.highlights {
+            println!("      highlight: {}..{} ({:?})", highlight.start, highlight.end, highlight.highlight_type);
+        }
+    }
+    Ok(())
+}
+
+/// Build a `SearchEngine` backed by a freshly-built trie snapshot (written to
+/// `snapshot_dir`) over the three sample cases, with an empty vector index alongside it.
+pub async fn build_demo_engine(snapshot_dir: &std::path::Path) -> Result<SearchEngine> {
+    let mut config = Config::default();
+    config.storage.db_path = snapshot_dir.join("storage");
+    let config = std::sync::Arc::new(config);
+
+    let storage = std::sync::Arc::new(StorageManager::new(config.storage.clone()).await?);
+    let text_processor = TextProcessor::new(config.text_processing.clone())?;
+
+    let mut trie_index = TrieIndex::new(config.trie.clone()).await?;
+    let vector_index = VectorIndex::new(config.vector.clone()).await?;
+
+    for (metadata, full_text) in sample_cases() {
+        storage.store_case_metadata(&metadata).await?;
+        storage.store_case_text(&metadata.id, &full_text, &full_text).await?;
+
+        trie_index.insert_case_name(&metadata.name, metadata.id)?;
+
+        let processed = text_processor.process_text(&full_text).await?;
+        for (index, sentence) in processed.sentences.iter().enumerate() {
+            let tokens = sentence.word_offsets();
+            if tokens.is_empty() {
+                continue;
+            }
+            trie_index.insert_content(
+                &tokens,
+                DocRef { case_id: metadata.id, paragraph_index: index, char_offset: None },
+            )?;
+        }
+
+        for citation in &metadata.citations {
+            trie_index.insert_citation(
+                citation,
+                DocRef { case_id: metadata.id, paragraph_index: 0, char_offset: None },
+            )?;
+        }
+    }
+
+    trie_index.save_to_disk(snapshot_dir.join("trie.bin")).await?;
+    vector_index.save_to_disk(snapshot_dir.join("vector_cache.bin")).await?;
+
+    SearchEngine::from_snapshot(config, storage, snapshot_dir).await
+}
+
+fn sample_cases() -> Vec<(CaseMetadata, String)> {
+    vec![
+        (
+            CaseMetadata {
+                id: Uuid::new_v4(),
+                name: "Brown v. Board of Education".to_string(),
+                citation: "347 U.S. 483 (1954)".to_string(),
+                court: "Supreme Court of the United States".to_string(),
+                decision_date: NaiveDate::from_ymd_opt(1954, 5, 17).unwrap(),
+                judges: vec!["Warren, C.J.".to_string()],
+                topics: vec!["Education".to_string(), "Equal Protection".to_string()],
+                full_text: "Separate educational facilities are inherently unequal. \
+                    We hold that the plaintiffs are deprived of the equal protection of the \
+                    laws guaranteed by the Fourteenth Amendment.".to_string(),
+                jurisdiction: Jurisdiction::Federal,
+                citations: vec!["347 U.S. 483 (1954)".to_string()],
+                docket_number: Some("1".to_string()),
+                source_url: None,
+                word_count: 0,
+                ingestion_date: Utc::now(),
+                validation_warnings: Vec::new(),
+                content_simhash: None,
+                duplicate_of: None,
+            },
+            "Separate educational facilities are inherently unequal. \
+                We hold that the plaintiffs are deprived of the equal protection of the \
+                laws guaranteed by the Fourteenth Amendment.".to_string(),
+        ),
+        (
+            CaseMetadata {
+                id: Uuid::new_v4(),
+                name: "Miranda v. Arizona".to_string(),
+                citation: "384 U.S. 436 (1966)".to_string(),
+                court: "Supreme Court of the United States".to_string(),
+                decision_date: NaiveDate::from_ymd_opt(1966, 6, 13).unwrap(),
+                judges: vec!["Warren, C.J.".to_string()],
+                topics: vec!["Criminal Law".to_string(), "Fifth Amendment".to_string()],
+                full_text: "Prior to any questioning, the person must be warned that he has \
+                    a right to remain silent, and that he has a right to the presence of an \
+                    attorney.".to_string(),
+                jurisdiction: Jurisdiction::Federal,
+                citations: vec!["384 U.S. 436 (1966)".to_string()],
+                docket_number: Some("759".to_string()),
+                source_url: None,
+                word_count: 0,
+                ingestion_date: Utc::now(),
+                validation_warnings: Vec::new(),
+                content_simhash: None,
+                duplicate_of: None,
+            },
+            "Prior to any questioning, the person must be warned that he has a right to \
+                remain silent, and that he has a right to the presence of an attorney."
+                .to_string(),
+        ),
+        (
+            CaseMetadata {
+                id: Uuid::new_v4(),
+                name: "Roe v. Wade".to_string(),
+                citation: "410 U.S. 113 (1973)".to_string(),
+                court: "Supreme Court of the United States".to_string(),
+                decision_date: NaiveDate::from_ymd_opt(1973, 1, 22).unwrap(),
+                judges: vec!["Blackmun, J.".to_string()],
+                topics: vec!["Privacy Rights".to_string(), "Due Process".to_string()],
+                full_text: "The Constitution does not explicitly mention any right of \
+                    privacy, but the Court has recognized that a right of personal privacy \
+                    does exist.".to_string(),
+                jurisdiction: Jurisdiction::Federal,
+                citations: vec!["410 U.S. 113 (1973)".to_string()],
+                docket_number: Some("70-18".to_string()),
+                source_url: None,
+                word_count: 0,
+                ingestion_date: Utc::now(),
+                validation_warnings: Vec::new(),
+                content_simhash: None,
+                duplicate_of: None,
+            },
+            "The Constitution does not explicitly mention any right of privacy, but the \
+                Court has recognized that a right of personal privacy does exist."
+                .to_string(),
+        ),
+    ]
+}
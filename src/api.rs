@@ -18,11 +18,95 @@
 
 use crate::config::Config;
 use crate::errors::{Result, SearchError};
-use crate::search::{SearchEngine, SearchQuery, SearchResult};
+use crate::search::{IndexComponentStatus, SearchEngine, SearchFacets, SearchQuery, SearchResult, SearchSyntax, SortOrder, Suggestion};
 use crate::storage::StorageManager;
-use actix_web::{web, App, HttpResponse, HttpServer, Result as ActixResult};
+use crate::taxonomy::{TopicFacet, TopicFilter};
+use actix_web::{http::KeepAlive, web, App, HttpRequest, HttpResponse, HttpServer, Result as ActixResult};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::io::BufReader;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Human-readable description of the shapes accepted by [`DateRangeFilter`], shared
+/// between every rejection message so clients see one consistent hint.
+const ACCEPTED_DATE_RANGE_FORMATS: &str = "a [\"YYYY-MM-DD\", \"YYYY-MM-DD\"] tuple, \
+a {\"from\": \"YYYY-MM-DD\", \"to\": \"YYYY-MM-DD\"} object, or a relative/partial date string \
+(\"1954\", \"1954-05\", \"1950s\", \"1954..1966\", \">=1973\", \"<=1973\", or a bare number as \
+a year)";
+
+/// Inclusive date range accepted from clients in one of several JSON shapes and
+/// normalized into a `NaiveDate` pair for [`SearchQuery`].
+///
+/// Accepts:
+/// - a two-element tuple of `"YYYY-MM-DD"` strings: `["1954-05-17", "1966-06-13"]`
+/// - an object with `from`/`to` string keys: `{"from": "1954-05-17", "to": "1966-06-13"}`
+/// - a relative/partial date string or bare number, parsed by
+///   `fielded_query::parse_date_range_expression`: a year (`1954`), a year-month (`1954-05`), a
+///   decade (`1950s`), a `start..end` span of any of those (`1954..1966`), or an open-ended
+///   `>=1973`/`<=1973` bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DateRangeFilter {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl<'de> Deserialize<'de> for DateRangeFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Self::from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl DateRangeFilter {
+    fn from_value(value: &serde_json::Value) -> std::result::Result<Self, String> {
+        let (start, end) = match value {
+            serde_json::Value::Array(items) if items.len() == 2 => (
+                Self::parse_date_string(&items[0], value)?,
+                Self::parse_date_string(&items[1], value)?,
+            ),
+            serde_json::Value::Object(map) if map.contains_key("from") && map.contains_key("to") => (
+                Self::parse_date_string(&map["from"], value)?,
+                Self::parse_date_string(&map["to"], value)?,
+            ),
+            serde_json::Value::Number(n) => {
+                crate::fielded_query::parse_date_range_expression(&n.to_string()).map_err(|reason| Self::error(value, &reason))?
+            }
+            serde_json::Value::String(s) => {
+                crate::fielded_query::parse_date_range_expression(s).map_err(|reason| Self::error(value, &reason))?
+            }
+            _ => return Err(Self::error(value, "unrecognized shape for date_range")),
+        };
+
+        if start > end {
+            return Err(Self::error(
+                value,
+                &format!("start date {} is after end date {}", start, end),
+            ));
+        }
+
+        Ok(Self { start, end })
+    }
+
+    fn parse_date_string(field: &serde_json::Value, whole: &serde_json::Value) -> std::result::Result<NaiveDate, String> {
+        let s = field
+            .as_str()
+            .ok_or_else(|| Self::error(whole, "dates must be given as \"YYYY-MM-DD\" strings"))?;
+        NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map_err(|_| Self::error(whole, &format!("could not parse \"{}\" as YYYY-MM-DD", s)))
+    }
+
+    fn error(value: &serde_json::Value, reason: &str) -> String {
+        format!(
+            "field \"date_range\": {} (received {}); accepted formats: {}",
+            reason, value, ACCEPTED_DATE_RANGE_FORMATS
+        )
+    }
+}
 
 /// Application state for the API server
 pub struct ApiServer {
@@ -35,7 +119,35 @@ pub struct SearchRequest {
     pub query: String,
     pub max_results: Option<usize>,
     pub court_filter: Option<Vec<String>>,
-    pub date_range: Option<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)>,
+    /// See `crate::search::SearchQuery::judge_filter`.
+    #[serde(default)]
+    pub judge_filter: Option<Vec<String>>,
+    pub date_range: Option<DateRangeFilter>,
+    pub topic_filter: Option<TopicFilter>,
+    /// Per-query override for the HNSW beam width the vector stage searches with; see
+    /// `SearchConfig::ef_search_override`. Out-of-range values are clamped rather than rejected.
+    #[serde(default)]
+    pub ef_search: Option<usize>,
+    /// Per-query override for how many candidates the vector stage's unfiltered ANN fetch
+    /// requests; see `SearchConfig::vector_top_k_override`.
+    #[serde(default)]
+    pub top_k: Option<usize>,
+    /// Number of matching results to skip before returning `max_results`, for paging beyond
+    /// the first page.
+    #[serde(default)]
+    pub offset: usize,
+    /// Query syntax to parse `query` as; see `crate::search::SearchSyntax`. Defaults to `Plain`.
+    #[serde(default)]
+    pub syntax: SearchSyntax,
+    /// Per-query override for `SearchConfig::auto_correct`. `None` uses the configured default.
+    #[serde(default)]
+    pub auto_correct: Option<bool>,
+    /// Result ordering; see `crate::search::SortOrder`. Defaults to `Relevance`.
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Named weighting profile to rank this query with; see `crate::search::SearchQuery::profile`.
+    #[serde(default)]
+    pub profile: Option<String>,
 }
 
 /// Search response payload
@@ -43,8 +155,44 @@ pub struct SearchRequest {
 pub struct SearchResponse {
     pub results: Vec<SearchResult>,
     pub total_results: usize,
+    /// Total matches after filtering, before truncation to the requested page size
+    pub total_candidates: usize,
     pub query_time_ms: u64,
     pub pagination: PaginationInfo,
+    pub topic_facets: Vec<TopicFacet>,
+    /// Machine-readable degradation notices, e.g. `SEMANTIC_DEGRADED` while the vector index
+    /// rebuilds from a corrupt snapshot, or `INDEX_WARMING` while the trie index does
+    pub warnings: Vec<String>,
+    /// Up to 3 "did you mean" rewrites of the query, present only when `results` is empty; see
+    /// `crate::search::SearchOutcome::suggestions`.
+    pub suggestions: Vec<String>,
+    /// Set when `SearchConfig::auto_correct` retried this query with the top suggestion; see
+    /// `crate::search::SearchOutcome::applied_correction`.
+    pub applied_correction: Option<String>,
+    /// Court/decade/jurisdiction/topic facet counts for rendering filter sidebars; see
+    /// `crate::search::SearchFacets`.
+    pub facets: SearchFacets,
+    /// Synonym phrases actually searched as OR-alternatives for this query; see
+    /// `crate::search::SearchOutcome::applied_synonym_expansions`.
+    pub applied_synonym_expansions: Vec<String>,
+}
+
+/// Request body for `POST /search/batch`; each element uses the same shape as `POST /search`'s
+/// body, executed as an independent query. Rejected up front if larger than
+/// `SearchEngineConfig::max_batch_queries`.
+#[derive(Debug, Deserialize)]
+pub struct BatchSearchRequest {
+    pub queries: Vec<SearchRequest>,
+}
+
+/// One query's outcome within a `POST /search/batch` response, in the same order as the
+/// request's `queries`. `error` is set instead of `results` when that particular query failed,
+/// so one bad query in the batch doesn't fail the others; see
+/// `crate::search::SearchEngine::search_batch`.
+#[derive(Debug, Serialize)]
+pub struct BatchSearchResult {
+    pub results: Option<Vec<SearchResult>>,
+    pub error: Option<String>,
 }
 
 /// Pagination information
@@ -73,6 +221,11 @@ pub struct HealthComponents {
     pub storage: String,
     pub trie_index: String,
     pub vector_index: String,
+    /// The embedding model's [`crate::vector::ModelState`], as a lowercase string
+    /// ("not_loaded"/"loading"/"ready"/"failed") — most relevant when
+    /// `EmbeddingModelConfig::lazy_load_model` is set, where a fresh server reports "not_loaded"
+    /// until the first semantic query or `--warm-up` completes.
+    pub embedding_model_state: String,
 }
 
 impl ApiServer {
@@ -83,68 +236,210 @@ impl ApiServer {
 
     /// Run the API server
     pub async fn run(self) -> Result<()> {
-        let bind_addr = format!("{}:{}", self.app_state.config.server.host, self.app_state.config.server.port);
-        
-        tracing::info!("Starting API server on {}", bind_addr);
+        let server_config = self.app_state.config.server.clone();
+        let bind_addr = format!("{}:{}", server_config.host, server_config.port);
+        let workers = self.app_state.config.performance.worker_threads.max(1);
+        let app_state = Arc::new(self.app_state);
+
+        tracing::info!("Starting API server on {} with {} workers", bind_addr, workers);
 
-        HttpServer::new(move || {
+        let server = HttpServer::new(move || {
             App::new()
-                .app_data(web::Data::new(self.app_state.clone()))
+                .app_data(web::Data::from(app_state.clone()))
+                .app_data(web::JsonConfig::default().error_handler(json_error_handler))
                 .route("/search", web::post().to(search_handler))
+                .route("/search", web::get().to(search_get_handler))
+                .route("/search/batch", web::post().to(search_batch_handler))
+                .route("/search/stream", web::post().to(search_stream_handler))
+                .route("/suggest", web::get().to(suggest_handler))
                 .route("/health", web::get().to(health_handler))
                 .route("/stats", web::get().to(stats_handler))
+                .route("/cases/{id}", web::get().to(case_handler))
+                .route("/cases/{id}/similar", web::get().to(similar_cases_handler))
+                .route("/cases/{id}/citations", web::get().to(case_citations_handler))
+                .route("/admin/sources", web::get().to(admin_sources_handler))
+                .route("/admin/sources/{source}/reset", web::post().to(admin_reset_source_handler))
+                .route("/admin/migrations", web::get().to(admin_migrations_handler))
+                .route("/admin/index-info", web::get().to(admin_index_info_handler))
+                .route("/admin/maintenance/rebuild-indexes", web::post().to(admin_rebuild_indexes_handler))
+                .route("/admin/maintenance/prune-content-trie", web::post().to(admin_prune_content_trie_handler))
+                .route("/admin/cache/invalidate", web::post().to(admin_invalidate_cache_handler))
                 .route("/", web::get().to(index_handler))
         })
-        .bind(&bind_addr)
+        .workers(workers)
+        .keep_alive(KeepAlive::Timeout(Duration::from_secs(server_config.request_timeout_seconds)))
+        .client_request_timeout(Duration::from_secs(server_config.request_timeout_seconds));
+
+        let server = if let Some(tls) = &server_config.tls {
+            let tls_config = load_rustls_config(tls)?;
+            server.bind_rustls(&bind_addr, tls_config)
+        } else {
+            server.bind(&bind_addr)
+        }
         .map_err(|e| SearchError::Internal {
             message: format!("Failed to bind server to {}: {}", bind_addr, e),
-        })?
-        .run()
-        .await
-        .map_err(|e| SearchError::Internal {
-            message: format!("Server error: {}", e),
         })?;
 
+        server
+            .run()
+            .await
+            .map_err(|e| SearchError::Internal {
+                message: format!("Server error: {}", e),
+            })?;
+
         Ok(())
     }
 }
 
-/// Search endpoint handler
-async fn search_handler(
-    app_state: web::Data<crate::AppState>,
-    request: web::Json<SearchRequest>,
-) -> ActixResult<HttpResponse> {
+/// Build a rustls server config from a `[server.tls]` configuration
+fn load_rustls_config(tls: &crate::config::TlsConfig) -> Result<rustls::ServerConfig> {
+    let cert_file = std::fs::File::open(&tls.cert_path).map_err(|e| SearchError::Config {
+        message: format!("Failed to read TLS certificate {:?}: {}", tls.cert_path, e),
+    })?;
+    let key_file = std::fs::File::open(&tls.key_path).map_err(|e| SearchError::Config {
+        message: format!("Failed to read TLS private key {:?}: {}", tls.key_path, e),
+    })?;
+
+    let cert_chain = rustls_pemfile::certs(&mut BufReader::new(cert_file))
+        .map_err(|e| SearchError::Config {
+            message: format!("Failed to parse TLS certificate {:?}: {}", tls.cert_path, e),
+        })?
+        .into_iter()
+        .map(rustls::Certificate)
+        .collect::<Vec<_>>();
+
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(key_file))
+        .map_err(|e| SearchError::Config {
+            message: format!("Failed to parse TLS private key {:?}: {}", tls.key_path, e),
+        })?;
+
+    if cert_chain.is_empty() || keys.is_empty() {
+        return Err(SearchError::Config {
+            message: format!(
+                "TLS certificate {:?} or key {:?} contained no usable PEM entries",
+                tls.cert_path, tls.key_path
+            ),
+        });
+    }
+
+    rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(cert_chain, rustls::PrivateKey(keys.remove(0)))
+        .map_err(|e| SearchError::Config {
+            message: format!("Invalid TLS certificate/key pair: {}", e),
+        })
+}
+
+/// Turn a JSON body deserialization failure into a structured 400 response instead of
+/// actix's default plaintext error, so field-level validation messages (e.g. from
+/// [`DateRangeFilter`]) reach the client intact.
+fn json_error_handler(err: actix_web::error::JsonPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    let message = err.to_string();
+    actix_web::error::InternalError::from_response(
+        err,
+        HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Invalid request body",
+            "message": message,
+        })),
+    )
+    .into()
+}
+
+/// Run `search_query` against the search engine and assemble the JSON body shared by the
+/// POST (complex bodies) and GET (cacheable, simple filters) `/search` variants.
+async fn build_search_response(
+    app_state: &crate::AppState,
+    search_query: SearchQuery,
+) -> Result<SearchResponse> {
+    let offset = search_query.offset;
+    let page_size = search_query.max_results.unwrap_or(search_query.config.max_results).max(1);
+
     let start_time = std::time::Instant::now();
+    let outcome = app_state.search_engine.search_with_params(search_query).await?;
+    let query_time_ms = start_time.elapsed().as_millis() as u64;
+    let total_results = outcome.total_candidates;
 
-    // Convert request to search query
-    let search_query = SearchQuery {
+    let index_health = app_state.search_engine.index_health().await;
+    let mut warnings = Vec::new();
+    if matches!(index_health.vector, IndexComponentStatus::Degraded { .. }) {
+        warnings.push("SEMANTIC_DEGRADED".to_string());
+    }
+    if matches!(index_health.trie, IndexComponentStatus::Degraded { .. }) {
+        warnings.push("INDEX_WARMING".to_string());
+    }
+    warnings.extend(outcome.warnings.clone());
+
+    // `page`/`total_pages` are 1-indexed for the client's benefit even though `offset` itself
+    // is 0-indexed; an `offset` that doesn't fall on a `page_size` boundary rounds down to
+    // whichever page contains it.
+    let page = offset / page_size + 1;
+    let total_pages = total_results.div_ceil(page_size).max(1);
+
+    Ok(SearchResponse {
+        results: outcome.results,
+        total_results,
+        total_candidates: outcome.total_candidates,
+        query_time_ms,
+        pagination: PaginationInfo {
+            page,
+            per_page: page_size,
+            total_pages,
+            has_next: offset + page_size < total_results,
+            has_prev: offset > 0,
+        },
+        topic_facets: outcome.topic_facets,
+        warnings,
+        suggestions: outcome.suggestions,
+        applied_correction: outcome.applied_correction,
+        facets: outcome.facets,
+        applied_synonym_expansions: outcome.applied_synonym_expansions,
+    })
+}
+
+/// Build a `SearchQuery` from a `POST /search`-shaped request body, shared by the single-query
+/// and batch handlers.
+fn search_query_from_request(app_state: &crate::AppState, request: &SearchRequest) -> SearchQuery {
+    let mut config = crate::SearchConfig::from_config(&app_state.config.search, &app_state.config.vector);
+    config.ef_search_override = request.ef_search;
+    config.vector_top_k_override = request.top_k;
+    if let Some(auto_correct) = request.auto_correct {
+        config.auto_correct = auto_correct;
+    }
+
+    SearchQuery {
         query: request.query.clone(),
         max_results: request.max_results,
+        offset: request.offset,
+        syntax: request.syntax,
+        sort: request.sort,
         court_filter: request.court_filter.clone(),
-        date_range: request.date_range,
-        config: crate::SearchConfig::default(),
-    };
+        judge_filter: request.judge_filter.clone(),
+        date_range: request.date_range.map(|r| (r.start, r.end)),
+        topic_filter: request.topic_filter.clone(),
+        profile: request.profile.clone(),
+        config,
+    }
+}
 
-    // Execute search
-    match app_state.search_engine.search_with_params(search_query).await {
-        Ok(results) => {
-            let query_time_ms = start_time.elapsed().as_millis() as u64;
-            let total_results = results.len();
-
-            let response = SearchResponse {
-                results,
-                total_results,
-                query_time_ms,
-                pagination: PaginationInfo {
-                    page: 1,
-                    per_page: total_results,
-                    total_pages: 1,
-                    has_next: false,
-                    has_prev: false,
-                },
-            };
+/// Search endpoint handler
+async fn search_handler(
+    app_state: web::Data<crate::AppState>,
+    request: web::Json<SearchRequest>,
+) -> ActixResult<HttpResponse> {
+    let search_query = search_query_from_request(&app_state, &request);
 
-            Ok(HttpResponse::Ok().json(response))
+    match build_search_response(&app_state, search_query).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(SearchError::SearchCapacityExceeded { current_load, details }) => {
+            tracing::warn!("Search shed at {}% load: {}", current_load, details);
+            Ok(HttpResponse::ServiceUnavailable()
+                .insert_header(("Retry-After", "1"))
+                .json(serde_json::json!({
+                    "error": "Search capacity exceeded",
+                    "message": details,
+                    "current_load_percent": current_load,
+                })))
         }
         Err(e) => {
             tracing::error!("Search error: {}", e);
@@ -156,6 +451,268 @@ async fn search_handler(
     }
 }
 
+/// Batch search endpoint handler: runs every query in `request.queries` against the search
+/// engine, sharing one embedding-model batch call across their query embeddings (see
+/// `crate::search::SearchEngine::search_batch`), and returns one result per input query in the
+/// same order. Rejected up front, before any query runs, if the batch is larger than
+/// `SearchEngineConfig::max_batch_queries`.
+async fn search_batch_handler(
+    app_state: web::Data<crate::AppState>,
+    request: web::Json<BatchSearchRequest>,
+) -> ActixResult<HttpResponse> {
+    let max_batch_queries = app_state.config.search.max_batch_queries;
+    if request.queries.len() > max_batch_queries {
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Batch too large",
+            "message": format!(
+                "Batch of {} queries exceeds the maximum of {} per request",
+                request.queries.len(),
+                max_batch_queries
+            ),
+        })));
+    }
+
+    let search_queries =
+        request.queries.iter().map(|r| search_query_from_request(&app_state, r)).collect();
+    let outcomes = app_state.search_engine.search_batch(search_queries).await;
+    let results: Vec<BatchSearchResult> = outcomes
+        .into_iter()
+        .map(|outcome| match outcome {
+            Ok(results) => BatchSearchResult { results: Some(results), error: None },
+            Err(e) => BatchSearchResult { results: None, error: Some(e.to_string()) },
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "results": results })))
+}
+
+/// Bound on how many scored-but-unsent results `search_stream_handler` will buffer in its
+/// channel before the consuming client's back-pressure stalls `SearchEngine::search_streamed`'s
+/// send loop, keeping a slow client from letting a whole result page pile up in memory.
+const SEARCH_STREAM_CHANNEL_CAPACITY: usize = 16;
+
+/// One line of a `POST /search/stream` response body: newline-delimited JSON, one object per
+/// line, internally tagged on `type` so a line-oriented consumer can dispatch each line without
+/// buffering the rest of the body. Always exactly one `metadata` line first and exactly one
+/// `summary` or `error` line last, with zero or more `result` lines in between.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+enum SearchStreamLine {
+    #[serde(rename = "metadata")]
+    Metadata {
+        query_id: uuid::Uuid,
+        /// Whether the trie or vector index was already known to be degraded before this query
+        /// ran; see `IndexComponentStatus::Degraded`. Reported up front, unlike
+        /// `SearchResponse::warnings`, since a streaming client may want to bail out before
+        /// waiting on any results at all.
+        degraded: bool,
+    },
+    #[serde(rename = "result")]
+    Result(SearchResult),
+    #[serde(rename = "summary")]
+    Summary { total_candidates: usize, degraded: bool, query_time_ms: u64 },
+    #[serde(rename = "error")]
+    Error { message: String },
+}
+
+/// Serialize one `SearchStreamLine` to a newline-terminated JSON byte string, the unit `.streaming()`
+/// writes to the response body.
+fn ndjson_line(line: &SearchStreamLine) -> ActixResult<web::Bytes> {
+    let mut json = serde_json::to_vec(line).map_err(actix_web::error::ErrorInternalServerError)?;
+    json.push(b'\n');
+    Ok(web::Bytes::from(json))
+}
+
+/// Stage of `search_stream_handler`'s response body, threaded through `futures::stream::unfold`
+/// so each `.streaming()` poll only does the work needed to produce its one line.
+enum SearchStreamStage {
+    Metadata,
+    Streaming,
+    Done,
+}
+
+struct SearchStreamState {
+    stage: SearchStreamStage,
+    query_id: uuid::Uuid,
+    degraded: bool,
+    results_rx: mpsc::Receiver<SearchResult>,
+    handle: tokio::task::JoinHandle<Result<crate::search::StreamedSearchSummary>>,
+}
+
+/// Streaming counterpart to `POST /search`: writes results as newline-delimited JSON as
+/// `SearchEngine::search_streamed` sends them through a bounded channel from its own task,
+/// rather than buffering the whole `SearchResponse` body before writing anything. See
+/// `SearchStreamLine` for the line shapes and their order.
+async fn search_stream_handler(
+    app_state: web::Data<crate::AppState>,
+    request: web::Json<SearchRequest>,
+) -> ActixResult<HttpResponse> {
+    let search_query = search_query_from_request(&app_state, &request);
+    let query_id = uuid::Uuid::new_v4();
+
+    let index_health = app_state.search_engine.index_health().await;
+    let degraded = matches!(index_health.vector, IndexComponentStatus::Degraded { .. })
+        || matches!(index_health.trie, IndexComponentStatus::Degraded { .. });
+
+    let (results_tx, results_rx) = mpsc::channel(SEARCH_STREAM_CHANNEL_CAPACITY);
+    let engine = app_state.search_engine.clone();
+    let handle = tokio::task::spawn(async move { engine.search_streamed(search_query, results_tx).await });
+
+    let state = SearchStreamState { stage: SearchStreamStage::Metadata, query_id, degraded, results_rx, handle };
+
+    let body = futures::stream::unfold(state, |mut state| async move {
+        match state.stage {
+            SearchStreamStage::Metadata => {
+                state.stage = SearchStreamStage::Streaming;
+                let line = ndjson_line(&SearchStreamLine::Metadata { query_id: state.query_id, degraded: state.degraded });
+                Some((line, state))
+            }
+            SearchStreamStage::Streaming => match state.results_rx.recv().await {
+                Some(result) => {
+                    let line = ndjson_line(&SearchStreamLine::Result(result));
+                    Some((line, state))
+                }
+                None => {
+                    state.stage = SearchStreamStage::Done;
+                    let line = match (&mut state.handle).await {
+                        Ok(Ok(summary)) => ndjson_line(&SearchStreamLine::Summary {
+                            total_candidates: summary.total_candidates,
+                            degraded: summary.degraded,
+                            query_time_ms: summary.query_time_ms,
+                        }),
+                        Ok(Err(e)) => ndjson_line(&SearchStreamLine::Error { message: e.to_string() }),
+                        Err(join_error) => ndjson_line(&SearchStreamLine::Error { message: join_error.to_string() }),
+                    };
+                    Some((line, state))
+                }
+            },
+            SearchStreamStage::Done => None,
+        }
+    });
+
+    Ok(HttpResponse::Ok().content_type("application/x-ndjson").streaming(body))
+}
+
+/// Simple filters accepted by `GET /search`, the idempotent counterpart to the `POST /search`
+/// body for queries a CDN can cache. Complex bodies (structured `date_range` shapes, full
+/// `TopicFilter`) stay on `POST /search`; this variant only takes flat query parameters.
+#[derive(Debug, Deserialize)]
+struct SearchGetQuery {
+    q: String,
+    max_results: Option<usize>,
+    /// Comma-separated court names, e.g. `court=Supreme%20Court,9th%20Circuit`
+    court: Option<String>,
+    /// Comma-separated judge names; see `crate::search::SearchQuery::judge_filter`.
+    judge: Option<String>,
+    date_from: Option<NaiveDate>,
+    date_to: Option<NaiveDate>,
+    /// Taxonomy node id; always matches descendants (there's no query-string-friendly way to
+    /// toggle `include_descendants`, so `POST /search` is the escape hatch for that)
+    topic: Option<String>,
+    #[serde(default)]
+    offset: usize,
+    /// Query syntax to parse `q` as; see `crate::search::SearchSyntax`. Defaults to `Plain`.
+    #[serde(default)]
+    syntax: SearchSyntax,
+    /// Result ordering; see `crate::search::SortOrder`. Defaults to `Relevance`.
+    #[serde(default)]
+    sort: SortOrder,
+    /// Named weighting profile to rank this query with; see `crate::search::SearchQuery::profile`.
+    profile: Option<String>,
+}
+
+/// Sort a raw query string's `key=value` pairs so equivalent requests with parameters in a
+/// different order produce the same canonical string, letting a CDN normalize its cache key
+/// off `Content-Location` instead of the client-supplied parameter order.
+fn canonicalize_query_string(raw: &str) -> String {
+    let mut pairs: Vec<&str> = raw.split('&').filter(|p| !p.is_empty()).collect();
+    pairs.sort_unstable();
+    pairs.join("&")
+}
+
+/// Idempotent `GET` variant of `/search` for simple queries, cacheable by intermediaries: a
+/// successful, non-degraded response gets `Cache-Control: public, max-age=<query_cache_ttl_seconds>`
+/// and an `X-Index-Generation` header so a client or CDN can detect the index has moved on. A
+/// response carrying any degradation warning (e.g. `SEMANTIC_DEGRADED`) is marked `no-store`
+/// instead, since it reflects transient server state that a CDN must not serve stale.
+async fn search_get_handler(
+    app_state: web::Data<crate::AppState>,
+    request: HttpRequest,
+    query: web::Query<SearchGetQuery>,
+) -> ActixResult<HttpResponse> {
+    let params = query.into_inner();
+    let court_filter = params.court.as_ref().map(|courts| {
+        courts
+            .split(',')
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<_>>()
+    });
+    let judge_filter = params.judge.as_ref().map(|judges| {
+        judges
+            .split(',')
+            .map(|j| j.trim().to_string())
+            .filter(|j| !j.is_empty())
+            .collect::<Vec<_>>()
+    });
+    let date_range = match (params.date_from, params.date_to) {
+        (Some(from), Some(to)) => Some((from, to)),
+        _ => None,
+    };
+
+    let search_query = SearchQuery {
+        query: params.q,
+        max_results: params.max_results,
+        offset: params.offset,
+        syntax: params.syntax,
+        sort: params.sort,
+        court_filter,
+        judge_filter,
+        date_range,
+        topic_filter: params.topic.map(|node_id| TopicFilter { node_id, include_descendants: true }),
+        profile: params.profile,
+        config: crate::SearchConfig::from_config(&app_state.config.search, &app_state.config.vector),
+    };
+
+    let canonical_query = canonicalize_query_string(request.query_string());
+    let index_generation = app_state.search_engine.index_generation();
+
+    match build_search_response(&app_state, search_query).await {
+        Ok(response) => {
+            let cache_control = if response.warnings.is_empty() {
+                format!("public, max-age={}", app_state.config.search.query_cache_ttl_seconds)
+            } else {
+                "no-store".to_string()
+            };
+            Ok(HttpResponse::Ok()
+                .insert_header(("Cache-Control", cache_control))
+                .insert_header(("X-Index-Generation", index_generation.to_string()))
+                .insert_header(("Content-Location", format!("/search?{}", canonical_query)))
+                .json(response))
+        }
+        Err(SearchError::SearchCapacityExceeded { current_load, details }) => {
+            tracing::warn!("Search shed at {}% load: {}", current_load, details);
+            Ok(HttpResponse::ServiceUnavailable()
+                .insert_header(("Retry-After", "1"))
+                .insert_header(("Cache-Control", "no-store"))
+                .json(serde_json::json!({
+                    "error": "Search capacity exceeded",
+                    "message": details,
+                    "current_load_percent": current_load,
+                })))
+        }
+        Err(e) => {
+            tracing::error!("Search error: {}", e);
+            Ok(HttpResponse::InternalServerError()
+                .insert_header(("Cache-Control", "no-store"))
+                .json(serde_json::json!({
+                    "error": "Search failed",
+                    "message": e.to_string(),
+                })))
+        }
+    }
+}
+
 /// Health check endpoint handler
 async fn health_handler(
     app_state: web::Data<crate::AppState>,
@@ -171,30 +728,75 @@ async fn health_handler(
         Err(_) => "unhealthy",
     };
 
+    let index_health = app_state.search_engine.index_health().await;
+    let trie_status = match &index_health.trie {
+        IndexComponentStatus::Healthy => "healthy",
+        IndexComponentStatus::Degraded { .. } => "degraded",
+    };
+    let vector_status = match &index_health.vector {
+        IndexComponentStatus::Healthy => "healthy",
+        IndexComponentStatus::Degraded { .. } => "degraded",
+    };
+
+    let embedding_model_state = app_state
+        .search_engine
+        .get_stats()
+        .await
+        .vector_index_stats
+        .model_state
+        .to_string();
+
+    let overall_status = if search_status != "healthy" || storage_status != "healthy" {
+        "unhealthy"
+    } else if trie_status == "degraded" || vector_status == "degraded" {
+        // A degraded index still serves the queries its healthy sibling index can handle
+        // (e.g. lexical search while the vector index rebuilds), so this isn't "unhealthy".
+        "degraded"
+    } else {
+        "healthy"
+    };
+
     let response = HealthResponse {
-        status: if search_status == "healthy" && storage_status == "healthy" {
-            "healthy".to_string()
-        } else {
-            "unhealthy".to_string()
-        },
+        status: overall_status.to_string(),
         version: "1.0.0".to_string(),
         uptime_seconds: 0, // TODO: Track actual uptime
         components: HealthComponents {
             search_engine: search_status.to_string(),
             storage: storage_status.to_string(),
-            trie_index: "healthy".to_string(), // TODO: Check actual status
-            vector_index: "healthy".to_string(), // TODO: Check actual status
+            trie_index: trie_status.to_string(),
+            vector_index: vector_status.to_string(),
+            embedding_model_state,
         },
     };
 
     Ok(HttpResponse::Ok().json(response))
 }
 
+#[derive(Deserialize)]
+struct StatsQuery {
+    /// When set, also runs `VectorIndex::estimate_recall`'s self-probe and reports it under
+    /// `vector_index_stats.recall_estimate` — slower than the default (one extra search per
+    /// sampled vector), so it's opt-in.
+    probe_recall: Option<bool>,
+    /// How many indexed vectors the recall self-probe samples. Ignored unless `probe_recall=true`.
+    #[serde(default = "default_recall_sample_size")]
+    recall_sample_size: usize,
+}
+
+fn default_recall_sample_size() -> usize {
+    100
+}
+
 /// Statistics endpoint handler
 async fn stats_handler(
     app_state: web::Data<crate::AppState>,
+    query: web::Query<StatsQuery>,
 ) -> ActixResult<HttpResponse> {
-    let search_stats = app_state.search_engine.get_stats().await;
+    let search_stats = if query.probe_recall.unwrap_or(false) {
+        app_state.search_engine.get_stats_with_recall_probe(query.recall_sample_size).await
+    } else {
+        app_state.search_engine.get_stats().await
+    };
     let storage_stats = match app_state.storage.get_stats().await {
         Ok(stats) => stats,
         Err(_) => crate::storage::StorageStats {
@@ -202,6 +804,7 @@ async fn stats_handler(
             total_size_bytes: 0,
             database_size_bytes: 0,
             last_backup: None,
+            last_modified: None,
         },
     };
 
@@ -213,6 +816,399 @@ async fn stats_handler(
     Ok(HttpResponse::Ok().json(response))
 }
 
+/// Case lookup endpoint handler, returning full case metadata (including any
+/// non-blocking `validation_warnings` recorded during ingestion) for a single case ID
+async fn case_handler(
+    app_state: web::Data<crate::AppState>,
+    path: web::Path<uuid::Uuid>,
+) -> ActixResult<HttpResponse> {
+    let case_id = path.into_inner();
+
+    match app_state.storage.get_case_metadata(&case_id).await {
+        Ok(Some(metadata)) => {
+            let provenance = app_state.ingestion.case_provenance(case_id).await.unwrap_or_else(|e| {
+                tracing::warn!("Failed to load provenance for case {}: {}", case_id, e);
+                Vec::new()
+            });
+            Ok(HttpResponse::Ok().json(CaseDetailResponse { metadata, provenance }))
+        }
+        Ok(None) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "Case not found",
+            "case_id": case_id,
+        }))),
+        Err(e) => {
+            tracing::error!("Failed to fetch case {}: {}", case_id, e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to fetch case",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
+/// Response body for `GET /cases/{id}`: the case record plus its full ingest provenance
+/// history, oldest first, so a maintainer tracing a wrong-looking result can see which source
+/// and job produced it and whether it's been reprocessed since
+#[derive(Debug, Serialize)]
+struct CaseDetailResponse {
+    metadata: crate::CaseMetadata,
+    provenance: Vec<crate::ingestion::ProvenanceRecord>,
+}
+
+/// Query parameters accepted by `GET /cases/{id}/similar`
+#[derive(Debug, Deserialize)]
+struct SimilarCasesQuery {
+    max_results: Option<usize>,
+}
+
+/// "More like this" endpoint handler: cases similar to the one at `{id}`, ranked by vector
+/// similarity and supplemented by lexical overlap on its own key phrases; see
+/// [`crate::search::SearchEngine::more_like_this`]. Mirrors [`case_handler`]'s not-found/error
+/// shape, mapping [`crate::errors::SearchError::CaseNotFound`] to a 404 rather than a 500.
+async fn similar_cases_handler(
+    app_state: web::Data<crate::AppState>,
+    path: web::Path<uuid::Uuid>,
+    query: web::Query<SimilarCasesQuery>,
+) -> ActixResult<HttpResponse> {
+    let case_id = path.into_inner();
+    let max_results = query.max_results.unwrap_or(10);
+
+    match app_state.search_engine.more_like_this(case_id, max_results).await {
+        Ok(results) => Ok(HttpResponse::Ok().json(serde_json::json!({ "results": results }))),
+        Err(SearchError::CaseNotFound { .. }) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "Case not found",
+            "case_id": case_id,
+        }))),
+        Err(e) => {
+            tracing::error!("Failed to fetch similar cases for {}: {}", case_id, e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to fetch similar cases",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
+/// Response body for `GET /cases/{id}/citations`: `case_id`'s position in the citation graph
+/// built by [`crate::search::SearchEngine::rebuild_citation_graph`], in both directions.
+#[derive(Debug, Serialize)]
+struct CaseCitationsResponse {
+    case_id: uuid::Uuid,
+    /// Cases `case_id` cites, each with the confidence of its resolution, or the raw citation
+    /// text when it didn't resolve to any indexed case.
+    cites: Vec<crate::storage::CitationEdge>,
+    /// Cases that cite `case_id`, each with the confidence of that resolution.
+    cited_by: Vec<crate::storage::CitingCase>,
+}
+
+/// Citation graph lookup endpoint handler: `case_id`'s outgoing citations ("cites") and
+/// incoming citations ("cited by"); see [`crate::search::SearchEngine::get_cited_cases`] and
+/// [`crate::search::SearchEngine::get_citing_cases`]. Mirrors [`case_handler`]'s not-found/error
+/// shape, mapping [`crate::errors::SearchError::CaseNotFound`] to a 404 rather than a 500.
+async fn case_citations_handler(
+    app_state: web::Data<crate::AppState>,
+    path: web::Path<uuid::Uuid>,
+) -> ActixResult<HttpResponse> {
+    let case_id = path.into_inner();
+
+    let cites = match app_state.search_engine.get_cited_cases(case_id).await {
+        Ok(cites) => cites,
+        Err(SearchError::CaseNotFound { .. }) => {
+            return Ok(HttpResponse::NotFound().json(serde_json::json!({
+                "error": "Case not found",
+                "case_id": case_id,
+            })));
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch citations for {}: {}", case_id, e);
+            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to fetch citations",
+                "message": e.to_string(),
+            })));
+        }
+    };
+
+    let cited_by = match app_state.search_engine.get_citing_cases(case_id).await {
+        Ok(cited_by) => cited_by,
+        Err(e) => {
+            tracing::error!("Failed to fetch citing cases for {}: {}", case_id, e);
+            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to fetch citations",
+                "message": e.to_string(),
+            })));
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(CaseCitationsResponse { case_id, cites, cited_by }))
+}
+
+/// Query parameters accepted by `GET /suggest`
+#[derive(Debug, Deserialize)]
+struct SuggestQuery {
+    q: String,
+    limit: Option<usize>,
+}
+
+/// Response body for `GET /suggest`
+#[derive(Debug, Serialize)]
+struct SuggestResponse {
+    suggestions: Vec<Suggestion>,
+}
+
+/// Autocomplete endpoint handler: completions for `q` across the case-name, citation, and
+/// content tries, tagged by origin with each one's matching case count; see
+/// `crate::search::SearchEngine::suggest`. `q` shorter than `SearchConfig::min_query_length`
+/// (or missing) still returns `200 OK` with an empty list rather than an error, so a UI can
+/// wire this to every keystroke without special-casing short input.
+async fn suggest_handler(app_state: web::Data<crate::AppState>, query: web::Query<SuggestQuery>) -> ActixResult<HttpResponse> {
+    let limit = query.limit.unwrap_or(10);
+    match app_state.search_engine.suggest(&query.q, limit).await {
+        Ok(suggestions) => Ok(HttpResponse::Ok().json(SuggestResponse { suggestions })),
+        Err(e) => {
+            tracing::error!("Failed to compute suggestions for {:?}: {}", query.q, e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to compute suggestions",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
+/// Query parameters accepted by `GET /admin/index-info`
+#[derive(Debug, Deserialize)]
+struct IndexInfoQuery {
+    /// When set, includes that case's ingest provenance history alongside index health
+    case_id: Option<uuid::Uuid>,
+}
+
+/// Admin endpoint reporting trie/vector index health, and optionally one case's ingest
+/// provenance history when `?case_id=` is given — the two things a maintainer reaches for
+/// together when a query result looks wrong: is the index itself healthy, and where did this
+/// particular case's data come from
+async fn admin_index_info_handler(
+    app_state: web::Data<crate::AppState>,
+    query: web::Query<IndexInfoQuery>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    let index_health = app_state.search_engine.index_health().await;
+
+    let case_provenance = match query.case_id {
+        Some(case_id) => match app_state.ingestion.case_provenance(case_id).await {
+            Ok(history) => Some(history),
+            Err(e) => {
+                tracing::error!("Failed to load provenance for case {}: {}", case_id, e);
+                return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Failed to load case provenance",
+                    "message": e.to_string(),
+                })));
+            }
+        },
+        None => None,
+    };
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "index_health": index_health,
+        "case_provenance": case_provenance,
+    })))
+}
+
+/// Admin endpoint rebuilding all secondary indexes (citation, court, decision date, judge,
+/// docket number) from the metadata tree, for when they've drifted after a partial ingestion
+/// failure or a version upgrade. See [`crate::storage::StorageManager::rebuild_secondary_indexes`].
+async fn admin_rebuild_indexes_handler(
+    app_state: web::Data<crate::AppState>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    match app_state.storage.rebuild_secondary_indexes().await {
+        Ok(stats) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "status": "rebuilt",
+            "stats": stats,
+        }))),
+        Err(e) => {
+            tracing::error!("Failed to rebuild secondary indexes: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to rebuild secondary indexes",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
+/// Query parameters accepted by `POST /admin/maintenance/prune-content-trie`
+#[derive(Debug, Deserialize)]
+struct PruneContentTrieQuery {
+    /// Content terms with a frequency below this are removed entirely
+    min_frequency: u32,
+    /// Content terms above `min_frequency` still have their posting list capped at this many
+    /// `DocRef`s
+    max_postings: usize,
+}
+
+/// Admin endpoint pruning rarely-occurring content terms and capping oversized posting lists in
+/// the content trie, for an operator to run after bulk ingestion once single-occurrence n-grams
+/// have accumulated. See [`crate::trie::TrieIndex::prune`].
+async fn admin_prune_content_trie_handler(
+    app_state: web::Data<crate::AppState>,
+    query: web::Query<PruneContentTrieQuery>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    let report = app_state
+        .search_engine
+        .prune_content_trie(query.min_frequency, query.max_postings)
+        .await;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "status": "pruned",
+        "report": report,
+    })))
+}
+
+/// Verify the `X-API-Key` header against the configured admin API key, if one is set
+fn check_admin_auth(app_state: &crate::AppState, request: &HttpRequest) -> Result<()> {
+    let Some(expected) = &app_state.config.server.api_key else {
+        return Ok(());
+    };
+
+    let provided = request
+        .headers()
+        .get("X-API-Key")
+        .and_then(|v| v.to_str().ok());
+
+    match provided {
+        Some(key) if key == expected => Ok(()),
+        _ => Err(SearchError::Config {
+            message: "Missing or invalid X-API-Key header".to_string(),
+        }),
+    }
+}
+
+/// Admin endpoint listing per-source ingestion statistics
+async fn admin_sources_handler(
+    app_state: web::Data<crate::AppState>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    match app_state.ingestion.get_all_source_stats().await {
+        Ok(stats) => Ok(HttpResponse::Ok().json(stats)),
+        Err(e) => {
+            tracing::error!("Failed to collect source stats: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to collect source stats",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
+/// Admin endpoint resetting the persisted statistics for a single ingestion source
+async fn admin_reset_source_handler(
+    app_state: web::Data<crate::AppState>,
+    path: web::Path<String>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    let source = path.into_inner();
+    match app_state.ingestion.reset_source_stats(&source).await {
+        Ok(()) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "status": "reset",
+            "source": source,
+        }))),
+        Err(e) => {
+            tracing::error!("Failed to reset stats for source {}: {}", source, e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to reset source stats",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
+/// Admin endpoint that invalidates every cached query result, via
+/// `SearchEngine::invalidate_cache`; for a caller (an ingestion job, a manual operator) that has
+/// just written new cases into the trie or vector index. `prune-content-trie` invalidates on its
+/// own only when it dropped at least one document ref; `rebuild-indexes` does not invalidate.
+async fn admin_invalidate_cache_handler(
+    app_state: web::Data<crate::AppState>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    app_state.search_engine.invalidate_cache();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "status": "invalidated",
+        "index_generation": app_state.search_engine.index_generation(),
+    })))
+}
+
+/// Admin endpoint reporting progress of an in-flight embedding model migration, if any
+async fn admin_migrations_handler(
+    app_state: web::Data<crate::AppState>,
+    request: HttpRequest,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = check_admin_auth(&app_state, &request) {
+        return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
+            "error": "Unauthorized",
+            "message": e.to_string(),
+        })));
+    }
+
+    let Some(migration) = &app_state.migration else {
+        return Ok(HttpResponse::Ok().json(serde_json::json!({
+            "status": "no_migration_in_progress",
+        })));
+    };
+
+    match migration.status_report().await {
+        Ok(report) => Ok(HttpResponse::Ok().json(report)),
+        Err(e) => {
+            tracing::error!("Failed to collect migration status: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to collect migration status",
+                "message": e.to_string(),
+            })))
+        }
+    }
+}
+
 /// Index page handler
 async fn index_handler() -> ActixResult<HttpResponse> {
     let html = r#"
@@ -259,4 +1255,231 @@ async fn index_handler() -> ActixResult<HttpResponse> {
     "#;
 
     Ok(HttpResponse::Ok().content_type("text/html").body(html))
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_tuple_shape_is_accepted() {
+        let filter: DateRangeFilter =
+            serde_json::from_value(serde_json::json!(["1954-05-17", "1966-06-13"])).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1954, 5, 17), end: date(1966, 6, 13) });
+    }
+
+    #[test]
+    fn test_object_shape_is_accepted() {
+        let filter: DateRangeFilter =
+            serde_json::from_value(serde_json::json!({"from": "1954-05-17", "to": "1966-06-13"})).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1954, 5, 17), end: date(1966, 6, 13) });
+    }
+
+    #[test]
+    fn test_numeric_year_shape_is_accepted() {
+        let filter: DateRangeFilter = serde_json::from_value(serde_json::json!(1954)).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1954, 1, 1), end: date(1954, 12, 31) });
+    }
+
+    #[test]
+    fn test_string_year_shape_is_accepted() {
+        let filter: DateRangeFilter = serde_json::from_value(serde_json::json!("1954")).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1954, 1, 1), end: date(1954, 12, 31) });
+    }
+
+    #[test]
+    fn test_decade_shape_is_accepted() {
+        let filter: DateRangeFilter = serde_json::from_value(serde_json::json!("1950s")).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1950, 1, 1), end: date(1959, 12, 31) });
+    }
+
+    #[test]
+    fn test_year_span_shape_is_accepted() {
+        let filter: DateRangeFilter = serde_json::from_value(serde_json::json!("1954..1966")).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1954, 1, 1), end: date(1966, 12, 31) });
+    }
+
+    #[test]
+    fn test_at_or_after_shape_is_accepted() {
+        let filter: DateRangeFilter = serde_json::from_value(serde_json::json!(">=1973")).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: date(1973, 1, 1), end: NaiveDate::MAX });
+    }
+
+    #[test]
+    fn test_at_or_before_shape_is_accepted() {
+        let filter: DateRangeFilter = serde_json::from_value(serde_json::json!("<=1973")).unwrap();
+        assert_eq!(filter, DateRangeFilter { start: NaiveDate::MIN, end: date(1973, 12, 31) });
+    }
+
+    #[test]
+    fn test_tuple_with_unparseable_date_is_rejected_with_field_value_and_formats() {
+        let err = serde_json::from_value::<DateRangeFilter>(serde_json::json!(["05/17/1954", "1966-06-13"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("date_range"), "{err}");
+        assert!(err.contains("05/17/1954"), "{err}");
+        assert!(err.contains("accepted formats"), "{err}");
+    }
+
+    #[test]
+    fn test_object_missing_to_key_is_rejected() {
+        let err = serde_json::from_value::<DateRangeFilter>(serde_json::json!({"from": "1954-05-17"}))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("date_range"), "{err}");
+        assert!(err.contains("accepted formats"), "{err}");
+    }
+
+    #[test]
+    fn test_from_after_to_is_rejected() {
+        let err = serde_json::from_value::<DateRangeFilter>(serde_json::json!(["1966-06-13", "1954-05-17"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("is after"), "{err}");
+    }
+
+    #[test]
+    fn test_invalid_year_is_rejected() {
+        let err = serde_json::from_value::<DateRangeFilter>(serde_json::json!("not-a-year"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("date_range"), "{err}");
+        assert!(err.contains("accepted formats"), "{err}");
+    }
+
+    #[test]
+    fn test_unrecognized_shape_is_rejected() {
+        let err = serde_json::from_value::<DateRangeFilter>(serde_json::json!(true))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("date_range"), "{err}");
+        assert!(err.contains("accepted formats"), "{err}");
+    }
+
+    #[test]
+    fn test_canonicalize_query_string_is_order_independent() {
+        let a = canonicalize_query_string("max_results=10&q=speech&court=Supreme%20Court");
+        let b = canonicalize_query_string("court=Supreme%20Court&q=speech&max_results=10");
+        assert_eq!(a, b);
+        assert_eq!(a, "court=Supreme%20Court&max_results=10&q=speech");
+    }
+
+    #[test]
+    fn test_canonicalize_query_string_handles_empty_and_single_param() {
+        assert_eq!(canonicalize_query_string(""), "");
+        assert_eq!(canonicalize_query_string("q=speech"), "q=speech");
+    }
+
+    // `suggest_handler` itself needs a real `AppState`, and building one requires
+    // `ingestion::IngestionManager::new`, which in turn requires
+    // `ingestion::sources::courtlistener` — a module this checkout doesn't have on disk. So
+    // rather than a full `actix_web::test::init_service` round trip through the handler, these
+    // exercise `SuggestQuery` the same way actix itself does: extracting it from a real HTTP
+    // request via `actix_web::test`, which is the part of `suggest_handler` most likely to
+    // silently drift (e.g. an optional `limit` becoming required).
+
+    #[actix_web::test]
+    async fn test_suggest_query_extracts_required_q_and_optional_limit() {
+        let req = actix_web::test::TestRequest::with_uri("/suggest?q=miranda&limit=5").to_http_request();
+        let query = web::Query::<SuggestQuery>::from_query(req.query_string()).unwrap();
+
+        assert_eq!(query.q, "miranda");
+        assert_eq!(query.limit, Some(5));
+    }
+
+    #[actix_web::test]
+    async fn test_suggest_query_limit_defaults_to_none_when_omitted() {
+        let req = actix_web::test::TestRequest::with_uri("/suggest?q=miranda").to_http_request();
+        let query = web::Query::<SuggestQuery>::from_query(req.query_string()).unwrap();
+
+        assert_eq!(query.q, "miranda");
+        assert_eq!(query.limit, None);
+    }
+
+    #[actix_web::test]
+    async fn test_suggest_query_missing_q_is_rejected() {
+        let req = actix_web::test::TestRequest::with_uri("/suggest").to_http_request();
+
+        assert!(web::Query::<SuggestQuery>::from_query(req.query_string()).is_err());
+    }
+
+    // `search_stream_handler` itself needs a real `AppState` for the same reason
+    // `suggest_handler` does (see above); these exercise `ndjson_line`/`SearchStreamLine`
+    // directly instead — the part of the handler responsible for the NDJSON framing a
+    // line-oriented consumer actually depends on.
+
+    #[test]
+    fn test_ndjson_line_is_one_newline_terminated_json_object_per_line() {
+        let bytes = ndjson_line(&SearchStreamLine::Metadata { query_id: uuid::Uuid::nil(), degraded: false }).unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+
+        assert!(text.ends_with('\n'));
+        assert_eq!(text.matches('\n').count(), 1);
+        let value: serde_json::Value = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(value["type"], "metadata");
+        assert_eq!(value["degraded"], false);
+    }
+
+    #[test]
+    fn test_ndjson_line_result_variant_flattens_the_search_result_fields() {
+        let result = SearchResult {
+            case_metadata: sample_case_metadata(),
+            score: 0.5,
+            lexical_score: Some(0.5),
+            semantic_score: None,
+            match_type: crate::search::MatchType::Exact,
+            provenance: crate::search::MatchProvenance::TrieCaseName,
+            snippet: "an appeal".to_string(),
+            passages: vec![],
+            highlights: vec![],
+            duplicates: vec![],
+        };
+        let bytes = ndjson_line(&SearchStreamLine::Result(result)).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(String::from_utf8(bytes.to_vec()).unwrap().trim_end()).unwrap();
+
+        assert_eq!(value["type"], "result");
+        assert_eq!(value["match_type"], "Exact");
+        assert_eq!(value["snippet"], "an appeal");
+    }
+
+    #[test]
+    fn test_ndjson_line_summary_and_error_variants_tag_correctly() {
+        let summary = ndjson_line(&SearchStreamLine::Summary { total_candidates: 3, degraded: true, query_time_ms: 42 }).unwrap();
+        let summary: serde_json::Value =
+            serde_json::from_str(String::from_utf8(summary.to_vec()).unwrap().trim_end()).unwrap();
+        assert_eq!(summary["type"], "summary");
+        assert_eq!(summary["total_candidates"], 3);
+
+        let error = ndjson_line(&SearchStreamLine::Error { message: "boom".to_string() }).unwrap();
+        let error: serde_json::Value = serde_json::from_str(String::from_utf8(error.to_vec()).unwrap().trim_end()).unwrap();
+        assert_eq!(error["type"], "error");
+        assert_eq!(error["message"], "boom");
+    }
+
+    fn sample_case_metadata() -> crate::CaseMetadata {
+        crate::CaseMetadata {
+            id: uuid::Uuid::nil(),
+            name: "Sample v. Case".to_string(),
+            citation: "1 U.S. 1".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+            judges: vec![],
+            topics: vec![],
+            full_text: "an appeal".to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec![],
+            docket_number: None,
+            source_url: None,
+            word_count: 2,
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,466 @@
+//! # Boolean Query Language
+//!
+//! ## Purpose
+//! Legal researchers expect Westlaw/Lexis-style Boolean queries —
+//! `"equal protection" AND segregation NOT employment` — rather than the plain bag-of-words
+//! matching [`crate::search::SearchEngine`] otherwise does. This module parses that syntax into
+//! a [`QueryNode`] AST and evaluates it against a [`crate::trie::TrieIndex`]'s auxiliary
+//! substring index (the same token -> `DocRef` posting lists
+//! [`crate::trie::TrieIndex::search`]'s substring fallback already uses), combining postings
+//! with set intersection/union/difference for `AND`/`OR`/`NOT`.
+//!
+//! ## Input/Output Specification
+//! - **Input**: A query string, e.g. `(miranda OR gideon) AND appeal NOT dissent`
+//! - **Output**: A [`QueryNode`] AST from [`parse`], or a [`BooleanQueryError`] describing why
+//!   the string couldn't be parsed as Boolean syntax — the caller (`SearchEngine`) falls back to
+//!   plain bag-of-words search on that error rather than failing the query outright
+//! - **Grammar**: `OR` binds loosest, then `AND`, then unary `NOT`; parentheses override both.
+//!   Two operands with no operator between them (`segregation NOT employment`) are treated as an
+//!   implicit `AND`, so `NOT` can trail a term without repeating the keyword.
+
+use crate::trie::TrieIndex;
+use crate::{CaseId, DocRef};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A parsed Boolean query. `Term`/`Phrase` are the leaves; a single quoted phrase like
+/// `"equal protection"` becomes one `Phrase` node rather than two `Term` nodes, since the
+/// underlying substring index only guarantees every phrase token occurs somewhere in the same
+/// case, not that they're adjacent — see [`evaluate`]'s doc comment for that caveat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryNode {
+    Term(String),
+    Phrase(Vec<String>),
+    And(Vec<QueryNode>),
+    Or(Vec<QueryNode>),
+    Not(Box<QueryNode>),
+}
+
+/// Why a query string couldn't be parsed (or accepted) as Boolean syntax
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BooleanQueryError {
+    /// The query was empty, or contained only whitespace/operators with no term or phrase
+    Empty,
+    /// An unterminated quoted phrase, e.g. `"equal protection AND segregation`
+    UnterminatedPhrase,
+    /// A `(` with no matching `)`, or vice versa
+    UnbalancedParentheses,
+    /// A binary operator (`AND`/`OR`) with no right-hand operand, e.g. `segregation AND`
+    DanglingOperator { operator: &'static str },
+    /// A query that only excludes cases (e.g. `NOT employment`, or `NOT a OR NOT b`) rather
+    /// than narrowing a positive match set. Evaluating this would mean scanning every indexed
+    /// case for the *absence* of a term, which this module deliberately doesn't support.
+    NoPositiveTerm,
+}
+
+impl fmt::Display for BooleanQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BooleanQueryError::Empty => write!(f, "query has no searchable term"),
+            BooleanQueryError::UnterminatedPhrase => write!(f, "unterminated quoted phrase"),
+            BooleanQueryError::UnbalancedParentheses => write!(f, "unbalanced parentheses"),
+            BooleanQueryError::DanglingOperator { operator } => {
+                write!(f, "'{operator}' has no right-hand operand")
+            }
+            BooleanQueryError::NoPositiveTerm => {
+                write!(f, "query excludes terms but never requires one")
+            }
+        }
+    }
+}
+
+/// One lexical token of a Boolean query string
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Term(String),
+    Phrase(Vec<String>),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+/// Split `query` into [`Token`]s: quoted phrases become one [`Token::Phrase`], `AND`/`OR`/`NOT`
+/// (uppercase only) become their own tokens, `(`/`)` are always their own token even when
+/// glued to a word (`(miranda)` tokenizes as `(`, `miranda`, `)`), and everything else is a bare
+/// [`Token::Term`], lowercased to match how [`crate::trie::TrieIndex`] indexes tokens.
+fn tokenize(query: &str) -> Result<Vec<Token>, BooleanQueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '(' {
+            chars.next();
+            tokens.push(Token::LParen);
+        } else if c == ')' {
+            chars.next();
+            tokens.push(Token::RParen);
+        } else if c == '"' {
+            chars.next();
+            let mut phrase = String::new();
+            let mut closed = false;
+            for c in chars.by_ref() {
+                if c == '"' {
+                    closed = true;
+                    break;
+                }
+                phrase.push(c);
+            }
+            if !closed {
+                return Err(BooleanQueryError::UnterminatedPhrase);
+            }
+            let words: Vec<String> = phrase.split_whitespace().map(|w| w.to_lowercase()).collect();
+            if words.is_empty() {
+                return Err(BooleanQueryError::Empty);
+            }
+            tokens.push(Token::Phrase(words));
+        } else {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            match word.as_str() {
+                "AND" => tokens.push(Token::And),
+                "OR" => tokens.push(Token::Or),
+                "NOT" => tokens.push(Token::Not),
+                _ => tokens.push(Token::Term(word.to_lowercase())),
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over a [`Token`] stream, one method per precedence level
+/// (`or` -> `and` -> `unary` -> `primary`), lowest precedence first.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<QueryNode, BooleanQueryError> {
+        let mut operands = vec![self.parse_and()?];
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.advance();
+            if matches!(self.peek(), None | Some(Token::RParen) | Some(Token::And) | Some(Token::Or)) {
+                return Err(BooleanQueryError::DanglingOperator { operator: "OR" });
+            }
+            operands.push(self.parse_and()?);
+        }
+        Ok(if operands.len() == 1 { operands.pop().unwrap() } else { QueryNode::Or(operands) })
+    }
+
+    fn parse_and(&mut self) -> Result<QueryNode, BooleanQueryError> {
+        let mut operands = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.advance();
+                    operands.push(self.parse_unary_operand("AND")?);
+                }
+                // No explicit `AND` between operands, but another operand follows directly
+                // (most commonly `NOT`, e.g. `segregation NOT employment`): treat it as an
+                // implicit `AND` rather than requiring the keyword to be repeated.
+                Some(Token::Not) | Some(Token::Term(_)) | Some(Token::Phrase(_)) | Some(Token::LParen) => {
+                    operands.push(self.parse_unary()?);
+                }
+                _ => break,
+            }
+        }
+        Ok(if operands.len() == 1 { operands.pop().unwrap() } else { QueryNode::And(operands) })
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryNode, BooleanQueryError> {
+        if matches!(self.peek(), Some(Token::Not)) {
+            self.advance();
+            let operand = self.parse_unary_operand("NOT")?;
+            return Ok(QueryNode::Not(Box::new(operand)));
+        }
+        self.parse_primary()
+    }
+
+    /// The operand of a binary operator (`AND`/`OR`) or `NOT`: reports which keyword was left
+    /// dangling if the stream ends (or hits a closing paren) instead of a real operand.
+    fn parse_unary_operand(&mut self, operator: &'static str) -> Result<QueryNode, BooleanQueryError> {
+        match self.peek() {
+            None | Some(Token::RParen) | Some(Token::And) | Some(Token::Or) => {
+                Err(BooleanQueryError::DanglingOperator { operator })
+            }
+            _ => self.parse_unary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<QueryNode, BooleanQueryError> {
+        match self.advance() {
+            Some(Token::Term(word)) => Ok(QueryNode::Term(word)),
+            Some(Token::Phrase(words)) => Ok(QueryNode::Phrase(words)),
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(BooleanQueryError::UnbalancedParentheses),
+                }
+            }
+            Some(Token::RParen) => Err(BooleanQueryError::UnbalancedParentheses),
+            Some(Token::And) => Err(BooleanQueryError::DanglingOperator { operator: "AND" }),
+            Some(Token::Or) => Err(BooleanQueryError::DanglingOperator { operator: "OR" }),
+            Some(Token::Not) => unreachable!("NOT is consumed by parse_unary before parse_primary sees it"),
+            None => Err(BooleanQueryError::Empty),
+        }
+    }
+}
+
+/// Whether `node` can be evaluated without scanning every indexed case for a term's *absence* —
+/// i.e. it requires at least one term/phrase somewhere that isn't itself negated. `And` only
+/// needs one bounded child (the rest can be `Not`-subtracted from it); `Or` needs every child
+/// bounded, since an unbounded branch would make the union unbounded too.
+fn has_positive_term(node: &QueryNode) -> bool {
+    match node {
+        QueryNode::Term(_) | QueryNode::Phrase(_) => true,
+        QueryNode::Not(_) => false,
+        QueryNode::And(children) => children.iter().any(has_positive_term),
+        QueryNode::Or(children) => children.iter().all(has_positive_term),
+    }
+}
+
+/// Parse `query` as a Boolean expression. Two operands with nothing between them are treated as
+/// an implicit `AND` (see the module doc comment); `AND`/`OR` are case-sensitive uppercase
+/// keywords so an ordinary query mentioning "and" in prose still parses as a single bare term.
+pub fn parse(query: &str) -> Result<QueryNode, BooleanQueryError> {
+    let tokens = tokenize(query)?;
+    if tokens.is_empty() {
+        return Err(BooleanQueryError::Empty);
+    }
+    let mut parser = Parser { tokens, position: 0 };
+    let ast = parser.parse_or()?;
+    if parser.position != parser.tokens.len() {
+        return Err(BooleanQueryError::UnbalancedParentheses);
+    }
+    if !has_positive_term(&ast) {
+        return Err(BooleanQueryError::NoPositiveTerm);
+    }
+    Ok(ast)
+}
+
+/// Evaluate `node` against `trie`'s auxiliary substring index, returning one representative
+/// [`DocRef`] per matching case. A leaf's postings come from
+/// [`crate::trie::TrieIndex::substring_match`], which — like the rest of that index — only
+/// guarantees every token in a phrase occurs *somewhere* in the case, not that they're adjacent;
+/// `"equal protection"` as a Boolean phrase is closer to `equal AND protection` restricted to a
+/// single sentence than to a true adjacency match. [`parse`] already rejects any query this
+/// would need a full-corpus scan to answer (see [`has_positive_term`]), so a bare [`QueryNode::Not`]
+/// reaching this function on its own (rather than as an `And` operand) can't happen from a
+/// successfully parsed query; it falls back to evaluating its inner node so this function stays
+/// total.
+pub fn evaluate(node: &QueryNode, trie: &TrieIndex) -> Vec<DocRef> {
+    match node {
+        QueryNode::Term(word) => trie.substring_match(std::slice::from_ref(word)),
+        QueryNode::Phrase(words) => trie.substring_match(words),
+        QueryNode::Not(inner) => evaluate(inner, trie),
+        QueryNode::And(children) => {
+            let mut positive: Vec<Vec<DocRef>> = Vec::new();
+            let mut excluded: HashSet<CaseId> = HashSet::new();
+            for child in children {
+                if let QueryNode::Not(inner) = child {
+                    excluded.extend(evaluate(inner, trie).into_iter().map(|d| d.case_id));
+                } else {
+                    positive.push(evaluate(child, trie));
+                }
+            }
+            let mut result = positive.into_iter();
+            let mut merged = result.next().unwrap_or_default();
+            for other in result {
+                let case_ids: HashSet<CaseId> = other.iter().map(|d| d.case_id).collect();
+                merged.retain(|doc_ref| case_ids.contains(&doc_ref.case_id));
+            }
+            merged.retain(|doc_ref| !excluded.contains(&doc_ref.case_id));
+            merged
+        }
+        QueryNode::Or(children) => {
+            let mut seen = HashSet::new();
+            let mut merged = Vec::new();
+            for child in children {
+                for doc_ref in evaluate(child, trie) {
+                    if seen.insert(doc_ref.case_id) {
+                        merged.push(doc_ref);
+                    }
+                }
+            }
+            merged
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_a_not_only_query() {
+        assert_eq!(parse("NOT employment"), Err(BooleanQueryError::NoPositiveTerm));
+    }
+
+    #[test]
+    fn test_parse_rejects_an_all_negated_or() {
+        assert_eq!(parse("NOT a OR NOT b"), Err(BooleanQueryError::NoPositiveTerm));
+    }
+
+    #[test]
+    fn test_parse_accepts_and_with_a_trailing_not() {
+        let ast = parse("\"equal protection\" AND segregation NOT employment").unwrap();
+        assert_eq!(
+            ast,
+            QueryNode::And(vec![
+                QueryNode::Phrase(vec!["equal".to_string(), "protection".to_string()]),
+                QueryNode::Term("segregation".to_string()),
+                QueryNode::Not(Box::new(QueryNode::Term("employment".to_string()))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_gives_and_higher_precedence_than_or() {
+        // `a OR b AND c` must parse as `a OR (b AND c)`, not `(a OR b) AND c`
+        let ast = parse("a OR b AND c").unwrap();
+        assert_eq!(
+            ast,
+            QueryNode::Or(vec![
+                QueryNode::Term("a".to_string()),
+                QueryNode::And(vec![QueryNode::Term("b".to_string()), QueryNode::Term("c".to_string())]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_honors_parentheses_over_default_precedence() {
+        let ast = parse("(a OR b) AND c").unwrap();
+        assert_eq!(
+            ast,
+            QueryNode::And(vec![
+                QueryNode::Or(vec![QueryNode::Term("a".to_string()), QueryNode::Term("b".to_string())]),
+                QueryNode::Term("c".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unbalanced_parentheses() {
+        assert_eq!(parse("(a AND b"), Err(BooleanQueryError::UnbalancedParentheses));
+        assert_eq!(parse("a AND b)"), Err(BooleanQueryError::UnbalancedParentheses));
+    }
+
+    #[test]
+    fn test_parse_rejects_a_dangling_operator() {
+        assert_eq!(parse("segregation AND"), Err(BooleanQueryError::DanglingOperator { operator: "AND" }));
+        assert_eq!(parse("OR segregation"), Err(BooleanQueryError::DanglingOperator { operator: "OR" }));
+    }
+
+    #[test]
+    fn test_parse_rejects_an_unterminated_phrase() {
+        assert_eq!(parse("\"equal protection AND segregation"), Err(BooleanQueryError::UnterminatedPhrase));
+    }
+
+    #[test]
+    fn test_parse_rejects_an_empty_query() {
+        assert_eq!(parse(""), Err(BooleanQueryError::Empty));
+        assert_eq!(parse("   "), Err(BooleanQueryError::Empty));
+    }
+
+    #[test]
+    fn test_parse_is_case_sensitive_on_operator_keywords() {
+        // Lowercase "and"/"or"/"not" are ordinary search terms, not operators, so a natural
+        // sentence like "civil and criminal procedure" parses as four ANDed bare terms.
+        let ast = parse("civil and criminal procedure").unwrap();
+        assert_eq!(
+            ast,
+            QueryNode::And(vec![
+                QueryNode::Term("civil".to_string()),
+                QueryNode::Term("and".to_string()),
+                QueryNode::Term("criminal".to_string()),
+                QueryNode::Term("procedure".to_string()),
+            ])
+        );
+    }
+
+    fn doc_ref(case_id: CaseId) -> DocRef {
+        DocRef { case_id, paragraph_index: 0, char_offset: None }
+    }
+
+    fn test_trie_config() -> crate::config::TrieConfig {
+        crate::config::TrieConfig {
+            use_fst: false,
+            index_case_names: true,
+            index_citations: true,
+            max_prefix_length: 50,
+            index_path: std::path::PathBuf::from("./data/trie_index"),
+            enable_memory_mapping: false,
+            fuzzy_short_token_length_threshold: 6,
+            fuzzy_max_edit_distance_short: 1,
+            fuzzy_max_edit_distance_long: 2,
+            wildcard_max_results: 500,
+            skip_stopword_only_ngrams: false,
+            min_token_length: 0,
+        }
+    }
+
+    async fn indexed_trie(entries: &[(&str, CaseId)]) -> TrieIndex {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        for (text, case_id) in entries {
+            let tokens: Vec<(String, usize)> =
+                text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            trie.insert_content(&tokens, doc_ref(*case_id)).unwrap();
+        }
+        trie
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_and_intersects_case_ids() {
+        let a = CaseId::new_v4();
+        let b = CaseId::new_v4();
+        let trie = indexed_trie(&[
+            ("equal protection segregation", a),
+            ("equal protection employment", b),
+        ])
+        .await;
+
+        let ast = parse("\"equal protection\" AND segregation NOT employment").unwrap();
+        let matches = evaluate(&ast, &trie);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].case_id, a);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_or_unions_case_ids_without_duplicates() {
+        let a = CaseId::new_v4();
+        let b = CaseId::new_v4();
+        let c = CaseId::new_v4();
+        let trie = indexed_trie(&[("miranda rights", a), ("gideon appeal", b), ("unrelated case", c)]).await;
+
+        let ast = parse("miranda OR gideon").unwrap();
+        let mut case_ids: Vec<CaseId> = evaluate(&ast, &trie).into_iter().map(|d| d.case_id).collect();
+        case_ids.sort();
+        let mut expected = vec![a, b];
+        expected.sort();
+
+        assert_eq!(case_ids, expected);
+    }
+}
@@ -0,0 +1,232 @@
+//! # Citation Normalization
+//!
+//! ## Purpose
+//! Reporter abbreviations show up in the wild with inconsistent spacing and punctuation
+//! ("S.Ct." vs "S. Ct.", "L.Ed.2d" vs "L. Ed. 2d"), and secondary sources sometimes misreport
+//! a case's decision year. Left alone, both cause exact-match citation lookups (in
+//! [`crate::trie::CitationTrie`]) to miss cases that a human would recognize as the same
+//! citation. This module gives [`crate::text_processing::TextProcessor`]'s citation extractor
+//! and [`crate::trie::TrieIndex`]'s citation search a single, shared place to normalize
+//! reporter spelling and to compare two citations while tolerating a year mismatch.
+//!
+//! ## Input/Output Specification
+//! - **Input**: Raw citation text (`"98 S. Ct. 2733 (1978)"`)
+//! - **Output**: Reporter-normalized text for indexing/search, or a [`CitationComparison`]
+//!   describing how two citations relate
+//! - **Alias table**: Bundled in [`REPORTER_ALIASES`]; add an entry there to cover a new
+//!   variant spelling, no code changes required
+
+use regex::Regex;
+
+/// Alias spellings mapped to the canonical reporter abbreviation used internally by
+/// [`normalize_reporter_spelling`]. Covers the common federal reporters; extend this table
+/// (not the matching logic) to recognize additional variants. Longer aliases are matched
+/// before shorter ones so e.g. `"L. Ed. 2d"` doesn't get partially rewritten by a `"Ed."` rule.
+pub const REPORTER_ALIASES: &[(&str, &str)] = &[
+    ("S. Ct.", "S.Ct."),
+    ("S.Ct", "S.Ct."),
+    ("L. Ed. 2d", "L.Ed.2d"),
+    ("L.Ed. 2d", "L.Ed.2d"),
+    ("L. Ed.2d", "L.Ed.2d"),
+    ("F. 2d", "F.2d"),
+    ("F. 3d", "F.3d"),
+    ("F. Supp. 2d", "F.Supp.2d"),
+    ("F. Supp.", "F.Supp."),
+    ("U. S.", "U.S."),
+];
+
+/// Replace every alias spelling in `text` with its canonical form, longest alias first so
+/// overlapping variants (`"L. Ed. 2d"` containing `"Ed."`) don't get mangled by a shorter
+/// match. Used to normalize a citation before it's tokenized for the citation trie, and to
+/// fill [`crate::text_processing::Citation::normalized`], so both indexing and lookup treat
+/// `"98 S.Ct. 2733"` and `"98 S. Ct. 2733"` as the same text.
+pub fn normalize_reporter_spelling(text: &str) -> String {
+    let mut aliases = REPORTER_ALIASES.to_vec();
+    aliases.sort_by_key(|(alias, _)| std::cmp::Reverse(alias.len()));
+
+    let mut normalized = text.to_string();
+    for (alias, canonical) in aliases {
+        // An alias that is a prefix of its own canonical form ("S.Ct" of "S.Ct.") must not
+        // rewrite text that is already canonical.
+        let mut result = String::with_capacity(normalized.len());
+        let mut rest = normalized.as_str();
+        while let Some(pos) = rest.find(alias) {
+            result.push_str(&rest[..pos]);
+            result.push_str(canonical);
+            let consumed = if rest[pos..].starts_with(canonical) { canonical.len() } else { alias.len() };
+            rest = &rest[pos + consumed..];
+        }
+        result.push_str(rest);
+        normalized = result;
+    }
+    normalized
+}
+
+/// Canonical text used to index and search a citation in [`crate::trie::CitationTrie`]:
+/// reporter spelling normalized (see [`normalize_reporter_spelling`]), a missing space before a
+/// glued-on parenthetical year inserted (`"113(1954)"` -> `"113 (1954)"`, so the year tokenizes
+/// as its own trie edge rather than being fused onto the page number), and all remaining runs
+/// of whitespace collapsed to one space. Reporter-variant citations that only differ in
+/// spacing/punctuation (`"347 U. S. 483"` vs `"347 U.S. 483"`) normalize identically and land
+/// on the same trie node; a differing parenthetical year is deliberately left in place rather
+/// than stripped, since [`crate::trie::TrieIndex::resolve_citation`] depends on the year still
+/// being its own trailing edge to tell "same citation, year mismatch" apart from a genuine miss.
+pub fn normalize_for_index(citation: &str) -> String {
+    let canonical = normalize_reporter_spelling(citation.trim());
+    let spaced = space_before_parenthetical(&canonical);
+    spaced.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Insert a space before `(` when it directly follows a non-whitespace character
+fn space_before_parenthetical(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 1);
+    let mut prev_char: Option<char> = None;
+    for ch in text.chars() {
+        if ch == '(' && prev_char.is_some_and(|c| !c.is_whitespace()) {
+            result.push(' ');
+        }
+        result.push(ch);
+        prev_char = Some(ch);
+    }
+    result
+}
+
+/// True when `text` starts with a volume number followed by more text — a full citation
+/// (`"410 U.S. 113"`) or a partial one (`"410 U.S."`) alike — regardless of whether any indexed
+/// citation actually matches it. Used by [`crate::search::SearchEngine`] to decide whether a
+/// query is worth a direct lookup against [`crate::trie::TrieIndex::resolve_citation`] before
+/// falling through to ordinary token search, where reporter punctuation splitting otherwise
+/// causes an exact citation query to miss.
+pub fn looks_like_citation(text: &str) -> bool {
+    let citation_prefix_regex = Regex::new(r"^\d+\s+\S").unwrap();
+    citation_prefix_regex.is_match(text.trim())
+}
+
+/// A citation broken into its volume/reporter/page/year parts, produced by [`parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCitation {
+    pub volume: String,
+    pub reporter: String,
+    pub page: String,
+    pub year: Option<u32>,
+}
+
+/// Parse a `"<volume> <reporter> <page> (<year>)"` citation, normalizing reporter spelling
+/// first so alias variants parse identically. Returns `None` for text that doesn't fit the
+/// pattern at all, rather than a partially-populated result.
+pub fn parse(citation: &str) -> Option<ParsedCitation> {
+    let normalized = normalize_reporter_spelling(citation.trim());
+    let citation_regex = Regex::new(r"^(\d+)\s+(.+?)\s+(\d+)(?:\s*\((\d{4})\))?$").unwrap();
+    let captures = citation_regex.captures(&normalized)?;
+
+    Some(ParsedCitation {
+        volume: captures.get(1)?.as_str().to_string(),
+        reporter: captures.get(2)?.as_str().to_string(),
+        page: captures.get(3)?.as_str().to_string(),
+        year: captures.get(4).and_then(|m| m.as_str().parse().ok()),
+    })
+}
+
+/// Result of comparing two citations' volume/reporter/page/year via [`compare`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CitationComparison {
+    /// Volume, reporter, and page all match, and the year matches (or neither cites one).
+    Exact,
+    /// Volume, reporter, and page all match, but the years differ. Common with secondary
+    /// sources that misreport a case's decision year; callers like
+    /// [`crate::trie::TrieIndex::resolve_citation`] treat this as a match with a warning
+    /// rather than a miss.
+    YearMismatch { queried_year: u32, indexed_year: u32 },
+    /// Volume, reporter, or page differ (or either citation failed to parse).
+    NoMatch,
+}
+
+/// Compare two citations, ignoring a year mismatch as long as volume/reporter/page agree
+/// exactly (after reporter-spelling normalization).
+pub fn compare(queried: &str, indexed: &str) -> CitationComparison {
+    let (Some(queried), Some(indexed)) = (parse(queried), parse(indexed)) else {
+        return CitationComparison::NoMatch;
+    };
+
+    if queried.volume != indexed.volume
+        || queried.reporter != indexed.reporter
+        || queried.page != indexed.page
+    {
+        return CitationComparison::NoMatch;
+    }
+
+    match (queried.year, indexed.year) {
+        (Some(queried_year), Some(indexed_year)) if queried_year != indexed_year => {
+            CitationComparison::YearMismatch { queried_year, indexed_year }
+        }
+        _ => CitationComparison::Exact,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_reporter_spelling_collapses_alias_variants() {
+        assert_eq!(normalize_reporter_spelling("98 S.Ct. 2733"), "98 S.Ct. 2733");
+        assert_eq!(normalize_reporter_spelling("98 S. Ct. 2733"), "98 S.Ct. 2733");
+        assert_eq!(normalize_reporter_spelling("98 S.Ct 2733"), "98 S.Ct. 2733");
+    }
+
+    #[test]
+    fn test_normalize_reporter_spelling_handles_led_2d_alias() {
+        assert_eq!(
+            normalize_reporter_spelling("58 L. Ed. 2d 466"),
+            "58 L.Ed.2d 466"
+        );
+    }
+
+    #[test]
+    fn test_parse_extracts_volume_reporter_page_year() {
+        let parsed = parse("410 U.S. 113 (1973)").unwrap();
+        assert_eq!(parsed.volume, "410");
+        assert_eq!(parsed.reporter, "U.S.");
+        assert_eq!(parsed.page, "113");
+        assert_eq!(parsed.year, Some(1973));
+    }
+
+    #[test]
+    fn test_compare_matches_alias_spellings_as_exact() {
+        assert_eq!(compare("98 S.Ct. 2733 (1978)", "98 S. Ct. 2733 (1978)"), CitationComparison::Exact);
+        assert_eq!(compare("98 S.Ct 2733 (1978)", "98 S. Ct. 2733 (1978)"), CitationComparison::Exact);
+        assert_eq!(
+            compare("58 L. Ed. 2d 466 (1978)", "58 L.Ed.2d 466 (1978)"),
+            CitationComparison::Exact
+        );
+    }
+
+    #[test]
+    fn test_compare_reports_year_mismatch_on_exact_triple() {
+        let comparison = compare("410 U.S. 113 (1974)", "410 U.S. 113 (1973)");
+        assert_eq!(
+            comparison,
+            CitationComparison::YearMismatch { queried_year: 1974, indexed_year: 1973 }
+        );
+    }
+
+    #[test]
+    fn test_compare_is_no_match_when_page_differs() {
+        assert_eq!(compare("410 U.S. 113 (1973)", "410 U.S. 114 (1973)"), CitationComparison::NoMatch);
+    }
+
+    #[test]
+    fn test_looks_like_citation_accepts_full_and_partial_citations() {
+        assert!(looks_like_citation("410 U.S. 113"));
+        assert!(looks_like_citation("410 U.S. 113 (1973)"));
+        assert!(looks_like_citation("410 U.S."));
+        assert!(looks_like_citation("  347 U.S. 483  "));
+    }
+
+    #[test]
+    fn test_looks_like_citation_rejects_ordinary_queries() {
+        assert!(!looks_like_citation("freedom of speech"));
+        assert!(!looks_like_citation(""));
+        assert!(!looks_like_citation("410"));
+    }
+}
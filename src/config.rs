@@ -38,7 +38,9 @@
 //! ```
 
 use crate::errors::{Result, SearchError};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -82,6 +84,17 @@ pub struct ServerConfig {
     pub api_key: Option<String>,
     /// Rate limiting (requests per minute)
     pub rate_limit_rpm: u32,
+    /// Optional TLS configuration; when set the server binds with rustls instead of plain HTTP
+    pub tls: Option<TlsConfig>,
+}
+
+/// TLS configuration for the API server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsConfig {
+    /// PEM-encoded certificate chain path
+    pub cert_path: PathBuf,
+    /// PEM-encoded private key path
+    pub key_path: PathBuf,
 }
 
 /// Data ingestion configuration
@@ -111,21 +124,94 @@ pub struct IngestionConfig {
     pub update_check_interval_hours: u64,
     /// Validation configuration
     pub validation: ValidationConfig,
+    /// Near-duplicate (reprint / parallel citation) detection configuration
+    pub dedup: DedupConfig,
     /// Cache configuration
     pub cache: CacheConfig,
 }
 
+/// Near-duplicate detection configuration: reporters often republish the same opinion with
+/// minor OCR differences, which exact citation dedup (see [`IngestionPipeline`](crate::ingestion::pipeline::IngestionPipeline))
+/// doesn't catch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupConfig {
+    /// Enable simhash-based near-duplicate detection at ingestion
+    pub enabled: bool,
+    /// Maximum Hamming distance between two content simhashes to consider them
+    /// near-duplicates (simhashes are 64 bits wide; a distance in the single digits to low
+    /// tens catches OCR-level differences without conflating genuinely distinct opinions,
+    /// which land close to the 32-bit chance-agreement midpoint)
+    pub hamming_threshold: u32,
+    /// What to do with an incoming case once a near-duplicate is found
+    pub on_match: DedupAction,
+    /// Minimum cosine similarity (see [`VectorIndex::find_near_duplicates`](crate::vector::VectorIndex::find_near_duplicates))
+    /// between an incoming case's embedding and an already-indexed one to treat them as the same
+    /// opinion, catching near-duplicates the simhash check misses (e.g. a reporter's headnotes
+    /// differing enough to shift the content simhash, or a re-typeset opinion with the same
+    /// substance). `None` disables semantic dedup; `IngestionPipeline` only calls
+    /// `find_near_duplicates` when this is set.
+    #[serde(default)]
+    pub semantic_similarity_threshold: Option<f32>,
+}
+
+/// What to do with an incoming case once [`DedupConfig`] detects a near-duplicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupAction {
+    /// Discard the incoming case, as if it were an exact duplicate
+    Skip,
+    /// Store the case, linking it to the canonical case via `CaseMetadata::duplicate_of`
+    Link,
+    /// Store the case independently; only record the match in `PipelineStats`
+    Store,
+}
+
 /// Caselaw Access Project configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CapConfig {
     /// API base URL
-    pub api_url: String,
-    /// API key for authentication
-    pub api_key: Option<String>,
-    /// Bulk data download URL
-    pub bulk_data_url: String,
-    /// Local cache directory for downloaded data
-    pub cache_dir: PathBuf,
+    pub base_url: String,
+    /// API authentication token
+    pub api_token: String,
+    /// Jurisdictions to fetch (empty = all)
+    pub jurisdictions: Vec<String>,
+    /// Start date for case filtering
+    pub start_date: Option<DateTime<Utc>>,
+    /// End date for case filtering
+    pub end_date: Option<DateTime<Utc>>,
+    /// Maximum cases per request
+    pub page_size: usize,
+    /// Timeout for establishing the TCP/TLS connection, shared by every request this source
+    /// makes. Kept short and separate from the per-request timeouts below so a slow full-case
+    /// fetch doesn't require loosening how quickly we give up on an unreachable host.
+    pub connect_timeout_seconds: u64,
+    /// Timeout for a single case-list page request (`GET /cases/`)
+    pub list_timeout_seconds: u64,
+    /// Timeout for a full-case fetch (`full_case=true` or `GET /cases/{id}/`), which
+    /// legitimately takes much longer than a list page
+    pub full_case_timeout_seconds: u64,
+    /// Rate limit: requests per minute
+    pub rate_limit_rpm: usize,
+    /// Whether to fetch full text (requires authentication)
+    pub fetch_full_text: bool,
+}
+
+impl Default for CapConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://api.case.law/v1".to_string(),
+            api_token: String::new(),
+            jurisdictions: Vec::new(),
+            start_date: None,
+            end_date: None,
+            page_size: 100,
+            connect_timeout_seconds: 5,
+            list_timeout_seconds: 30,
+            full_case_timeout_seconds: 120,
+            rate_limit_rpm: 1000,
+            fetch_full_text: true,
+        }
+    }
 }
 
 /// CourtListener configuration
@@ -190,6 +276,32 @@ pub struct TrieConfig {
     pub index_path: PathBuf,
     /// Enable memory mapping for FST
     pub enable_memory_mapping: bool,
+    /// Token length (in characters) below which `fuzzy_max_edit_distance_short` applies
+    /// instead of `fuzzy_max_edit_distance_long` in `TrieIndex::search_fuzzy`; a 1-character
+    /// edit on a short word is a much bigger relative change than on a long one
+    pub fuzzy_short_token_length_threshold: usize,
+    /// Default max Levenshtein distance for tokens shorter than
+    /// `fuzzy_short_token_length_threshold`
+    pub fuzzy_max_edit_distance_short: usize,
+    /// Default max Levenshtein distance for tokens at or above
+    /// `fuzzy_short_token_length_threshold`
+    pub fuzzy_max_edit_distance_long: usize,
+    /// Cap on the number of `DocRef`s a single wildcard query (e.g. `"freedom of *"` or
+    /// `"* v. board of education"`) can aggregate across matched branches in
+    /// [`crate::trie::TrieIndex::search_wildcard`], to bound the cost of a wildcard token that
+    /// happens to sit at a highly-branching position
+    pub wildcard_max_results: usize,
+    /// When true, [`crate::trie::TrieIndex::insert_content`] drops a sentence entirely if every
+    /// one of its tokens is a stopword (per [`crate::text_processing::TextProcessor::stopwords`],
+    /// injected via `TrieIndex::set_stopwords`) rather than indexing it as a useless content-trie
+    /// path. A sentence with even one non-stopword token is indexed in full, so a stopword
+    /// appearing mid-phrase (`"freedom of speech"`) is never affected.
+    pub skip_stopword_only_ngrams: bool,
+    /// Companion to `skip_stopword_only_ngrams`: a sentence is also dropped if every one of its
+    /// tokens is shorter than this many characters. `0` disables the check. Like the stopword
+    /// filter, this only drops a sentence with no token meeting the bar — it never trims
+    /// individual tokens out of an otherwise-indexed sentence.
+    pub min_token_length: usize,
 }
 
 /// Vector search configuration
@@ -201,10 +313,114 @@ pub struct VectorConfig {
     pub hnsw: HnswConfig,
     /// Vector dimension (must match model output)
     pub dimension: usize,
+    /// Distance metric the HNSW index measures neighbor distance with, and
+    /// `VectorSearchResult::similarity_score` is derived from
+    pub metric: DistanceMetric,
+    /// Maximum number of embeddings the LRU vector cache holds before evicting the
+    /// least-recently-used entry
+    pub cache_max_entries: usize,
+    /// Maximum total bytes of cached embeddings (approximately `f32` count * 4 summed across
+    /// entries) before evicting the least-recently-used entry
+    pub cache_max_bytes: usize,
     /// Similarity threshold for results
     pub similarity_threshold: f32,
     /// Maximum vectors to return from ANN search
     pub max_ann_results: usize,
+    /// When a query carries post-filters (court/date/topic), the vector stage requests
+    /// `top_k * filter_overfetch_multiplier` results instead of `top_k`, since filtering
+    /// after the ANN search can otherwise starve the requested page. A pragmatic stand-in
+    /// for filter-aware ANN, which is a much larger change.
+    pub filter_overfetch_multiplier: usize,
+    /// Ceiling the overfetch multiplier can double up to when the filtered result set is
+    /// still short of the requested page after a round
+    pub max_overfetch_multiplier: usize,
+    /// When set, a background re-embedding migration from `previous_model_type` to
+    /// `model.model_type` is started at startup instead of a big-bang rebuild
+    pub pending_migration: Option<ModelMigrationConfig>,
+    /// Below this many live vectors, `VectorIndex` searches with a brute-force `ExactIndex`
+    /// instead of `HnswIndex` — HNSW's graph-building overhead and approximation error aren't
+    /// worth it for a corpus this small, and the graph may still be incomplete mid-index-build.
+    /// Ignored when `force_backend` is set. See [`VectorBackendKind`].
+    pub exact_search_threshold: usize,
+    /// When set, pins `VectorIndex` to this backend regardless of `exact_search_threshold` and
+    /// live vector count — e.g. to force `Exact` while an index build is still in progress, or
+    /// `Hnsw` for benchmarking at a scale `exact_search_threshold` wouldn't otherwise reach.
+    pub force_backend: Option<VectorBackendKind>,
+    /// Storage precision for indexed vectors. `Int8` cuts memory ~4x (one byte per dimension
+    /// instead of four) at the cost of a small amount of recall; see
+    /// `VectorIndex::calibrate_quantization`.
+    pub quantization: QuantizationMode,
+    /// Paragraph/window chunking applied to a case's full text before embedding, so a document
+    /// far longer than `model.max_sequence_length` doesn't have most of it silently discarded;
+    /// see `VectorIndex::add_case_document`.
+    pub chunking: ChunkingConfig,
+}
+
+/// Controls how `VectorIndex::add_case_document` splits a long document into overlapping,
+/// separately-embedded windows before indexing, each under its own `DocRef::paragraph_index` so
+/// search results point at the specific window that matched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkingConfig {
+    /// Maximum whitespace-delimited words per chunk. Should stay comfortably under
+    /// `EmbeddingModelConfig::max_sequence_length` so a chunk isn't itself truncated by the model.
+    pub chunk_size_tokens: usize,
+    /// Words repeated at the start of each chunk after the first, so a sentence spanning a chunk
+    /// boundary still appears in full in at least one chunk. Must be less than
+    /// `chunk_size_tokens`.
+    pub overlap_tokens: usize,
+}
+
+/// Distance metric a [`VectorConfig`]'s HNSW index measures neighbor distance with. Determines
+/// both which distance function `HnswIndex` runs internally and how a raw distance is converted
+/// into `VectorSearchResult::similarity_score` — `1.0 - distance` is only correct for normalized
+/// cosine distance, and produces out-of-range or non-monotonic scores under the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DistanceMetric {
+    /// `1.0 - cosine_similarity(a, b)`. Embeddings are normalized to unit length on insert, so
+    /// this reduces to a plain dot product internally.
+    #[default]
+    Cosine,
+    /// Raw (unnormalized) dot product; larger means more similar.
+    DotProduct,
+    /// Straight-line (L2) distance between unnormalized embeddings.
+    Euclidean,
+}
+
+/// Which of `VectorIndex`'s two nearest-neighbor backends is active: `Exact`'s brute-force
+/// linear scan, or `Hnsw`'s approximate graph search. See `VectorConfig::exact_search_threshold`/
+/// `force_backend` for how `VectorIndex` picks between them, and `VectorIndexStats::active_backend`
+/// for reporting which one is currently in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorBackendKind {
+    /// Brute-force linear scan against every live vector; always exact, never approximate.
+    Exact,
+    /// Approximate nearest-neighbor search over the in-crate HNSW-style graph.
+    Hnsw,
+}
+
+/// Storage precision for vectors held by either of `VectorIndex`'s backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuantizationMode {
+    /// Store the full-precision `f32` embedding, unchanged.
+    #[default]
+    None,
+    /// Store one `i8` per dimension, scaled against per-dimension min/max bounds computed by
+    /// `VectorIndex::calibrate_quantization`. Cuts stored vector size ~4x at a small recall
+    /// cost — see the crate's quantization recall test for the tolerance this is held to.
+    Int8,
+}
+
+/// Configuration for a background embedding model migration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelMigrationConfig {
+    /// Model type identifier that cases are currently embedded with
+    pub previous_model_type: String,
+    /// Fraction of cases (0.0-1.0) that must be re-embedded before queries cut over
+    /// to the new model's index
+    pub cutover_threshold: f32,
 }
 
 /// Embedding model configuration
@@ -216,12 +432,32 @@ pub struct EmbeddingModelConfig {
     pub tokenizer_path: PathBuf,
     /// Model type identifier
     pub model_type: String,
-    /// Use GPU acceleration if available
+    /// Use GPU acceleration if available. See [`crate::vector::EmbeddingModel::select_provider`]
+    /// for how this is resolved to an actual [`ExecutionProvider`] — a GPU provider that fails
+    /// to initialize falls back to CPU with a warning rather than failing embedding entirely.
     pub use_gpu: bool,
     /// Batch size for embedding generation
     pub batch_size: usize,
     /// Maximum sequence length
     pub max_sequence_length: usize,
+    /// ONNX Runtime intra-op thread count for the CPU execution provider (parallelism within a
+    /// single operator); `0` lets the runtime pick based on available cores, mirroring
+    /// `PerformanceConfig::worker_threads` defaulting to `num_cpus::get()` elsewhere in this
+    /// config. Ignored when a GPU provider is active.
+    #[serde(default)]
+    pub intra_op_threads: usize,
+    /// ONNX Runtime inter-op thread count for the CPU execution provider (parallelism across
+    /// independent operators); `0` lets the runtime pick. Ignored when a GPU provider is active.
+    #[serde(default)]
+    pub inter_op_threads: usize,
+    /// When set, [`crate::vector::EmbeddingModel::new`] skips loading the model at construction
+    /// time and instead loads it on the first call that needs it (see
+    /// [`crate::vector::EmbeddingModel::ensure_loaded`]/[`crate::vector::VectorIndex::warm_up`]),
+    /// so `SearchEngine::new` doesn't block server startup on a multi-hundred-MB model load when
+    /// a deployment only serves lexical search. Defaults to `false` (eager, the pre-existing
+    /// behavior) so an operator has to opt in.
+    #[serde(default)]
+    pub lazy_load_model: bool,
 }
 
 /// HNSW (Hierarchical Navigable Small World) configuration
@@ -248,12 +484,35 @@ pub struct StorageConfig {
     pub db_path: PathBuf,
     /// Maximum database size in GB
     pub max_db_size_gb: u64,
-    /// Enable database compression
-    pub enable_compression: bool,
+    /// Which algorithm `StorageManager::compress_text` uses for newly-written text. Existing
+    /// values keep decompressing correctly regardless of this setting, since each stored value
+    /// is tagged with the algorithm it was written under (see `StorageManager::decode_text`).
+    pub compression_algorithm: CompressionAlgorithm,
+    /// `zstd` compression level, ignored for `Gzip`/`None`. Higher compresses smaller but
+    /// slower; `zstd::DEFAULT_COMPRESSION_LEVEL` (3) is a reasonable default for write-heavy
+    /// ingestion.
+    pub compression_level: i32,
     /// Backup configuration
     pub backup: BackupConfig,
 }
 
+/// Which compression `StorageManager::compress_text` applies to newly-written case text.
+/// Gzip was the original (and until now, only) option; `Zstd` compresses legal text smaller and
+/// faster at ingestion time. Every stored value is prefixed with a one-byte tag naming the
+/// algorithm it was written under, so changing this doesn't strand values written under a
+/// previous setting — see `StorageManager::decode_text`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionAlgorithm {
+    /// Store text as-is, uncompressed.
+    None,
+    /// `flate2`'s gzip encoder at the default compression level.
+    #[default]
+    Gzip,
+    /// `zstd` at `StorageConfig::compression_level`.
+    Zstd,
+}
+
 /// Backup configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackupConfig {
@@ -267,6 +526,69 @@ pub struct BackupConfig {
     pub max_backups: u32,
 }
 
+/// A named bundle of ranking knobs selectable per query via
+/// `search::SearchQuery::profile`/`api::SearchRequest::profile`, so a caller can ask for
+/// "autocomplete-style, lexical-only" or "research-style, semantic-heavy" ranking without
+/// spelling out every knob on every request. Three built-ins (`"lexical"`, `"balanced"`,
+/// `"semantic"`; see [`builtin_weighting_profiles`]) are always available; `SearchEngineConfig`'s
+/// `weighting_profiles` map can add more, or override a built-in by reusing its name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightingProfile {
+    /// See `SearchConfig::exact_match_weight`.
+    pub exact_match_weight: f32,
+    /// See `SearchConfig::min_similarity`.
+    pub min_similarity: f32,
+    /// See `SearchConfig::enable_semantic`.
+    pub enable_semantic: bool,
+    /// See `SearchConfig::enable_prefix`.
+    pub enable_prefix: bool,
+    /// See `SearchConfig::enable_rerank`.
+    pub enable_rerank: bool,
+    /// See `SearchConfig::rrf_k`.
+    pub rrf_k: f32,
+}
+
+/// The always-available named profiles, keyed by the name a query selects them with. Values for
+/// `"balanced"` mirror `SearchEngineConfig::default()`'s own weights, so a query that doesn't
+/// select a profile at all still ranks exactly as before this feature existed.
+fn builtin_weighting_profiles() -> HashMap<String, WeightingProfile> {
+    HashMap::from([
+        (
+            "lexical".to_string(),
+            WeightingProfile {
+                exact_match_weight: 3.0,
+                min_similarity: 1.0,
+                enable_semantic: false,
+                enable_prefix: true,
+                enable_rerank: false,
+                rrf_k: default_rrf_k(),
+            },
+        ),
+        (
+            "balanced".to_string(),
+            WeightingProfile {
+                exact_match_weight: 2.0,
+                min_similarity: 0.5,
+                enable_semantic: true,
+                enable_prefix: true,
+                enable_rerank: false,
+                rrf_k: default_rrf_k(),
+            },
+        ),
+        (
+            "semantic".to_string(),
+            WeightingProfile {
+                exact_match_weight: 1.0,
+                min_similarity: 0.3,
+                enable_semantic: true,
+                enable_prefix: false,
+                enable_rerank: true,
+                rrf_k: default_rrf_k(),
+            },
+        ),
+    ])
+}
+
 /// Search engine configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchEngineConfig {
@@ -280,10 +602,177 @@ pub struct SearchEngineConfig {
     pub query_cache_size: usize,
     /// Query cache TTL in seconds
     pub query_cache_ttl_seconds: u64,
+    /// How often the background sweep task (see
+    /// `search::SearchEngine::spawn_query_cache_sweep`) scans the query cache for expired
+    /// entries, so stale entries are reclaimed even for queries that are never repeated (and so
+    /// never get the lazy on-access expiry check in `QueryCache::get`).
+    #[serde(default = "default_query_cache_sweep_interval_seconds")]
+    pub query_cache_sweep_interval_seconds: u64,
     /// Minimum query length
     pub min_query_length: usize,
     /// Maximum query length
     pub max_query_length: usize,
+    /// Optional path to a user-supplied topic taxonomy JSON file; falls back to the bundled taxonomy when unset
+    pub taxonomy_path: Option<PathBuf>,
+    /// Maximum number of searches allowed to execute concurrently
+    pub max_concurrent_queries: usize,
+    /// Maximum time a query will wait for a concurrency slot before being shed with
+    /// `SearchCapacityExceeded`
+    pub max_queue_wait_ms: u64,
+    /// Maximum number of queries accepted in one `POST /search/batch` request (see
+    /// `search::SearchEngine::search_batch`); a larger request is rejected up front rather than
+    /// silently truncated.
+    #[serde(default = "default_max_batch_queries")]
+    pub max_batch_queries: usize,
+    /// Baseline weight applied to exact (trie content) matches vs semantic matches, used to
+    /// build the default `SearchConfig` every query starts from (see
+    /// `SearchConfig::from_config`)
+    pub exact_match_weight: f32,
+    /// Baseline weight applied to case-name-origin trie matches; see
+    /// `SearchConfig::case_name_match_weight`
+    pub case_name_match_weight: f32,
+    /// Baseline weight applied to citation-origin trie matches; see
+    /// `SearchConfig::citation_match_weight`
+    pub citation_match_weight: f32,
+    /// Whether semantic (vector) search runs by default
+    pub enable_semantic: bool,
+    /// Whether prefix (trie) search runs by default
+    pub enable_prefix: bool,
+    /// Threshold, in milliseconds, above which a `trie_index`/`vector_index` lock hold logs a
+    /// warning (see `crate::utils::InstrumentedRwLock`). Sized well above a typical read
+    /// (sub-millisecond) so it only fires for genuine offenders like serializing a snapshot
+    /// under the lock.
+    pub lock_hold_warn_threshold_ms: u64,
+    /// Per-stage latency budgets, checked cooperatively around each stage of
+    /// `SearchEngine::execute_hybrid_search` rather than relying solely on `search_timeout_ms`
+    /// as one global deadline
+    pub budgets: SearchStageBudgets,
+    /// Whether the vector stage re-scores its top `rerank_candidates` ANN hits with exact
+    /// (non-approximate) similarity before they're merged with lexical results; see
+    /// `VectorIndex::search_and_rerank`. Off by default, matching every other `enable_*` flag
+    /// here defaulting to today's existing behavior.
+    pub enable_rerank: bool,
+    /// How many of the ANN stage's top hits `search_and_rerank` re-scores exactly when
+    /// `enable_rerank` is set. Ignored otherwise.
+    pub rerank_candidates: usize,
+    /// Baseline `k` constant for Reciprocal Rank Fusion, used to build the default
+    /// `SearchConfig` every query starts from; see `SearchConfig::rrf_k`.
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f32,
+    /// Baseline auto-correct setting, used to build the default `SearchConfig` every query
+    /// starts from; see `SearchConfig::auto_correct`.
+    #[serde(default)]
+    pub auto_correct: bool,
+    /// Baseline cap on how many of the pre-filter, pre-truncation candidates
+    /// `search::SearchEngine::compute_facets` counts over, used to build the default
+    /// `SearchConfig` every query starts from; see `SearchConfig::facet_candidate_limit`.
+    #[serde(default = "default_facet_candidate_limit")]
+    pub facet_candidate_limit: usize,
+    /// Court name -> rank override (lower rank sorts first) for `search::SortOrder::CourtRank`,
+    /// consulted before the built-in Supreme Court / circuit / district heuristic in
+    /// `search::SearchEngine::court_rank`. Empty by default, deferring entirely to that
+    /// heuristic; an operator with courts the heuristic doesn't recognize (e.g. state supreme
+    /// courts that should outrank federal district courts) can rank them explicitly here.
+    #[serde(default)]
+    pub court_rank_overrides: HashMap<String, u32>,
+    /// Optional path to a user-supplied synonym table JSON file; falls back to the bundled
+    /// legal-concept default set when unset. See `synonyms::SynonymTable::load_from_file`.
+    #[serde(default)]
+    pub synonyms_path: Option<PathBuf>,
+    /// Baseline synonym-expansion setting, used to build the default `SearchConfig` every query
+    /// starts from; see `SearchConfig::enable_synonyms`.
+    #[serde(default)]
+    pub enable_synonyms: bool,
+    /// Baseline cap on synonym expansions per query, used to build the default `SearchConfig`
+    /// every query starts from; see `SearchConfig::max_synonym_expansions`.
+    #[serde(default = "default_max_synonym_expansions")]
+    pub max_synonym_expansions: usize,
+    /// Baseline citation-dedup setting, used to build the default `SearchConfig` every query
+    /// starts from; see `SearchConfig::enable_citation_dedup`.
+    #[serde(default = "default_enable_citation_dedup")]
+    pub enable_citation_dedup: bool,
+    /// Baseline minimum-should-match spec, used to build the default `SearchConfig` every query
+    /// starts from; see `SearchConfig::min_should_match`.
+    #[serde(default = "default_min_should_match")]
+    pub min_should_match: String,
+    /// Baseline vector-short-circuit setting, used to build the default `SearchConfig` every
+    /// query starts from; see `SearchConfig::enable_vector_short_circuit`.
+    #[serde(default)]
+    pub enable_vector_short_circuit: bool,
+    /// User-defined weighting profiles, in addition to the three built-ins (see
+    /// [`builtin_weighting_profiles`]). A name reused from a built-in overrides it.
+    #[serde(default)]
+    pub weighting_profiles: HashMap<String, WeightingProfile>,
+    /// Name of the profile applied to a query that doesn't select one via
+    /// `search::SearchQuery::profile`. `None` (the default) leaves every query's ranking knobs
+    /// exactly as this config's other fields already set them, i.e. today's existing behavior.
+    #[serde(default)]
+    pub default_weighting_profile: Option<String>,
+}
+
+impl SearchEngineConfig {
+    /// Look up a named weighting profile: `weighting_profiles` first (so a deployment can
+    /// override a built-in), falling back to [`builtin_weighting_profiles`].
+    pub fn weighting_profile(&self, name: &str) -> Option<WeightingProfile> {
+        self.weighting_profiles.get(name).cloned().or_else(|| builtin_weighting_profiles().get(name).cloned())
+    }
+
+    /// Every profile name this config recognizes, sorted for a deterministic error message; see
+    /// [`SearchEngineConfig::weighting_profile`].
+    pub fn known_weighting_profile_names(&self) -> Vec<String> {
+        let mut names: std::collections::HashSet<String> = builtin_weighting_profiles().into_keys().collect();
+        names.extend(self.weighting_profiles.keys().cloned());
+        let mut names: Vec<String> = names.into_iter().collect();
+        names.sort();
+        names
+    }
+}
+
+fn default_min_should_match() -> String {
+    "2<75%".to_string()
+}
+
+fn default_max_synonym_expansions() -> usize {
+    3
+}
+
+fn default_enable_citation_dedup() -> bool {
+    true
+}
+
+fn default_max_batch_queries() -> usize {
+    100
+}
+
+fn default_rrf_k() -> f32 {
+    60.0
+}
+
+fn default_query_cache_sweep_interval_seconds() -> u64 {
+    300
+}
+
+fn default_facet_candidate_limit() -> usize {
+    500
+}
+
+/// Per-stage latency budgets for a single search, each independently optional. A stage that
+/// exceeds its budget is cut short rather than failing the whole query: lexical/semantic search
+/// keep whatever matches were already found, rerank is skipped (results keep the order the
+/// lexical/semantic stages produced them in), and snippet generation falls back to a cheap
+/// placeholder for any result it hasn't reached yet. Each cutoff appends a corresponding
+/// `*_BUDGET_EXCEEDED` warning to `SearchOutcome::warnings`. `None` (the default for every
+/// field) means unbounded, preserving pre-existing behavior for anyone who hasn't opted in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchStageBudgets {
+    /// Budget for the trie (exact/prefix/wildcard) search stage
+    pub lexical_ms: Option<u64>,
+    /// Budget for the vector (semantic) search stage
+    pub semantic_ms: Option<u64>,
+    /// Budget for the result-reranking stage
+    pub rerank_ms: Option<u64>,
+    /// Budget for per-result snippet generation, applied across the whole batch of results
+    pub snippets_ms: Option<u64>,
 }
 
 /// Logging and monitoring configuration
@@ -340,6 +829,24 @@ pub struct ValidationConfig {
     pub validate_dates: bool,
     /// Validate citations
     pub validate_citations: bool,
+    /// Per-rule severity override, keyed by [`ValidationRule::name`](crate::ingestion::validation::ValidationRule::name)
+    /// (e.g. `"citation_format" -> RuleSeverity::Warn"`). A rule not listed here falls back
+    /// to its own default severity. Unknown rule names are rejected by [`Config::validate`],
+    /// against [`crate::ingestion::validation::KNOWN_RULE_NAMES`].
+    #[serde(default)]
+    pub rule_severity: HashMap<String, RuleSeverity>,
+}
+
+/// How a validation rule violation is treated once detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// Recorded on the case (`CaseMetadata::validation_warnings`), ingestion proceeds
+    Warn,
+    /// Blocks ingestion of the case
+    Error,
+    /// The rule is not evaluated at all
+    Off,
 }
 
 /// Cache configuration
@@ -461,6 +968,66 @@ impl Config {
             });
         }
 
+        if self.search.max_concurrent_queries == 0 {
+            return Err(SearchError::ValidationFailed {
+                field: "search.max_concurrent_queries".to_string(),
+                reason: "Must allow at least one concurrent query".to_string(),
+            });
+        }
+
+        if self.search.max_batch_queries == 0 {
+            return Err(SearchError::ValidationFailed {
+                field: "search.max_batch_queries".to_string(),
+                reason: "Must allow at least one query per batch request".to_string(),
+            });
+        }
+
+        if self.vector.filter_overfetch_multiplier == 0 {
+            return Err(SearchError::ValidationFailed {
+                field: "vector.filter_overfetch_multiplier".to_string(),
+                reason: "Overfetch multiplier must be at least 1".to_string(),
+            });
+        }
+
+        if self.vector.max_overfetch_multiplier < self.vector.filter_overfetch_multiplier {
+            return Err(SearchError::ValidationFailed {
+                field: "vector.max_overfetch_multiplier".to_string(),
+                reason: "Cannot be smaller than vector.filter_overfetch_multiplier".to_string(),
+            });
+        }
+
+        for rule_name in self.ingestion.validation.rule_severity.keys() {
+            if !crate::ingestion::validation::KNOWN_RULE_NAMES.contains(&rule_name.as_str()) {
+                return Err(SearchError::ValidationFailed {
+                    field: format!("ingestion.validation.rule_severity.{}", rule_name),
+                    reason: format!(
+                        "Unknown validation rule name; known rules are: {}",
+                        crate::ingestion::validation::KNOWN_RULE_NAMES.join(", ")
+                    ),
+                });
+            }
+        }
+
+        if self.ingestion.dedup.hamming_threshold > 64 {
+            return Err(SearchError::ValidationFailed {
+                field: "ingestion.dedup.hamming_threshold".to_string(),
+                reason: "Cannot exceed 64, the width of a simhash".to_string(),
+            });
+        }
+
+        if let Some(default_profile) = &self.search.default_weighting_profile {
+            if self.search.weighting_profile(default_profile).is_none() {
+                return Err(SearchError::ValidationFailed {
+                    field: "search.default_weighting_profile".to_string(),
+                    reason: format!(
+                        "Unknown weighting profile '{}'; available profiles: {}",
+                        default_profile,
+                        self.search.known_weighting_profile_names().join(", ")
+                    ),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -490,14 +1057,10 @@ impl Default for Config {
                 enable_cors: true,
                 api_key: None,
                 rate_limit_rpm: 1000,
+                tls: None,
             },
             ingestion: IngestionConfig {
-                cap: CapConfig {
-                    api_url: "https://api.case.law/v1/".to_string(),
-                    api_key: None,
-                    bulk_data_url: "https://bulk.case.law/".to_string(),
-                    cache_dir: PathBuf::from("./data/cap_cache"),
-                },
+                cap: CapConfig::default(),
                 courtlistener: CourtListenerConfig {
                     api_url: "https://www.courtlistener.com/api/rest/v3/".to_string(),
                     api_key: None,
@@ -519,6 +1082,13 @@ impl Default for Config {
                     allow_empty_citations: false,
                     validate_dates: true,
                     validate_citations: true,
+                    rule_severity: HashMap::new(),
+                },
+                dedup: DedupConfig {
+                    enabled: true,
+                    hamming_threshold: 10,
+                    on_match: DedupAction::Link,
+                    semantic_similarity_threshold: Some(0.97),
                 },
                 cache: CacheConfig {
                     enabled: true,
@@ -551,6 +1121,12 @@ impl Default for Config {
                 max_prefix_length: 50,
                 index_path: PathBuf::from("./data/trie_index"),
                 enable_memory_mapping: true,
+                fuzzy_short_token_length_threshold: 6,
+                fuzzy_max_edit_distance_short: 1,
+                fuzzy_max_edit_distance_long: 2,
+                wildcard_max_results: 500,
+                skip_stopword_only_ngrams: true,
+                min_token_length: 2,
             },
             vector: VectorConfig {
                 model: EmbeddingModelConfig {
@@ -560,6 +1136,9 @@ impl Default for Config {
                     use_gpu: false,
                     batch_size: 32,
                     max_sequence_length: 512,
+                    intra_op_threads: 0,
+                    inter_op_threads: 0,
+                    lazy_load_model: false,
                 },
                 hnsw: HnswConfig {
                     m: 16,
@@ -569,14 +1148,28 @@ impl Default for Config {
                     index_path: PathBuf::from("./data/vector_index"),
                 },
                 dimension: 768,
+                metric: DistanceMetric::Cosine,
+                cache_max_entries: 1000,
+                cache_max_bytes: 256 * 1024 * 1024,
                 similarity_threshold: 0.5,
                 max_ann_results: 100,
+                filter_overfetch_multiplier: 3,
+                max_overfetch_multiplier: 24,
+                pending_migration: None,
+                exact_search_threshold: 5_000,
+                force_backend: None,
+                quantization: QuantizationMode::None,
+                chunking: ChunkingConfig {
+                    chunk_size_tokens: 200,
+                    overlap_tokens: 50,
+                },
             },
             storage: StorageConfig {
                 db_type: "sled".to_string(),
                 db_path: PathBuf::from("./data/legal_search.db"),
                 max_db_size_gb: 100,
-                enable_compression: true,
+                compression_algorithm: CompressionAlgorithm::Gzip,
+                compression_level: zstd::DEFAULT_COMPRESSION_LEVEL,
                 backup: BackupConfig {
                     enabled: true,
                     backup_dir: PathBuf::from("./backups"),
@@ -590,8 +1183,34 @@ impl Default for Config {
                 enable_query_cache: true,
                 query_cache_size: 10000,
                 query_cache_ttl_seconds: 3600,
+                query_cache_sweep_interval_seconds: default_query_cache_sweep_interval_seconds(),
                 min_query_length: 2,
                 max_query_length: 1000,
+                taxonomy_path: None,
+                max_concurrent_queries: 100,
+                max_queue_wait_ms: 500,
+                max_batch_queries: default_max_batch_queries(),
+                exact_match_weight: 2.0,
+                case_name_match_weight: 3.0,
+                citation_match_weight: 3.0,
+                enable_semantic: true,
+                enable_prefix: true,
+                lock_hold_warn_threshold_ms: 50,
+                budgets: SearchStageBudgets::default(),
+                enable_rerank: false,
+                rerank_candidates: 100,
+                rrf_k: default_rrf_k(),
+                auto_correct: false,
+                facet_candidate_limit: default_facet_candidate_limit(),
+                court_rank_overrides: HashMap::new(),
+                synonyms_path: None,
+                enable_synonyms: false,
+                max_synonym_expansions: default_max_synonym_expansions(),
+                enable_citation_dedup: default_enable_citation_dedup(),
+                min_should_match: default_min_should_match(),
+                enable_vector_short_circuit: false,
+                weighting_profiles: HashMap::new(),
+                default_weighting_profile: None,
             },
             logging: LoggingConfig {
                 level: "info".to_string(),
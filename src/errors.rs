@@ -168,6 +168,9 @@ pub enum SearchError {
         available_gb: u64,
     },
 
+    #[error("Case not found: {case_id}")]
+    CaseNotFound { case_id: crate::CaseId },
+
     // Search engine errors
     #[error("Index not found: {index_name}")]
     IndexNotFound { index_name: String },
@@ -258,7 +261,8 @@ impl SearchError {
             | SearchError::IndexCorrupted { .. }
             | SearchError::SearchTimeout { .. }
             | SearchError::InvalidSearchQuery { .. }
-            | SearchError::SearchCapacityExceeded { .. } => "search",
+            | SearchError::SearchCapacityExceeded { .. }
+            | SearchError::CaseNotFound { .. } => "search",
             SearchError::InvalidApiRequest { .. }
             | SearchError::AuthenticationFailed { .. }
             | SearchError::ApiRateLimitExceeded { .. }
@@ -0,0 +1,427 @@
+//! Fielded query prefix parsing: pulls recognized `field:value` prefixes (`court:`, `judge:`,
+//! `topic:`, `date:`, `cite:`) out of a raw plain-syntax query string and turns them into
+//! structured filters, leaving everything else — including quoted phrases untouched by a field
+//! prefix, so `search::SearchEngine`'s phrase-adjacency handling still sees them — as residual
+//! free text. See `search::SearchEngine::apply_fielded_query_syntax` for how the result is
+//! merged back onto a `SearchQuery`.
+
+use chrono::NaiveDate;
+
+const RECOGNIZED_FIELDS: &[&str] = &["court", "judge", "topic", "date", "cite"];
+
+/// The result of pulling recognized field prefixes out of a raw query string. See
+/// [`parse`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldedQuery {
+    /// `query` with every recognized field:value token removed; quoted phrases and other free
+    /// text are otherwise untouched (still quoted, if they were).
+    pub text: String,
+    pub court: Option<Vec<String>>,
+    pub judge: Option<Vec<String>>,
+    /// Taxonomy node ids from `topic:` prefixes, in query order.
+    pub topic: Option<Vec<String>>,
+    /// Citation strings from `cite:` prefixes. Not a separate filter field — citation search
+    /// already happens through the trie's citation index — so the caller folds these back into
+    /// `text` rather than a structured field of its own.
+    pub citation: Option<Vec<String>>,
+    pub date_range: Option<(NaiveDate, NaiveDate)>,
+    /// One `UNKNOWN_FIELD: <field>` warning per unrecognized `field:value` token, and one
+    /// `INVALID_DATE_FIELD: <reason>` warning per `date:` token that failed to parse. The
+    /// offending token is left in `text` rather than dropped.
+    pub warnings: Vec<String>,
+}
+
+/// Parse `query` for recognized field prefixes. See [`FieldedQuery`].
+pub fn parse(query: &str) -> FieldedQuery {
+    let mut result = FieldedQuery::default();
+    let mut residual_tokens = Vec::new();
+
+    for token in split_preserving_quotes(query) {
+        let Some((key, value)) = token.split_once(':') else {
+            residual_tokens.push(token);
+            continue;
+        };
+
+        if !is_field_key(key) {
+            if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphabetic()) {
+                result.warnings.push(format!("UNKNOWN_FIELD: {key}"));
+            }
+            residual_tokens.push(token);
+            continue;
+        }
+
+        let value = strip_matching_quotes(value);
+        if value.is_empty() {
+            residual_tokens.push(token);
+            continue;
+        }
+
+        match key.to_lowercase().as_str() {
+            "court" => result.court.get_or_insert_with(Vec::new).push(value.to_string()),
+            "judge" => result.judge.get_or_insert_with(Vec::new).push(value.to_string()),
+            "topic" => result.topic.get_or_insert_with(Vec::new).push(value.to_string()),
+            "cite" => result.citation.get_or_insert_with(Vec::new).push(value.to_string()),
+            "date" => match parse_date_field(value) {
+                Ok(range) => result.date_range = Some(range),
+                Err(reason) => {
+                    result.warnings.push(format!("INVALID_DATE_FIELD: {reason}"));
+                    residual_tokens.push(token);
+                }
+            },
+            _ => unreachable!("is_field_key only accepts RECOGNIZED_FIELDS"),
+        }
+    }
+
+    for citation in result.citation.iter().flatten() {
+        if citation.contains(char::is_whitespace) {
+            residual_tokens.push(format!("\"{citation}\""));
+        } else {
+            residual_tokens.push(citation.clone());
+        }
+    }
+
+    result.text = residual_tokens.join(" ");
+    result
+}
+
+fn is_field_key(key: &str) -> bool {
+    !key.is_empty() && RECOGNIZED_FIELDS.contains(&key.to_lowercase().as_str())
+}
+
+/// Strip one layer of matching double quotes from `value`, if present (e.g. the `"Supreme
+/// Court"` in `court:"Supreme Court"`); otherwise returns `value` unchanged.
+fn strip_matching_quotes(value: &str) -> &str {
+    value.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')).unwrap_or(value)
+}
+
+/// Parse a `date:` field's value into an inclusive `(start, end)` range: a bare year (`1950`)
+/// spans that whole year, `YYYY-MM` spans that whole month, `1950s` spans that whole decade, and
+/// `start..end` (each side any of the above) spans from the start of `start`'s range to the end
+/// of `end`'s range. See [`parse_date_range_expression`] for the open-ended `>=`/`<=` forms this
+/// doesn't handle.
+fn parse_date_field(value: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    if let Some((left, right)) = value.split_once("..") {
+        let (start, _) = parse_date_span(left)?;
+        let (_, end) = parse_date_span(right)?;
+        Ok((start, end))
+    } else {
+        parse_date_span(value)
+    }
+}
+
+/// [`parse_date_field`], plus the open-ended `>=YYYY`/`<=YYYY` forms `SearchRequest::date_range`
+/// accepts as a bare string (see `api::DateRangeFilter`) — not offered on `date:` fielded-query
+/// tokens too, since an unbounded date filter there would silently make every other result
+/// invisible with no error to explain why.
+pub(crate) fn parse_date_range_expression(value: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    if let Some(rest) = value.strip_prefix(">=") {
+        let (start, _) = parse_date_span(rest.trim())?;
+        Ok((start, NaiveDate::MAX))
+    } else if let Some(rest) = value.strip_prefix("<=") {
+        let (_, end) = parse_date_span(rest.trim())?;
+        Ok((NaiveDate::MIN, end))
+    } else {
+        parse_date_field(value)
+    }
+}
+
+fn parse_date_span(value: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    if let Some(decade) = value.strip_suffix('s') {
+        if let Ok(decade_start) = decade.parse::<i32>() {
+            let decade_start = (decade_start / 10) * 10;
+            let start = NaiveDate::from_ymd_opt(decade_start, 1, 1).ok_or_else(|| format!("invalid decade {value:?}"))?;
+            let end = NaiveDate::from_ymd_opt(decade_start + 9, 12, 31).ok_or_else(|| format!("invalid decade {value:?}"))?;
+            return Ok((start, end));
+        }
+    }
+
+    let parts: Vec<&str> = value.split('-').collect();
+    match parts.as_slice() {
+        [year] => {
+            let year = parse_year(year)?;
+            let start = NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(|| format!("invalid year {year}"))?;
+            let end = NaiveDate::from_ymd_opt(year, 12, 31).ok_or_else(|| format!("invalid year {year}"))?;
+            Ok((start, end))
+        }
+        [year, month] => {
+            let year = parse_year(year)?;
+            let month: u32 = month
+                .parse()
+                .map_err(|_| format!("unrecognized date field value: {value:?} (expected YYYY or YYYY-MM)"))?;
+            let start = NaiveDate::from_ymd_opt(year, month, 1)
+                .ok_or_else(|| format!("unrecognized date field value: {value:?} (expected YYYY or YYYY-MM)"))?;
+            let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+            let end = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+                .and_then(|d| d.pred_opt())
+                .ok_or_else(|| format!("unrecognized date field value: {value:?} (expected YYYY or YYYY-MM)"))?;
+            Ok((start, end))
+        }
+        [year, month, day] => {
+            let year = parse_year(year)?;
+            let month: u32 = month
+                .parse()
+                .map_err(|_| format!("unrecognized date field value: {value:?} (expected YYYY-MM-DD)"))?;
+            let day: u32 =
+                day.parse().map_err(|_| format!("unrecognized date field value: {value:?} (expected YYYY-MM-DD)"))?;
+            let date = NaiveDate::from_ymd_opt(year, month, day)
+                .ok_or_else(|| format!("unrecognized date field value: {value:?} (expected YYYY-MM-DD)"))?;
+            Ok((date, date))
+        }
+        _ => Err(format!(
+            "unrecognized date field value: {value:?} (expected YYYY, YYYY-MM, YYYY-MM-DD, or 1950s-style decade)"
+        )),
+    }
+}
+
+fn parse_year(raw: &str) -> Result<i32, String> {
+    raw.parse::<i32>().map_err(|_| format!("invalid year: {raw:?}"))
+}
+
+/// Split `query` on whitespace, treating a `"..."` span (even one embedded mid-token, as in
+/// `court:"Supreme Court"`) as part of the same token rather than a delimiter — this keeps a
+/// field's quoted value, or a standalone quoted phrase, intact as a single token, quotes and
+/// all, for [`parse`] to inspect.
+fn split_preserving_quotes(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in query.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+            current.push(c);
+        } else if c.is_whitespace() && !in_quotes {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_court_judge_and_leaves_free_text() {
+        let result = parse("court:\"Supreme Court\" judge:Warren equal protection");
+        assert_eq!(result.court, Some(vec!["Supreme Court".to_string()]));
+        assert_eq!(result.judge, Some(vec!["Warren".to_string()]));
+        assert_eq!(result.text, "equal protection");
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_unquoted_single_token_value() {
+        let result = parse("court:9th_Circuit due process");
+        assert_eq!(result.court, Some(vec!["9th_Circuit".to_string()]));
+        assert_eq!(result.text, "due process");
+    }
+
+    #[test]
+    fn test_topic_field() {
+        let result = parse("topic:equal_protection segregation");
+        assert_eq!(result.topic, Some(vec!["equal_protection".to_string()]));
+        assert_eq!(result.text, "segregation");
+    }
+
+    #[test]
+    fn test_cite_field_folds_back_into_text_as_a_quoted_phrase() {
+        let result = parse("cite:\"410 U.S. 113\" privacy");
+        assert_eq!(result.citation, Some(vec!["410 U.S. 113".to_string()]));
+        assert_eq!(result.text, "privacy \"410 U.S. 113\"");
+    }
+
+    #[test]
+    fn test_cite_field_single_token_stays_unquoted() {
+        let result = parse("cite:410-U.S.-113");
+        assert_eq!(result.citation, Some(vec!["410-U.S.-113".to_string()]));
+        assert_eq!(result.text, "410-U.S.-113");
+    }
+
+    #[test]
+    fn test_date_field_bare_year() {
+        let result = parse("date:1970 commerce clause");
+        assert_eq!(
+            result.date_range,
+            Some((NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1970, 12, 31).unwrap()))
+        );
+        assert_eq!(result.text, "commerce clause");
+    }
+
+    #[test]
+    fn test_date_field_year_month() {
+        let result = parse("date:1970-02 filing deadline");
+        assert_eq!(
+            result.date_range,
+            Some((NaiveDate::from_ymd_opt(1970, 2, 1).unwrap(), NaiveDate::from_ymd_opt(1970, 2, 28).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_field_year_month_end_of_year_rolls_over() {
+        let result = parse("date:1970-12 term");
+        assert_eq!(
+            result.date_range,
+            Some((NaiveDate::from_ymd_opt(1970, 12, 1).unwrap(), NaiveDate::from_ymd_opt(1970, 12, 31).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_field_range_of_bare_years() {
+        let result = parse("date:1950..1970 due process");
+        assert_eq!(
+            result.date_range,
+            Some((NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1970, 12, 31).unwrap()))
+        );
+        assert_eq!(result.text, "due process");
+    }
+
+    #[test]
+    fn test_date_field_range_of_year_months() {
+        let result = parse("date:1950-06..1970-03");
+        assert_eq!(
+            result.date_range,
+            Some((NaiveDate::from_ymd_opt(1950, 6, 1).unwrap(), NaiveDate::from_ymd_opt(1970, 3, 31).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_field_decade() {
+        let result = parse("date:1950s due process");
+        assert_eq!(
+            result.date_range,
+            Some((NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1959, 12, 31).unwrap()))
+        );
+        assert_eq!(result.text, "due process");
+    }
+
+    #[test]
+    fn test_date_range_expression_bare_year() {
+        assert_eq!(
+            parse_date_range_expression("1954"),
+            Ok((NaiveDate::from_ymd_opt(1954, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1954, 12, 31).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_range_expression_year_month() {
+        assert_eq!(
+            parse_date_range_expression("1954-05"),
+            Ok((NaiveDate::from_ymd_opt(1954, 5, 1).unwrap(), NaiveDate::from_ymd_opt(1954, 5, 31).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_range_expression_year_month_day() {
+        let day = NaiveDate::from_ymd_opt(1954, 5, 17).unwrap();
+        assert_eq!(parse_date_range_expression("1954-05-17"), Ok((day, day)));
+    }
+
+    #[test]
+    fn test_date_range_expression_decade() {
+        assert_eq!(
+            parse_date_range_expression("1950s"),
+            Ok((NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1959, 12, 31).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_range_expression_year_span() {
+        assert_eq!(
+            parse_date_range_expression("1954..1966"),
+            Ok((NaiveDate::from_ymd_opt(1954, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1966, 12, 31).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_date_range_expression_at_or_after() {
+        let (start, end) = parse_date_range_expression(">=1973").unwrap();
+        assert_eq!(start, NaiveDate::from_ymd_opt(1973, 1, 1).unwrap());
+        assert_eq!(end, NaiveDate::MAX);
+    }
+
+    #[test]
+    fn test_date_range_expression_at_or_before() {
+        let (start, end) = parse_date_range_expression("<=1973").unwrap();
+        assert_eq!(start, NaiveDate::MIN);
+        assert_eq!(end, NaiveDate::from_ymd_opt(1973, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn test_date_range_expression_rejects_garbage() {
+        assert!(parse_date_range_expression("not-a-date").is_err());
+    }
+
+    #[test]
+    fn test_unparseable_date_field_is_left_in_text_with_a_warning() {
+        let result = parse("date:not-a-date free speech");
+        assert!(result.date_range.is_none());
+        assert!(result.warnings.iter().any(|w| w.starts_with("INVALID_DATE_FIELD")));
+        assert_eq!(result.text, "date:not-a-date free speech");
+    }
+
+    #[test]
+    fn test_unknown_field_left_in_text_with_a_warning() {
+        let result = parse("jurisdiction:federal equal protection");
+        assert!(result.warnings.iter().any(|w| w == "UNKNOWN_FIELD: jurisdiction"));
+        assert_eq!(result.text, "jurisdiction:federal equal protection");
+    }
+
+    #[test]
+    fn test_field_keys_are_case_insensitive() {
+        let result = parse("COURT:\"Supreme Court\" JUDGE:Warren");
+        assert_eq!(result.court, Some(vec!["Supreme Court".to_string()]));
+        assert_eq!(result.judge, Some(vec!["Warren".to_string()]));
+    }
+
+    #[test]
+    fn test_repeated_field_accumulates_multiple_values() {
+        let result = parse("court:\"9th Circuit\" court:\"2nd Circuit\" appeal");
+        assert_eq!(result.court, Some(vec!["9th Circuit".to_string(), "2nd Circuit".to_string()]));
+        assert_eq!(result.text, "appeal");
+    }
+
+    #[test]
+    fn test_plain_quoted_phrase_with_no_field_prefix_keeps_its_quotes() {
+        let result = parse("\"separate but equal\" doctrine");
+        assert!(result.court.is_none());
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.text, "\"separate but equal\" doctrine");
+    }
+
+    #[test]
+    fn test_query_with_no_fields_is_unchanged() {
+        let result = parse("equal protection incorporation");
+        assert_eq!(result.text, "equal protection incorporation");
+        assert_eq!(result, FieldedQuery { text: "equal protection incorporation".to_string(), ..Default::default() });
+    }
+
+    #[test]
+    fn test_colon_in_a_numeric_token_is_not_treated_as_a_field() {
+        // Not a field prefix candidate at all: the key half isn't purely alphabetic.
+        let result = parse("410:113 citation");
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.text, "410:113 citation");
+    }
+
+    #[test]
+    fn test_empty_field_value_is_left_in_text() {
+        let result = parse("court: equal protection");
+        assert!(result.court.is_none());
+        assert_eq!(result.text, "court: equal protection");
+    }
+
+    #[test]
+    fn test_mixed_fields_and_phrase() {
+        let result = parse("court:\"Supreme Court\" judge:Warren \"due process\" incorporation");
+        assert_eq!(result.court, Some(vec!["Supreme Court".to_string()]));
+        assert_eq!(result.judge, Some(vec!["Warren".to_string()]));
+        assert_eq!(result.text, "\"due process\" incorporation");
+    }
+}
@@ -0,0 +1,276 @@
+//! # Offline Index Build Module
+//!
+//! ## Purpose
+//! Builds trie and vector index snapshots from a JSONL export of case records, without
+//! requiring a live `StorageManager`/sled database. Intended for environments — e.g. a data
+//! team's Spark cluster — that only have an exported case dataset, not the running server.
+//!
+//! ## Input/Output Specification
+//! - **Input**: JSONL file of `CaseMetadata` records, one per line
+//! - **Output**: `trie.bin` and (unless `--no-vectors`) `vector_cache.bin` snapshot files plus
+//!   a `manifest.json`, consumable by `SearchEngine::from_snapshot`
+//! - **Memory**: Records are streamed line-by-line so a build's memory footprint is bounded by
+//!   the in-progress indices, not the size of the input file
+//!
+//! ## Note
+//! `CaseMetadata`, the existing case record type, is already `Serialize`/`Deserialize` and is
+//! used here as the canonical JSONL record — the same one
+//! [`crate::storage::StorageManager::export_jsonl`]/[`crate::storage::StorageManager::import_jsonl`]
+//! read and write, so an export from a live database can be fed straight into this module's
+//! `--input` without a conversion step.
+
+use crate::config::Config;
+use crate::errors::Result;
+use crate::text_processing::TextProcessor;
+use crate::trie::{TrieEntry, TrieIndex};
+use crate::vector::{chunk_text, VectorIndex};
+use crate::{CaseMetadata, DocRef};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+/// Number of [`TrieEntry`] values (one case name, plus one per sentence and per citation, for
+/// every case read) buffered before a [`TrieIndex::insert_batch`] flush.
+/// Bounds how much a build's memory footprint grows past "records read so far" (per this
+/// module's own streaming design) while still batching enough entries per flush for shared
+/// prefixes (e.g. many case names starting with "United States v.") to pay off.
+const TRIE_BATCH_FLUSH_SIZE: usize = 500;
+
+/// Options controlling an offline index build
+pub struct IndexBuildOptions {
+    /// Path to the input JSONL file of `CaseMetadata` records
+    pub input_path: PathBuf,
+    /// Directory to write `trie.bin`, `vector_cache.bin`, and `manifest.json` into
+    pub output_dir: PathBuf,
+    /// Skip embedding generation and vector snapshot output entirely
+    pub no_vectors: bool,
+}
+
+/// Summary of a completed offline index build, also written to `manifest.json` alongside
+/// the snapshot files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildManifest {
+    pub cases_indexed: usize,
+    pub content_entries_indexed: usize,
+    pub citations_indexed: usize,
+    pub vectors_generated: usize,
+    pub trie_snapshot: PathBuf,
+    pub vector_snapshot: Option<PathBuf>,
+}
+
+/// Stream `options.input_path` line by line and build trie/vector snapshots into
+/// `options.output_dir`
+pub async fn build_snapshot(config: &Config, options: &IndexBuildOptions) -> Result<BuildManifest> {
+    let mut trie_index = TrieIndex::new(config.trie.clone()).await?;
+    let mut vector_index = if options.no_vectors {
+        None
+    } else {
+        Some(VectorIndex::new(config.vector.clone()).await?)
+    };
+
+    let text_processor = TextProcessor::new(config.text_processing.clone())?;
+    trie_index.set_stopwords(text_processor.stopwords().clone());
+
+    let file = tokio::fs::File::open(&options.input_path).await?;
+    let mut lines = BufReader::new(file).lines();
+
+    let mut cases_indexed = 0usize;
+    let mut content_entries_indexed = 0usize;
+    let mut citations_indexed = 0usize;
+    let mut vectors_generated = 0usize;
+    let mut pending_entries: Vec<TrieEntry> = Vec::with_capacity(TRIE_BATCH_FLUSH_SIZE);
+    let vector_batch_size = config.vector.model.batch_size.max(1);
+    let mut pending_vectors: Vec<(DocRef, String)> = Vec::with_capacity(vector_batch_size);
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let case: CaseMetadata = serde_json::from_str(&line)?;
+
+        pending_entries.push(TrieEntry::CaseName { case_name: case.name.clone(), case_id: case.id });
+
+        let processed = text_processor.process_text(&case.full_text).await?;
+        for (index, sentence) in processed.sentences.iter().enumerate() {
+            let tokens = sentence.word_offsets();
+            if tokens.is_empty() {
+                continue;
+            }
+            pending_entries.push(TrieEntry::Content {
+                tokens,
+                doc_ref: DocRef {
+                    case_id: case.id,
+                    paragraph_index: index,
+                    char_offset: None,
+                },
+            });
+            content_entries_indexed += 1;
+        }
+
+        for citation in &case.citations {
+            pending_entries.push(TrieEntry::Citation {
+                citation: citation.clone(),
+                doc_ref: DocRef {
+                    case_id: case.id,
+                    paragraph_index: 0,
+                    char_offset: None,
+                },
+            });
+            citations_indexed += 1;
+        }
+
+        if pending_entries.len() >= TRIE_BATCH_FLUSH_SIZE {
+            trie_index.insert_batch(std::mem::take(&mut pending_entries))?;
+        }
+
+        if vector_index.is_some() {
+            let chunks = chunk_text(
+                &case.full_text,
+                config.vector.chunking.chunk_size_tokens,
+                config.vector.chunking.overlap_tokens,
+            );
+            for (paragraph_index, (char_offset, chunk)) in chunks.into_iter().enumerate() {
+                pending_vectors.push((
+                    DocRef { case_id: case.id, paragraph_index, char_offset: Some(char_offset) },
+                    chunk,
+                ));
+            }
+            if pending_vectors.len() >= vector_batch_size {
+                vectors_generated += flush_vector_batch(
+                    vector_index.as_mut().expect("checked is_some above"),
+                    std::mem::take(&mut pending_vectors),
+                )
+                .await?;
+            }
+        }
+
+        cases_indexed += 1;
+    }
+
+    if !pending_entries.is_empty() {
+        trie_index.insert_batch(pending_entries)?;
+    }
+
+    if let Some(vector_index) = vector_index.as_mut() {
+        if !pending_vectors.is_empty() {
+            vectors_generated += flush_vector_batch(vector_index, std::mem::take(&mut pending_vectors)).await?;
+        }
+    }
+
+    tokio::fs::create_dir_all(&options.output_dir).await?;
+
+    let trie_snapshot = options.output_dir.join("trie.bin");
+    trie_index.save_to_disk(&trie_snapshot).await?;
+
+    let vector_snapshot = if let Some(vector_index) = vector_index.as_ref() {
+        let path = options.output_dir.join("vector_cache.bin");
+        vector_index.save_to_disk(&path).await?;
+        Some(path)
+    } else {
+        None
+    };
+
+    let manifest = BuildManifest {
+        cases_indexed,
+        content_entries_indexed,
+        citations_indexed,
+        vectors_generated,
+        trie_snapshot,
+        vector_snapshot,
+    };
+
+    let manifest_json = serde_json::to_string_pretty(&manifest)?;
+    tokio::fs::write(options.output_dir.join("manifest.json"), manifest_json).await?;
+
+    Ok(manifest)
+}
+
+/// Embed and index one flush's worth of buffered documents via [`VectorIndex::add_documents`],
+/// logging (rather than aborting the whole build on) any individual document's failure, and
+/// returning how many succeeded.
+async fn flush_vector_batch(
+    vector_index: &mut VectorIndex,
+    batch: Vec<(DocRef, String)>,
+) -> Result<usize> {
+    let outcomes = vector_index.add_documents(batch).await;
+    let mut succeeded = 0usize;
+    for (doc_ref, outcome) in outcomes {
+        match outcome {
+            Ok(()) => succeeded += 1,
+            Err(e) => tracing::warn!("Failed to embed case {}: {}", doc_ref.case_id, e),
+        }
+    }
+    Ok(succeeded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Jurisdiction;
+    use chrono::{NaiveDate, Utc};
+    use uuid::Uuid;
+
+    fn generated_case(index: usize) -> CaseMetadata {
+        CaseMetadata {
+            id: Uuid::new_v4(),
+            name: format!("Test Case {} v. State", index),
+            citation: format!("{} U.S. {}", 100 + index, index),
+            court: "Supreme Court".to_string(),
+            decision_date: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+            judges: vec!["Judge Roe".to_string()],
+            topics: vec!["constitutional-law".to_string()],
+            full_text: format!(
+                "This is the opinion for test case {}. The court holds that freedom of speech applies.",
+                index
+            ),
+            jurisdiction: Jurisdiction::Federal,
+            citations: vec![format!("{} U.S. {}", 100 + index, index)],
+            docket_number: None,
+            source_url: None,
+            word_count: 15,
+            ingestion_date: Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_from_generated_corpus_loads_into_indices() {
+        let temp_dir = std::env::temp_dir().join(format!("index-build-test-{}", Uuid::new_v4()));
+        let input_path = temp_dir.join("cases.jsonl");
+        let output_dir = temp_dir.join("snapshot");
+        tokio::fs::create_dir_all(&temp_dir).await.unwrap();
+
+        let jsonl: String = (0..100)
+            .map(|i| serde_json::to_string(&generated_case(i)).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        tokio::fs::write(&input_path, jsonl).await.unwrap();
+
+        let config = Config::default();
+        let options = IndexBuildOptions {
+            input_path,
+            output_dir: output_dir.clone(),
+            no_vectors: false,
+        };
+
+        let manifest = build_snapshot(&config, &options).await.unwrap();
+        assert_eq!(manifest.cases_indexed, 100);
+        assert_eq!(manifest.vectors_generated, 100);
+        assert!(manifest.vector_snapshot.is_some());
+
+        // The snapshots the build just wrote must be loadable back into fresh indices.
+        let trie_index = TrieIndex::load_from_disk(config.trie.clone(), &manifest.trie_snapshot)
+            .await
+            .unwrap();
+        assert!(trie_index.search("Test Case 0 v. State").unwrap().total_matches > 0);
+
+        let vector_snapshot = manifest.vector_snapshot.unwrap();
+        VectorIndex::load_from_disk(config.vector.clone(), &vector_snapshot)
+            .await
+            .unwrap();
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+}
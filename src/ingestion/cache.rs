@@ -18,47 +18,214 @@
 
 use crate::config::IngestionConfig;
 use crate::errors::{Result, SearchError};
+use crate::ingestion::sources::SourceStats;
+use crate::CaseId;
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Cache manager for ingestion data
 pub struct CacheManager {
     config: IngestionConfig,
     cache_dir: PathBuf,
+    db: Arc<sled::Db>,
+    last_update_tree: sled::Tree,
+    source_stats_tree: sled::Tree,
+    processed_case_tree: sled::Tree,
 }
 
 impl CacheManager {
     /// Create new cache manager
     pub async fn new(config: &IngestionConfig) -> Result<Self> {
         let cache_dir = PathBuf::from("./data/cache");
-        
+
         // Ensure cache directory exists
         tokio::fs::create_dir_all(&cache_dir).await?;
-        
+
+        let db = sled::open(cache_dir.join("ingestion_cache.db"))
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: cache_dir.to_string_lossy().to_string(),
+                reason: format!("Failed to open ingestion cache: {}", e),
+            })?;
+
+        let last_update_tree = db.open_tree("last_update").map_err(|e| {
+            SearchError::DatabaseConnectionFailed {
+                db_path: cache_dir.to_string_lossy().to_string(),
+                reason: format!("Failed to open last_update tree: {}", e),
+            }
+        })?;
+
+        let source_stats_tree = db.open_tree("source_stats").map_err(|e| {
+            SearchError::DatabaseConnectionFailed {
+                db_path: cache_dir.to_string_lossy().to_string(),
+                reason: format!("Failed to open source_stats tree: {}", e),
+            }
+        })?;
+
+        let processed_case_tree = db.open_tree("processed_case").map_err(|e| {
+            SearchError::DatabaseConnectionFailed {
+                db_path: cache_dir.to_string_lossy().to_string(),
+                reason: format!("Failed to open processed_case tree: {}", e),
+            }
+        })?;
+
         Ok(Self {
             config: config.clone(),
             cache_dir,
+            db: Arc::new(db),
+            last_update_tree,
+            source_stats_tree,
+            processed_case_tree,
         })
     }
-    
+
     /// Get last update time for a data source
     pub async fn get_last_update_time(&self, source: &str) -> Result<Option<DateTime<Utc>>> {
-        // TODO: Implement cache lookup
-        Ok(None)
+        match self.last_update_tree.get(source.as_bytes()).map_err(|e| {
+            SearchError::Internal {
+                message: format!("Failed to read last update time for {}: {}", source, e),
+            }
+        })? {
+            Some(value) => {
+                let timestamp: DateTime<Utc> = bincode::deserialize(&value)?;
+                Ok(Some(timestamp))
+            }
+            None => Ok(None),
+        }
     }
-    
+
     /// Set last update time for a data source
     pub async fn set_last_update_time(&self, source: &str, timestamp: DateTime<Utc>) -> Result<()> {
-        // TODO: Implement cache storage
+        let value = bincode::serialize(&timestamp)?;
+        self.last_update_tree
+            .insert(source.as_bytes(), value)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to persist last update time for {}: {}", source, e),
+            })?;
         Ok(())
     }
-    
+
+    /// Load persisted cumulative statistics for a data source, if any
+    pub async fn get_source_stats(&self, source: &str) -> Result<Option<SourceStats>> {
+        match self.source_stats_tree.get(source.as_bytes()).map_err(|e| {
+            SearchError::Internal {
+                message: format!("Failed to read persisted stats for {}: {}", source, e),
+            }
+        })? {
+            Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Persist cumulative statistics for a data source
+    pub async fn save_source_stats(&self, source: &str, stats: &SourceStats) -> Result<()> {
+        let value = bincode::serialize(stats)?;
+        self.source_stats_tree
+            .insert(source.as_bytes(), value)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to persist stats for {}: {}", source, e),
+            })?;
+        Ok(())
+    }
+
+    /// Reset persisted statistics for a data source
+    pub async fn reset_source_stats(&self, source: &str) -> Result<()> {
+        self.source_stats_tree
+            .remove(source.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to reset stats for {}: {}", source, e),
+            })?;
+        Ok(())
+    }
+
     /// Clear cache for a specific source
     pub async fn clear_source_cache(&self, source: &str) -> Result<()> {
-        // TODO: Implement cache clearing
+        self.last_update_tree
+            .remove(source.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to clear cache for {}: {}", source, e),
+            })?;
+        self.reset_source_stats(source).await?;
         Ok(())
     }
     
+    /// Load a previously cached processed-case result for `case_id`, if the pipeline has
+    /// already processed it in an earlier run
+    pub async fn get_processed_case<T: DeserializeOwned>(&self, case_id: &CaseId) -> Result<Option<T>> {
+        match self.processed_case_tree.get(case_id.as_bytes()).map_err(|e| {
+            SearchError::Internal {
+                message: format!("Failed to read cached processed case {}: {}", case_id, e),
+            }
+        })? {
+            Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Cache a processed-case result under `case_id`, overwriting any existing entry
+    pub async fn store_processed_case<T: Serialize>(&self, case_id: &CaseId, value: &T) -> Result<()> {
+        let bytes = bincode::serialize(value)?;
+        self.processed_case_tree
+            .insert(case_id.as_bytes(), bytes)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to cache processed case {}: {}", case_id, e),
+            })?;
+        Ok(())
+    }
+
+    /// Drop every cached processed-case entry. Called by [`crate::ingestion::pipeline::IngestionPipeline`]
+    /// when memory usage crosses `max_memory_usage_mb`, since cached processed results (not the
+    /// on-disk `last_update`/`source_stats` bookkeeping) are what accumulates during a large run.
+    pub async fn clear_memory_cache(&self) -> Result<()> {
+        self.processed_case_tree
+            .clear()
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to clear processed-case cache: {}", e),
+            })?;
+        Ok(())
+    }
+
+    /// Verify the cache database is reachable, mirroring [`crate::storage::StorageManager::health_check`]
+    pub async fn health_check(&self) -> Result<()> {
+        let test_key = b"health_check";
+        let test_value = b"ok";
+
+        self.last_update_tree.insert(test_key, test_value)
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: self.cache_dir.to_string_lossy().to_string(),
+                reason: format!("Health check write failed: {}", e),
+            })?;
+
+        let result = self.last_update_tree.get(test_key)
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: self.cache_dir.to_string_lossy().to_string(),
+                reason: format!("Health check read failed: {}", e),
+            })?;
+
+        if result.is_none() {
+            return Err(SearchError::DatabaseConnectionFailed {
+                db_path: self.cache_dir.to_string_lossy().to_string(),
+                reason: "Health check value not found".to_string(),
+            });
+        }
+
+        self.last_update_tree.remove(test_key)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Health check cleanup failed: {}", e),
+            })?;
+        Ok(())
+    }
+
+    /// Flush all pending writes to disk
+    pub async fn flush(&self) -> Result<()> {
+        self.db.flush_async().await.map_err(|e| SearchError::Internal {
+            message: format!("Failed to flush ingestion cache: {}", e),
+        })?;
+        Ok(())
+    }
+
     /// Get cache statistics
     pub async fn get_cache_stats(&self) -> Result<CacheStats> {
         // TODO: Implement cache statistics
@@ -0,0 +1,194 @@
+//! # Near-Duplicate Detection
+//!
+//! ## Purpose
+//! Catches reprints and parallel citations that exact citation dedup (see
+//! [`crate::ingestion::pipeline::IngestionPipeline`]'s `duplicates_skipped` counter) misses:
+//! reporters routinely republish the same opinion with minor OCR differences, so two records
+//! can have different citations and still be the same case.
+//!
+//! ## Input/Output Specification
+//! - **Input**: Normalized case text
+//! - **Output**: A 64-bit simhash fingerprint, and (via [`DuplicateIndex`]) the id of an
+//!   already-ingested case whose fingerprint is within a configurable Hamming distance
+//!
+//! ## Key Features
+//! - Simhash: a locality-sensitive fingerprint where near-identical texts produce hashes a
+//!   small Hamming distance apart, unlike a cryptographic hash
+//! - Banded LSH lookup so matching a new case's simhash against every previously-seen one
+//!   isn't O(n) per case
+
+use crate::CaseId;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// Number of LSH bands the 64-bit simhash is split into for candidate lookup
+const NUM_BANDS: usize = 8;
+/// Width in bits of each band (`NUM_BANDS * BAND_BITS` must equal 64)
+const BAND_BITS: u32 = 8;
+
+/// Lowercase, alphanumeric-only tokens, matching the normalization the rest of the ingestion
+/// pipeline applies before indexing
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Compute a 64-bit simhash over `text`: each distinct token contributes its term frequency,
+/// weighted by sign, to each bit position of its hash; the result bit is set wherever the
+/// weighted sum across all tokens is positive. Two texts differing by a few OCR typos share
+/// almost all their tokens and land within a few bits of each other; two genuinely different
+/// texts land close to 32 bits apart (chance agreement).
+pub fn simhash(text: &str) -> u64 {
+    let mut term_counts: HashMap<String, i64> = HashMap::new();
+    for token in tokenize(text) {
+        *term_counts.entry(token).or_insert(0) += 1;
+    }
+
+    let mut weights = [0i64; 64];
+    for (token, count) in &term_counts {
+        let mut hasher = DefaultHasher::new();
+        token.hash(&mut hasher);
+        let token_hash = hasher.finish();
+
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            if (token_hash >> bit) & 1 == 1 {
+                *weight += count;
+            } else {
+                *weight -= count;
+            }
+        }
+    }
+
+    let mut hash = 0u64;
+    for (bit, weight) in weights.iter().enumerate() {
+        if *weight > 0 {
+            hash |= 1 << bit;
+        }
+    }
+    hash
+}
+
+/// Number of differing bits between two simhashes
+pub fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// In-memory banded LSH index over ingested cases' simhashes, used to find a near-duplicate
+/// candidate for an incoming case without comparing it against every case ever ingested.
+/// Rebuilt at pipeline startup from `CaseMetadata::content_simhash` already in storage, the
+/// same way `SearchEngine`'s background rebuild replays cases from storage rather than
+/// persisting its own snapshot.
+#[derive(Default)]
+pub struct DuplicateIndex {
+    bands: [HashMap<u16, Vec<(CaseId, u64)>>; NUM_BANDS],
+}
+
+impl DuplicateIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn band_key(hash: u64, band: usize) -> u16 {
+        ((hash >> (band as u32 * BAND_BITS)) & ((1u64 << BAND_BITS) - 1)) as u16
+    }
+
+    /// Record `case_id`'s simhash so later `find_near_duplicate` calls can match against it
+    pub fn insert(&mut self, case_id: CaseId, hash: u64) {
+        for (band, table) in self.bands.iter_mut().enumerate() {
+            table.entry(Self::band_key(hash, band)).or_default().push((case_id, hash));
+        }
+    }
+
+    /// Return the first already-indexed case within `threshold` Hamming distance of `hash`,
+    /// if any candidate sharing an LSH band with it is actually close enough. Two cases that
+    /// share no 8-bit band are never compared, which is how the index avoids scanning
+    /// everything for every lookup; two simhashes fewer than `NUM_BANDS` bits apart always
+    /// share at least one band, and a few more bits apart still almost always do.
+    pub fn find_near_duplicate(&self, hash: u64, threshold: u32) -> Option<CaseId> {
+        let mut checked = HashSet::new();
+        for (band, table) in self.bands.iter().enumerate() {
+            let Some(candidates) = table.get(&Self::band_key(hash, band)) else { continue };
+            for (case_id, candidate_hash) in candidates {
+                if !checked.insert(*case_id) {
+                    continue;
+                }
+                if hamming_distance(hash, *candidate_hash) <= threshold {
+                    return Some(*case_id);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    const OPINION_A: &str = "The court holds that freedom of speech under the First Amendment \
+        extends to symbolic conduct. The defendant's conviction is reversed and the case is \
+        remanded for further proceedings consistent with this opinion.";
+
+    // A few OCR-style typos: "freedom" -> "freedorn", "Amendment" -> "Arnendment", a dropped
+    // word, and a substituted word, but otherwise the same opinion.
+    const OPINION_A_OCR: &str = "The court holds that freedorn of speech under the First \
+        Arnendment extends to symbolic conduct. The defendant's conviction is reversed and the \
+        case remanded for further proceedings consistent with this ruling.";
+
+    const OPINION_B: &str = "The plaintiff alleges a breach of contract arising from a failed \
+        real estate transaction. Summary judgment is granted in favor of the defendant because \
+        no reasonable jury could find the contract terms ambiguous.";
+
+    /// Default `ingestion.dedup.hamming_threshold` (see `Config::default`), duplicated here
+    /// so this test fails loudly if the two drift apart instead of silently testing the
+    /// wrong number.
+    const DEFAULT_HAMMING_THRESHOLD: u32 = 10;
+
+    #[test]
+    fn test_near_duplicate_with_ocr_typos_is_within_default_threshold() {
+        let hash_a = simhash(OPINION_A);
+        let hash_a_ocr = simhash(OPINION_A_OCR);
+        assert!(
+            hamming_distance(hash_a, hash_a_ocr) <= DEFAULT_HAMMING_THRESHOLD,
+            "expected OCR-typo variants within the default hamming_threshold"
+        );
+    }
+
+    #[test]
+    fn test_genuinely_different_cases_exceed_default_threshold() {
+        let hash_a = simhash(OPINION_A);
+        let hash_b = simhash(OPINION_B);
+        assert!(
+            hamming_distance(hash_a, hash_b) > DEFAULT_HAMMING_THRESHOLD,
+            "expected unrelated opinions to exceed the default hamming_threshold"
+        );
+    }
+
+    #[test]
+    fn test_simhash_is_deterministic() {
+        assert_eq!(simhash(OPINION_A), simhash(OPINION_A));
+    }
+
+    #[test]
+    fn test_duplicate_index_finds_near_duplicate_across_bands() {
+        let mut index = DuplicateIndex::new();
+        let canonical_id = Uuid::new_v4();
+        index.insert(canonical_id, simhash(OPINION_A));
+
+        let found = index.find_near_duplicate(simhash(OPINION_A_OCR), DEFAULT_HAMMING_THRESHOLD);
+        assert_eq!(found, Some(canonical_id));
+    }
+
+    #[test]
+    fn test_duplicate_index_does_not_match_unrelated_case() {
+        let mut index = DuplicateIndex::new();
+        index.insert(Uuid::new_v4(), simhash(OPINION_A));
+
+        let found = index.find_near_duplicate(simhash(OPINION_B), DEFAULT_HAMMING_THRESHOLD);
+        assert_eq!(found, None);
+    }
+}
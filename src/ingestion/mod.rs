@@ -31,7 +31,7 @@
 //! use crate::ingestion::{IngestionManager, IngestionConfig};
 //!
 //! let config = IngestionConfig::default();
-//! let manager = IngestionManager::new(config).await?;
+//! let manager = IngestionManager::new(config, storage, text_processing_config).await?;
 //! 
 //! // Bulk ingestion
 //! manager.ingest_bulk().await?;
@@ -44,9 +44,12 @@ pub mod sources;
 pub mod pipeline;
 pub mod validation;
 pub mod cache;
+pub mod dedup;
+pub mod provenance;
 
-use crate::config::IngestionConfig;
+use crate::config::{IngestionConfig, TextProcessingConfig};
 use crate::errors::{Result, SearchError};
+use crate::storage::StorageManager;
 use crate::{CaseId, CaseMetadata};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -58,7 +61,8 @@ use uuid::Uuid;
 
 pub use sources::{cap::CapDataSource, courtlistener::CourtListenerSource, DataSource};
 pub use pipeline::{IngestionPipeline, PipelineStats};
-pub use validation::{CaseValidator, ValidationResult};
+pub use provenance::ProvenanceRecord;
+pub use validation::CaseValidator;
 
 /// Main ingestion manager coordinating all data sources and processing
 pub struct IngestionManager {
@@ -67,7 +71,7 @@ pub struct IngestionManager {
     pipeline: IngestionPipeline,
     validator: CaseValidator,
     semaphore: Arc<Semaphore>,
-    cache: cache::CacheManager,
+    cache: Arc<cache::CacheManager>,
 }
 
 /// Ingestion statistics and progress tracking
@@ -110,6 +114,8 @@ pub struct SourceStats {
     pub processing_errors: usize,
     /// Last successful update
     pub last_update: Option<DateTime<Utc>>,
+    /// Number of times the source's rate limit was hit
+    pub rate_limit_hits: usize,
 }
 
 /// Ingestion job configuration and state
@@ -186,29 +192,33 @@ pub struct IngestionJobConfig {
 
 impl IngestionManager {
     /// Create new ingestion manager with configuration
-    pub async fn new(config: IngestionConfig) -> Result<Self> {
+    pub async fn new(
+        config: IngestionConfig,
+        storage: Arc<StorageManager>,
+        text_processing_config: TextProcessingConfig,
+    ) -> Result<Self> {
         let semaphore = Arc::new(Semaphore::new(config.max_concurrent_downloads));
-        
+
+        // Initialize cache manager first so sources can load/persist their stats through it
+        let cache = Arc::new(cache::CacheManager::new(&config).await?);
+
         // Initialize data sources
         let mut sources: Vec<Box<dyn DataSource + Send + Sync>> = Vec::new();
-        
+
         // Add CAP source
-        let cap_source = sources::cap::CapDataSource::new(config.cap.clone())?;
+        let cap_source = sources::cap::CapDataSource::new(config.cap.clone(), cache.clone()).await?;
         sources.push(Box::new(cap_source));
-        
+
         // Add CourtListener source
         let cl_source = sources::courtlistener::CourtListenerSource::new(config.courtlistener.clone()).await?;
         sources.push(Box::new(cl_source));
-        
+
         // Initialize pipeline
-        let pipeline = IngestionPipeline::new(config.clone()).await?;
-        
+        let pipeline = IngestionPipeline::new(config.clone(), storage, text_processing_config).await?;
+
         // Initialize validator
-        let validator = CaseValidator::new()?;
-        
-        // Initialize cache manager
-        let cache = cache::CacheManager::new(&config).await?;
-        
+        let validator = CaseValidator::new(config.validation.clone())?;
+
         Ok(Self {
             config,
             sources,
@@ -335,12 +345,13 @@ impl IngestionManager {
         Ok(())
     }
     
-    /// Execute data reprocessing
-    async fn execute_reprocessing(&self, _job: &mut IngestionJob) -> Result<()> {
-        // TODO: Implement reprocessing logic
-        Err(SearchError::NotSupported {
-            operation: "Data reprocessing".to_string(),
-        })
+    /// Execute data reprocessing: re-run text processing for every stored case against its
+    /// `Raw` text, so an improved normalizer's benefits reach cases ingested under an older one
+    /// without re-fetching them from their original source.
+    async fn execute_reprocessing(&self, job: &mut IngestionJob) -> Result<()> {
+        let reprocessed = self.pipeline.reprocess_all_cases().await?;
+        job.stats.total_processed += reprocessed;
+        Ok(())
     }
     
     /// Process bulk data from a specific source
@@ -353,6 +364,7 @@ impl IngestionManager {
             download_errors: 0,
             processing_errors: 0,
             last_update: None,
+            rate_limit_hits: 0,
         };
         
         // Get available case IDs from source
@@ -383,6 +395,7 @@ impl IngestionManager {
             download_errors: 0,
             processing_errors: 0,
             last_update: None,
+            rate_limit_hits: 0,
         };
         
         // Get last update timestamp for this source
@@ -421,61 +434,95 @@ impl IngestionManager {
         case_ids: &[String],
         stats: &mut SourceStats,
     ) -> Result<Vec<Result<CaseMetadata>>> {
-        let mut results = Vec::new();
-        
-        // Create futures for concurrent processing
+        // Create futures for concurrent processing. Each future reports which counters it hit
+        // instead of mutating `stats` itself, since `stats` can only be borrowed mutably once
+        // batch-processing.
         let mut futures = Vec::new();
-        
+
         for case_id in case_ids {
             let permit = self.semaphore.clone().acquire_owned().await.unwrap();
             let case_id = case_id.clone();
             let source_name = source.name().to_string();
-            
+
             let future = async move {
                 let _permit = permit; // Hold permit for duration of operation
-                
+
                 // Download case data
                 let case_data = match source.fetch_case(&case_id).await {
-                    Ok(data) => {
-                        stats.downloaded += 1;
-                        data
-                    }
+                    Ok(data) => data,
                     Err(e) => {
-                        stats.download_errors += 1;
                         tracing::warn!("Failed to download case {} from {}: {}", case_id, source_name, e);
-                        return Err(e);
+                        return CaseBatchOutcome { result: Err(e), downloaded: false, processed: false };
                     }
                 };
-                
+
                 // Validate case data
-                match self.validator.validate(&case_data) {
-                    Ok(_) => {
-                        stats.processed += 1;
-                        Ok(case_data)
-                    }
+                match self.validator.validate_case(&case_data, &case_data.full_text).await {
+                    Ok(_) => CaseBatchOutcome { result: Ok(case_data), downloaded: true, processed: true },
                     Err(e) => {
-                        stats.processing_errors += 1;
                         tracing::warn!("Case validation failed for {}: {}", case_id, e);
-                        Err(e)
+                        CaseBatchOutcome { result: Err(e), downloaded: true, processed: false }
                     }
                 }
             };
-            
+
             futures.push(future);
         }
-        
-        // Execute all futures concurrently
+
+        // Execute all futures concurrently, then apply their outcomes to `stats` sequentially
         let batch_results = futures::future::join_all(futures).await;
-        results.extend(batch_results);
-        
+
+        let mut results = Vec::with_capacity(batch_results.len());
+        for outcome in batch_results {
+            if outcome.downloaded {
+                stats.downloaded += 1;
+                if outcome.processed {
+                    stats.processed += 1;
+                } else {
+                    stats.processing_errors += 1;
+                }
+            } else {
+                stats.download_errors += 1;
+            }
+            results.push(outcome.result);
+        }
+
         Ok(results)
     }
-    
+
     /// Get current ingestion statistics
     pub fn get_stats(&self) -> IngestionStats {
         // TODO: Implement real-time statistics collection
         IngestionStats::new()
     }
+
+    /// Get persisted cumulative statistics for every configured data source
+    pub async fn get_all_source_stats(&self) -> Result<HashMap<String, sources::SourceStats>> {
+        let mut all_stats = HashMap::new();
+        for source in &self.sources {
+            all_stats.insert(source.name().to_string(), source.get_stats().await?);
+        }
+        Ok(all_stats)
+    }
+
+    /// Reset the persisted cumulative statistics for a single data source
+    pub async fn reset_source_stats(&self, source_name: &str) -> Result<()> {
+        self.cache.reset_source_stats(source_name).await
+    }
+
+    /// Full ingest provenance history for a case, oldest first; empty for a case that predates
+    /// provenance tracking. See [`provenance::ProvenanceStore`] for what's recorded.
+    pub async fn case_provenance(&self, case_id: CaseId) -> Result<Vec<ProvenanceRecord>> {
+        self.pipeline.provenance_history(case_id).await
+    }
+}
+
+/// Which [`SourceStats`] counters a single case's processing in [`IngestionManager::process_case_batch`]
+/// should bump, reported back from its future instead of mutating `stats` directly
+struct CaseBatchOutcome {
+    result: Result<CaseMetadata>,
+    downloaded: bool,
+    processed: bool,
 }
 
 impl IngestionStats {
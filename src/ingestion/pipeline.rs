@@ -16,13 +16,18 @@
 //! - Progress tracking and performance metrics
 //! - Incremental updates and deduplication
 
-use crate::config::{IngestionConfig, TextProcessingConfig};
+use crate::config::{DedupAction, IngestionConfig, TextProcessingConfig};
 use crate::errors::{Result, SearchError};
 use crate::ingestion::cache::CacheManager;
+use crate::ingestion::dedup::{self, DuplicateIndex};
+use crate::ingestion::provenance::{ProvenanceRecord, ProvenanceStore, SCHEMA_VERSION};
 use crate::ingestion::sources::{DataSource, SourceStats};
 use crate::ingestion::validation::CaseValidator as DataValidator;
-use crate::storage::StorageManager;
-use crate::text_processing::{ProcessedText, TextProcessor};
+use crate::storage::{StorageManager, TextForm};
+use crate::taxonomy::Taxonomy;
+use crate::text_processing::{ProcessedArtifacts, ProcessedText, TextProcessor};
+use crate::utils::InstrumentedRwLock;
+use crate::vector::VectorIndex;
 use crate::{CaseId, CaseMetadata};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -38,8 +43,19 @@ pub struct IngestionPipeline {
     text_processor: Arc<TextProcessor>,
     validator: Arc<DataValidator>,
     cache_manager: Arc<CacheManager>,
+    taxonomy: Arc<Taxonomy>,
     stats: Arc<RwLock<PipelineStats>>,
     processing_semaphore: Arc<Semaphore>,
+    /// LSH index over already-ingested cases' content simhashes, consulted by
+    /// `ingestion.dedup` before a case is stored. Rebuilt from storage at startup.
+    duplicate_index: Arc<RwLock<DuplicateIndex>>,
+    /// Per-case ingest provenance history, on a tree of the same storage database
+    provenance: Arc<ProvenanceStore>,
+    /// Shared handle to the search engine's vector index, consulted by
+    /// `ingestion.dedup.semantic_similarity_threshold` before a case is stored. `None` when the
+    /// pipeline was constructed without one, in which case semantic dedup is skipped regardless
+    /// of config (e.g. in tests that don't need a real embedding model).
+    vector_index: Option<Arc<InstrumentedRwLock<VectorIndex>>>,
 }
 
 /// Pipeline execution statistics
@@ -55,6 +71,13 @@ pub struct PipelineStats {
     pub validation_failures: usize,
     /// Duplicate cases skipped
     pub duplicates_skipped: usize,
+    /// Cases whose content simhash matched an already-ingested case within
+    /// `ingestion.dedup.hamming_threshold` (see `ingestion::dedup`), regardless of what
+    /// `ingestion.dedup.on_match` did with them
+    pub near_duplicates_detected: usize,
+    /// Number of cases blocked by each error-severity validation rule, keyed by rule name
+    /// (see `ValidationConfig::rule_severity`)
+    pub blocked_by_rule: HashMap<String, usize>,
     /// Processing rate (cases per second)
     pub processing_rate: f64,
     /// Start time of current run
@@ -84,6 +107,9 @@ struct ProcessingJob {
     cases: Vec<(CaseMetadata, String)>,
     batch_id: usize,
     source_name: String,
+    /// Shared across every batch of one `run_ingestion` call, so provenance for cases ingested
+    /// in the same run can be traced back to that run
+    job_id: String,
 }
 
 /// Processing result for a batch
@@ -104,6 +130,8 @@ impl Default for PipelineStats {
             failed_processing: 0,
             validation_failures: 0,
             duplicates_skipped: 0,
+            near_duplicates_detected: 0,
+            blocked_by_rule: HashMap::new(),
             processing_rate: 0.0,
             start_time: None,
             end_time: None,
@@ -123,25 +151,62 @@ impl IngestionPipeline {
         config: IngestionConfig,
         storage: Arc<StorageManager>,
         text_processing_config: TextProcessingConfig,
+    ) -> Result<Self> {
+        Self::new_with_vector_index(config, storage, text_processing_config, None).await
+    }
+
+    /// Like [`IngestionPipeline::new`], but also wires in the vector index that
+    /// `ingestion.dedup.semantic_similarity_threshold` checks against (see
+    /// [`VectorIndex::find_near_duplicates`]). Passing `None` disables semantic dedup
+    /// regardless of config, same as `new`.
+    pub async fn new_with_vector_index(
+        config: IngestionConfig,
+        storage: Arc<StorageManager>,
+        text_processing_config: TextProcessingConfig,
+        vector_index: Option<Arc<InstrumentedRwLock<VectorIndex>>>,
     ) -> Result<Self> {
         let text_processor = Arc::new(TextProcessor::new(text_processing_config)?);
-        let validator = Arc::new(DataValidator::new(config.validation.clone()));
-        let cache_manager = Arc::new(CacheManager::new(config.cache.clone()).await?);
-        
+        let validator = Arc::new(DataValidator::new(config.validation.clone())?);
+        let cache_manager = Arc::new(CacheManager::new(&config).await?);
+        let taxonomy = Arc::new(Taxonomy::load_bundled()?);
+
         let stats = Arc::new(RwLock::new(PipelineStats::default()));
         let processing_semaphore = Arc::new(Semaphore::new(config.max_concurrent_jobs));
 
+        let duplicate_index = Arc::new(RwLock::new(Self::load_duplicate_index(&storage).await?));
+        let provenance = Arc::new(ProvenanceStore::new(storage.db()).await?);
+
         Ok(Self {
             config,
             storage,
             text_processor,
             validator,
             cache_manager,
+            taxonomy,
             stats,
             processing_semaphore,
+            duplicate_index,
+            provenance,
+            vector_index,
         })
     }
 
+    /// Replay every stored case's `content_simhash` into a fresh [`DuplicateIndex`], mirroring
+    /// how `SearchEngine`'s background rebuild replays cases from storage rather than
+    /// persisting its own snapshot. Cases ingested before near-duplicate detection was enabled
+    /// have no `content_simhash` and are simply skipped.
+    async fn load_duplicate_index(storage: &StorageManager) -> Result<DuplicateIndex> {
+        let mut index = DuplicateIndex::new();
+        for case_id in storage.list_case_ids().await? {
+            if let Some(metadata) = storage.get_case_metadata(&case_id).await? {
+                if let Some(hash) = metadata.content_simhash {
+                    index.insert(case_id, hash);
+                }
+            }
+        }
+        Ok(index)
+    }
+
     /// Run full ingestion pipeline with a data source
     pub async fn run_ingestion<T: DataSource + Send + Sync>(
         &self,
@@ -166,7 +231,25 @@ impl IngestionPipeline {
 
         // Fetch cases from data source
         tracing::info!("Fetching cases from data source");
-        let cases = data_source.fetch_cases(limit).await?;
+        let mut case_ids = data_source.list_available_cases().await?;
+        if let Some(limit) = limit {
+            case_ids.truncate(limit);
+        }
+        let cases: Vec<(CaseMetadata, String)> = data_source
+            .fetch_cases(&case_ids)
+            .await?
+            .into_iter()
+            .filter_map(|result| match result {
+                Ok(metadata) => {
+                    let full_text = metadata.full_text.clone();
+                    Some((metadata, full_text))
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch case: {}", e);
+                    None
+                }
+            })
+            .collect();
         tracing::info!("Fetched {} cases from source", cases.len());
 
         if cases.is_empty() {
@@ -186,16 +269,18 @@ impl IngestionPipeline {
         let batch_size = self.config.batch_size;
         let total_batches = (cases.len() + batch_size - 1) / batch_size;
         
-        tracing::info!("Processing {} cases in {} batches of size {}", 
+        tracing::info!("Processing {} cases in {} batches of size {}",
             cases.len(), total_batches, batch_size);
 
+        let job_id = uuid::Uuid::new_v4().to_string();
         let mut batch_results = Vec::new();
-        
+
         for (batch_id, batch) in cases.chunks(batch_size).enumerate() {
             let job = ProcessingJob {
                 cases: batch.to_vec(),
                 batch_id,
                 source_name: data_source.get_source_config().name.clone(),
+                job_id: job_id.clone(),
             };
 
             let result = self.process_batch(job).await?;
@@ -251,7 +336,7 @@ impl IngestionPipeline {
         let mut errors = Vec::new();
 
         for (metadata, raw_text) in job.cases {
-            match self.process_single_case(metadata, raw_text).await {
+            match self.process_single_case(metadata, raw_text, &job.source_name, &job.job_id).await {
                 Ok(processed) => {
                     if processed {
                         successful_count += 1;
@@ -282,7 +367,13 @@ impl IngestionPipeline {
     }
 
     /// Process a single case through the complete pipeline
-    async fn process_single_case(&self, metadata: CaseMetadata, raw_text: String) -> Result<bool> {
+    async fn process_single_case(
+        &self,
+        metadata: CaseMetadata,
+        raw_text: String,
+        source_name: &str,
+        job_id: &str,
+    ) -> Result<bool> {
         // Check for duplicates
         if self.storage.case_exists(&metadata.id).await? {
             let mut stats = self.stats.write().await;
@@ -291,30 +382,105 @@ impl IngestionPipeline {
         }
 
         // Check cache
-        if let Some(cached_result) = self.cache_manager.get_processed_case(&metadata.id).await? {
+        if let Some(cached_result) = self.cache_manager.get_processed_case::<CachedProcessingResult>(&metadata.id).await? {
             tracing::debug!("Using cached result for case: {}", metadata.id);
-            self.storage.store_case_metadata(&cached_result.metadata).await?;
-            self.storage.store_case_text(&metadata.id, &cached_result.processed_text.normalized).await?;
+            self.storage.store_case_atomic(
+                &cached_result.metadata,
+                &cached_result.processed_text.original,
+                &cached_result.processed_text.normalized,
+            ).await?;
+            let artifacts = ProcessedArtifacts::from(&cached_result.processed_text);
+            if let Err(e) = self.storage.store_processed(&cached_result.metadata.id, &artifacts).await {
+                tracing::warn!("Failed to store processed artifacts for cached case {}: {}", cached_result.metadata.id, e);
+            }
+            self.record_provenance(&cached_result.metadata, source_name, job_id).await?;
             return Ok(true);
         }
 
-        // Validate input data
-        if let Err(validation_error) = self.validator.validate_case(&metadata, &raw_text).await {
-            tracing::warn!("Case validation failed: {}", validation_error);
-            let mut stats = self.stats.write().await;
-            stats.validation_failures += 1;
-            return Err(validation_error);
-        }
+        // Validate input data; a blocking (error-severity) rule violation aborts the case,
+        // a non-blocking (warn-severity) one is recorded on the case record instead
+        let validation_warnings = match self.validator.validate_case(&metadata, &raw_text).await {
+            Ok(warnings) => warnings,
+            Err(validation_error) => {
+                tracing::warn!("Case validation failed: {}", validation_error);
+                let mut stats = self.stats.write().await;
+                stats.validation_failures += 1;
+                if let SearchError::ValidationFailed { field, .. } = &validation_error {
+                    *stats.blocked_by_rule.entry(field.clone()).or_insert(0) += 1;
+                }
+                return Err(validation_error);
+            }
+        };
 
         // Process text
         let processed_text = self.text_processor.process_text(&raw_text).await?;
 
         // Create enhanced metadata with processing results
-        let enhanced_metadata = self.enhance_metadata(metadata, &processed_text)?;
+        let mut enhanced_metadata = self.enhance_metadata(metadata, &processed_text)?;
+        enhanced_metadata.validation_warnings = validation_warnings;
+
+        // Near-duplicate detection: reprints and parallel citations slip past the exact-id
+        // check above because they arrive under a different case id
+        if self.config.dedup.enabled {
+            let hash = dedup::simhash(&processed_text.normalized);
+            enhanced_metadata.content_simhash = Some(hash);
+
+            let canonical = self
+                .duplicate_index
+                .read()
+                .await
+                .find_near_duplicate(hash, self.config.dedup.hamming_threshold);
+
+            if let Some(canonical_id) = canonical {
+                self.stats.write().await.near_duplicates_detected += 1;
+                tracing::info!(
+                    "Case {} flagged as a near-duplicate of {}",
+                    enhanced_metadata.id,
+                    canonical_id
+                );
+
+                match self.config.dedup.on_match {
+                    DedupAction::Skip => return Ok(false),
+                    DedupAction::Link => enhanced_metadata.duplicate_of = Some(canonical_id),
+                    DedupAction::Store => {}
+                }
+            }
+
+            self.duplicate_index.write().await.insert(enhanced_metadata.id, hash);
+        }
+
+        // Semantic near-duplicate detection: catches reprints the simhash check misses (e.g. a
+        // re-typeset opinion whose text differs enough to shift the content simhash but whose
+        // meaning embeds to nearly the same vector). Only runs when both a vector index has been
+        // wired in and `ingestion.dedup.semantic_similarity_threshold` is set.
+        if let (Some(vector_index), Some(threshold)) =
+            (&self.vector_index, self.config.dedup.semantic_similarity_threshold)
+        {
+            let matches = vector_index
+                .write("process_single_case:find_near_duplicates")
+                .await
+                .find_near_duplicates(&processed_text.normalized, threshold)
+                .await?;
+
+            if let Some((doc_ref, similarity)) = matches.into_iter().next() {
+                self.stats.write().await.duplicates_skipped += 1;
+                tracing::info!(
+                    "Case {} skipped as a semantic duplicate of case {} (similarity {:.4})",
+                    enhanced_metadata.id,
+                    doc_ref.case_id,
+                    similarity
+                );
+                return Ok(false);
+            }
+        }
 
         // Store in database
-        self.storage.store_case_metadata(&enhanced_metadata).await?;
-        self.storage.store_case_text(&enhanced_metadata.id, &processed_text.normalized).await?;
+        self.storage.store_case_atomic(&enhanced_metadata, &processed_text.original, &processed_text.normalized).await?;
+        let artifacts = ProcessedArtifacts::from(&processed_text);
+        if let Err(e) = self.storage.store_processed(&enhanced_metadata.id, &artifacts).await {
+            tracing::warn!("Failed to store processed artifacts for case {}: {}", enhanced_metadata.id, e);
+        }
+        self.record_provenance(&enhanced_metadata, source_name, job_id).await?;
 
         // Cache the result
         let cache_entry = CachedProcessingResult {
@@ -332,6 +498,21 @@ impl IngestionPipeline {
         Ok(true)
     }
 
+    /// Append a provenance record for a case that was just stored, so a maintainer tracing a
+    /// wrong-looking result later can see which source and job produced it
+    async fn record_provenance(&self, metadata: &CaseMetadata, source_name: &str, job_id: &str) -> Result<()> {
+        let source_case_id = metadata.source_url.clone().unwrap_or_else(|| metadata.id.to_string());
+        self.provenance.record(metadata.id, ProvenanceRecord {
+            source_name: source_name.to_string(),
+            source_case_id,
+            job_id: job_id.to_string(),
+            ingested_at: chrono::Utc::now(),
+            pipeline_version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: SCHEMA_VERSION,
+            validation_warning_count: metadata.validation_warnings.len(),
+        }).await
+    }
+
     /// Enhance metadata with processing results
     fn enhance_metadata(&self, mut metadata: CaseMetadata, processed_text: &ProcessedText) -> Result<CaseMetadata> {
         // Update word count from processed text
@@ -356,6 +537,11 @@ impl IngestionPipeline {
                 .collect();
         }
 
+        // Classify into taxonomy nodes if not already present
+        if metadata.topics.is_empty() {
+            metadata.topics = self.taxonomy.classify(&processed_text.normalized);
+        }
+
         Ok(metadata)
     }
 
@@ -434,6 +620,62 @@ impl IngestionPipeline {
         *stats = PipelineStats::default();
     }
 
+    /// Re-run text processing for one already-stored case against its stored `Raw` text,
+    /// rewriting the `Normalized` text with the pipeline's current normalizer. Reprocessing
+    /// reads `Raw` rather than the (possibly stale-normalizer) `Normalized` text so an improved
+    /// normalizer's benefits reach cases ingested under an older one; a case stored before raw
+    /// text was kept separately falls back to reprocessing its normalized text, per
+    /// [`StorageManager::get_case_text`]'s migration fallback.
+    pub async fn reprocess_case(&self, case_id: CaseId) -> Result<()> {
+        let Some(raw) = self.storage.get_case_text(&case_id, TextForm::Raw).await? else {
+            return Err(SearchError::CaseNotFound { case_id });
+        };
+
+        let processed_text = self.text_processor.process_text(&raw.text).await?;
+        self.storage.store_case_text(&case_id, &processed_text.original, &processed_text.normalized).await?;
+
+        // Carry the original source name forward rather than losing it, since a reprocess
+        // isn't a fresh ingest from any source
+        let source_name = self.provenance.latest(case_id).await?
+            .map(|record| record.source_name)
+            .unwrap_or_else(|| "reprocess".to_string());
+        self.provenance.record(case_id, ProvenanceRecord {
+            source_name,
+            source_case_id: case_id.to_string(),
+            job_id: format!("reprocess-{}", uuid::Uuid::new_v4()),
+            ingested_at: chrono::Utc::now(),
+            pipeline_version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: SCHEMA_VERSION,
+            validation_warning_count: 0,
+        }).await?;
+
+        tracing::debug!("Reprocessed case: {}", case_id);
+        Ok(())
+    }
+
+    /// Full ingest provenance history for a case, oldest first; see [`ProvenanceStore::history`]
+    pub async fn provenance_history(&self, case_id: CaseId) -> Result<Vec<ProvenanceRecord>> {
+        self.provenance.history(case_id).await
+    }
+
+    /// Reprocess every stored case's text against the pipeline's current normalizer. Used to
+    /// implement [`crate::ingestion::IngestionManager`]'s `Reprocess` job type; a per-case
+    /// failure is logged and counted rather than aborting the whole run. Returns the number of
+    /// cases successfully reprocessed.
+    pub async fn reprocess_all_cases(&self) -> Result<usize> {
+        let case_ids = self.storage.list_case_ids().await?;
+        let mut reprocessed = 0;
+
+        for case_id in case_ids {
+            match self.reprocess_case(case_id).await {
+                Ok(()) => reprocessed += 1,
+                Err(e) => tracing::warn!("Failed to reprocess case {}: {}", case_id, e),
+            }
+        }
+
+        Ok(reprocessed)
+    }
+
     /// Health check for the pipeline
     pub async fn health_check(&self) -> Result<()> {
         // Check storage health
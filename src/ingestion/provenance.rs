@@ -0,0 +1,147 @@
+//! # Case Ingest Provenance
+//!
+//! ## Purpose
+//! Tracks, per case, which source produced it, which ingestion job, when, and with what
+//! pipeline/schema version — the trail a maintainer needs when a result looks wrong and the
+//! question is "where did this come from and has it been reprocessed since". Mirrors
+//! `ModelMigrationManager`'s pattern of a small manager owning a dedicated sled tree on the
+//! shared storage database rather than adding more fields to `CaseMetadata` itself.
+//!
+//! ## Input/Output Specification
+//! - **Input**: A [`ProvenanceRecord`] per ingest or reprocess of a case
+//! - **Output**: The full, append-only history for a case, oldest first
+//! - **Persistence**: One sled entry per case, holding its full history as a bincode-encoded
+//!   `Vec<ProvenanceRecord>`; re-ingestion appends rather than overwriting
+
+use crate::errors::{Result, SearchError};
+use crate::CaseId;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Current shape of `CaseMetadata`/`ProcessedText`, bumped whenever it changes in a way that
+/// makes previously-ingested records worth reprocessing. Recorded on every provenance entry so
+/// "which cases were ingested under an old schema" is a query over this tree, not a guess.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// One ingest (or reprocess) event for a case
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceRecord {
+    /// Name of the data source that produced this record (see `SourceConfig::name`), or
+    /// `"reprocess"`/a carried-forward source name for records written by reprocessing rather
+    /// than a fresh ingest
+    pub source_name: String,
+    /// Identifier for this case within its source. This repo's `CaseMetadata` has no dedicated
+    /// source-id field, so this falls back to `source_url` when present, else the case's own id
+    pub source_case_id: String,
+    /// Identifier of the ingestion run (or reprocess run) that wrote this record
+    pub job_id: String,
+    /// When this record was written
+    pub ingested_at: chrono::DateTime<chrono::Utc>,
+    /// `CARGO_PKG_VERSION` of the pipeline that wrote this record
+    pub pipeline_version: String,
+    /// [`SCHEMA_VERSION`] at the time this record was written
+    pub schema_version: u32,
+    /// Number of non-blocking validation warnings recorded for the case at this ingest
+    pub validation_warning_count: usize,
+}
+
+/// Append-only per-case ingest provenance history, backed by a dedicated sled tree on the
+/// shared storage database
+pub struct ProvenanceStore {
+    tree: sled::Tree,
+}
+
+impl ProvenanceStore {
+    /// Open the provenance tree on `db`, the same database [`crate::storage::StorageManager`]
+    /// uses for everything else
+    pub async fn new(db: Arc<sled::Db>) -> Result<Self> {
+        let tree = db.open_tree("case_provenance").map_err(|e| SearchError::Internal {
+            message: format!("Failed to open provenance tree: {}", e),
+        })?;
+        Ok(Self { tree })
+    }
+
+    /// Append a record to `case_id`'s history
+    pub async fn record(&self, case_id: CaseId, entry: ProvenanceRecord) -> Result<()> {
+        let mut history = self.history(case_id).await?;
+        history.push(entry);
+
+        let value = bincode::serialize(&history)?;
+        self.tree.insert(case_id.as_bytes(), value).map_err(|e| SearchError::Internal {
+            message: format!("Failed to persist provenance for {}: {}", case_id, e),
+        })?;
+        Ok(())
+    }
+
+    /// Full ingest history for a case, oldest first. Empty (not an error) for a case that
+    /// predates provenance tracking or was never ingested through a path that records it
+    pub async fn history(&self, case_id: CaseId) -> Result<Vec<ProvenanceRecord>> {
+        match self.tree.get(case_id.as_bytes()).map_err(|e| SearchError::Internal {
+            message: format!("Failed to read provenance for {}: {}", case_id, e),
+        })? {
+            Some(bytes) => Ok(bincode::deserialize(&bytes)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Most recent provenance record for a case, if any
+    pub async fn latest(&self, case_id: CaseId) -> Result<Option<ProvenanceRecord>> {
+        Ok(self.history(case_id).await?.into_iter().last())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_store() -> ProvenanceStore {
+        let db_path = std::env::temp_dir().join(format!("provenance-test-{}", uuid::Uuid::new_v4()));
+        let db = Arc::new(sled::open(db_path).unwrap());
+        ProvenanceStore::new(db).await.unwrap()
+    }
+
+    fn sample_record(job_id: &str) -> ProvenanceRecord {
+        ProvenanceRecord {
+            source_name: "cap".to_string(),
+            source_case_id: "cap-12345".to_string(),
+            job_id: job_id.to_string(),
+            ingested_at: chrono::Utc::now(),
+            pipeline_version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: SCHEMA_VERSION,
+            validation_warning_count: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_is_empty_for_an_unknown_case() {
+        let store = test_store().await;
+        assert!(store.history(uuid::Uuid::new_v4()).await.unwrap().is_empty());
+        assert!(store.latest(uuid::Uuid::new_v4()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_record_is_retrievable_via_history_and_latest() {
+        let store = test_store().await;
+        let case_id = uuid::Uuid::new_v4();
+        store.record(case_id, sample_record("job-1")).await.unwrap();
+
+        let history = store.history(case_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].job_id, "job-1");
+        assert_eq!(store.latest(case_id).await.unwrap().unwrap().job_id, "job-1");
+    }
+
+    #[tokio::test]
+    async fn test_reingestion_appends_rather_than_overwrites() {
+        let store = test_store().await;
+        let case_id = uuid::Uuid::new_v4();
+        store.record(case_id, sample_record("job-1")).await.unwrap();
+        store.record(case_id, sample_record("job-2")).await.unwrap();
+
+        let history = store.history(case_id).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].job_id, "job-1");
+        assert_eq!(history[1].job_id, "job-2");
+        assert_eq!(store.latest(case_id).await.unwrap().unwrap().job_id, "job-2");
+    }
+}
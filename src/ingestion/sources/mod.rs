@@ -49,6 +49,12 @@ pub struct SourceHealth {
     pub last_check: DateTime<Utc>,
     pub response_time_ms: u64,
     pub error_message: Option<String>,
+    /// Requests remaining in the source's current rate-limit window, parsed from a response
+    /// header (e.g. CAP's `X-RateLimit-Remaining`) when the source reports one. `None` when
+    /// the source doesn't expose quota headers, or the health status came from a cached probe
+    /// taken before this field existed.
+    #[serde(default)]
+    pub remaining_quota: Option<u64>,
 }
 
 /// Information about a data source
@@ -79,6 +85,8 @@ pub struct SourceStats {
     pub download_errors: usize,
     pub processing_errors: usize,
     pub last_update: Option<DateTime<Utc>>,
+    /// Number of times the source's rate limit was hit
+    pub rate_limit_hits: usize,
 }
 
 /// Trait for legal data sources
@@ -132,20 +140,6 @@ pub struct SourceConfig {
     pub retry_attempts: u32,
 }
 
-impl Default for SearchQuery {
-    fn default() -> Self {
-        Self {
-            query: None,
-            court: None,
-            date_range: None,
-            judge: None,
-            case_type: None,
-            limit: Some(100),
-            offset: Some(0),
-        }
-    }
-}
-
 impl Default for RateLimits {
     fn default() -> Self {
         Self {
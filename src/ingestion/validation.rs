@@ -6,88 +6,297 @@
 //!
 //! ## Input/Output Specification
 //! - **Input**: CaseMetadata structures from various sources
-//! - **Output**: ValidationResult with pass/fail status and detailed feedback
-//! - **Validation Rules**: Format, completeness, consistency, legal citation format
+//! - **Output**: Either a set of non-blocking warnings (recorded on the case) or a blocking
+//!   error, depending on the resolved severity of the rule that fired
+//! - **Validation Rules**: Text length, required fields, legal citation format
 //!
 //! ## Key Features
-//! - Comprehensive validation rules for legal data
-//! - Configurable validation severity levels
-//! - Detailed error reporting and suggestions
-//! - Performance-optimized validation checks
-//! - Extensible rule system
+//! - Per-rule severity, configurable per deployment (see [`crate::config::RuleSeverity`])
+//! - Non-blocking violations are surfaced to the caller as warning strings, so they can be
+//!   attached to `CaseMetadata::validation_warnings`
+//! - Extensible rule system: add a [`ValidationRule`] impl and register it in
+//!   [`CaseValidator::new`]; add its name to [`KNOWN_RULE_NAMES`] so
+//!   `Config::validate` accepts overrides for it
 
+use crate::config::{RuleSeverity, ValidationConfig};
 use crate::errors::{Result, SearchError};
 use crate::CaseMetadata;
-use serde::{Deserialize, Serialize};
 
-/// Case data validator
-pub struct CaseValidator {
-    rules: Vec<Box<dyn ValidationRule + Send + Sync>>,
-}
+/// Every rule name [`CaseValidator`] can register, i.e. every valid key for
+/// `ValidationConfig::rule_severity`. `Config::validate` rejects any other key at startup.
+pub const KNOWN_RULE_NAMES: &[&str] = &["text_length", "required_fields", "citation_format"];
 
-/// Trait for validation rules
-pub trait ValidationRule {
+/// Trait for validation rules. A rule reports at most one violation message per case; the
+/// severity that violation is treated with (warn/error/off) is resolved by [`CaseValidator`]
+/// from configuration, not by the rule itself.
+trait ValidationRule {
+    /// Stable name used both as the config key (`ValidationConfig::rule_severity`) and as the
+    /// label attached to warnings/errors this rule produces
     fn name(&self) -> &str;
-    fn validate(&self, case: &CaseMetadata) -> ValidationResult;
+
+    /// Severity applied when configuration has no override for this rule
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Error
+    }
+
+    /// Returns a human-readable violation message if the rule fails for this case, or `None`
+    /// if the case satisfies the rule
+    fn check(&self, case: &CaseMetadata, raw_text: &str) -> Option<String>;
+}
+
+/// Case text must fall within `[min_text_length, max_text_length]`
+struct TextLengthRule {
+    min_text_length: usize,
+    max_text_length: usize,
 }
 
-/// Result of validation check
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ValidationResult {
-    pub passed: bool,
-    pub errors: Vec<ValidationError>,
-    pub warnings: Vec<ValidationWarning>,
+impl ValidationRule for TextLengthRule {
+    fn name(&self) -> &str {
+        "text_length"
+    }
+
+    fn check(&self, _case: &CaseMetadata, raw_text: &str) -> Option<String> {
+        let len = raw_text.len();
+        if len < self.min_text_length {
+            Some(format!(
+                "Case text is {} characters, below the minimum of {}",
+                len, self.min_text_length
+            ))
+        } else if len > self.max_text_length {
+            Some(format!(
+                "Case text is {} characters, above the maximum of {}",
+                len, self.max_text_length
+            ))
+        } else {
+            None
+        }
+    }
 }
 
-/// Validation error details
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ValidationError {
-    pub field: String,
-    pub message: String,
-    pub severity: ValidationSeverity,
+/// Every field name listed in `ValidationConfig::required_fields` must be non-empty on the
+/// case. Unrecognized field names are ignored here (this rule cannot see fields it doesn't
+/// know about); `Config::validate` only checks *rule* names, not field names.
+struct RequiredFieldsRule {
+    required_fields: Vec<String>,
 }
 
-/// Validation warning details
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ValidationWarning {
-    pub field: String,
-    pub message: String,
+impl ValidationRule for RequiredFieldsRule {
+    fn name(&self) -> &str {
+        "required_fields"
+    }
+
+    fn check(&self, case: &CaseMetadata, _raw_text: &str) -> Option<String> {
+        let missing: Vec<&str> = self
+            .required_fields
+            .iter()
+            .map(|field| field.as_str())
+            .filter(|field| match *field {
+                "case_name" | "name" | "title" => case.name.is_empty(),
+                "court" => case.court.is_empty(),
+                "citation" => case.citation.is_empty(),
+                _ => false,
+            })
+            .collect();
+
+        if missing.is_empty() {
+            None
+        } else {
+            Some(format!("Missing required field(s): {}", missing.join(", ")))
+        }
+    }
 }
 
-/// Severity levels for validation issues
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ValidationSeverity {
-    Critical,
-    High,
-    Medium,
-    Low,
+/// Every entry in `case.citations` must look like `"<volume> <reporter> <page>"`
+/// (e.g. `"5 U.S. 137"`). Defaults to a warning rather than a hard error, since malformed
+/// citations are common in scanned/OCR'd source data and are rarely worth losing the case
+/// over.
+struct CitationFormatRule {
+    pattern: regex::Regex,
+}
+
+impl CitationFormatRule {
+    fn new() -> Result<Self> {
+        let pattern = regex::Regex::new(r"^\d+\s+[A-Za-z0-9.]+\s+\d+$").map_err(|e| SearchError::Config {
+            message: format!("Failed to compile citation_format pattern: {}", e),
+        })?;
+        Ok(Self { pattern })
+    }
+}
+
+impl ValidationRule for CitationFormatRule {
+    fn name(&self) -> &str {
+        "citation_format"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Warn
+    }
+
+    fn check(&self, case: &CaseMetadata, _raw_text: &str) -> Option<String> {
+        let malformed: Vec<&str> = case
+            .citations
+            .iter()
+            .map(|c| c.as_str())
+            .filter(|c| !self.pattern.is_match(c))
+            .collect();
+
+        if malformed.is_empty() {
+            None
+        } else {
+            Some(format!(
+                "Citation(s) do not match the expected \"volume reporter page\" format: {}",
+                malformed.join(", ")
+            ))
+        }
+    }
+}
+
+/// Case data validator
+pub struct CaseValidator {
+    config: ValidationConfig,
+    rules: Vec<Box<dyn ValidationRule + Send + Sync>>,
 }
 
 impl CaseValidator {
-    /// Create new case validator
-    pub fn new() -> Result<Self> {
-        let rules: Vec<Box<dyn ValidationRule + Send + Sync>> = vec![
-            // TODO: Add validation rules
-        ];
-        
-        Ok(Self { rules })
-    }
-    
-    /// Validate a case against all rules
-    pub fn validate(&self, case: &CaseMetadata) -> Result<ValidationResult> {
-        let mut errors = Vec::new();
+    /// Create a new case validator, registering the rules `config` calls for
+    pub fn new(config: ValidationConfig) -> Result<Self> {
+        let mut rules: Vec<Box<dyn ValidationRule + Send + Sync>> = vec![Box::new(TextLengthRule {
+            min_text_length: config.min_text_length,
+            max_text_length: config.max_text_length,
+        })];
+
+        if !config.required_fields.is_empty() {
+            rules.push(Box::new(RequiredFieldsRule {
+                required_fields: config.required_fields.clone(),
+            }));
+        }
+
+        if config.validate_citations {
+            rules.push(Box::new(CitationFormatRule::new()?));
+        }
+
+        Ok(Self { config, rules })
+    }
+
+    /// Resolve the severity a rule's violation should be treated with: the configured
+    /// override if one exists, otherwise the rule's own default
+    fn resolved_severity(&self, rule_name: &str, default: RuleSeverity) -> RuleSeverity {
+        self.config
+            .rule_severity
+            .get(rule_name)
+            .copied()
+            .unwrap_or(default)
+    }
+
+    /// Validate a case against all registered rules.
+    ///
+    /// Returns `Ok(warnings)` if the case may proceed (possibly with non-blocking warnings
+    /// to attach to `CaseMetadata::validation_warnings`), or `Err(SearchError::ValidationFailed)`
+    /// naming the first rule whose resolved severity is [`RuleSeverity::Error`].
+    pub async fn validate_case(&self, case: &CaseMetadata, raw_text: &str) -> Result<Vec<String>> {
         let mut warnings = Vec::new();
-        
+
         for rule in &self.rules {
-            let result = rule.validate(case);
-            errors.extend(result.errors);
-            warnings.extend(result.warnings);
+            let Some(message) = rule.check(case, raw_text) else {
+                continue;
+            };
+
+            match self.resolved_severity(rule.name(), rule.default_severity()) {
+                RuleSeverity::Off => {}
+                RuleSeverity::Warn => warnings.push(format!("[{}] {}", rule.name(), message)),
+                RuleSeverity::Error => {
+                    return Err(SearchError::ValidationFailed {
+                        field: rule.name().to_string(),
+                        reason: message,
+                    });
+                }
+            }
         }
-        
-        Ok(ValidationResult {
-            passed: errors.is_empty(),
-            errors,
-            warnings,
-        })
-    }
-} 
\ No newline at end of file
+
+        Ok(warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Jurisdiction;
+    use chrono::{NaiveDate, Utc};
+    use uuid::Uuid;
+
+    fn case_with_bad_citation() -> CaseMetadata {
+        CaseMetadata {
+            id: Uuid::new_v4(),
+            name: "Doe v. Roe".to_string(),
+            citation: "not-a-real-citation".to_string(),
+            court: "Test Court".to_string(),
+            decision_date: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+            judges: vec![],
+            topics: vec![],
+            full_text: "This case has more than enough text to pass the length check easily."
+                .repeat(3),
+            jurisdiction: Jurisdiction::Federal,
+            citations: vec!["not-a-real-citation".to_string()],
+            docket_number: None,
+            source_url: None,
+            word_count: 20,
+            ingestion_date: Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        }
+    }
+
+    fn validation_config() -> ValidationConfig {
+        ValidationConfig {
+            min_text_length: 10,
+            max_text_length: 1_000_000,
+            required_fields: vec![],
+            allow_empty_citations: false,
+            validate_dates: true,
+            validate_citations: true,
+            rule_severity: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_citation_format_rule_warns_by_default() {
+        let validator = CaseValidator::new(validation_config()).unwrap();
+        let case = case_with_bad_citation();
+
+        let warnings = validator
+            .validate_case(&case, &case.full_text)
+            .await
+            .expect("a warn-severity violation must not block ingestion");
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("citation_format"));
+    }
+
+    #[tokio::test]
+    async fn test_citation_format_rule_blocks_when_overridden_to_error() {
+        let mut config = validation_config();
+        config.rule_severity.insert("citation_format".to_string(), RuleSeverity::Error);
+        let validator = CaseValidator::new(config).unwrap();
+        let case = case_with_bad_citation();
+
+        let result = validator.validate_case(&case, &case.full_text).await;
+
+        match result {
+            Err(SearchError::ValidationFailed { field, .. }) => assert_eq!(field, "citation_format"),
+            other => panic!("expected a blocking ValidationFailed error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_citation_format_rule_off_produces_no_warning() {
+        let mut config = validation_config();
+        config.rule_severity.insert("citation_format".to_string(), RuleSeverity::Off);
+        let validator = CaseValidator::new(config).unwrap();
+        let case = case_with_bad_citation();
+
+        let warnings = validator.validate_case(&case, &case.full_text).await.unwrap();
+
+        assert!(warnings.is_empty());
+    }
+}
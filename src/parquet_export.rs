@@ -0,0 +1,312 @@
+//! # Parquet Export Module (feature = "parquet-export")
+//!
+//! ## Purpose
+//! Exports the case corpus already held in [`StorageManager`] to Parquet, for data
+//! scientists who want to load the corpus into a notebook (pandas/polars/DuckDB) instead of
+//! querying the search API. Wired to `export --format parquet` on the CLI.
+//!
+//! ## Input/Output Specification
+//! - **Input**: Case metadata read one record at a time from storage (via
+//!   `StorageManager::list_case_ids` + `StorageManager::get_case_metadata`); every record read
+//!   is kept in memory until the file is written, so memory use grows with the corpus
+//! - **Output**: A `.parquet` file with one row per case (see [`case_schema`]), and
+//!   optionally a second `.parquet` file with one row per paragraph
+//!
+//! ## Schema
+//! Case rows flatten [`CaseMetadata`]: scalar fields map directly, `judges`/`topics`/
+//! `citations` become `List<Utf8>` columns, and `jurisdiction` is flattened to a string
+//! (`"Federal"`, `"State:NY"`, `"Local:Springfield"`, `"International"`). Dates and
+//! timestamps are written as ISO-8601/RFC-3339 strings rather than Arrow's `Date32`/
+//! `Timestamp` types, to keep the schema simple and avoid an epoch-conversion round-trip bug
+//! class entirely.
+//!
+//! Paragraph rows (written when `ParquetExportOptions::paragraphs_output_path` is set) are `case_id`,
+//! `paragraph_index`, `text` — paragraphs are `full_text` split on blank lines, since no
+//! paragraph-level storage exists independently of the case's full text.
+
+use crate::errors::{Result, SearchError};
+use crate::storage::StorageManager;
+use crate::{CaseMetadata, Jurisdiction};
+use arrow2::array::{Array, ListArray, MutableListArray, MutableUtf8Array, TryPush, UInt64Array, Utf8Array};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::io::parquet::write::{
+    transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+};
+use std::path::Path;
+
+/// Column schema for the case-level Parquet file. Kept as a function (rather than a
+/// `const`/`static`) since `Schema`/`Field` construction isn't `const`-evaluable.
+pub fn case_schema() -> Schema {
+    let string_list = || DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+
+    Schema::from(vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("citation", DataType::Utf8, false),
+        Field::new("court", DataType::Utf8, false),
+        Field::new("decision_date", DataType::Utf8, false),
+        Field::new("judges", string_list(), false),
+        Field::new("topics", string_list(), false),
+        Field::new("full_text", DataType::Utf8, false),
+        Field::new("jurisdiction", DataType::Utf8, false),
+        Field::new("citations", string_list(), false),
+        Field::new("docket_number", DataType::Utf8, true),
+        Field::new("source_url", DataType::Utf8, true),
+        Field::new("word_count", DataType::UInt64, false),
+        Field::new("ingestion_date", DataType::Utf8, false),
+    ])
+}
+
+/// Column schema for the optional paragraph-level Parquet file
+pub fn paragraph_schema() -> Schema {
+    Schema::from(vec![
+        Field::new("case_id", DataType::Utf8, false),
+        Field::new("paragraph_index", DataType::UInt64, false),
+        Field::new("text", DataType::Utf8, false),
+    ])
+}
+
+/// Options controlling a Parquet export
+pub struct ParquetExportOptions {
+    /// Destination for the case-level Parquet file
+    pub output_path: std::path::PathBuf,
+    /// When set, a second file at this path is written with one row per paragraph
+    pub paragraphs_output_path: Option<std::path::PathBuf>,
+}
+
+/// Result of a completed export, for logging/CLI output
+pub struct ParquetExportSummary {
+    pub cases_written: usize,
+    pub paragraphs_written: usize,
+}
+
+/// Flatten [`Jurisdiction`] into the single string stored in the `jurisdiction` column
+fn jurisdiction_to_string(jurisdiction: &Jurisdiction) -> String {
+    match jurisdiction {
+        Jurisdiction::Federal => "Federal".to_string(),
+        Jurisdiction::State(state) => format!("State:{}", state),
+        Jurisdiction::Local(locality) => format!("Local:{}", locality),
+        Jurisdiction::International => "International".to_string(),
+    }
+}
+
+/// Split a case's full text into paragraphs on blank lines, falling back to the whole text
+/// as a single paragraph if no blank-line breaks are present
+fn split_paragraphs(full_text: &str) -> Vec<&str> {
+    let paragraphs: Vec<&str> = full_text
+        .split("\n\n")
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    if paragraphs.is_empty() {
+        vec![full_text]
+    } else {
+        paragraphs
+    }
+}
+
+fn string_list_array(values: Vec<Vec<String>>) -> Result<ListArray<i32>> {
+    let mut builder = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    for row in values {
+        builder
+            .try_push(Some(row.into_iter().map(Some)))
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("Failed to build Parquet list column: {}", e),
+            })?;
+    }
+    Ok(builder.into())
+}
+
+/// Build the case-level Arrow chunk for one batch of cases
+fn case_chunk(cases: &[CaseMetadata]) -> Result<Chunk<Box<dyn Array>>> {
+    let id: Utf8Array<i32> = cases.iter().map(|c| Some(c.id.to_string())).collect();
+    let name: Utf8Array<i32> = cases.iter().map(|c| Some(c.name.clone())).collect();
+    let citation: Utf8Array<i32> = cases.iter().map(|c| Some(c.citation.clone())).collect();
+    let court: Utf8Array<i32> = cases.iter().map(|c| Some(c.court.clone())).collect();
+    let decision_date: Utf8Array<i32> = cases.iter().map(|c| Some(c.decision_date.to_string())).collect();
+    let judges = string_list_array(cases.iter().map(|c| c.judges.clone()).collect())?;
+    let topics = string_list_array(cases.iter().map(|c| c.topics.clone()).collect())?;
+    let full_text: Utf8Array<i32> = cases.iter().map(|c| Some(c.full_text.clone())).collect();
+    let jurisdiction: Utf8Array<i32> = cases.iter().map(|c| Some(jurisdiction_to_string(&c.jurisdiction))).collect();
+    let citations = string_list_array(cases.iter().map(|c| c.citations.clone()).collect())?;
+    let docket_number: Utf8Array<i32> = cases.iter().map(|c| c.docket_number.clone()).collect();
+    let source_url: Utf8Array<i32> = cases.iter().map(|c| c.source_url.clone()).collect();
+    let word_count: UInt64Array = cases.iter().map(|c| Some(c.word_count as u64)).collect();
+    let ingestion_date: Utf8Array<i32> = cases.iter().map(|c| Some(c.ingestion_date.to_rfc3339())).collect();
+
+    Ok(Chunk::new(vec![
+        id.boxed(),
+        name.boxed(),
+        citation.boxed(),
+        court.boxed(),
+        decision_date.boxed(),
+        judges.boxed(),
+        topics.boxed(),
+        full_text.boxed(),
+        jurisdiction.boxed(),
+        citations.boxed(),
+        docket_number.boxed(),
+        source_url.boxed(),
+        word_count.boxed(),
+        ingestion_date.boxed(),
+    ]))
+}
+
+/// Build the paragraph-level Arrow chunk for one batch of (case_id, paragraph_index, text)
+fn paragraph_chunk(rows: &[(String, u64, String)]) -> Chunk<Box<dyn Array>> {
+    let case_id: Utf8Array<i32> = rows.iter().map(|(id, _, _)| Some(id.clone())).collect();
+    let paragraph_index: UInt64Array = rows.iter().map(|(_, index, _)| Some(*index)).collect();
+    let text: Utf8Array<i32> = rows.iter().map(|(_, _, text)| Some(text.clone())).collect();
+
+    Chunk::new(vec![case_id.boxed(), paragraph_index.boxed(), text.boxed()])
+}
+
+fn write_options() -> WriteOptions {
+    WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Snappy,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    }
+}
+
+fn write_chunk<P: AsRef<Path>>(path: P, schema: &Schema, chunk: Chunk<Box<dyn Array>>) -> Result<()> {
+    let options = write_options();
+    let encodings: Vec<Vec<Encoding>> = schema
+        .fields
+        .iter()
+        .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+        .collect();
+
+    let row_groups = RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), schema, options, encodings)
+        .map_err(|e| SearchError::SerializationFailed {
+            message: format!("Failed to build Parquet row group: {}", e),
+        })?;
+
+    let file = std::fs::File::create(path)?;
+    let mut writer = FileWriter::try_new(file, schema.clone(), options).map_err(|e| SearchError::SerializationFailed {
+        message: format!("Failed to open Parquet writer: {}", e),
+    })?;
+
+    for group in row_groups {
+        let group = group.map_err(|e| SearchError::SerializationFailed {
+            message: format!("Failed to encode Parquet row group: {}", e),
+        })?;
+        writer.write(group).map_err(|e| SearchError::SerializationFailed {
+            message: format!("Failed to write Parquet row group: {}", e),
+        })?;
+    }
+
+    writer.end(None).map_err(|e| SearchError::SerializationFailed {
+        message: format!("Failed to finalize Parquet file: {}", e),
+    })?;
+
+    Ok(())
+}
+
+/// Stream every case out of `storage` and write it to Parquet per [`ParquetExportOptions`].
+/// Cases are fetched one at a time from storage, but every case (and every paragraph row) is
+/// held in memory and written as a single row group, so peak memory grows with the corpus.
+pub async fn export_to_parquet(storage: &StorageManager, options: &ParquetExportOptions) -> Result<ParquetExportSummary> {
+    let case_ids = storage.list_case_ids().await?;
+
+    let mut cases = Vec::with_capacity(case_ids.len());
+    let mut paragraph_rows = Vec::new();
+
+    for case_id in case_ids {
+        let Some(metadata) = storage.get_case_metadata(&case_id).await? else {
+            continue;
+        };
+
+        if options.paragraphs_output_path.is_some() {
+            for (index, paragraph) in split_paragraphs(&metadata.full_text).iter().enumerate() {
+                paragraph_rows.push((metadata.id.to_string(), index as u64, paragraph.to_string()));
+            }
+        }
+
+        cases.push(metadata);
+    }
+
+    let cases_written = cases.len();
+    let case_schema = case_schema();
+    write_chunk(&options.output_path, &case_schema, case_chunk(&cases)?)?;
+
+    let paragraphs_written = paragraph_rows.len();
+    if let Some(paragraphs_path) = &options.paragraphs_output_path {
+        let paragraph_schema = paragraph_schema();
+        write_chunk(paragraphs_path, &paragraph_schema, paragraph_chunk(&paragraph_rows))?;
+    }
+
+    Ok(ParquetExportSummary { cases_written, paragraphs_written })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::io::parquet::read::{infer_schema, read_metadata, FileReader};
+    use chrono::{NaiveDate, Utc};
+    use uuid::Uuid;
+
+    fn sample_case() -> CaseMetadata {
+        CaseMetadata {
+            id: Uuid::new_v4(),
+            name: "Marbury v. Madison".to_string(),
+            citation: "5 U.S. 137".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: NaiveDate::from_ymd_opt(1803, 2, 24).unwrap(),
+            judges: vec!["Marshall".to_string()],
+            topics: vec!["judicial-review".to_string(), "constitutional-law".to_string()],
+            full_text: "Paragraph one.\n\nParagraph two.".to_string(),
+            jurisdiction: Jurisdiction::Federal,
+            citations: vec!["5 U.S. 137".to_string(), "1 Cranch 137".to_string()],
+            docket_number: None,
+            source_url: None,
+            word_count: 4,
+            ingestion_date: Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        }
+    }
+
+    #[test]
+    fn test_case_chunk_round_trips_through_parquet() {
+        let temp_dir = std::env::temp_dir().join(format!("parquet-export-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let path = temp_dir.join("cases.parquet");
+
+        let cases = vec![sample_case()];
+        let schema = case_schema();
+        write_chunk(&path, &schema, case_chunk(&cases).unwrap()).unwrap();
+
+        let mut file = std::fs::File::open(&path).unwrap();
+        let metadata = read_metadata(&mut file).unwrap();
+        let read_schema = infer_schema(&metadata).unwrap();
+        let reader = FileReader::new(file, metadata.row_groups, read_schema, None, None, None);
+
+        let chunks: Vec<_> = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        let total_rows: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total_rows, 1);
+
+        let chunk = &chunks[0];
+        let citations = chunk[9]
+            .as_any()
+            .downcast_ref::<ListArray<i32>>()
+            .expect("citations column should be a list array");
+        let first_case_citations = citations.value(0);
+        let first_case_citations = first_case_citations
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        assert_eq!(first_case_citations.len(), 2);
+        assert_eq!(first_case_citations.value(0), "5 U.S. 137");
+        assert_eq!(first_case_citations.value(1), "1 Cranch 137");
+
+        let name = chunk[1].as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+        assert_eq!(name.value(0), "Marbury v. Madison");
+
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+    }
+}
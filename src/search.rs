@@ -16,24 +16,120 @@
 //! - Query caching and performance optimization
 //! - Configurable search behavior
 
-use crate::config::{Config, SearchEngineConfig};
+use crate::config::{Config, SearchEngineConfig, SearchStageBudgets};
 use crate::errors::{Result, SearchError};
-use crate::storage::StorageManager;
-use crate::trie::{TrieIndex, TrieSearchResult};
-use crate::vector::{VectorIndex, VectorSearchResult};
-use crate::{CaseId, CaseMetadata, DocRef, SearchConfig};
+use crate::storage::{SecondaryIndexField, StorageManager, TextForm};
+use crate::taxonomy::{Taxonomy, TopicFacet, TopicFilter};
+use crate::text_processing::TextProcessor;
+use crate::trie::{CitationResolution, FuzzyMatch, PruneReport, TrieCompletion, TrieIndex, TrieIndexHandle, TrieSearchResult, TrieSource};
+use crate::utils::InstrumentedRwLock;
+use crate::vector::{chunk_text, VectorIndex, VectorSearchResult};
+use crate::{CaseId, CaseMetadata, DocRef, Jurisdiction, SearchConfig};
+use chrono::Datelike;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock, Semaphore};
+
+/// Fraction of `SearchConfig::exact_match_weight` given to a [`MatchType::Substring`] result, so
+/// a query matched mid-sequence via `TrieIndex`'s auxiliary substring index ranks below a true
+/// exact/prefix match but still ahead of most semantic matches.
+const SUBSTRING_MATCH_SCORE_FACTOR: f32 = 0.5;
+
+/// Fraction of a bucket's ordinary match score given to a [`MatchType::Synonym`] result, so an
+/// expanded-term match (see `synonyms::SynonymTable::expand`) ranks below the same bucket's
+/// original-term match, per `SearchConfig::enable_synonyms`.
+const SYNONYM_MATCH_SCORE_FACTOR: f32 = 0.75;
+
+/// Score bonus per additional paragraph of the same case matching within one trie bucket (see
+/// [`Candidate::extra_doc_refs`]), applied as `ln(1 + extra_hit_count) *
+/// MULTI_PASSAGE_BONUS_FACTOR` on top of the case's base match score. Logarithmic rather than
+/// linear so a case matching in, say, 20 paragraphs doesn't drown out one matching a stronger
+/// origin (case name/citation) in just one; the final score is still clamped to `1.0` in
+/// [`SearchEngine::finish_ranking`] regardless.
+const MULTI_PASSAGE_BONUS_FACTOR: f32 = 0.05;
+
+/// Cap on `SearchResult::passages`'s length — one primary paragraph plus a small number of
+/// extras is enough to show a user why a case matched more than once without the response
+/// growing with a case's total paragraph count.
+const MULTI_PASSAGE_MAX_SNIPPETS: usize = 3;
+
+/// Per-token Levenshtein budget [`SearchEngine::generate_spelling_suggestions`] passes to
+/// [`TrieIndex::search_fuzzy`] — a "did you mean" nudge only makes sense for a genuine typo, not
+/// a completely different phrase two tokens happen to be edit-distance-2 away from.
+const MAX_SUGGESTION_EDIT_DISTANCE: usize = 2;
+
+/// Maximum number of "did you mean" rewrites returned on [`SearchOutcome::suggestions`]
+const MAX_SUGGESTIONS: usize = 3;
+
+/// How many of a case's own extracted key phrases [`SearchEngine::more_like_this`] searches the
+/// content trie with to find lexically-similar cases, supplementing its vector-similarity results
+const MORE_LIKE_THIS_KEY_PHRASES: usize = 3;
+
+/// Normalizes incoming query text the same way indexed text was normalized by
+/// [`crate::text_processing::TextProcessor`], so a query containing curly quotes or an
+/// NFD-decomposed character still matches content indexed in NFC. Every trie search method
+/// currently lowercases its own query text ad hoc (`query.split_whitespace().map(str::to_lowercase)`)
+/// without first NFC-composing or quote-folding it — harmless for plain ASCII queries, but a
+/// query typed with `’` (U+2019) or a decomposed `é` (`e` + combining acute) won't lowercase to
+/// the same bytes as the NFC-composed, straight-quote form `TextProcessor::normalize_text`
+/// already produced when the matching content was indexed. Rather than duplicate that
+/// normalization's regexes here, `QueryNormalizer` wraps a `TextProcessor` and reuses its
+/// `normalize_text` directly; [`SearchEngine::execute_search_isolated`] runs every query through
+/// it before any trie or vector search sees the query text.
+pub struct QueryNormalizer {
+    text_processor: TextProcessor,
+}
+
+impl QueryNormalizer {
+    pub fn new(config: crate::config::TextProcessingConfig) -> Result<Self> {
+        Ok(Self { text_processor: TextProcessor::new(config)? })
+    }
+
+    /// Normalize `query` the same way indexed text was normalized: NFC-compose, fold curly
+    /// quotes to straight ones, collapse whitespace, and strip control characters. Case is left
+    /// untouched here too — each trie search method still lowercases the (now NFC-composed)
+    /// text itself, and leaving citations' casing alone is what "citation preservation" means in
+    /// practice, since `normalize_text` never touches case at all.
+    pub fn normalize(&self, query: &str) -> String {
+        self.text_processor.normalize_text(query).unwrap_or_else(|_| query.to_string())
+    }
+}
 
 /// Main search engine
+///
+/// Every field is `Arc`-wrapped, so cloning `SearchEngine` is just a handful of refcount
+/// bumps — used to move a cheap handle into the isolated `tokio::task::spawn` that
+/// `search_with_params` runs each query's execution in (see [`SearchEngine::search_with_params`]).
+#[derive(Clone)]
 pub struct SearchEngine {
     config: Arc<Config>,
-    trie_index: Arc<RwLock<TrieIndex>>,
-    vector_index: Arc<RwLock<VectorIndex>>,
+    /// Arc-swap style handle, not an `InstrumentedRwLock` like `vector_index` below: a query
+    /// takes a snapshot ([`TrieIndexHandle::snapshot`]) and searches it lock-free, and a writer
+    /// stages a batch of mutations off to the side and publishes it in one pointer swap, so no
+    /// search is ever blocked behind an in-progress trie update. See [`TrieIndexHandle`].
+    trie_index: Arc<TrieIndexHandle>,
+    vector_index: Arc<InstrumentedRwLock<VectorIndex>>,
     storage: Arc<StorageManager>,
     query_cache: Arc<RwLock<QueryCache>>,
+    taxonomy: Arc<Taxonomy>,
+    synonym_table: Arc<crate::synonyms::SynonymTable>,
+    /// Shared with indexing's own normalization (see [`QueryNormalizer`]); applied to every
+    /// query's text before it reaches [`SearchEngine::apply_fielded_query_syntax`] or any trie/
+    /// vector search.
+    query_normalizer: Arc<QueryNormalizer>,
+    /// Bounds the number of searches executing at once; queries that wait longer than
+    /// `search.max_queue_wait_ms` for a slot are shed with `SearchCapacityExceeded`
+    concurrency_limiter: Arc<Semaphore>,
+    /// Trie/vector index load health, degraded in place of a hard startup failure when a
+    /// snapshot is missing or corrupt
+    index_health: Arc<RwLock<IndexHealth>>,
+    /// Monotonic counter bumped each time the trie or vector index changes shape (currently:
+    /// a background rebuild completes). Exposed as `X-Index-Generation` on `GET /search` so a
+    /// CDN or client caching that response can detect when the underlying index has moved on.
+    index_generation: Arc<AtomicU64>,
 }
 
 /// Search query with parameters
@@ -41,44 +137,204 @@ pub struct SearchEngine {
 pub struct SearchQuery {
     /// Query text
     pub query: String,
-    /// Maximum number of results
+    /// Maximum number of results (also doubles as the page size when `offset` is non-zero)
     pub max_results: Option<usize>,
+    /// Number of ranked candidates to skip before taking `max_results`, for paging through a
+    /// result set beyond the first page. Clamped to `total_candidates` rather than erroring on
+    /// an out-of-range value.
+    #[serde(default)]
+    pub offset: usize,
     /// Court filter
     pub court_filter: Option<Vec<String>>,
-    /// Date range filter
-    pub date_range: Option<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)>,
+    /// Judge filter: a case qualifies if any of its `CaseMetadata::judges` contains one of
+    /// these names as a case-insensitive substring (so `"Warren"` matches a judges list
+    /// recorded as `"Warren, C.J."`).
+    #[serde(default)]
+    pub judge_filter: Option<Vec<String>>,
+    /// Date range filter, inclusive on both ends
+    pub date_range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    /// Topic taxonomy filter, optionally including descendant nodes
+    pub topic_filter: Option<TopicFilter>,
+    /// Which query syntax `query` should be parsed as. Defaults to `Plain` bag-of-words
+    /// matching; `Boolean` enables `AND`/`OR`/`NOT`/quoted-phrase syntax (see
+    /// [`crate::boolean_query`]), falling back to `Plain` with a warning if `query` fails to
+    /// parse as Boolean.
+    #[serde(default)]
+    pub syntax: SearchSyntax,
+    /// How the final candidate set (post-scoring, post-filtering) is ordered before truncation
+    /// to `max_results`; see [`SortOrder`]. Defaults to `Relevance`, today's existing behavior.
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Named weighting profile to rank this query with (see
+    /// [`crate::config::WeightingProfile`]), overriding `config`'s exact-match weight, minimum
+    /// similarity, semantic/prefix/rerank flags, and RRF `k` in one shot. `None` falls back to
+    /// `SearchEngineConfig::default_weighting_profile`, and if that's also unset, `config`'s
+    /// values are left untouched — see [`SearchEngine::apply_weighting_profile`]. An unrecognized
+    /// name is rejected with [`SearchError::ValidationFailed`] listing the available profiles.
+    #[serde(default)]
+    pub profile: Option<String>,
     /// Search configuration
     pub config: SearchConfig,
 }
 
+/// How [`SearchEngine::rank_candidates`] orders its final candidate list, applied after scoring
+/// and filtering but before the `offset`/`max_results` page window. Every mode breaks ties
+/// deterministically by case id, so repeated queries against an unchanged index always return
+/// results in the same order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Descending fused relevance score (today's existing behavior)
+    #[default]
+    Relevance,
+    /// Most recent [`CaseMetadata::decision_date`] first
+    DateDesc,
+    /// Oldest [`CaseMetadata::decision_date`] first
+    DateAsc,
+    /// Court hierarchy first (Supreme Court, then circuit/appellate courts, then district
+    /// courts, then anything unrecognized), via [`SearchEngine::court_rank`]
+    CourtRank,
+}
+
+/// Which grammar [`SearchQuery::query`] should be parsed as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSyntax {
+    /// Ordinary bag-of-words matching (the existing lexical + semantic hybrid search)
+    #[default]
+    Plain,
+    /// `AND`/`OR`/`NOT`/quoted-phrase syntax evaluated against the trie's substring index; see
+    /// [`crate::boolean_query`]
+    Boolean,
+}
+
 /// Search result with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
     /// Case metadata
     pub case_metadata: CaseMetadata,
-    /// Relevance score (0.0 to 1.0)
+    /// Final relevance score used for ordering, always in `0.0..=1.0`: usually the Reciprocal
+    /// Rank Fusion of `lexical_score` and `semantic_score` (see
+    /// `SearchEngine::fuse_reciprocal_rank`), or a direct citation/lexical match's own bounded
+    /// score (see `SearchEngine::relative_lexical_weight_for`) when only one source produced
+    /// results. Not a raw `SearchConfig::exact_match_weight`-scaled value in either case.
     pub score: f32,
+    /// This result's raw score from the lexical (trie) stage, before fusion; `None` if it
+    /// wasn't found by the lexical stage. For debugging/tuning `SearchConfig::rrf_k`.
+    pub lexical_score: Option<f32>,
+    /// This result's raw cosine similarity from the vector stage, before fusion; `None` if it
+    /// wasn't found by the vector stage. For debugging/tuning `SearchConfig::rrf_k`.
+    pub semantic_score: Option<f32>,
     /// Match type (exact, semantic, etc.)
     pub match_type: MatchType,
+    /// Which index produced this result
+    pub provenance: MatchProvenance,
     /// Text snippet showing the match
     pub snippet: String,
     /// Highlighted query terms in snippet
     pub highlights: Vec<TextHighlight>,
+    /// Ids of other results in this same page collapsed into this one by
+    /// `SearchEngine::dedup_citation_overlap` (same normalized citation, or matching name and
+    /// decision date) because `SearchConfig::enable_citation_dedup` is set. Empty when no
+    /// duplicate was found, or the flag is unset.
+    #[serde(default)]
+    pub duplicates: Vec<CaseId>,
+    /// Up to 3 snippets, one per matching paragraph, when this case matched in more than one
+    /// place within the same trie bucket (see `Candidate::extra_doc_refs`); the first entry is
+    /// always the same text as `snippet`. Empty when the case matched only once, mirroring
+    /// `snippet` instead of duplicating it.
+    #[serde(default)]
+    pub passages: Vec<String>,
 }
 
 /// Type of match found
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MatchType {
     /// Exact text match from trie
     Exact,
     /// Prefix match from trie
     Prefix,
+    /// Matched via `TrieIndex`'s auxiliary substring index — the query occurred somewhere
+    /// inside an indexed case name or sentence rather than at its first token (see
+    /// `crate::trie::TrieSearchResult::is_substring_match`). Ranked below `Exact` and `Prefix`
+    /// since a mid-sequence match says less about relevance than one anchored at the start.
+    Substring,
     /// Semantic similarity match
     Semantic,
     /// Case name match
     CaseName,
     /// Citation match
     Citation,
+    /// Matched via a `SearchSyntax::Boolean` query (`AND`/`OR`/`NOT`/quoted phrases); see
+    /// `crate::boolean_query`
+    Boolean,
+    /// Matched a `SearchSyntax::Plain` query's quoted phrase(s) as an exact, adjacent run of
+    /// words in the same paragraph, rather than an unordered bag-of-words match; see
+    /// `SearchEngine::extract_quoted_phrases`
+    Phrase,
+    /// Surfaced by `SearchEngine::more_like_this` via lexical overlap on one of the source
+    /// case's extracted key phrases, supplementing its vector-similarity results
+    MoreLikeThis,
+    /// Matched an OR-alternative synonym phrase (see `synonyms::SynonymTable::expand`) rather
+    /// than the query's own text; see `SearchConfig::enable_synonyms`.
+    Synonym,
+    /// Matched only some — at least `SearchConfig::min_should_match` worth — of a multi-token
+    /// query's tokens, via `crate::trie::TrieIndex::search_min_should_match`, rather than the
+    /// full sequence `MatchType::Exact`/`Substring` require.
+    PartialMatch,
+}
+
+/// Which index produced a search result, kept separate from [`MatchType`] so existing
+/// clients matching on `match_type` are unaffected
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MatchProvenance {
+    TrieCaseName,
+    TrieContent,
+    TrieCitation,
+    Vector,
+    /// A semantic match whose score came from `VectorIndex::search_and_rerank` rather than the
+    /// ANN stage's own ordering; see `config::SearchEngineConfig::enable_rerank`.
+    Reranker,
+}
+
+impl From<TrieSource> for MatchProvenance {
+    fn from(source: TrieSource) -> Self {
+        match source {
+            TrieSource::CaseName => MatchProvenance::TrieCaseName,
+            TrieSource::Content => MatchProvenance::TrieContent,
+            TrieSource::Citation => MatchProvenance::TrieCitation,
+        }
+    }
+}
+
+/// Which trie a `GET /suggest` completion came from — see [`SearchEngine::suggest`]. A separate
+/// enum from [`MatchType`]/[`MatchProvenance`] rather than reusing `TrieSource` directly, since
+/// "content trie" is an implementation detail; the suggestion is a phrase from a case's text.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SuggestionType {
+    CaseName,
+    Citation,
+    Phrase,
+}
+
+impl From<TrieSource> for SuggestionType {
+    fn from(source: TrieSource) -> Self {
+        match source {
+            TrieSource::CaseName => SuggestionType::CaseName,
+            TrieSource::Content => SuggestionType::Phrase,
+            TrieSource::Citation => SuggestionType::Citation,
+        }
+    }
+}
+
+/// One autocomplete suggestion from [`SearchEngine::suggest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub text: String,
+    pub suggestion_type: SuggestionType,
+    /// Number of distinct cases matching `text` exactly, so a UI can show e.g. "riparian rights
+    /// (42)" and let a user gauge how broad a suggestion is before committing to it.
+    pub case_count: usize,
 }
 
 /// Text highlighting information
@@ -101,18 +357,345 @@ pub enum HighlightType {
     Citation,
 }
 
-/// Query cache for performance optimization
+/// Search results plus the total matching candidate count from before the `max_results`
+/// truncation, so clients can render "N cases match, showing K" pagination messaging
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchOutcome {
+    pub results: Vec<SearchResult>,
+    /// Total results remaining after filtering, before truncation to `max_results`
+    pub total_candidates: usize,
+    /// Topic facet roll-up computed over the same pre-truncation candidate set
+    pub topic_facets: Vec<TopicFacet>,
+    /// `*_BUDGET_EXCEEDED` warnings raised by `execute_hybrid_search` when a per-stage latency
+    /// budget (see `config::SearchStageBudgets`) cut a stage short. Merged into
+    /// `SearchResponse::warnings` alongside the index-health warnings in `build_search_response`.
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// Up to [`MAX_SUGGESTIONS`] "did you mean" rewrites of the query text, populated only when
+    /// `results` came back empty; see [`SearchEngine::generate_spelling_suggestions`]. Empty when
+    /// `applied_correction` is set, since the top suggestion was already applied.
+    #[serde(default)]
+    pub suggestions: Vec<String>,
+    /// Set when `SearchConfig::auto_correct` retried this query with the top spelling
+    /// suggestion, to whichever corrected query text was actually used, so a caller can tell the
+    /// user their query was rewritten rather than silently return results for different text.
+    #[serde(default)]
+    pub applied_correction: Option<String>,
+    /// Court/decade/jurisdiction/topic facet roll-ups for rendering filter-sidebar counts; see
+    /// [`SearchFacets`].
+    #[serde(default)]
+    pub facets: SearchFacets,
+    /// Synonym phrases (see `synonyms::SynonymTable::expand`) actually searched as
+    /// OR-alternatives for this query, when `SearchConfig::enable_synonyms` is set; empty
+    /// otherwise.
+    #[serde(default)]
+    pub applied_synonym_expansions: Vec<String>,
+}
+
+/// Aggregate fields returned once [`SearchEngine::search_streamed`] has finished sending
+/// every result through its channel, mirroring the subset of [`SearchOutcome`] that a
+/// streaming consumer can't derive by simply counting the result lines it received.
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamedSearchSummary {
+    pub total_candidates: usize,
+    pub degraded: bool,
+    pub query_time_ms: u64,
+}
+
+/// A single facet value's count within a facet roll-up, e.g. `{value: "9th Circuit", count:
+/// 12}` for one bucket of a court facet. Distinct from [`TopicFacet`] since court/decade/
+/// jurisdiction have no hierarchy of their own to roll counts up through.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: usize,
+}
+
+/// Court/decade/jurisdiction/topic facet roll-ups over the pre-truncation candidate set (see
+/// [`SearchEngine::compute_facets`]), for rendering search-result filter sidebars. Each
+/// dimension's counts honor every *other* active [`SearchQuery`] filter but ignore its own
+/// (standard faceting semantics) — e.g. `court` is counted with the judge/date/topic filters
+/// applied but the query's own `court_filter` ignored, so a UI can show "how many results if I
+/// also picked this court" rather than just echo the filter the caller already chose.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchFacets {
+    /// Counts grouped by [`CaseMetadata::court`]
+    pub court: Vec<FacetCount>,
+    /// Counts grouped by the decade of [`CaseMetadata::decision_date`], e.g. `"1950s"`
+    pub decade: Vec<FacetCount>,
+    /// Counts grouped by [`crate::Jurisdiction`] variant (`"Federal"`, `"State"`, `"Local"`,
+    /// `"International"`), not by the specific state/locality named inside it
+    pub jurisdiction: Vec<FacetCount>,
+    /// Counts grouped by topic taxonomy node, rolled up through ancestor nodes the same way as
+    /// [`SearchEngine::topic_facets`]
+    pub topic: Vec<TopicFacet>,
+}
+
+/// Which of a [`SearchQuery`]'s filters a facet dimension corresponds to, so
+/// [`SearchEngine::matches_filters_except`] can apply every *other* active filter while a
+/// dimension's own counts are computed. `Jurisdiction` has no corresponding query filter today
+/// (there's no `jurisdiction_filter` on `SearchQuery`), so it never excludes anything — it exists
+/// purely so `compute_facets` can dispatch uniformly over all four dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FacetDimension {
+    Court,
+    Decade,
+    Jurisdiction,
+    Topic,
+}
+
+/// Health of a single index component. A component starts `Degraded` when its on-disk
+/// snapshot failed to load (missing or corrupt) rather than failing engine startup outright;
+/// the engine serves whatever indices ARE healthy while a background rebuild runs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum IndexComponentStatus {
+    Healthy,
+    /// `reason` is the load failure that caused the fallback to an empty index; `rebuilding`
+    /// is true while a background reindex from storage is in flight
+    Degraded { reason: String, rebuilding: bool },
+}
+
+/// Health of the trie and vector indices, tracked independently since either can degrade
+/// without the other. Exposed via `/health` so lexical search can keep serving while the
+/// vector index (or vice versa) is being rebuilt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexHealth {
+    pub trie: IndexComponentStatus,
+    pub vector: IndexComponentStatus,
+}
+
+/// Summary of a [`SearchEngine::rebuild_citation_graph`] run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationGraphRebuildStats {
+    pub cases_scanned: usize,
+    pub edges_resolved: usize,
+    pub edges_unresolved: usize,
+}
+
+/// Distinct case ids in `result.exact_matches` other than `own_case_id`, sorted for a
+/// deterministic pick when more than one distinct case shares an indexed citation. Used by
+/// [`rebuild_citation_graph_with`] to drop a case's own reporter citation string (a running-head
+/// self-reference, not a citation to another case) while still resolving a genuine cross-case
+/// citation that happens to land on the same trie node.
+fn resolved_case_ids_excluding(result: &TrieSearchResult, own_case_id: CaseId) -> Vec<CaseId> {
+    let mut ids: Vec<CaseId> = result
+        .exact_matches
+        .iter()
+        .map(|doc_ref| doc_ref.case_id)
+        .filter(|&id| id != own_case_id)
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// Shared implementation behind [`SearchEngine::rebuild_citation_graph`] and the background
+/// index rebuild's citation-graph pass (see [`SearchEngine::spawn_background_index_rebuild`]):
+/// for every stored case, extract its in-text citations and resolve each one against `trie`,
+/// recording a [`crate::storage::CitationEdge`] per citation via
+/// [`StorageManager::store_citation_edges`].
+async fn rebuild_citation_graph_with(
+    storage: &StorageManager,
+    trie: &TrieIndex,
+    text_processor: &TextProcessor,
+) -> Result<CitationGraphRebuildStats> {
+    let mut stats = CitationGraphRebuildStats::default();
+
+    for case_id in storage.list_case_ids().await? {
+        let Some(full_text) = storage.get_case_text(&case_id, TextForm::Normalized).await? else { continue };
+        let processed = text_processor.process_text(&full_text.text).await?;
+
+        let mut edges = Vec::with_capacity(processed.citations.len());
+        for citation in &processed.citations {
+            let resolution = trie.resolve_citation(&citation.full_text);
+            let (other_case_ids, confidence, had_any_match) = match &resolution {
+                CitationResolution::Exact(result) => (
+                    resolved_case_ids_excluding(result, case_id),
+                    crate::storage::CitationConfidence::Exact,
+                    !result.exact_matches.is_empty(),
+                ),
+                CitationResolution::YearMismatch { result, .. } => (
+                    resolved_case_ids_excluding(result, case_id),
+                    crate::storage::CitationConfidence::YearMismatch,
+                    !result.exact_matches.is_empty(),
+                ),
+                CitationResolution::Prefix(_) | CitationResolution::NoMatch => {
+                    (Vec::new(), crate::storage::CitationConfidence::Exact, false)
+                }
+            };
+
+            if other_case_ids.is_empty() && had_any_match {
+                // The only match was this case's own citation recurring in its own text — not a
+                // citation to another case, so it isn't recorded as an edge at all.
+                continue;
+            }
+
+            let edge = match other_case_ids.first() {
+                Some(&cited_case_id) => {
+                    stats.edges_resolved += 1;
+                    crate::storage::CitationEdge::Resolved {
+                        case_id: cited_case_id,
+                        raw_citation: citation.full_text.clone(),
+                        confidence,
+                    }
+                }
+                None => {
+                    stats.edges_unresolved += 1;
+                    crate::storage::CitationEdge::Unresolved { raw_citation: citation.full_text.clone() }
+                }
+            };
+            edges.push(edge);
+        }
+
+        storage.store_citation_edges(case_id, edges).await?;
+        stats.cases_scanned += 1;
+    }
+
+    Ok(stats)
+}
+
+/// Fields needed by [`SearchEngine::matches_filters`] and [`SearchEngine::topic_facets`], so
+/// both can run against either a fully-hydrated [`CaseMetadata`] or the lightweight
+/// [`crate::storage::CaseSummary`] projection without caring which.
+trait FilterableCase {
+    fn court(&self) -> &str;
+    fn decision_date(&self) -> chrono::NaiveDate;
+    fn topics(&self) -> &[String];
+    fn judges(&self) -> &[String];
+}
+
+impl FilterableCase for CaseMetadata {
+    fn court(&self) -> &str {
+        &self.court
+    }
+    fn decision_date(&self) -> chrono::NaiveDate {
+        self.decision_date
+    }
+    fn topics(&self) -> &[String] {
+        &self.topics
+    }
+    fn judges(&self) -> &[String] {
+        &self.judges
+    }
+}
+
+impl FilterableCase for crate::storage::CaseSummary {
+    fn court(&self) -> &str {
+        &self.court
+    }
+    fn decision_date(&self) -> chrono::NaiveDate {
+        self.decision_date
+    }
+    fn topics(&self) -> &[String] {
+        &self.topics
+    }
+    fn judges(&self) -> &[String] {
+        &self.judges
+    }
+}
+
+/// A search match before full-metadata hydration: everything `execute_hybrid_search` needs to
+/// dedup, filter, rank, and facet a result, backed by the cheap
+/// [`crate::storage::CaseSummary`] projection rather than the full [`CaseMetadata`] record.
+/// Only the page-truncated slice of candidates is ever upgraded to a [`SearchResult`] (see
+/// [`SearchEngine::hydrate_candidate`]), so a query with a large candidate pool but a small
+/// page size pays full-metadata deserialization only `max_results` times, not once per
+/// candidate.
+#[derive(Debug, Clone)]
+struct Candidate {
+    doc_ref: DocRef,
+    summary: crate::storage::CaseSummary,
+    /// Final relevance score used for ordering: the Reciprocal Rank Fusion of `lexical_score`'s
+    /// and `semantic_score`'s ranks (see [`SearchEngine::fuse_reciprocal_rank`]), not a raw
+    /// score from either stage.
+    score: f32,
+    match_type: MatchType,
+    provenance: MatchProvenance,
+    /// This candidate's raw score from the lexical (trie) stage, before fusion; `None` if the
+    /// lexical stage didn't find it. Carried through to [`SearchResult::lexical_score`].
+    lexical_score: Option<f32>,
+    /// This candidate's raw cosine similarity from the vector stage, before fusion; `None` if
+    /// the vector stage didn't find it. Carried through to [`SearchResult::semantic_score`].
+    semantic_score: Option<f32>,
+    /// Additional `DocRef`s for this same case matched within the same trie bucket as `doc_ref`
+    /// (e.g. a different paragraph containing the same query term), collapsed into this
+    /// candidate instead of appearing as separate results — see
+    /// `SearchEngine::run_plain_lexical_stage`'s per-bucket dedup and
+    /// [`SearchResult::passages`]. Empty for any candidate that isn't from that stage, or that
+    /// matched only one paragraph.
+    extra_doc_refs: Vec<DocRef>,
+}
+
+/// The output of the trie/vector search, dedup, sort, and filter stages: every matching
+/// candidate, not just the page that was requested. [`SearchEngine::execute_hybrid_search`]
+/// caches this (see [`QueryCache`]) so that paging through a large result set with
+/// [`SearchQuery::offset`] only re-runs the (comparatively cheap) hydration step, not the trie
+/// or vector search.
+#[derive(Debug, Clone)]
+struct RankedCandidates {
+    candidates: Vec<Candidate>,
+    /// Roll-up over the full `candidates` set, computed once up front since it doesn't depend
+    /// on which page is requested.
+    topic_facets: Vec<TopicFacet>,
+    /// Court/decade/jurisdiction/topic facet roll-ups, computed once up front for the same
+    /// reason as `topic_facets`; see [`SearchEngine::compute_facets`].
+    facets: SearchFacets,
+    /// `*_BUDGET_EXCEEDED` warnings raised while ranking (lexical, semantic, rerank stages);
+    /// combined with the per-page snippet warnings when a page is hydrated.
+    warnings: Vec<String>,
+    /// Synonym phrases actually searched as OR-alternatives; see
+    /// `SearchOutcome::applied_synonym_expansions`.
+    applied_synonym_expansions: Vec<String>,
+}
+
+/// Query cache for performance optimization, keyed on everything that affects
+/// [`SearchEngine::rank_candidates`]'s output — query text, syntax, sort order, filters,
+/// `max_results` (which sizes the vector stage's fetch; see `search_vector_for_query`), the
+/// weighting profile, and the per-query vector overrides — composed by
+/// [`SearchEngine::compose_cache_key`]. `offset` is deliberately excluded: `RankedCandidates` is
+/// captured before the page window is applied, specifically so a later page request for the same
+/// query reuses it (see [`SearchEngine::execute_hybrid_search`] and
+/// `test_paginating_with_offset_covers_every_result_with_no_duplicates_or_gaps`); folding
+/// `offset` into the key would just fragment the cache into one entry per page for no benefit.
+///
+/// Eviction is genuine least-recently-used, tracked via `order` (front = least recently used,
+/// back = most recently used) rather than whatever `HashMap::keys().next()` happened to return.
+/// Expired entries are reclaimed lazily on `get`/`insert` and periodically by
+/// [`SearchEngine::spawn_query_cache_sweep`], so a cache that only ever sees each expired query
+/// once still gets its memory back.
 struct QueryCache {
-    cache: HashMap<String, CachedResult>,
+    entries: HashMap<String, CachedResult>,
+    /// Recency order, least-recently-used first. Kept in sync with `entries` on every access;
+    /// invariant: exactly one entry per key in `entries`, no duplicates.
+    order: VecDeque<String>,
     max_size: usize,
+    hits: usize,
+    misses: usize,
+    evictions: usize,
+    expirations: usize,
+    /// Cumulative entries reclaimed on lookup for having been computed against an
+    /// [`SearchEngine`] index generation that's since moved on; see
+    /// [`SearchEngine::invalidate_cache`].
+    stale_invalidations: usize,
 }
 
-/// Cached search result
+/// Cached ranked candidate set, from before the `offset`/`max_results` page window is applied
 #[derive(Debug, Clone)]
 struct CachedResult {
-    results: Vec<SearchResult>,
+    ranked: RankedCandidates,
     timestamp: chrono::DateTime<chrono::Utc>,
     ttl_seconds: u64,
+    /// [`SearchEngine::index_generation`] at the time this entry was computed. Compared against
+    /// the engine's current generation on lookup so a query cached before the trie/vector index
+    /// changed underneath it is treated as a miss instead of serving stale results — see
+    /// [`SearchEngine::invalidate_cache`].
+    generation: u64,
+}
+
+impl CachedResult {
+    fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        now.timestamp() - self.timestamp.timestamp() >= self.ttl_seconds as i64
+    }
 }
 
 impl SearchEngine {
@@ -121,280 +704,5404 @@ impl SearchEngine {
         config: Arc<Config>,
         storage: Arc<StorageManager>,
     ) -> Result<Self> {
-        // Initialize trie index
-        let trie_index = Arc::new(RwLock::new(
-            TrieIndex::new(config.trie.clone()).await?
-        ));
+        let trie_index = TrieIndex::new(config.trie.clone()).await?;
+        let vector_index = VectorIndex::new(config.vector.clone()).await?;
+        let index_health = IndexHealth {
+            trie: IndexComponentStatus::Healthy,
+            vector: IndexComponentStatus::Healthy,
+        };
+        Self::from_indices(config, storage, trie_index, vector_index, index_health).await
+    }
 
-        // Initialize vector index
-        let vector_index = Arc::new(RwLock::new(
-            VectorIndex::new(config.vector.clone()).await?
-        ));
+    /// Create a search engine from trie/vector indices restored from an offline snapshot
+    /// directory (see the `index-build` CLI subcommand), rather than building them empty
+    /// and populating them via `reembed_case`/live ingestion.
+    ///
+    /// A missing or corrupt snapshot file does not fail startup: the bad file is quarantined
+    /// alongside itself as `<name>.corrupt-<timestamp>`, that component starts as an empty
+    /// index marked `Degraded` in [`SearchEngine::index_health`], and a background rebuild
+    /// from `storage` is scheduled automatically. Lexical (trie) search keeps serving while
+    /// the vector index rebuilds, and vice versa.
+    pub async fn from_snapshot<P: AsRef<std::path::Path>>(
+        config: Arc<Config>,
+        storage: Arc<StorageManager>,
+        snapshot_dir: P,
+    ) -> Result<Self> {
+        let snapshot_dir = snapshot_dir.as_ref();
+
+        let trie_path = snapshot_dir.join("trie.bin");
+        let (trie_index, trie_status) = match TrieIndex::load_from_disk(config.trie.clone(), &trie_path).await {
+            Ok(trie_index) => (trie_index, IndexComponentStatus::Healthy),
+            Err(e) => {
+                let reason = format!("Failed to load trie snapshot {:?}: {}", trie_path, e);
+                tracing::error!("{}", reason);
+                quarantine_corrupt_snapshot(&trie_path).await;
+                (
+                    TrieIndex::new(config.trie.clone()).await?,
+                    IndexComponentStatus::Degraded { reason, rebuilding: true },
+                )
+            }
+        };
+
+        let vector_path = snapshot_dir.join("vector_cache.bin");
+        let (vector_index, vector_status) = match VectorIndex::load_from_disk(config.vector.clone(), &vector_path).await {
+            Ok(vector_index) => (vector_index, IndexComponentStatus::Healthy),
+            Err(e) => {
+                let reason = format!("Failed to load vector snapshot {:?}: {}", vector_path, e);
+                tracing::error!("{}", reason);
+                quarantine_corrupt_snapshot(&vector_path).await;
+                (
+                    VectorIndex::new(config.vector.clone()).await?,
+                    IndexComponentStatus::Degraded { reason, rebuilding: true },
+                )
+            }
+        };
+
+        let any_degraded = matches!(trie_status, IndexComponentStatus::Degraded { .. })
+            || matches!(vector_status, IndexComponentStatus::Degraded { .. });
+
+        let index_health = IndexHealth { trie: trie_status, vector: vector_status };
+        let engine = Self::from_indices(config, storage, trie_index, vector_index, index_health).await?;
+
+        if any_degraded {
+            engine.spawn_background_index_rebuild();
+        }
+
+        Ok(engine)
+    }
+
+    /// Shared construction logic for [`SearchEngine::new`] and [`SearchEngine::from_snapshot`]
+    async fn from_indices(
+        config: Arc<Config>,
+        storage: Arc<StorageManager>,
+        mut trie_index: TrieIndex,
+        vector_index: VectorIndex,
+        index_health: IndexHealth,
+    ) -> Result<Self> {
+        // Inject the stopword set so `TrieConfig::skip_stopword_only_ngrams`/`min_token_length`
+        // (consulted by `TrieIndex::insert_content`/`insert_batch`) can tell a content-bearing
+        // sentence from a useless run of filler tokens; a construction failure here just leaves
+        // the filter dormant (an empty stopword set) rather than failing engine startup.
+        if let Ok(text_processor) = crate::text_processing::TextProcessor::new(config.text_processing.clone()) {
+            trie_index.set_stopwords(text_processor.stopwords().clone());
+        }
+
+        let query_normalizer = Arc::new(QueryNormalizer::new(config.text_processing.clone())?);
+
+        let lock_warn_threshold = Duration::from_millis(config.search.lock_hold_warn_threshold_ms);
+        let trie_index = Arc::new(TrieIndexHandle::new(trie_index));
+        let vector_index = Arc::new(InstrumentedRwLock::new("vector_index", lock_warn_threshold, vector_index));
 
         // Initialize query cache
         let query_cache = Arc::new(RwLock::new(
             QueryCache::new(config.search.query_cache_size)
         ));
 
-        Ok(Self {
+        // Load the topic taxonomy, preferring a user-supplied override over the bundled default
+        let taxonomy = Arc::new(match &config.search.taxonomy_path {
+            Some(path) => Taxonomy::load_from_file(path)?,
+            None => Taxonomy::load_bundled()?,
+        });
+
+        // Load the synonym table, preferring a user-supplied override over the bundled default,
+        // the same way `taxonomy` above does.
+        let synonym_table = Arc::new(match &config.search.synonyms_path {
+            Some(path) => crate::synonyms::SynonymTable::load_from_file(path)?,
+            None => crate::synonyms::SynonymTable::load_bundled()?,
+        });
+
+        let concurrency_limiter = Arc::new(Semaphore::new(config.search.max_concurrent_queries.max(1)));
+
+        let engine = Self {
             config,
             trie_index,
             vector_index,
             storage,
             query_cache,
-        })
+            taxonomy,
+            synonym_table,
+            query_normalizer,
+            concurrency_limiter,
+            index_health: Arc::new(RwLock::new(index_health)),
+            index_generation: Arc::new(AtomicU64::new(0)),
+        };
+        engine.spawn_query_cache_sweep();
+
+        Ok(engine)
     }
 
-    /// Perform search with the given query
-    pub async fn search(&self, query: &str) -> Result<Vec<SearchResult>> {
-        let search_query = SearchQuery {
-            query: query.to_string(),
-            max_results: Some(self.config.search.default_max_results),
-            court_filter: None,
-            date_range: None,
-            config: SearchConfig::default(),
-        };
+    /// Current index generation, bumped each time a background index rebuild completes.
+    /// Callers (e.g. `GET /search`) use this to let a CDN or client detect that a cached
+    /// response was served against an index that has since moved on.
+    pub fn index_generation(&self) -> u64 {
+        self.index_generation.load(Ordering::SeqCst)
+    }
 
-        self.search_with_params(search_query).await
+    /// Current trie/vector index load health, for the `/health` endpoint and search response
+    /// warnings (`INDEX_WARMING` for a degraded trie, `SEMANTIC_DEGRADED` for a degraded
+    /// vector index)
+    pub async fn index_health(&self) -> IndexHealth {
+        self.index_health.read().await.clone()
     }
 
-    /// Perform search with detailed parameters
-    pub async fn search_with_params(&self, query: SearchQuery) -> Result<Vec<SearchResult>> {
-        // Check cache first
-        if self.config.search.enable_query_cache {
-            if let Some(cached) = self.get_cached_result(&query.query).await? {
-                return Ok(cached);
+    /// Spawn a background task that rebuilds any `Degraded` index component from case data
+    /// already in `storage` and marks it `Healthy` once done. The trie is rebuilt one case at a
+    /// time but staged into a single writer (see below); the vector index is re-embedded via
+    /// [`VectorIndex::add_documents`] in `EmbeddingModelConfig::batch_size`-sized flushes rather
+    /// than one `add_document` call per case.
+    fn spawn_background_index_rebuild(&self) {
+        let trie_index = self.trie_index.clone();
+        let vector_index = self.vector_index.clone();
+        let storage = self.storage.clone();
+        let config = self.config.clone();
+        let index_health = self.index_health.clone();
+        let index_generation = self.index_generation.clone();
+
+        tokio::spawn(async move {
+            let (rebuild_trie, rebuild_vector) = {
+                let health = index_health.read().await;
+                (
+                    matches!(health.trie, IndexComponentStatus::Degraded { .. }),
+                    matches!(health.vector, IndexComponentStatus::Degraded { .. }),
+                )
+            };
+
+            if !rebuild_trie && !rebuild_vector {
+                return;
             }
-        }
 
-        // Validate query
-        self.validate_query(&query)?;
+            if storage.count_cases() == 0 {
+                tracing::info!("Background index rebuild found no stored cases; degraded index will stay empty");
+                return;
+            }
 
-        // Execute hybrid search
-        let results = self.execute_hybrid_search(&query).await?;
+            let text_processor = match crate::text_processing::TextProcessor::new(config.text_processing.clone()) {
+                Ok(processor) => processor,
+                Err(e) => {
+                    tracing::error!("Background index rebuild aborted: failed to construct text processor: {}", e);
+                    return;
+                }
+            };
 
-        // Cache results
-        if self.config.search.enable_query_cache {
-            self.cache_results(&query.query, &results).await?;
-        }
+            // Stage every case's trie mutations into one writer and publish it with a single
+            // commit at the end, rather than per case — that's what keeps this rebuild's clone
+            // cost proportional to one batch instead of one clone per case (see
+            // `TrieIndexHandle`'s docs).
+            let mut trie_writer = rebuild_trie.then(|| trie_index.begin_write());
+            let vector_batch_size = config.vector.model.batch_size.max(1);
+            let mut pending_vectors: Vec<(DocRef, String)> = Vec::with_capacity(vector_batch_size);
 
-        Ok(results)
-    }
+            // Walk every stored case a page at a time via `scan_cases` rather than
+            // `list_case_ids` + one `get_case_metadata` per id — this is exactly the "millions
+            // of cases" rebuild `scan_cases` was added for, so a page's worth of metadata never
+            // has to be materialized twice.
+            const REBUILD_PAGE_SIZE: usize = 256;
+            let mut cursor = None;
+            loop {
+                let (page, next_cursor) = match storage.scan_cases(cursor, REBUILD_PAGE_SIZE).await {
+                    Ok(page) => page,
+                    Err(e) => {
+                        tracing::error!("Background index rebuild aborted: failed to scan stored cases: {}", e);
+                        break;
+                    }
+                };
 
-    /// Execute hybrid search combining trie and vector search
-    async fn execute_hybrid_search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
-        let mut all_results = Vec::new();
-        let mut seen_cases = HashSet::new();
-
-        // 1. Trie search for exact matches
-        if query.config.enable_prefix {
-            let trie_results = self.search_trie(&query.query).await?;
-            for trie_result in trie_results.exact_matches {
-                if let Some(case_metadata) = self.storage.get_case_metadata(&trie_result.case_id).await? {
-                    if seen_cases.insert(trie_result.case_id) {
-                        let search_result = SearchResult {
-                            case_metadata,
-                            score: query.config.exact_match_weight,
-                            match_type: MatchType::Exact,
-                            snippet: self.generate_snippet(&trie_result, &query.query).await?,
-                            highlights: Vec::new(), // TODO: Generate highlights
-                        };
-                        all_results.push(search_result);
+                for metadata in page {
+                    let case_id = metadata.id;
+                    let Ok(Some(full_text)) = storage.get_case_text(&case_id, crate::storage::TextForm::Normalized).await else { continue };
+
+                    if let Some(trie) = trie_writer.as_mut() {
+                        let _ = trie.insert_case_name(&metadata.name, case_id);
+                        if let Ok(processed) = text_processor.process_text(&full_text.text).await {
+                            for (index, sentence) in processed.sentences.iter().enumerate() {
+                                let tokens = sentence.word_offsets();
+                                if !tokens.is_empty() {
+                                    let _ = trie.insert_content(
+                                        &tokens,
+                                        DocRef { case_id, paragraph_index: index, char_offset: None },
+                                    );
+                                }
+                            }
+                        }
+                        for citation in &metadata.citations {
+                            let _ = trie.insert_citation(
+                                citation,
+                                DocRef { case_id, paragraph_index: 0, char_offset: None },
+                            );
+                        }
                     }
-                }
-            }
-        }
 
-        // 2. Vector search for semantic matches
-        if query.config.enable_semantic && all_results.len() < query.config.max_results {
-            let vector_results = self.search_vector(&query.query).await?;
-            for vector_result in vector_results {
-                if vector_result.similarity_score >= query.config.min_similarity {
-                    if let Some(case_metadata) = self.storage.get_case_metadata(&vector_result.doc_ref.case_id).await? {
-                        if seen_cases.insert(vector_result.doc_ref.case_id) {
-                            let search_result = SearchResult {
-                                case_metadata,
-                                score: vector_result.similarity_score,
-                                match_type: MatchType::Semantic,
-                                snippet: self.generate_snippet(&vector_result.doc_ref, &query.query).await?,
-                                highlights: Vec::new(), // TODO: Generate highlights
-                            };
-                            all_results.push(search_result);
+                    if rebuild_vector {
+                        pending_vectors.push((
+                            DocRef { case_id, paragraph_index: 0, char_offset: None },
+                            full_text.text,
+                        ));
+                        if pending_vectors.len() >= vector_batch_size {
+                            let mut vector = vector_index.write("background_index_rebuild").await;
+                            let _ = vector.add_documents(std::mem::take(&mut pending_vectors)).await;
                         }
                     }
                 }
+
+                if next_cursor.is_none() {
+                    break;
+                }
+                cursor = next_cursor;
             }
-        }
 
-        // 3. Sort by score and apply filters
-        all_results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+            if let Some(trie_writer) = trie_writer {
+                trie_index.commit(trie_writer);
+            }
 
-        // Apply filters
-        all_results = self.apply_filters(all_results, query).await?;
+            if !pending_vectors.is_empty() {
+                let mut vector = vector_index.write("background_index_rebuild").await;
+                let _ = vector.add_documents(pending_vectors).await;
+            }
 
-        // Limit results
-        let max_results = query.max_results.unwrap_or(query.config.max_results);
-        all_results.truncate(max_results);
+            if rebuild_trie {
+                // The citation trie now has every case's own citation indexed, so this is the
+                // earliest point in the rebuild where in-text citations can resolve to anything.
+                let trie = trie_index.snapshot();
+                if let Err(e) = rebuild_citation_graph_with(&storage, &trie, &text_processor).await {
+                    tracing::error!("Background citation graph rebuild failed: {}", e);
+                }
+            }
 
-        Ok(all_results)
+            let mut health = index_health.write().await;
+            if rebuild_trie {
+                health.trie = IndexComponentStatus::Healthy;
+                tracing::info!("Background trie rebuild from storage completed");
+            }
+            if rebuild_vector {
+                health.vector = IndexComponentStatus::Healthy;
+                tracing::info!("Background vector rebuild from storage completed");
+            }
+            index_generation.fetch_add(1, Ordering::SeqCst);
+        });
     }
 
-    /// Search trie index
-    async fn search_trie(&self, query: &str) -> Result<TrieSearchResult> {
-        let trie = self.trie_index.read().await;
-        trie.search(query)
+    /// Access the topic taxonomy backing this search engine's topic filters and facets
+    pub fn taxonomy(&self) -> &Taxonomy {
+        &self.taxonomy
     }
 
-    /// Search vector index
-    async fn search_vector(&self, query: &str) -> Result<Vec<VectorSearchResult>> {
-        let mut vector = self.vector_index.write().await;
-        vector.search(query, 50).await // Get top 50 from vector search
+    /// Current search concurrency load as a percentage of `search.max_concurrent_queries`,
+    /// exported via `/stats` and logged when queries are shed
+    pub fn current_load_percent(&self) -> u8 {
+        let max_concurrent = self.config.search.max_concurrent_queries.max(1);
+        let busy = max_concurrent.saturating_sub(self.concurrency_limiter.available_permits());
+        ((busy as f64 / max_concurrent as f64) * 100.0).round() as u8
     }
 
-    /// Apply filters to search results
-    async fn apply_filters(
-        &self,
-        mut results: Vec<SearchResult>,
-        query: &SearchQuery,
-    ) -> Result<Vec<SearchResult>> {
-        // Court filter
-        if let Some(court_filter) = &query.court_filter {
-            results.retain(|result| court_filter.contains(&result.case_metadata.court));
-        }
-
-        // Date range filter
-        if let Some((start_date, end_date)) = &query.date_range {
-            results.retain(|result| {
-                result.case_metadata.decision_date >= *start_date
-                    && result.case_metadata.decision_date <= *end_date
-            });
-        }
-
-        Ok(results)
+    /// Roll up topic facet counts across a set of search results
+    pub fn topic_facets(&self, results: &[SearchResult]) -> Vec<TopicFacet> {
+        let assigned: Vec<String> = results
+            .iter()
+            .flat_map(|r| r.case_metadata.topics.clone())
+            .collect();
+        self.taxonomy.facet_counts(&assigned)
     }
 
-    /// Generate text snippet for search result
-    async fn generate_snippet(&self, doc_ref: &DocRef, query: &str) -> Result<String> {
-        // TODO: Generate intelligent snippet with context
-        // For now, return placeholder
-        Ok(format!("Snippet for case {} paragraph {}", doc_ref.case_id, doc_ref.paragraph_index))
+    /// Same roll-up as [`SearchEngine::topic_facets`], computed from pre-hydration
+    /// [`Candidate`]s so `execute_hybrid_search` can facet the full (pre-truncation) candidate
+    /// set without hydrating full [`CaseMetadata`] for candidates outside the returned page.
+    fn topic_facets_from_candidates(&self, candidates: &[Candidate]) -> Vec<TopicFacet> {
+        let assigned: Vec<String> = candidates
+            .iter()
+            .flat_map(|c| c.summary.topics.clone())
+            .collect();
+        self.taxonomy.facet_counts(&assigned)
     }
 
-    /// Validate search query
-    fn validate_query(&self, query: &SearchQuery) -> Result<()> {
-        if query.query.len() < self.config.search.min_query_length {
-            return Err(SearchError::InvalidSearchQuery {
-                query: query.query.clone(),
-                reason: format!("Query too short: minimum {} characters", self.config.search.min_query_length),
-            });
+    /// Court/decade/jurisdiction/topic facet roll-ups over `candidates` (the fused, deduped,
+    /// pre-filter candidate set, already capped to `SearchConfig::facet_candidate_limit` by the
+    /// caller). Each dimension is counted against candidates passing every filter *except* its
+    /// own (see [`SearchEngine::matches_filters_except`]), so faceting is answered entirely from
+    /// the [`crate::storage::CaseSummary`] projections already in hand — no extra metadata
+    /// hydration or storage round-trips.
+    fn compute_facets(&self, candidates: &[Candidate], query: &SearchQuery) -> Result<SearchFacets> {
+        let mut court_counts: HashMap<String, usize> = HashMap::new();
+        for candidate in candidates {
+            if self.matches_filters_except(&candidate.summary, query, Some(FacetDimension::Court))? {
+                *court_counts.entry(candidate.summary.court.clone()).or_insert(0) += 1;
+            }
         }
 
-        if query.query.len() > self.config.search.max_query_length {
-            return Err(SearchError::InvalidSearchQuery {
-                query: query.query.clone(),
-                reason: format!("Query too long: maximum {} characters", self.config.search.max_query_length),
-            });
+        let mut decade_counts: HashMap<String, usize> = HashMap::new();
+        for candidate in candidates {
+            if self.matches_filters_except(&candidate.summary, query, Some(FacetDimension::Decade))? {
+                *decade_counts.entry(Self::decade_label(candidate.summary.decision_date)).or_insert(0) += 1;
+            }
         }
 
-        Ok(())
+        let mut jurisdiction_counts: HashMap<String, usize> = HashMap::new();
+        for candidate in candidates {
+            if self.matches_filters_except(&candidate.summary, query, Some(FacetDimension::Jurisdiction))? {
+                *jurisdiction_counts.entry(Self::jurisdiction_label(&candidate.summary.jurisdiction)).or_insert(0) += 1;
+            }
+        }
+
+        let mut topic_assigned = Vec::new();
+        for candidate in candidates {
+            if self.matches_filters_except(&candidate.summary, query, Some(FacetDimension::Topic))? {
+                topic_assigned.extend(candidate.summary.topics.clone());
+            }
+        }
+
+        Ok(SearchFacets {
+            court: Self::sorted_facet_counts(court_counts),
+            decade: Self::sorted_facet_counts(decade_counts),
+            jurisdiction: Self::sorted_facet_counts(jurisdiction_counts),
+            topic: self.taxonomy.facet_counts(&topic_assigned),
+        })
     }
 
-    /// Get cached search result
-    async fn get_cached_result(&self, query: &str) -> Result<Option<Vec<SearchResult>>> {
-        let cache = self.query_cache.read().await;
-        Ok(cache.get(query))
+    /// The decade of `date`, formatted like `"1950s"`
+    fn decade_label(date: chrono::NaiveDate) -> String {
+        format!("{}s", (date.year() / 10) * 10)
     }
 
-    /// Cache search results
-    async fn cache_results(&self, query: &str, results: &[SearchResult]) -> Result<()> {
-        let mut cache = self.query_cache.write().await;
-        cache.insert(
-            query.to_string(),
-            results.to_vec(),
-            self.config.search.query_cache_ttl_seconds,
-        );
-        Ok(())
+    /// The variant name of `jurisdiction`, discarding the state/locality it carries — a
+    /// jurisdiction facet groups "how many are Federal vs. State", not "how many are
+    /// California vs. Texas".
+    fn jurisdiction_label(jurisdiction: &Jurisdiction) -> String {
+        match jurisdiction {
+            Jurisdiction::Federal => "Federal".to_string(),
+            Jurisdiction::State(_) => "State".to_string(),
+            Jurisdiction::Local(_) => "Local".to_string(),
+            Jurisdiction::International => "International".to_string(),
+        }
     }
 
-    /// Health check for search engine
-    pub async fn health_check(&self) -> Result<()> {
-        // Check if indices are loaded
-        let _trie = self.trie_index.read().await;
-        let _vector = self.vector_index.read().await;
-        
-        // Check storage connectivity
-        self.storage.health_check().await?;
-        
-        Ok(())
+    /// Turn a value -> count map into a [`FacetCount`] list, sorted by count descending (ties
+    /// broken lexicographically by value for deterministic ordering) — the most populous
+    /// buckets are what a filter sidebar wants shown first.
+    fn sorted_facet_counts(counts: HashMap<String, usize>) -> Vec<FacetCount> {
+        let mut facets: Vec<FacetCount> =
+            counts.into_iter().map(|(value, count)| FacetCount { value, count }).collect();
+        facets.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+        facets
     }
 
-    /// Get search engine statistics
-    pub async fn get_stats(&self) -> SearchEngineStats {
-        let vector = self.vector_index.read().await;
-        let cache = self.query_cache.read().await;
-        
-        SearchEngineStats {
-            total_cases_indexed: 0, // TODO: Get from storage
-            vector_index_stats: vector.get_stats(),
-            cache_stats: cache.get_stats(),
-        }
+    /// Perform search with the given query
+    pub async fn search(&self, query: &str) -> Result<Vec<SearchResult>> {
+        let search_query = SearchQuery {
+            query: query.to_string(),
+            max_results: Some(self.config.search.default_max_results),
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&self.config.search, &self.config.vector),
+        };
+
+        Ok(self.search_with_params(search_query).await?.results)
     }
-}
 
-impl QueryCache {
-    fn new(max_size: usize) -> Self {
-        Self {
-            cache: HashMap::new(),
-            max_size,
+    /// Run many independent queries sharing one embedding-model batch call for query
+    /// embeddings (see [`VectorIndex::generate_embeddings_batch`]) instead of encoding each
+    /// query's text on its own model invocation, then execute each query through the ordinary
+    /// single-query path ([`SearchEngine::search_with_params`]), so trie/vector work for
+    /// different queries in the batch still runs concurrently, bounded by the same
+    /// `search.max_concurrent_queries` semaphore every other caller shares. A failure embedding
+    /// one query's text up front doesn't fail the batch: the embedding cache is just left cold
+    /// for that text, and `search_vector_for_query` re-encodes it (surfacing the same error)
+    /// when that query's own turn comes up. Results are returned in the same order as `queries`,
+    /// one [`Result`] per query, so a failure in one query never drops or reorders the rest.
+    pub async fn search_batch(&self, queries: Vec<SearchQuery>) -> Vec<Result<Vec<SearchResult>>> {
+        let semantic_texts: Vec<&str> =
+            queries.iter().filter(|q| q.config.enable_semantic).map(|q| q.query.as_str()).collect();
+        if !semantic_texts.is_empty() {
+            let mut vector = self.vector_index.write("search_batch").await;
+            let _ = vector.generate_embeddings_batch(&semantic_texts).await;
         }
+
+        let futures = queries.into_iter().map(|query| self.search_with_params(query));
+        futures::future::join_all(futures).await.into_iter().map(|result| result.map(|outcome| outcome.results)).collect()
     }
 
-    fn get(&self, query: &str) -> Option<Vec<SearchResult>> {
-        if let Some(cached) = self.cache.get(query) {
-            let now = chrono::Utc::now();
-            let age = now.timestamp() - cached.timestamp.timestamp();
-            
-            if age < cached.ttl_seconds as i64 {
-                return Some(cached.results.clone());
+    /// Perform search with detailed parameters, returning the full outcome (results plus
+    /// the pre-truncation candidate count and facets). Use [`SearchEngine::search`] if only
+    /// the truncated result list is needed.
+    pub async fn search_with_params(&self, query: SearchQuery) -> Result<SearchOutcome> {
+        // Bound concurrent executions; shed load rather than let unbounded queries pile up.
+        // Acquired as an owned permit (rather than borrowed from `&self`) so it can move into
+        // the isolated task spawned below.
+        let wait_budget = Duration::from_millis(self.config.search.max_queue_wait_ms);
+        let limiter = self.concurrency_limiter.clone();
+        let permit = match tokio::time::timeout(wait_budget, limiter.acquire_owned()).await {
+            Ok(Ok(permit)) => permit,
+            Ok(Err(_)) => {
+                return Err(SearchError::Internal {
+                    message: "Search concurrency semaphore was closed".to_string(),
+                })
+            }
+            Err(_) => {
+                let current_load = self.current_load_percent();
+                tracing::warn!(
+                    current_load_percent = current_load,
+                    max_concurrent_queries = self.config.search.max_concurrent_queries,
+                    "Shedding search query: concurrency queue wait exceeded budget"
+                );
+                return Err(SearchError::SearchCapacityExceeded {
+                    current_load,
+                    details: format!(
+                        "Timed out after {}ms waiting for a search concurrency slot ({}% busy)",
+                        self.config.search.max_queue_wait_ms, current_load
+                    ),
+                });
             }
+        };
+
+        // Run the actual query execution (validation, hybrid search, snippet generation) in
+        // its own task. A panic anywhere in that path — e.g. a bad snippet
+        // generator — then fails only this request via `JoinError`, rather than unwinding
+        // through this future and taking down whatever else this connection's task was
+        // doing. `self.clone()` is a handful of Arc refcount bumps (see the type's doc
+        // comment), and moving the permit in keeps the concurrency slot held for the isolated
+        // task's whole lifetime, including if it panics.
+        let engine = self.clone();
+        let handle = tokio::task::spawn(async move {
+            let _permit = permit;
+            engine.execute_search_isolated(query).await
+        });
+
+        match handle.await {
+            Ok(result) => result,
+            Err(join_error) => Err(Self::panic_to_search_error(join_error)),
         }
-        None
     }
 
-    fn insert(&mut self, query: String, results: Vec<SearchResult>, ttl_seconds: u64) {
-        if self.cache.len() >= self.max_size {
-            // Simple eviction: remove oldest entry
-            if let Some(oldest_key) = self.cache.keys().next().cloned() {
-                self.cache.remove(&oldest_key);
+    /// Streamed counterpart to [`SearchEngine::search_with_params`], for `POST /search/stream`:
+    /// sends each result through `results_tx` as it's finalized instead of handing the caller
+    /// one `Vec` only once the whole page is ready, and returns the same aggregate fields
+    /// [`SearchOutcome`] carries once every result has gone out (or the receiver was dropped,
+    /// meaning the client walked away mid-stream).
+    ///
+    /// This crate's ranking fuses lexical and vector candidates via reciprocal rank fusion
+    /// before sorting and truncating (see [`SearchEngine::execute_hybrid_search`]), so results
+    /// genuinely become available only once the whole page has been scored — there's no way to
+    /// emit the first result before the last is known. What this buys a caller is therefore not
+    /// earlier results, but bounded memory on both ends of the wire: `results_tx` is expected to
+    /// be a bounded channel, so a slow consumer's back-pressure propagates through `send`'s
+    /// await point exactly as it would for a truly incremental producer, instead of this method
+    /// buffering the whole page in memory while the client drains it slowly.
+    pub async fn search_streamed(
+        &self,
+        query: SearchQuery,
+        results_tx: mpsc::Sender<SearchResult>,
+    ) -> Result<StreamedSearchSummary> {
+        let start_time = std::time::Instant::now();
+        let outcome = self.search_with_params(query).await?;
+        let degraded = !outcome.warnings.is_empty();
+
+        for result in outcome.results {
+            if results_tx.send(result).await.is_err() {
+                break;
             }
         }
 
-        self.cache.insert(query, CachedResult {
-            results,
-            timestamp: chrono::Utc::now(),
-            ttl_seconds,
-        });
+        Ok(StreamedSearchSummary {
+            total_candidates: outcome.total_candidates,
+            degraded,
+            query_time_ms: start_time.elapsed().as_millis() as u64,
+        })
     }
 
-    fn get_stats(&self) -> CacheStats {
-        CacheStats {
-            size: self.cache.len(),
-            max_size: self.max_size,
-        }
-    }
-}
+    /// Validation and hybrid search — the part of a query's execution run inside the isolated
+    /// task spawned by [`SearchEngine::search_with_params`]. Ranked-candidate cache population
+    /// happens inside [`SearchEngine::execute_hybrid_search`] itself, keyed on everything that
+    /// affects ranking except `offset` (see [`QueryCache`]) so that a later page request for the
+    /// same query reuses it regardless of `offset`.
+    async fn execute_search_isolated(&self, mut query: SearchQuery) -> Result<SearchOutcome> {
+        query.query = self.query_normalizer.normalize(&query.query);
+        let (mut query, fielded_warnings) = self.apply_fielded_query_syntax(query);
 
-/// Search engine statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SearchEngineStats {
-    pub total_cases_indexed: usize,
+        self.apply_weighting_profile(&mut query)?;
+
+        // Validate query
+        self.validate_query(&query)?;
+
+        // Validate topic filter node id, if present
+        if let Some(topic_filter) = &query.topic_filter {
+            self.taxonomy.validate_node_id(&topic_filter.node_id)?;
+        }
+
+        // Execute hybrid search
+        let mut outcome = self.execute_hybrid_search(&query).await?;
+        outcome.warnings.splice(0..0, fielded_warnings);
+
+        if outcome.total_candidates == 0 {
+            let suggestions = self.generate_spelling_suggestions(&query.query)?;
+
+            if query.config.auto_correct {
+                if let Some(correction) = suggestions.first().cloned() {
+                    let mut corrected_query = query.clone();
+                    corrected_query.query = correction.clone();
+                    let mut corrected_outcome = self.execute_hybrid_search(&corrected_query).await?;
+                    corrected_outcome.warnings.splice(0..0, outcome.warnings);
+                    corrected_outcome.applied_correction = Some(correction);
+                    return Ok(corrected_outcome);
+                }
+            }
+
+            outcome.suggestions = suggestions;
+        }
+
+        Ok(outcome)
+    }
+
+    /// Generate up to [`MAX_SUGGESTIONS`] "did you mean" rewrites of `query_text` for a query
+    /// that came back with zero results, by reusing [`TrieIndex::search_fuzzy`]'s trie traversal
+    /// rather than a separate spelling index — a suggestion is only ever offered for text that's
+    /// actually indexed as a case name, content phrase, or citation. Ranked by corpus frequency
+    /// (how many documents the matched text appears in) rather than edit distance alone, since a
+    /// well-known case one edit away is a better suggestion than an obscure one that's closer.
+    fn generate_spelling_suggestions(&self, query_text: &str) -> Result<Vec<String>> {
+        let trie = self.trie_index.snapshot();
+        let matches = trie.search_fuzzy(query_text, Some(MAX_SUGGESTION_EDIT_DISTANCE))?;
+
+        // An exact (edit_distance == 0) "match" can't happen here since the caller only asks
+        // when the search that already tried an exact match came back empty, but a defensive
+        // filter costs nothing and keeps this correct if that assumption ever changes.
+        let mut best_by_text: HashMap<String, FuzzyMatch> = HashMap::new();
+        for candidate in matches.into_iter().filter(|m| m.edit_distance > 0 && !m.document_refs.is_empty()) {
+            let key = candidate.text.to_lowercase();
+            best_by_text
+                .entry(key)
+                .and_modify(|existing| {
+                    if candidate.document_refs.len() > existing.document_refs.len() {
+                        *existing = candidate.clone();
+                    }
+                })
+                .or_insert(candidate);
+        }
+
+        let mut ranked: Vec<FuzzyMatch> = best_by_text.into_values().collect();
+        ranked.sort_by(|a, b| {
+            b.document_refs
+                .len()
+                .cmp(&a.document_refs.len())
+                .then_with(|| a.edit_distance.cmp(&b.edit_distance))
+                .then_with(|| a.text.cmp(&b.text))
+        });
+
+        Ok(ranked.into_iter().take(MAX_SUGGESTIONS).map(|m| m.text).collect())
+    }
+
+    /// Pull recognized `field:value` prefixes (see [`crate::fielded_query`]) out of a
+    /// `Plain`-syntax query's text and fold them into `court_filter`/`judge_filter`/
+    /// `topic_filter`/`date_range`, leaving the residual free text as `query.query`. A
+    /// `Boolean`-syntax query is returned unchanged — fielded prefixes are a plain-search
+    /// convenience layered on top of the bag-of-words path, not part of the AND/OR/NOT grammar.
+    /// An explicit filter already set on `query` (e.g. from `SearchRequest::court_filter`) is
+    /// extended, not overridden, by a matching field prefix found in the text. Only the first
+    /// `topic:` value is honored, since `SearchQuery::topic_filter` holds a single taxonomy
+    /// node; an already-set `topic_filter` wins over any prefix found in the text.
+    fn apply_fielded_query_syntax(&self, mut query: SearchQuery) -> (SearchQuery, Vec<String>) {
+        if query.syntax != SearchSyntax::Plain {
+            return (query, Vec::new());
+        }
+
+        let fielded = crate::fielded_query::parse(&query.query);
+        query.query = fielded.text;
+
+        if let Some(court) = fielded.court {
+            query.court_filter.get_or_insert_with(Vec::new).extend(court);
+        }
+        if let Some(judge) = fielded.judge {
+            query.judge_filter.get_or_insert_with(Vec::new).extend(judge);
+        }
+        if query.topic_filter.is_none() {
+            if let Some(node_id) = fielded.topic.and_then(|topics| topics.into_iter().next()) {
+                query.topic_filter = Some(TopicFilter { node_id, include_descendants: false });
+            }
+        }
+        if query.date_range.is_none() {
+            if let Some(date_range) = fielded.date_range {
+                query.date_range = Some(date_range);
+            }
+        }
+
+        (query, fielded.warnings)
+    }
+
+    /// Converts a panicked or cancelled isolated-search task into a `SearchError::Internal`.
+    /// Rust's default panic hook already prints the panic message and backtrace to stderr
+    /// (with `RUST_BACKTRACE=1`) before `JoinError` reaches us, so we only need to log a
+    /// short summary here, not recapture the backtrace ourselves.
+    fn panic_to_search_error(join_error: tokio::task::JoinError) -> SearchError {
+        if join_error.is_panic() {
+            let panic_payload = join_error.into_panic();
+            let message = panic_payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic_payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "search task panicked with a non-string payload".to_string());
+            tracing::error!(panic_message = %message, "Search task panicked; isolating failure to this request");
+            SearchError::Internal { message: format!("search panicked: {message}") }
+        } else {
+            tracing::warn!("Search task was cancelled before completing");
+            SearchError::Internal { message: "search task was cancelled".to_string() }
+        }
+    }
+
+    /// Execute hybrid search combining trie and vector search, honoring the per-stage latency
+    /// budgets in `query.config.budgets` (see `config::SearchStageBudgets`). Each budget is
+    /// enforced cooperatively via `tokio::time::timeout` around that stage rather than by
+    /// cancelling the underlying work partway through, so a stage that overruns its budget
+    /// contributes whatever it had already produced (or, for snippets, falls back to a cheap
+    /// placeholder) instead of failing the query outright.
+    async fn execute_hybrid_search(&self, query: &SearchQuery) -> Result<SearchOutcome> {
+        let started_at = std::time::Instant::now();
+
+        let ranked = if self.config.search.enable_query_cache {
+            if let Some(cached) = self.get_cached_ranked_candidates(query).await {
+                cached
+            } else {
+                let ranked = self.rank_candidates(query).await?;
+                self.cache_ranked_candidates(query, &ranked).await;
+                ranked
+            }
+        } else {
+            self.rank_candidates(query).await?
+        };
+
+        let total_candidates = ranked.candidates.len();
+        let offset = query.offset.min(total_candidates);
+        let max_results = query.max_results.unwrap_or(query.config.max_results);
+        let page: Vec<Candidate> = ranked.candidates.into_iter().skip(offset).take(max_results).collect();
+
+        let applied_synonym_expansions = ranked.applied_synonym_expansions.clone();
+        let hydrated_before = self.storage.metadata_read_count();
+
+        // One batched fetch instead of one `get_case_metadata` await per candidate below.
+        let page_case_ids: Vec<CaseId> = page.iter().map(|c| c.doc_ref.case_id).collect();
+        let metadata_by_id = self.storage.get_cases_metadata(&page_case_ids).await?;
+
+        // Snippet generation is budgeted across the whole batch of results being hydrated, so
+        // the clock starts here rather than being reset per-stage.
+        let snippets_started_at = std::time::Instant::now();
+        let mut snippets_budget_exceeded = false;
+        let mut warnings = ranked.warnings.clone();
+        let mut results = Vec::with_capacity(page.len());
+        for candidate in page {
+            let Some(case_metadata) = metadata_by_id.get(&candidate.doc_ref.case_id).cloned() else {
+                continue;
+            };
+            if let Some(result) = self
+                .hydrate_candidate(candidate, case_metadata, &query.query, query.config.budgets.snippets_ms, snippets_started_at, &mut snippets_budget_exceeded, &mut warnings)
+                .await?
+            {
+                results.push(result);
+            }
+        }
+
+        if query.config.enable_citation_dedup {
+            results = Self::dedup_citation_overlap(results);
+        }
+
+        tracing::debug!(
+            elapsed_ms = started_at.elapsed().as_millis() as u64,
+            total_candidates,
+            offset,
+            page_size = results.len(),
+            metadata_hydrations = self.storage.metadata_read_count() - hydrated_before,
+            "Hybrid search query timing breakdown"
+        );
+
+        Ok(SearchOutcome {
+            results,
+            total_candidates,
+            topic_facets: ranked.topic_facets,
+            warnings,
+            suggestions: Vec::new(),
+            applied_correction: None,
+            facets: ranked.facets,
+            applied_synonym_expansions,
+        })
+    }
+
+    /// Trie search for exact matches (or wildcard matches, if the query contains a `*` token —
+    /// see `TrieIndex::search`'s internal routing to `search_wildcard`). Dedup and filtering
+    /// below run against `storage.get_case_summary` rather than `get_case_metadata`, so a query
+    /// with a large candidate pool doesn't pay full-metadata deserialization for candidates that
+    /// never make it past `max_results`. Split out of [`SearchEngine::rank_candidates`] so it can
+    /// run concurrently with [`SearchEngine::run_vector_stage`] via `tokio::join!`; returns its
+    /// own warnings and synonym expansions rather than writing into a shared `&mut` accumulator,
+    /// since both stages hold only a shared `&self` while running side by side.
+    async fn run_lexical_stage(&self, query: &SearchQuery) -> Result<(Vec<Candidate>, Vec<String>, Vec<String>)> {
+        let mut lexical_seen = HashSet::new();
+        let mut warnings = Vec::new();
+        let mut applied_synonym_expansions = Vec::new();
+        let candidates = if query.config.enable_prefix {
+            match query.syntax {
+                SearchSyntax::Plain => {
+                    self.run_plain_lexical_stage(query, &mut lexical_seen, &mut warnings, &mut applied_synonym_expansions).await?
+                }
+                SearchSyntax::Boolean => match crate::boolean_query::parse(&query.query) {
+                    Ok(ast) => self.run_boolean_lexical_stage(query, &ast, &mut lexical_seen, &mut warnings).await?,
+                    Err(err) => {
+                        warnings.push(format!("BOOLEAN_QUERY_FALLBACK: {err}"));
+                        self.run_plain_lexical_stage(query, &mut lexical_seen, &mut warnings, &mut applied_synonym_expansions).await?
+                    }
+                },
+            }
+        } else {
+            Vec::new()
+        };
+        Ok((candidates, warnings, applied_synonym_expansions))
+    }
+
+    /// Vector search for semantic matches. Deliberately not deduped against the lexical stage's
+    /// candidates — a case matched by both stages needs its rank in *this* list too, so fusion in
+    /// [`SearchEngine::rank_candidates`] can credit it for appearing in both. Split out for the
+    /// same reason as [`SearchEngine::run_lexical_stage`]; `warnings` is that caller's own
+    /// accumulator (or a scratch one it merges in afterward), not shared with the lexical stage's.
+    async fn run_vector_stage(&self, query: &SearchQuery, warnings: &mut Vec<String>) -> Result<Vec<Candidate>> {
+        if !query.config.enable_semantic {
+            return Ok(Vec::new());
+        }
+
+        let vector_results = self
+            .run_with_stage_budget(query.config.budgets.semantic_ms, "SEMANTIC_BUDGET_EXCEEDED", warnings, self.search_vector_for_query(query))
+            .await?
+            .unwrap_or_default();
+        let provenance = if query.config.enable_rerank { MatchProvenance::Reranker } else { MatchProvenance::Vector };
+        let mut vector_seen = HashSet::new();
+        let mut out = Vec::new();
+        for vector_result in vector_results {
+            if vector_result.similarity_score >= query.config.min_similarity {
+                if let Some(summary) = self.storage.get_case_summary(&vector_result.doc_ref.case_id).await? {
+                    let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                    if vector_seen.insert(dedup_key) {
+                        out.push(Candidate {
+                            doc_ref: vector_result.doc_ref,
+                            summary,
+                            score: vector_result.similarity_score,
+                            match_type: MatchType::Semantic,
+                            provenance,
+                            lexical_score: None,
+                            semantic_score: Some(vector_result.similarity_score),
+                            extra_doc_refs: Vec::new(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Run the trie/vector search, dedup, sort, and filter stages to produce the full ranked
+    /// candidate set for `query`, independent of which page ([`SearchQuery::offset`] /
+    /// `max_results`) was requested. Split out of [`SearchEngine::execute_hybrid_search`] so it
+    /// can be cached and reused across page requests for the same query.
+    async fn rank_candidates(&self, query: &SearchQuery) -> Result<RankedCandidates> {
+        // 0. Direct citation lookup: a query that's entirely a citation (`"347 U.S. 483"`) or a
+        // partial one (`"410 U.S."`) is resolved against the citation trie and the
+        // storage-level citation index directly, short-circuiting the lexical/semantic stages
+        // below entirely — see `resolve_citation_query`'s doc comment for why token search on
+        // its own often misses these.
+        if let Some(citation_candidates) = self.resolve_citation_query(query).await? {
+            return self.finish_ranking(citation_candidates, query, Vec::new(), Vec::new());
+        }
+
+        let budgets = &query.config.budgets;
+
+        // 1 & 2. Trie search for exact/wildcard matches and vector search for semantic matches,
+        // run concurrently via `tokio::join!` — neither stage depends on the other's output, and
+        // the vector stage's own network/ANN latency previously sat entirely behind the trie
+        // stage's. `query.config.enable_vector_short_circuit` opts back into the old sequential
+        // behavior (skip the vector stage once the lexical stage alone has filled
+        // `max_results`), at the cost of losing that overlap — see its doc comment for why this
+        // can no longer be the default behavior once the two stages are launched together: there
+        // is no lexical result count to inspect before the vector stage has to decide whether to
+        // start at all. Kept as two independently-ranked lists (not merged into one) so
+        // `Self::fuse_reciprocal_rank` can rank each independently before fusing them.
+        let (lexical_candidates, vector_candidates, mut warnings, applied_synonym_expansions) =
+            if query.config.enable_vector_short_circuit {
+                let (lexical_candidates, mut warnings, applied_synonym_expansions) =
+                    self.run_lexical_stage(query).await?;
+                let vector_candidates = if lexical_candidates.len() < query.config.max_results {
+                    self.run_vector_stage(query, &mut warnings).await?
+                } else {
+                    Vec::new()
+                };
+                (lexical_candidates, vector_candidates, warnings, applied_synonym_expansions)
+            } else {
+                let mut vector_warnings = Vec::new();
+                let (lexical_result, vector_result) =
+                    tokio::join!(self.run_lexical_stage(query), self.run_vector_stage(query, &mut vector_warnings));
+                let (lexical_candidates, mut warnings, applied_synonym_expansions) = lexical_result?;
+                warnings.extend(vector_warnings);
+                (lexical_candidates, vector_result?, warnings, applied_synonym_expansions)
+            };
+
+        // 3. Fuse the two independently-ranked lists via Reciprocal Rank Fusion (see
+        // `Self::fuse_reciprocal_rank`) rather than comparing their raw, non-comparable scores.
+        // A rerank budget guards this fusion (the closest thing this engine has today to a
+        // distinct reranking stage — see `MatchProvenance::Reranker`'s doc comment); skipping it
+        // falls back to lexical hits first, then unmatched semantic hits, in each stage's own
+        // order — still a usable (if unfused) result set.
+        let rerank_stage = async {
+            #[cfg(test)]
+            tests::maybe_delay_rerank().await;
+        };
+        let candidates = match budgets.rerank_ms {
+            Some(ms) => {
+                if tokio::time::timeout(Duration::from_millis(ms), rerank_stage).await.is_ok() {
+                    Self::fuse_reciprocal_rank(lexical_candidates, vector_candidates, query.config.rrf_k)
+                } else {
+                    warnings.push("RERANK_BUDGET_EXCEEDED".to_string());
+                    Self::concat_unfused(lexical_candidates, vector_candidates)
+                }
+            }
+            None => {
+                rerank_stage.await;
+                Self::fuse_reciprocal_rank(lexical_candidates, vector_candidates, query.config.rrf_k)
+            }
+        };
+
+        self.finish_ranking(candidates, query, warnings, applied_synonym_expansions)
+    }
+
+    /// Shared tail of `rank_candidates`: compute facets, apply filters, sort, and roll up topic
+    /// facets over whatever candidate set the caller produced — either the fused lexical/vector
+    /// list, or a direct citation-lookup hit from `resolve_citation_query`.
+    fn finish_ranking(
+        &self,
+        mut candidates: Vec<Candidate>,
+        query: &SearchQuery,
+        warnings: Vec<String>,
+        applied_synonym_expansions: Vec<String>,
+    ) -> Result<RankedCandidates> {
+        // Facets are computed from the fused-but-not-yet-filtered candidate set, capped to
+        // bound latency on a query with a very large candidate pool, and *before*
+        // `apply_filters` below — each dimension applies every filter except its own (standard
+        // faceting semantics; see `compute_facets`), which the fully-filtered `candidates` list
+        // can no longer do once its own dimension's filter has already excluded non-matches.
+        let facet_limit = query.config.facet_candidate_limit;
+        let facets = self.compute_facets(&candidates[..candidates.len().min(facet_limit)], query)?;
+
+        // Apply filters
+        candidates = self.apply_filters(candidates, query)?;
+
+        // Sort applies after scoring and filtering but before the page window; see `SortOrder`.
+        self.sort_candidates(&mut candidates, query);
+
+        // Belt-and-suspenders clamp on `SearchResult::score`'s documented `0.0..=1.0` contract:
+        // every scoring path above (`relative_lexical_weight`/`relative_lexical_weight_for`,
+        // `fuse_reciprocal_rank`, `min_similarity`-gated semantic scores) already produces a
+        // value in range, but a future custom rerank hook (see `AnnSearchHook`) isn't obligated
+        // to, and there's no cheaper place to catch that than right before scores leave this
+        // module.
+        for candidate in &mut candidates {
+            candidate.score = candidate.score.clamp(0.0, 1.0);
+        }
+
+        // The pre-existing (non-faceting-semantics) topic roll-up is computed from the same
+        // pre-page-window pass, and can be answered entirely from the summaries already in hand
+        let topic_facets = self.topic_facets_from_candidates(&candidates);
+
+        Ok(RankedCandidates {
+            candidates,
+            topic_facets,
+            facets,
+            warnings,
+            applied_synonym_expansions,
+        })
+    }
+
+    /// Resolve `query.query` as a direct citation lookup (see `crate::citation::looks_like_citation`
+    /// and `TrieIndex::resolve_citation`) rather than falling through to ordinary token search,
+    /// where reporter punctuation splitting (`"410 U.S. 113"` tokenizing as `"u.s."` mid-sentence
+    /// in the content trie) often causes an exact citation to be missed. An exact or
+    /// year-mismatched match scores `1.0` and is labeled `MatchType::Citation`; a partial
+    /// citation (`"410 U.S."`) resolves to `MatchType::Citation` completions scored at
+    /// `citation_match_weight`, the same weight ordinary citation-trie bucket hits get. Also
+    /// checks the storage-level citation index (`storage::SecondaryIndexField::Citation`) for a
+    /// case whose primary citation matches but was never tokenized into the citation trie.
+    /// Returns `None` — not an error — when the query doesn't look like a citation, or looks
+    /// like one but resolves to nothing, so the caller falls back to normal search instead of
+    /// returning an empty result for what might just be a query that starts with a number.
+    async fn resolve_citation_query(&self, query: &SearchQuery) -> Result<Option<Vec<Candidate>>> {
+        if !crate::citation::looks_like_citation(&query.query) {
+            return Ok(None);
+        }
+
+        let trie = self.trie_index.snapshot();
+        let (doc_refs, score): (Vec<DocRef>, f32) = match trie.resolve_citation(&query.query) {
+            CitationResolution::Exact(result) => (result.exact_matches, 1.0),
+            CitationResolution::YearMismatch { result, .. } => (result.exact_matches, 1.0),
+            CitationResolution::Prefix(result) => {
+                // `prefix_completions` are the completed citation *text* (e.g. `"410 U.S.
+                // 113"`), not document references; resolve each one exactly to collect its refs.
+                let mut doc_refs = Vec::new();
+                for completion in &result.prefix_completions {
+                    if let CitationResolution::Exact(exact) = trie.resolve_citation(completion) {
+                        doc_refs.extend(exact.exact_matches);
+                    }
+                }
+                (doc_refs, Self::relative_lexical_weight_for(query.config.citation_match_weight, &query.config))
+            }
+            CitationResolution::NoMatch => (Vec::new(), 0.0),
+        };
+
+        let normalized = crate::citation::normalize_for_index(&query.query);
+        let storage_case_ids =
+            self.storage.find_case_ids_by(SecondaryIndexField::Citation, &normalized).await?;
+
+        let mut seen = HashSet::new();
+        let mut candidates = Vec::new();
+        for doc_ref in doc_refs {
+            if let Some(summary) = self.storage.get_case_summary(&doc_ref.case_id).await? {
+                let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                if seen.insert(dedup_key) {
+                    candidates.push(Candidate {
+                        doc_ref,
+                        summary,
+                        score,
+                        match_type: MatchType::Citation,
+                        provenance: MatchProvenance::TrieCitation,
+                        lexical_score: Some(score),
+                        semantic_score: None,
+                        extra_doc_refs: Vec::new(),
+                    });
+                }
+            }
+        }
+        for case_id in storage_case_ids {
+            if let Some(summary) = self.storage.get_case_summary(&case_id).await? {
+                let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                if seen.insert(dedup_key) {
+                    candidates.push(Candidate {
+                        doc_ref: DocRef { case_id, paragraph_index: 0, char_offset: None },
+                        summary,
+                        score: 1.0,
+                        match_type: MatchType::Citation,
+                        provenance: MatchProvenance::TrieCitation,
+                        lexical_score: Some(1.0),
+                        semantic_score: None,
+                        extra_doc_refs: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        if candidates.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(candidates))
+        }
+    }
+
+    /// Combine independently-ranked lexical and vector candidate lists into one, via Reciprocal
+    /// Rank Fusion: each list is sorted by its own raw score (descending) to establish a
+    /// 1-indexed rank, then every candidate's fused score is `Σ 1/(k + rank)` summed over every
+    /// list it appears in. A case appearing in both lists therefore sums two terms and outranks
+    /// a single-source hit at the same per-list rank, without needing the lexical and vector raw
+    /// scores to be on comparable scales in the first place. `Candidate::score` is overwritten
+    /// with the fused score; `lexical_score`/`semantic_score` keep each source's raw score for
+    /// `SearchResult`'s debugging fields of the same name. When both lists contain a case, the
+    /// lexical candidate's `doc_ref`/`match_type`/`provenance` win (a lexical hit says more about
+    /// *why* a case matched), but the vector candidate's `semantic_score` is still recorded.
+    fn fuse_reciprocal_rank(mut lexical: Vec<Candidate>, mut vector: Vec<Candidate>, k: f32) -> Vec<Candidate> {
+        lexical.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        vector.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut fused: HashMap<CaseId, Candidate> = HashMap::new();
+        let mut rrf_scores: HashMap<CaseId, f32> = HashMap::new();
+
+        for (rank, candidate) in lexical.into_iter().enumerate() {
+            let dedup_key = candidate.summary.duplicate_of.unwrap_or(candidate.summary.id);
+            *rrf_scores.entry(dedup_key).or_insert(0.0) += 1.0 / (k + (rank + 1) as f32);
+            fused.entry(dedup_key).or_insert(candidate);
+        }
+        for (rank, candidate) in vector.into_iter().enumerate() {
+            let dedup_key = candidate.summary.duplicate_of.unwrap_or(candidate.summary.id);
+            *rrf_scores.entry(dedup_key).or_insert(0.0) += 1.0 / (k + (rank + 1) as f32);
+            let semantic_score = candidate.semantic_score;
+            fused.entry(dedup_key).and_modify(|existing| existing.semantic_score = existing.semantic_score.or(semantic_score)).or_insert(candidate);
+        }
+
+        let mut out: Vec<Candidate> = fused
+            .into_iter()
+            .map(|(dedup_key, mut candidate)| {
+                candidate.score = rrf_scores[&dedup_key];
+                candidate
+            })
+            .collect();
+        out.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        out
+    }
+
+    /// Fallback merge used when `RERANK_BUDGET_EXCEEDED`: lexical hits first (in the lexical
+    /// stage's own order), then any semantic hits not already covered — no fused score, since
+    /// fusion is exactly the work this budget cut short.
+    fn concat_unfused(lexical: Vec<Candidate>, vector: Vec<Candidate>) -> Vec<Candidate> {
+        let seen: HashSet<CaseId> = lexical.iter().map(|c| c.summary.duplicate_of.unwrap_or(c.summary.id)).collect();
+        let mut out = lexical;
+        out.extend(vector.into_iter().filter(|c| !seen.contains(&c.summary.duplicate_of.unwrap_or(c.summary.id))));
+        out
+    }
+
+    /// Rescale a raw `*_match_weight` config value into `[0, 1]` relative to the strongest of
+    /// the three configured lexical weights, so `SearchResult::score`'s documented `0.0..=1.0`
+    /// range holds even when a deployment raises `exact_match_weight`/`case_name_match_weight`/
+    /// `citation_match_weight` above `1.0` to tune *relative* ranking between match origins.
+    /// Every lexical candidate's score is `relative_lexical_weight(origin_weight, ...) *
+    /// term_coverage`, where `term_coverage` is `1.0` for a full trie match, the fraction of
+    /// query tokens matched for a minimum-should-match partial match, and a smaller
+    /// per-match-type factor (e.g. [`SUBSTRING_MATCH_SCORE_FACTOR`]) for a weaker match on the
+    /// same origin — so the
+    /// weight only ever selects *which* origin ranks higher, never how far above `1.0` the final
+    /// score can go. Takes the three weights directly, rather than a `SearchConfig`, so
+    /// `SearchEngine::more_like_this` (which only has `config::SearchEngineConfig` in scope) can
+    /// call it too.
+    fn relative_lexical_weight(weight: f32, exact_match_weight: f32, case_name_match_weight: f32, citation_match_weight: f32) -> f32 {
+        let strongest = exact_match_weight.max(case_name_match_weight).max(citation_match_weight).max(1.0);
+        (weight / strongest).clamp(0.0, 1.0)
+    }
+
+    /// [`Self::relative_lexical_weight`], reading the three weights off a [`SearchConfig`] —
+    /// the common case, since every lexical candidate is scored during a specific query with its
+    /// own (possibly per-request-overridden) `SearchConfig` already in hand.
+    fn relative_lexical_weight_for(weight: f32, config: &SearchConfig) -> f32 {
+        Self::relative_lexical_weight(
+            weight,
+            config.exact_match_weight,
+            config.case_name_match_weight,
+            config.citation_match_weight,
+        )
+    }
+
+    /// Parse a `SearchConfig::min_should_match` spec into the number of `term_count` tokens a
+    /// document must contain to qualify for the lexical stage's coverage-based fallback (see
+    /// [`SearchEngine::run_plain_lexical_stage`]). Three shapes, checked in order:
+    /// - `"N<P%"` (the default `"2<75%"`): every token is required when `term_count <= N`,
+    ///   otherwise `P%` of them (rounded up).
+    /// - `"P%"`: always `P%` of `term_count` (rounded up), regardless of query length.
+    /// - a bare integer `"N"`: always exactly `N` tokens (clamped to `term_count`).
+    ///
+    /// An unparseable spec falls back to requiring every token — the pre-existing behavior
+    /// before `min_should_match` existed — rather than erroring a query over a config typo.
+    fn required_term_count(spec: &str, term_count: usize) -> usize {
+        fn percentage_of(term_count: usize, percent_text: &str) -> Option<usize> {
+            let percent: f32 = percent_text.strip_suffix('%')?.trim().parse().ok()?;
+            Some(((term_count as f32 * percent / 100.0).ceil() as usize).clamp(0, term_count))
+        }
+
+        let spec = spec.trim();
+        if let Some((threshold_text, percent_text)) = spec.split_once('<') {
+            if let (Ok(threshold), Some(required)) = (threshold_text.trim().parse::<usize>(), percentage_of(term_count, percent_text.trim())) {
+                return if term_count <= threshold { term_count } else { required };
+            }
+        } else if let Some(required) = percentage_of(term_count, spec) {
+            return required;
+        } else if let Ok(required) = spec.parse::<usize>() {
+            return required.min(term_count);
+        }
+
+        term_count
+    }
+
+    /// Fold a newly matched `DocRef` for a case already present in `raw_candidates` into that
+    /// candidate's [`Candidate::extra_doc_refs`] instead of pushing a second, competing
+    /// candidate for the same case — see [`MULTI_PASSAGE_BONUS_FACTOR`]. `base_score` is the raw
+    /// per-match-type score this trie bucket would otherwise have assigned the duplicate on its
+    /// own; the existing candidate's score becomes `base_score` plus a logarithmic bonus for
+    /// each extra paragraph, so a case matching in many paragraphs of a weak-origin bucket still
+    /// can't out-rank a single strong-origin (case name/citation) match — the final clamp in
+    /// [`Self::finish_ranking`] keeps the sum within `SearchResult::score`'s documented range.
+    fn merge_multi_passage_candidate(
+        raw_candidates: &mut Vec<Candidate>,
+        local_seen: &mut HashMap<CaseId, usize>,
+        dedup_key: CaseId,
+        base_score: f32,
+        candidate: Candidate,
+    ) {
+        match local_seen.get(&dedup_key) {
+            Some(&index) => {
+                raw_candidates[index].extra_doc_refs.push(candidate.doc_ref);
+                let extra_hits = raw_candidates[index].extra_doc_refs.len() as f32;
+                let bonus = extra_hits.ln_1p() * MULTI_PASSAGE_BONUS_FACTOR;
+                raw_candidates[index].score = (base_score + bonus).min(1.0);
+                raw_candidates[index].lexical_score = Some(raw_candidates[index].score);
+            }
+            None => {
+                local_seen.insert(dedup_key, raw_candidates.len());
+                raw_candidates.push(candidate);
+            }
+        }
+    }
+
+    /// Grouping key for `Self::dedup_citation_overlap`: a case's normalized primary citation
+    /// (see `citation::normalize_for_index`, the same normalization the citation trie indexes
+    /// under), or — when a case has no citation at all — its lowercased name paired with its
+    /// decision date, since two ingestion sources sometimes supply the same opinion with the
+    /// citation field missing on one side.
+    fn citation_dedup_key(case_metadata: &CaseMetadata) -> String {
+        if case_metadata.citation.trim().is_empty() {
+            format!("{}|{}", case_metadata.name.trim().to_lowercase(), case_metadata.decision_date)
+        } else {
+            crate::citation::normalize_for_index(&case_metadata.citation)
+        }
+    }
+
+    /// Collapse same-page `results` that refer to the same underlying decision (see
+    /// `Self::citation_dedup_key`) into a single, highest-scoring representative, recording the
+    /// ids of the results it absorbed in `SearchResult::duplicates`. Gated behind
+    /// `SearchConfig::enable_citation_dedup` because two different reporters legitimately
+    /// reprinting a citation-sharing multi-case disposition (rare, but real) would otherwise
+    /// have one arbitrarily dropped from view. Preserves the surviving representative's
+    /// original rank position, not the highest position among the group.
+    fn dedup_citation_overlap(results: Vec<SearchResult>) -> Vec<SearchResult> {
+        let mut order: Vec<String> = Vec::new();
+        let mut groups: HashMap<String, Vec<SearchResult>> = HashMap::new();
+        for result in results {
+            let key = Self::citation_dedup_key(&result.case_metadata);
+            if !groups.contains_key(&key) {
+                order.push(key.clone());
+            }
+            groups.entry(key).or_default().push(result);
+        }
+
+        order
+            .into_iter()
+            .map(|key| {
+                let mut group = groups.remove(&key).unwrap();
+                let best_index = group
+                    .iter()
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| a.score.total_cmp(&b.score))
+                    .map(|(index, _)| index)
+                    .unwrap();
+                let mut best = group.swap_remove(best_index);
+                best.duplicates = group.into_iter().map(|r| r.case_metadata.id).collect();
+                best
+            })
+            .collect()
+    }
+
+    /// The original bag-of-words lexical stage: trie search for exact matches (or wildcard
+    /// matches, if the query contains a `*` token — see `TrieIndex::search`'s internal routing
+    /// to `search_wildcard`), deduped against `seen_cases`. Shared between `SearchSyntax::Plain`
+    /// queries and `SearchSyntax::Boolean` queries that failed to parse (see `rank_candidates`).
+    async fn run_plain_lexical_stage(
+        &self,
+        query: &SearchQuery,
+        seen_cases: &mut HashSet<CaseId>,
+        warnings: &mut Vec<String>,
+        applied_synonym_expansions: &mut Vec<String>,
+    ) -> Result<Vec<Candidate>> {
+        let (phrases, search_text) = Self::extract_quoted_phrases(&query.query);
+        let mut raw_candidates = Vec::new();
+        let mut local_seen: HashMap<CaseId, usize> = HashMap::new();
+        let is_wildcard = TrieIndex::is_wildcard_query(&search_text);
+        let trie_results = self
+            .run_with_stage_budget(query.config.budgets.lexical_ms, "LEXICAL_BUDGET_EXCEEDED", warnings, self.search_trie(&search_text))
+            .await?;
+        if let Some(trie_results) = trie_results {
+            if is_wildcard {
+                // `TrieIndex::search_wildcard` returns a single-origin result with no
+                // per-origin `buckets` to merge, unlike `TrieIndex::search` below.
+                let score = Self::relative_lexical_weight_for(query.config.exact_match_weight, &query.config);
+                let provenance = MatchProvenance::from(trie_results.source);
+                for trie_result in trie_results.exact_matches {
+                    if let Some(summary) = self.storage.get_case_summary(&trie_result.case_id).await? {
+                        let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                        Self::merge_multi_passage_candidate(
+                            &mut raw_candidates,
+                            &mut local_seen,
+                            dedup_key,
+                            score,
+                            Candidate {
+                                doc_ref: trie_result,
+                                summary,
+                                score,
+                                match_type: MatchType::Prefix,
+                                provenance,
+                                lexical_score: Some(score),
+                                semantic_score: None,
+                                extra_doc_refs: Vec::new(),
+                            },
+                        );
+                    }
+                }
+            } else {
+                // `TrieIndex::search` merges matches from all three sub-tries into
+                // `buckets` (see its doc comment) instead of returning as soon as one
+                // origin matches; assign each bucket its own `MatchType`/weight based on
+                // which trie produced it, rather than labeling every match `Exact`.
+                for bucket in &trie_results.buckets {
+                    // Multi-passage merging is per bucket; a case also found in a later bucket
+                    // is left to the `seen_cases` dedup below, where the first bucket wins.
+                    local_seen.clear();
+                    let (match_type, score) = if bucket.is_substring_match {
+                        (
+                            MatchType::Substring,
+                            Self::relative_lexical_weight_for(query.config.exact_match_weight, &query.config)
+                                * SUBSTRING_MATCH_SCORE_FACTOR,
+                        )
+                    } else {
+                        let (match_type, weight) = match bucket.source {
+                            TrieSource::CaseName => (MatchType::CaseName, query.config.case_name_match_weight),
+                            TrieSource::Citation => (MatchType::Citation, query.config.citation_match_weight),
+                            TrieSource::Content => (MatchType::Exact, query.config.exact_match_weight),
+                        };
+                        (match_type, Self::relative_lexical_weight_for(weight, &query.config))
+                    };
+                    let provenance = MatchProvenance::from(bucket.source);
+
+                    for trie_result in &bucket.exact_matches {
+                        if let Some(summary) = self.storage.get_case_summary(&trie_result.case_id).await? {
+                            let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                            Self::merge_multi_passage_candidate(
+                                &mut raw_candidates,
+                                &mut local_seen,
+                                dedup_key,
+                                score,
+                                Candidate {
+                                    doc_ref: trie_result.clone(),
+                                    summary,
+                                    score,
+                                    match_type: match_type.clone(),
+                                    provenance,
+                                    lexical_score: Some(score),
+                                    semantic_score: None,
+                                    extra_doc_refs: Vec::new(),
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        // Quoted segments (see `extract_quoted_phrases`) narrow `raw_candidates` down to cases
+        // where every phrase actually occurs as an adjacent run of words, not merely as
+        // co-occurring tokens somewhere in the case — the trie's substring-index fallback
+        // above (used when a bucket isn't `search_text`'s literal sentence-start prefix) only
+        // guarantees the latter. The final, authoritative `seen_cases` insert happens here
+        // rather than while building `raw_candidates`, so a case a phrase filter rejects is
+        // still eligible for the semantic stage that follows this one.
+        let qualifying =
+            if phrases.is_empty() { raw_candidates } else { self.filter_requiring_quoted_phrases(raw_candidates, &phrases).await? };
+        let mut candidates = Vec::with_capacity(qualifying.len());
+        for mut candidate in qualifying {
+            let dedup_key = candidate.summary.duplicate_of.unwrap_or(candidate.summary.id);
+            if seen_cases.insert(dedup_key) {
+                if !phrases.is_empty() {
+                    candidate.match_type = MatchType::Phrase;
+                }
+                candidates.push(candidate);
+            }
+        }
+
+        // Minimum-should-match fallback (see `SearchConfig::min_should_match`): a case containing
+        // most, but not all, of `search_text`'s tokens still qualifies here, scored down by how
+        // much of the query it covers. Skipped for wildcard/quoted queries — a wildcard already
+        // has its own matching semantics, and "most of the words in this exact phrase" isn't a
+        // meaningful relaxation of "the exact phrase". Cases already found above (full match, or
+        // already surfaced by `search_trie`'s own all-tokens substring fallback) are excluded via
+        // `seen_cases`, so this only ever adds genuinely partial matches.
+        let search_tokens: Vec<&str> = search_text.split_whitespace().collect();
+        if !is_wildcard && phrases.is_empty() && search_tokens.len() > 1 {
+            let min_should_match = Self::required_term_count(&query.config.min_should_match, search_tokens.len());
+            if min_should_match < search_tokens.len() {
+                let partial_matches = self.trie_index.snapshot().search_min_should_match(&search_text, min_should_match);
+                for partial in partial_matches {
+                    if let Some(summary) = self.storage.get_case_summary(&partial.doc_ref.case_id).await? {
+                        let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                        if seen_cases.insert(dedup_key) {
+                            let coverage = partial.matched_terms as f32 / partial.total_terms as f32;
+                            let score = Self::relative_lexical_weight_for(query.config.exact_match_weight, &query.config) * coverage;
+                            candidates.push(Candidate {
+                                doc_ref: partial.doc_ref,
+                                summary,
+                                score,
+                                match_type: MatchType::PartialMatch,
+                                provenance: MatchProvenance::TrieContent,
+                                lexical_score: Some(score),
+                                semantic_score: None,
+                                extra_doc_refs: Vec::new(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // Synonym expansion (see `synonyms::SynonymTable::expand`): search each expansion
+        // phrase as an additional OR-alternative, scored below the query's own matches via
+        // `SYNONYM_MATCH_SCORE_FACTOR`, so a case that only contains a synonym term still
+        // surfaces without ever outranking (or duplicating) a genuine original-term match.
+        // Skipped for wildcard queries, since `expand` looks for whole-word phrase containment
+        // rather than wildcard tokens.
+        if query.config.enable_synonyms && !is_wildcard {
+            for expansion in self.synonym_table.expand(&search_text, query.config.max_synonym_expansions) {
+                let expansion_results = self
+                    .run_with_stage_budget(query.config.budgets.lexical_ms, "LEXICAL_BUDGET_EXCEEDED", warnings, self.search_trie(&expansion))
+                    .await?;
+                let Some(expansion_results) = expansion_results else { continue };
+                let mut expansion_used = false;
+                for bucket in &expansion_results.buckets {
+                    if bucket.is_substring_match {
+                        continue;
+                    }
+                    let base_weight = match bucket.source {
+                        TrieSource::CaseName => query.config.case_name_match_weight,
+                        TrieSource::Citation => query.config.citation_match_weight,
+                        TrieSource::Content => query.config.exact_match_weight,
+                    };
+                    let score = Self::relative_lexical_weight_for(base_weight, &query.config) * SYNONYM_MATCH_SCORE_FACTOR;
+                    let provenance = MatchProvenance::from(bucket.source);
+                    for trie_result in &bucket.exact_matches {
+                        if let Some(summary) = self.storage.get_case_summary(&trie_result.case_id).await? {
+                            let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                            if seen_cases.insert(dedup_key) {
+                                candidates.push(Candidate {
+                                    doc_ref: trie_result.clone(),
+                                    summary,
+                                    score,
+                                    match_type: MatchType::Synonym,
+                                    provenance,
+                                    lexical_score: Some(score),
+                                    semantic_score: None,
+                                    extra_doc_refs: Vec::new(),
+                                });
+                                expansion_used = true;
+                            }
+                        }
+                    }
+                }
+                if expansion_used {
+                    applied_synonym_expansions.push(expansion);
+                }
+            }
+        }
+
+        Ok(candidates)
+    }
+
+    /// Split `query` into its quoted phrases (each a lowercased word list, in query order) and
+    /// `query` itself with quote characters stripped, e.g. `"due process" incorporation` yields
+    /// `(vec![["due", "process"]], "due process incorporation")` — the returned text keeps
+    /// every word (phrase and unquoted alike) so it can still be handed to the ordinary
+    /// bag-of-words trie search for candidate recall, with adjacency enforced separately by
+    /// `filter_requiring_quoted_phrases`. An unterminated trailing quote runs to the end of the
+    /// string rather than silently dropping that phrase.
+    fn extract_quoted_phrases(query: &str) -> (Vec<Vec<String>>, String) {
+        let mut phrases = Vec::new();
+        let mut chars = query.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c == '"' {
+                chars.next();
+                let mut phrase = String::new();
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    phrase.push(c);
+                }
+                let words: Vec<String> = phrase.split_whitespace().map(|w| w.to_lowercase()).collect();
+                if !words.is_empty() {
+                    phrases.push(words);
+                }
+            } else {
+                chars.next();
+            }
+        }
+        (phrases, query.replace('"', ""))
+    }
+
+    /// Keep only `candidates` whose case contains every phrase in `phrases` as a contiguous,
+    /// same-paragraph run of words (see `case_contains_phrase_adjacently`). A candidate whose
+    /// case has all of a phrase's words scattered non-adjacently — the case that motivates this
+    /// filter existing at all — does not qualify.
+    async fn filter_requiring_quoted_phrases(&self, candidates: Vec<Candidate>, phrases: &[Vec<String>]) -> Result<Vec<Candidate>> {
+        let mut kept = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let mut qualifies = true;
+            for phrase in phrases {
+                if !self.case_contains_phrase_adjacently(candidate.doc_ref.case_id, phrase).await? {
+                    qualifies = false;
+                    break;
+                }
+            }
+            if qualifies {
+                kept.push(candidate);
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Whether `case_id`'s stored text contains `phrase`'s words as a contiguous run within a
+    /// single paragraph — one of `chunk_text`'s chunks, the same granularity
+    /// `DocRef::paragraph_index` addresses elsewhere in this file — rather than merely
+    /// co-occurring anywhere in the case, which is all the trie's substring-index fallback
+    /// guarantees for a multi-word query.
+    async fn case_contains_phrase_adjacently(&self, case_id: CaseId, phrase: &[String]) -> Result<bool> {
+        let Some(case_text) = self.storage.get_case_text(&case_id, TextForm::Normalized).await? else {
+            return Ok(false);
+        };
+        let chunks = chunk_text(&case_text.text, self.config.vector.chunking.chunk_size_tokens, self.config.vector.chunking.overlap_tokens);
+        Ok(chunks.iter().any(|(_, chunk)| Self::chunk_contains_phrase(chunk, phrase)))
+    }
+
+    /// Whether `phrase`'s words appear consecutively, case-insensitively, anywhere in `chunk`
+    fn chunk_contains_phrase(chunk: &str, phrase: &[String]) -> bool {
+        if phrase.is_empty() {
+            return false;
+        }
+        let words: Vec<String> = chunk.split_whitespace().map(|w| w.to_lowercase()).collect();
+        if words.len() < phrase.len() {
+            return false;
+        }
+        words.windows(phrase.len()).any(|window| window == phrase)
+    }
+
+    /// The `SearchSyntax::Boolean` lexical stage: evaluate `ast` (already parsed from
+    /// `query.query` by `crate::boolean_query::parse`) against a trie snapshot's auxiliary
+    /// substring index, deduped against `seen_cases` the same way `run_plain_lexical_stage` is.
+    /// Every match scores `exact_match_weight` and is tagged `MatchType::Boolean` /
+    /// `MatchProvenance::TrieContent`, since a Boolean match doesn't come from a single trie
+    /// bucket the way a plain match's `MatchType` does.
+    async fn run_boolean_lexical_stage(
+        &self,
+        query: &SearchQuery,
+        ast: &crate::boolean_query::QueryNode,
+        seen_cases: &mut HashSet<CaseId>,
+        warnings: &mut Vec<String>,
+    ) -> Result<Vec<Candidate>> {
+        let mut candidates = Vec::new();
+        let doc_refs = self
+            .run_with_stage_budget(
+                query.config.budgets.lexical_ms,
+                "LEXICAL_BUDGET_EXCEEDED",
+                warnings,
+                self.evaluate_boolean_query(ast),
+            )
+            .await?
+            .unwrap_or_default();
+        let score = Self::relative_lexical_weight_for(query.config.exact_match_weight, &query.config);
+        for doc_ref in doc_refs {
+            if let Some(summary) = self.storage.get_case_summary(&doc_ref.case_id).await? {
+                let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                if seen_cases.insert(dedup_key) {
+                    candidates.push(Candidate {
+                        doc_ref,
+                        summary,
+                        score,
+                        match_type: MatchType::Boolean,
+                        provenance: MatchProvenance::TrieContent,
+                        lexical_score: Some(score),
+                        semantic_score: None,
+                        extra_doc_refs: Vec::new(),
+                    });
+                }
+            }
+        }
+        Ok(candidates)
+    }
+
+    /// Evaluate a parsed Boolean query against a lock-free trie snapshot (see
+    /// [`TrieIndexHandle::snapshot`]), mirroring [`SearchEngine::search_trie`]'s snapshot usage
+    async fn evaluate_boolean_query(&self, ast: &crate::boolean_query::QueryNode) -> Result<Vec<DocRef>> {
+        let trie = self.trie_index.snapshot();
+        Ok(crate::boolean_query::evaluate(ast, &trie))
+    }
+
+    /// Upgrade a filtered, ranked, page-truncated [`Candidate`] into a full [`SearchResult`] by
+    /// hydrating its [`CaseMetadata`] and generating its snippet. Always returns `Some`; a case
+    /// deleted between the summary lookup earlier in `execute_hybrid_search` and hydration is
+    /// skipped by the caller, which finds no metadata for it, rather than failing the whole query.
+    #[allow(clippy::too_many_arguments)]
+    async fn hydrate_candidate(
+        &self,
+        candidate: Candidate,
+        case_metadata: CaseMetadata,
+        query_text: &str,
+        snippets_budget_ms: Option<u64>,
+        snippets_started_at: std::time::Instant,
+        snippets_budget_exceeded: &mut bool,
+        warnings: &mut Vec<String>,
+    ) -> Result<Option<SearchResult>> {
+        let snippet = self
+            .snippet_for_result(&candidate.doc_ref, query_text, snippets_budget_ms, snippets_started_at, snippets_budget_exceeded, warnings)
+            .await?;
+        // Up to `MULTI_PASSAGE_MAX_SNIPPETS` total passages, primary paragraph first, per
+        // `SearchResult::passages`'s doc comment; extra paragraphs (see
+        // `Candidate::extra_doc_refs`) are rendered through the same budget-aware snippet path
+        // as the primary one, so a slow snippet run still respects `snippets_budget_ms` overall.
+        let mut passages = vec![snippet.clone()];
+        for extra_doc_ref in candidate.extra_doc_refs.iter().take(MULTI_PASSAGE_MAX_SNIPPETS - 1) {
+            let passage = self
+                .snippet_for_result(extra_doc_ref, query_text, snippets_budget_ms, snippets_started_at, snippets_budget_exceeded, warnings)
+                .await?;
+            passages.push(passage);
+        }
+        if passages.len() == 1 {
+            passages.clear();
+        }
+        Ok(Some(SearchResult {
+            case_metadata,
+            score: candidate.score,
+            lexical_score: candidate.lexical_score,
+            semantic_score: candidate.semantic_score,
+            match_type: candidate.match_type,
+            provenance: candidate.provenance,
+            snippet,
+            highlights: Vec::new(), // TODO: Generate highlights
+            duplicates: Vec::new(),
+            passages,
+        }))
+    }
+
+    /// Runs `stage` to completion, or — if `budget_ms` is `Some` and elapses first — records
+    /// `warning` and returns `None` in its place. `budget_ms` of `None` means unbounded, i.e.
+    /// always runs `stage` to completion.
+    async fn run_with_stage_budget<T>(
+        &self,
+        budget_ms: Option<u64>,
+        warning: &str,
+        warnings: &mut Vec<String>,
+        stage: impl std::future::Future<Output = Result<T>>,
+    ) -> Result<Option<T>> {
+        match budget_ms {
+            None => Ok(Some(stage.await?)),
+            Some(ms) => match tokio::time::timeout(Duration::from_millis(ms), stage).await {
+                Ok(result) => Ok(Some(result?)),
+                Err(_) => {
+                    warnings.push(warning.to_string());
+                    Ok(None)
+                }
+            },
+        }
+    }
+
+    /// Snippet for one result, honoring the batch-wide `snippets_ms` budget: once
+    /// `started_at.elapsed()` reaches the budget, this and every subsequent call in the batch
+    /// return `fallback_snippet` instead of paying for `generate_snippet`, and
+    /// `SNIPPETS_BUDGET_EXCEEDED` is appended to `warnings` exactly once.
+    #[allow(clippy::too_many_arguments)]
+    async fn snippet_for_result(
+        &self,
+        doc_ref: &DocRef,
+        query_text: &str,
+        budget_ms: Option<u64>,
+        started_at: std::time::Instant,
+        budget_exceeded: &mut bool,
+        warnings: &mut Vec<String>,
+    ) -> Result<String> {
+        if let Some(ms) = budget_ms {
+            if *budget_exceeded || started_at.elapsed() >= Duration::from_millis(ms) {
+                if !*budget_exceeded {
+                    *budget_exceeded = true;
+                    warnings.push("SNIPPETS_BUDGET_EXCEEDED".to_string());
+                }
+                return Ok(self.fallback_snippet(doc_ref));
+            }
+        }
+        self.generate_snippet(doc_ref, query_text).await
+    }
+
+    /// Search trie index against a lock-free snapshot (see [`TrieIndexHandle::snapshot`])
+    async fn search_trie(&self, query: &str) -> Result<TrieSearchResult> {
+        #[cfg(test)]
+        tests::maybe_delay_lexical().await;
+        let trie = self.trie_index.snapshot();
+        trie.search(query)
+    }
+
+    /// Search vector index for a fixed number of top results. When `rerank_candidates` is set,
+    /// this re-scores that many top ANN hits with exact similarity via
+    /// [`VectorIndex::search_and_rerank`] instead of trusting the ANN stage's own ordering; see
+    /// `config::SearchEngineConfig::enable_rerank`.
+    async fn search_vector(
+        &self,
+        query: &str,
+        top_k: usize,
+        rerank_candidates: Option<usize>,
+        ef_override: Option<usize>,
+    ) -> Result<Vec<VectorSearchResult>> {
+        let mut vector = self.vector_index.write("search_vector").await;
+        match rerank_candidates {
+            Some(rerank_n) => vector.search_and_rerank(query, top_k, rerank_n, None, ef_override).await,
+            None => vector.search(query, top_k, ef_override).await,
+        }
+    }
+
+    /// Search the vector index for a query. When the query carries post-filters (court/date/
+    /// topic), this resolves them to a `CaseId` allow-list up front and searches via
+    /// [`VectorIndex::search_filtered`] so a heavily filtered query (e.g. a single small court)
+    /// doesn't starve the requested page. Without filters this behaves exactly like the
+    /// unfiltered top-50 fetch.
+    ///
+    /// `VectorIndex::search_filtered` doesn't know about [`SearchConfig::enable_rerank`] — the
+    /// filtered path and the exact-rerank path are both pragmatic, independently-scoped
+    /// corrections to the plain top-`k` ANN fetch, and combining them would mean threading the
+    /// allow-list through `search_and_rerank` too. A filtered query with reranking enabled falls
+    /// back to the ANN stage's own ordering rather than exact rescoring.
+    async fn search_vector_for_query(&self, query: &SearchQuery) -> Result<Vec<VectorSearchResult>> {
+        #[cfg(test)]
+        tests::maybe_delay_semantic().await;
+
+        let rerank_candidates = query.config.enable_rerank.then_some(query.config.rerank_candidates);
+        let ef_override = query.config.ef_search_override;
+
+        if !self.has_post_filters(query) {
+            let top_k = query.config.vector_top_k_override.unwrap_or(50);
+            return self.search_vector(&query.query, top_k, rerank_candidates, ef_override).await;
+        }
+
+        let requested = query.max_results.unwrap_or(query.config.max_results).max(1);
+        let allowed = self.compute_allowed_case_ids(query).await?;
+
+        let mut vector = self.vector_index.write("search_vector_for_query").await;
+        let results = vector.search_filtered(&query.query, requested, &allowed, ef_override).await?;
+
+        tracing::debug!(
+            requested_results = requested,
+            allowed_cases = allowed.len(),
+            vector_results_fetched = results.len(),
+            "filtered ANN search"
+        );
+
+        Ok(results)
+    }
+
+    /// Resolve `query`'s court/date/topic filters into the set of case ids that satisfy all of
+    /// them, by scanning every case's summary in storage. This is the same predicate
+    /// [`SearchEngine::matches_filters`] applies after the fact elsewhere in this file, just
+    /// evaluated up front so [`VectorIndex::search_filtered`] can restrict the ANN fetch to
+    /// documents that could actually survive the page.
+    async fn compute_allowed_case_ids(&self, query: &SearchQuery) -> Result<HashSet<CaseId>> {
+        let mut allowed = HashSet::new();
+        for case_id in self.storage.list_case_ids().await? {
+            if let Some(summary) = self.storage.get_case_summary(&case_id).await? {
+                if self.matches_filters(&summary, query)? {
+                    allowed.insert(case_id);
+                }
+            }
+        }
+        Ok(allowed)
+    }
+
+    /// Whether a query carries any post-filter that could shrink the vector stage's results
+    fn has_post_filters(&self, query: &SearchQuery) -> bool {
+        query.court_filter.is_some()
+            || query.judge_filter.is_some()
+            || query.date_range.is_some()
+            || query.topic_filter.is_some()
+    }
+
+    /// Whether a case satisfies a query's court/judge/date/topic filters. Generic over
+    /// [`FilterableCase`] so it can run against either a fully-hydrated [`CaseMetadata`] or the
+    /// cheaper [`crate::storage::CaseSummary`] projection, since both carry the same
+    /// court/decision-date/topics/judges fields this check needs.
+    fn matches_filters<T: FilterableCase>(&self, fields: &T, query: &SearchQuery) -> Result<bool> {
+        self.matches_filters_except(fields, query, None)
+    }
+
+    /// Same check as [`SearchEngine::matches_filters`], but skips the filter corresponding to
+    /// `exclude`, if any — used by [`SearchEngine::compute_facets`] so a facet dimension's own
+    /// counts aren't narrowed by the query's own filter on that dimension (standard faceting
+    /// semantics), while every *other* active filter still applies.
+    fn matches_filters_except<T: FilterableCase>(
+        &self,
+        fields: &T,
+        query: &SearchQuery,
+        exclude: Option<FacetDimension>,
+    ) -> Result<bool> {
+        if exclude != Some(FacetDimension::Court) {
+            if let Some(court_filter) = &query.court_filter {
+                if !court_filter.contains(&fields.court().to_string()) {
+                    return Ok(false);
+                }
+            }
+        }
+
+        if let Some(judge_filter) = &query.judge_filter {
+            let judges = fields.judges();
+            let matches = judge_filter.iter().any(|wanted| {
+                judges.iter().any(|judge| judge.to_lowercase().contains(&wanted.to_lowercase()))
+            });
+            if !matches {
+                return Ok(false);
+            }
+        }
+
+        if exclude != Some(FacetDimension::Decade) {
+            if let Some((start_date, end_date)) = &query.date_range {
+                if !(fields.decision_date() >= *start_date && fields.decision_date() <= *end_date) {
+                    return Ok(false);
+                }
+            }
+        }
+
+        if exclude != Some(FacetDimension::Topic) {
+            if let Some(topic_filter) = &query.topic_filter {
+                let matching_nodes: HashSet<String> = if topic_filter.include_descendants {
+                    self.taxonomy.descendants(&topic_filter.node_id)?.into_iter().collect()
+                } else {
+                    std::iter::once(topic_filter.node_id.clone()).collect()
+                };
+
+                if !fields.topics().iter().any(|topic| matching_nodes.contains(topic)) {
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Apply filters to search candidates, ahead of full-metadata hydration
+    fn apply_filters(
+        &self,
+        candidates: Vec<Candidate>,
+        query: &SearchQuery,
+    ) -> Result<Vec<Candidate>> {
+        let mut kept = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            if self.matches_filters(&candidate.summary, query)? {
+                kept.push(candidate);
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Order `candidates` per `query.sort`, in place. Every mode breaks ties on case id (rather
+    /// than leaving them in whatever order the fusion/filter stages happened to produce) so
+    /// repeated queries against an unchanged index return results in a stable order.
+    fn sort_candidates(&self, candidates: &mut [Candidate], query: &SearchQuery) {
+        match query.sort {
+            SortOrder::Relevance => candidates.sort_by(|a, b| {
+                b.score
+                    .partial_cmp(&a.score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.summary.id.cmp(&b.summary.id))
+            }),
+            SortOrder::DateDesc => candidates.sort_by(|a, b| {
+                b.summary
+                    .decision_date
+                    .cmp(&a.summary.decision_date)
+                    .then_with(|| a.summary.id.cmp(&b.summary.id))
+            }),
+            SortOrder::DateAsc => candidates.sort_by(|a, b| {
+                a.summary
+                    .decision_date
+                    .cmp(&b.summary.decision_date)
+                    .then_with(|| a.summary.id.cmp(&b.summary.id))
+            }),
+            SortOrder::CourtRank => candidates.sort_by(|a, b| {
+                self.court_rank(&a.summary.court, query)
+                    .cmp(&self.court_rank(&b.summary.court, query))
+                    .then_with(|| a.summary.id.cmp(&b.summary.id))
+            }),
+        }
+    }
+
+    /// Where `court` sits in the court hierarchy for [`SortOrder::CourtRank`]: lower sorts
+    /// first. Consults `query.config.court_rank_overrides` before falling back to a small
+    /// built-in heuristic (case-insensitive substring match) — Supreme Court, then
+    /// circuit/appellate courts, then district courts, then anything unrecognized.
+    fn court_rank(&self, court: &str, query: &SearchQuery) -> u32 {
+        if let Some(&rank) = query.config.court_rank_overrides.get(court) {
+            return rank;
+        }
+
+        let lower = court.to_lowercase();
+        if lower.contains("supreme court") {
+            0
+        } else if lower.contains("circuit") || lower.contains("appeals") || lower.contains("appellate") {
+            1
+        } else if lower.contains("district") {
+            2
+        } else {
+            3
+        }
+    }
+
+    /// Generate a text snippet for a search result: load the case's normalized text, re-derive
+    /// the same paragraph chunking [`VectorIndex::add_case_document`] used to index it, and pick
+    /// out `doc_ref.paragraph_index`'s chunk. Within that chunk, a window of context around the
+    /// first query-term occurrence is returned (see [`Self::snippet_from_paragraph`]); a semantic
+    /// match with no literal query term in the paragraph falls back to its leading sentences.
+    async fn generate_snippet(&self, doc_ref: &DocRef, query: &str) -> Result<String> {
+        #[cfg(test)]
+        tests::maybe_panic_for_snippet(doc_ref.case_id);
+        #[cfg(test)]
+        tests::maybe_delay_snippet().await;
+
+        let Some(case_text) = self.storage.get_case_text(&doc_ref.case_id, TextForm::Normalized).await? else {
+            return Ok(self.fallback_snippet(doc_ref));
+        };
+
+        let chunks = chunk_text(
+            &case_text.text,
+            self.config.vector.chunking.chunk_size_tokens,
+            self.config.vector.chunking.overlap_tokens,
+        );
+        let Some((_, paragraph)) = chunks.get(doc_ref.paragraph_index) else {
+            return Ok(self.fallback_snippet(doc_ref));
+        };
+
+        Ok(Self::snippet_from_paragraph(paragraph, query))
+    }
+
+    /// Split `text` into `(byte_offset, word)` pairs on whitespace, the same way
+    /// [`crate::vector::chunk_text`] does, so word boundaries and slicing stay UTF-8-safe.
+    fn words_with_offsets(text: &str) -> Vec<(usize, &str)> {
+        let mut words = Vec::new();
+        let mut word_start: Option<usize> = None;
+        for (index, ch) in text.char_indices() {
+            if ch.is_whitespace() {
+                if let Some(start) = word_start.take() {
+                    words.push((start, &text[start..index]));
+                }
+            } else if word_start.is_none() {
+                word_start = Some(index);
+            }
+        }
+        if let Some(start) = word_start {
+            words.push((start, &text[start..]));
+        }
+        words
+    }
+
+    /// A roughly 40-word window of `paragraph` centered on the first occurrence of any term in
+    /// `query`, with `…` marking a truncated start/end. If no query term appears in the
+    /// paragraph at all (a semantic-only match), returns its leading one or two sentences
+    /// instead, since there's no term occurrence to center a window on.
+    const SNIPPET_WINDOW_WORDS: usize = 40;
+
+    fn snippet_from_paragraph(paragraph: &str, query: &str) -> String {
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .map(|term| term.to_lowercase())
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        let words = Self::words_with_offsets(paragraph);
+        let match_index = if terms.is_empty() {
+            None
+        } else {
+            words.iter().position(|(_, word)| {
+                let word = word.to_lowercase();
+                terms.iter().any(|term| word.contains(term.as_str()))
+            })
+        };
+
+        let Some(match_index) = match_index else {
+            return Self::leading_sentences(paragraph);
+        };
+
+        let half_window = Self::SNIPPET_WINDOW_WORDS / 2;
+        let start = match_index.saturating_sub(half_window);
+        let end = (match_index + half_window + 1).min(words.len());
+
+        let start_byte = words[start].0;
+        let (last_offset, last_word) = words[end - 1];
+        let end_byte = last_offset + last_word.len();
+
+        let mut snippet = String::new();
+        if start > 0 {
+            snippet.push_str("… ");
+        }
+        snippet.push_str(paragraph[start_byte..end_byte].trim());
+        if end < words.len() {
+            snippet.push_str(" …");
+        }
+        snippet
+    }
+
+    /// Leading one or two sentences of `paragraph`, falling back to a `SNIPPET_WINDOW_WORDS`-word
+    /// prefix (with a trailing `…`) if it has no sentence-ending punctuation at all.
+    fn leading_sentences(paragraph: &str) -> String {
+        let sentence_boundary = regex::Regex::new(r"[.!?]+\s+").unwrap();
+        let mut ends = sentence_boundary.find_iter(paragraph).map(|m| m.end());
+
+        let end = match (ends.next(), ends.next()) {
+            (Some(_first), Some(second)) => second,
+            (Some(first), None) => first,
+            (None, _) => {
+                let words = Self::words_with_offsets(paragraph);
+                if words.len() <= Self::SNIPPET_WINDOW_WORDS {
+                    return paragraph.trim().to_string();
+                }
+                let (last_offset, last_word) = words[Self::SNIPPET_WINDOW_WORDS - 1];
+                return format!("{} …", paragraph[..last_offset + last_word.len()].trim());
+            }
+        };
+
+        paragraph[..end].trim().to_string()
+    }
+
+    /// Cheap stand-in for `generate_snippet`, used once a query's `budgets.snippets_ms` has
+    /// been exhausted (see `snippet_for_result`). Unlike `generate_snippet` this never touches
+    /// per-case content, so it costs effectively nothing regardless of how many results still
+    /// need a snippet.
+    fn fallback_snippet(&self, doc_ref: &DocRef) -> String {
+        format!("Case {} (snippet omitted: latency budget exceeded)", doc_ref.case_id)
+    }
+
+    /// Resolve `query.profile` (or, absent that, `SearchEngineConfig::default_weighting_profile`)
+    /// against `self.config.search`'s known profiles and overwrite `query.config`'s exact-match
+    /// weight, minimum similarity, semantic/prefix/rerank flags, and RRF `k` with the profile's
+    /// values. A no-op when neither is set, leaving `query.config` exactly as
+    /// `search_query_from_request`/the caller built it — today's existing behavior. Returns
+    /// [`SearchError::ValidationFailed`] naming the unrecognized profile and listing the known
+    /// ones if `query.profile` doesn't match any.
+    fn apply_weighting_profile(&self, query: &mut SearchQuery) -> Result<()> {
+        let Some(name) = query.profile.as_deref().or(self.config.search.default_weighting_profile.as_deref()) else {
+            return Ok(());
+        };
+
+        let Some(profile) = self.config.search.weighting_profile(name) else {
+            return Err(SearchError::ValidationFailed {
+                field: "profile".to_string(),
+                reason: format!(
+                    "Unknown weighting profile '{}'; available profiles: {}",
+                    name,
+                    self.config.search.known_weighting_profile_names().join(", ")
+                ),
+            });
+        };
+
+        query.config.exact_match_weight = profile.exact_match_weight;
+        query.config.min_similarity = profile.min_similarity;
+        query.config.enable_semantic = profile.enable_semantic;
+        query.config.enable_prefix = profile.enable_prefix;
+        query.config.enable_rerank = profile.enable_rerank;
+        query.config.rrf_k = profile.rrf_k;
+        Ok(())
+    }
+
+    /// Validate search query
+    fn validate_query(&self, query: &SearchQuery) -> Result<()> {
+        if query.query.len() < self.config.search.min_query_length {
+            return Err(SearchError::InvalidSearchQuery {
+                query: query.query.clone(),
+                reason: format!("Query too short: minimum {} characters", self.config.search.min_query_length),
+            });
+        }
+
+        if query.query.len() > self.config.search.max_query_length {
+            return Err(SearchError::InvalidSearchQuery {
+                query: query.query.clone(),
+                reason: format!("Query too long: maximum {} characters", self.config.search.max_query_length),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Compose the `QueryCache` key for `query`: everything that can change what
+    /// `rank_candidates` produces (query text, syntax, sort order, court/judge/topic/date
+    /// filters, `max_results`, weighting profile, and the vector `top_k`/`ef_search`
+    /// overrides) except `offset` — see the [`QueryCache`] doc comment for why
+    /// offset is deliberately left out. Field values are joined with control characters that
+    /// can't appear in the JSON/TOML-sourced strings that feed them, and each `Vec<String>`
+    /// filter is sorted first so semantically-identical filter lists (same courts, different
+    /// order) share a cache entry.
+    fn compose_cache_key(query: &SearchQuery) -> String {
+        const SEP: char = '\u{1}';
+        const LIST_SEP: char = '\u{2}';
+
+        fn joined_sorted(values: &Option<Vec<String>>) -> String {
+            let mut values = values.clone().unwrap_or_default();
+            values.sort();
+            values.join(&LIST_SEP.to_string())
+        }
+
+        let syntax = match query.syntax {
+            SearchSyntax::Plain => "plain",
+            SearchSyntax::Boolean => "boolean",
+        };
+        let sort = match query.sort {
+            SortOrder::Relevance => "relevance",
+            SortOrder::DateDesc => "date_desc",
+            SortOrder::DateAsc => "date_asc",
+            SortOrder::CourtRank => "court_rank",
+        };
+        let topic = query
+            .topic_filter
+            .as_ref()
+            .map(|filter| format!("{}:{}", filter.node_id, filter.include_descendants))
+            .unwrap_or_default();
+        let date_range = query
+            .date_range
+            .map(|(start, end)| format!("{start}..{end}"))
+            .unwrap_or_default();
+        let max_results = query.max_results.map(|n| n.to_string()).unwrap_or_default();
+        let profile = query.profile.clone().unwrap_or_default();
+        let top_k = query.config.vector_top_k_override.map(|n| n.to_string()).unwrap_or_default();
+        let ef_search = query.config.ef_search_override.map(|n| n.to_string()).unwrap_or_default();
+
+        [
+            query.query.as_str(),
+            syntax,
+            sort,
+            &joined_sorted(&query.court_filter),
+            &joined_sorted(&query.judge_filter),
+            &topic,
+            &date_range,
+            &max_results,
+            &profile,
+            &top_k,
+            &ef_search,
+        ]
+        .join(&SEP.to_string())
+    }
+
+    /// Get the cached ranked candidate set for `query`, if present, not yet expired, and
+    /// computed against the current index generation (see [`SearchEngine::invalidate_cache`])
+    async fn get_cached_ranked_candidates(&self, query: &SearchQuery) -> Option<RankedCandidates> {
+        let mut cache = self.query_cache.write().await;
+        cache.get(&Self::compose_cache_key(query), self.index_generation())
+    }
+
+    /// Cache a query's ranked candidate set, tagged with the current index generation, so a
+    /// later page request for the same query (see [`SearchQuery::offset`]) can skip straight to
+    /// hydration — unless the trie or vector index has since changed underneath it.
+    async fn cache_ranked_candidates(&self, query: &SearchQuery, ranked: &RankedCandidates) {
+        let mut cache = self.query_cache.write().await;
+        cache.insert(
+            Self::compose_cache_key(query),
+            ranked.clone(),
+            self.config.search.query_cache_ttl_seconds,
+            self.index_generation(),
+        );
+    }
+
+    /// Invalidate every cached query result by bumping [`SearchEngine::index_generation`]: the
+    /// next lookup for any previously-cached query will find its entry's recorded generation no
+    /// longer matches and treat it as a miss (see [`QueryCache::get`]), re-running the query
+    /// against the now-current trie/vector index instead of serving a stale result. Cheaper than
+    /// clearing the cache outright — entries are reclaimed lazily as they're looked up or swept,
+    /// same as expired ones — and shares its counter with the one `GET /search` already reports
+    /// as `X-Index-Generation`, so a generation bump always means "the index moved and any
+    /// cached result computed before it is now stale", regardless of which triggered it.
+    ///
+    /// [`SearchEngine::spawn_background_index_rebuild`] bumps the same counter directly once a
+    /// degraded index finishes rebuilding (it only holds cloned `Arc` fields, not a `&SearchEngine`
+    /// to call this through); [`SearchEngine::prune_content_trie`] calls this method after a
+    /// prune actually drops postings. Exposed here (`pub`, not `pub(crate)`) for a live ingestion
+    /// pipeline or admin endpoint (see `api::admin_invalidate_cache_handler`) to call after
+    /// writing new cases into the trie or vector index directly.
+    pub fn invalidate_cache(&self) {
+        self.index_generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Spawn a background task that periodically sweeps expired entries out of the query cache,
+    /// so memory used by queries that are never repeated (and so never hit the lazy expiry check
+    /// in `QueryCache::get`) is still reclaimed. Runs for the lifetime of the process; there's no
+    /// corresponding shutdown handle, matching `spawn_background_index_rebuild`'s one-shot,
+    /// fire-and-forget style.
+    fn spawn_query_cache_sweep(&self) {
+        let query_cache = self.query_cache.clone();
+        let interval_seconds = self.config.search.query_cache_sweep_interval_seconds;
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_seconds.max(1)));
+            loop {
+                interval.tick().await;
+                let expired = query_cache.write().await.sweep_expired();
+                if expired > 0 {
+                    tracing::debug!(expired, "Query cache sweep reclaimed expired entries");
+                }
+            }
+        });
+    }
+
+    /// Re-embed a single case's text with the current embedding model, used by the
+    /// background model migration task to backfill the new model's index one case
+    /// at a time. Queries continue to be served from whichever index is active
+    /// until `ModelMigrationManager` reports the cutover threshold has been reached.
+    pub async fn reembed_case(&self, case_id: CaseId) -> Result<()> {
+        let Some(full_text) = self.storage.get_case_text(&case_id, crate::storage::TextForm::Normalized).await? else {
+            return Err(SearchError::CaseNotFound { case_id });
+        };
+
+        let mut vector_index = self.vector_index.write("reembed_case").await;
+        vector_index
+            .add_document(
+                DocRef {
+                    case_id,
+                    paragraph_index: 0,
+                    char_offset: None,
+                },
+                &full_text.text,
+            )
+            .await
+    }
+
+    /// Autocomplete suggestions for `prefix`, merged and ranked across the case-name, citation,
+    /// and content tries (see [`TrieIndex::get_completions_by_source`]) against a lock-free
+    /// snapshot — no storage or vector lookups, so this stays fast enough for a UI to call on
+    /// every keystroke. A `prefix` shorter than `SearchConfig::min_query_length` returns an
+    /// empty list rather than [`SearchError::InvalidSearchQuery`], since an autocomplete caller
+    /// has no error UI for a query that's merely too short yet — it'll just keep typing.
+    pub async fn suggest(&self, prefix: &str, limit: usize) -> Result<Vec<Suggestion>> {
+        if prefix.len() < self.config.search.min_query_length {
+            return Ok(Vec::new());
+        }
+
+        let prefix = self.query_normalizer.normalize(prefix);
+        let trie = self.trie_index.snapshot();
+        let completions = trie.get_completions_by_source(&prefix, limit, true)?;
+        completions
+            .into_iter()
+            .map(|completion| {
+                let case_count = Self::completion_case_count(&trie, &completion)?;
+                Ok(Suggestion { text: completion.text, suggestion_type: SuggestionType::from(completion.source), case_count })
+            })
+            .collect()
+    }
+
+    /// Number of distinct cases matching `completion.text` exactly within the sub-trie
+    /// `completion.source` names, for [`SearchEngine::suggest`]. Re-runs `completion.text`
+    /// through [`TrieIndex::search`] rather than trusting the completion's raw
+    /// [`TrieNode::frequency`], since frequency counts insertions (e.g. one per paragraph for a
+    /// content-trie phrase repeated within a case) rather than distinct cases.
+    fn completion_case_count(trie: &TrieIndex, completion: &TrieCompletion) -> Result<usize> {
+        let result = trie.search(&completion.text)?;
+        let case_ids: HashSet<CaseId> = result
+            .buckets
+            .iter()
+            .filter(|bucket| bucket.source == completion.source)
+            .flat_map(|bucket| bucket.exact_matches.iter().map(|doc_ref| doc_ref.case_id))
+            .collect();
+        Ok(case_ids.len())
+    }
+
+    /// Find cases similar to `case_id`: its text is embedded and searched against the vector
+    /// index, then supplemented with lexical hits on its own extracted key phrases, deduped and
+    /// sorted by score with vector matches (a stronger topical signal) breaking ties ahead of
+    /// key-phrase matches. `case_id` itself, and anything already recorded as a duplicate of it,
+    /// is excluded from the results. Returns [`SearchError::CaseNotFound`] if `case_id` has no
+    /// stored text.
+    ///
+    /// Ideally this would embed the average of the case's stored chunk vectors — closer to its
+    /// overall meaning than any single chunk — but `VectorIndex` has no API to look up all of a
+    /// case's stored vectors (only a single `DocRef`'s, via `AnnIndex::vector_for`). This embeds
+    /// the case's first chunk as a representative sample instead.
+    pub async fn more_like_this(&self, case_id: CaseId, max_results: usize) -> Result<Vec<SearchResult>> {
+        let Some(full_text) = self.storage.get_case_text(&case_id, TextForm::Normalized).await? else {
+            return Err(SearchError::CaseNotFound { case_id });
+        };
+        let representative_chunk = chunk_text(&full_text.text, 200, 0)
+            .into_iter()
+            .next()
+            .map(|(_, chunk)| chunk)
+            .unwrap_or_else(|| full_text.text.clone());
+
+        let mut seen = HashSet::new();
+        seen.insert(case_id);
+        let mut candidates = Vec::new();
+
+        let vector_hits = self.search_vector(&representative_chunk, max_results + 1, None, None).await?;
+        for hit in vector_hits {
+            if let Some(summary) = self.storage.get_case_summary(&hit.doc_ref.case_id).await? {
+                let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                if seen.insert(dedup_key) {
+                    candidates.push(Candidate {
+                        doc_ref: hit.doc_ref,
+                        summary,
+                        score: hit.similarity_score,
+                        match_type: MatchType::Semantic,
+                        provenance: MatchProvenance::Vector,
+                        lexical_score: None,
+                        semantic_score: Some(hit.similarity_score),
+                        extra_doc_refs: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        let text_processor = crate::text_processing::TextProcessor::new(self.config.text_processing.clone())?;
+        let processed = text_processor.process_text(&full_text.text).await?;
+        let key_phrases = text_processor.extract_key_phrases(&processed.tokens, MORE_LIKE_THIS_KEY_PHRASES);
+
+        let key_phrase_score = Self::relative_lexical_weight(
+            self.config.search.exact_match_weight,
+            self.config.search.exact_match_weight,
+            self.config.search.case_name_match_weight,
+            self.config.search.citation_match_weight,
+        ) * SUBSTRING_MATCH_SCORE_FACTOR;
+
+        let trie = self.trie_index.snapshot();
+        for phrase in &key_phrases {
+            let Ok(result) = trie.search(phrase) else { continue };
+            for doc_ref in result.exact_matches {
+                if doc_ref.case_id == case_id {
+                    continue;
+                }
+                let Some(summary) = self.storage.get_case_summary(&doc_ref.case_id).await? else {
+                    continue;
+                };
+                let dedup_key = summary.duplicate_of.unwrap_or(summary.id);
+                if seen.insert(dedup_key) {
+                    candidates.push(Candidate {
+                        doc_ref,
+                        summary,
+                        score: key_phrase_score,
+                        match_type: MatchType::MoreLikeThis,
+                        provenance: MatchProvenance::TrieContent,
+                        lexical_score: Some(key_phrase_score),
+                        semantic_score: None,
+                        extra_doc_refs: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| {
+            b.score.total_cmp(&a.score).then_with(|| a.doc_ref.case_id.cmp(&b.doc_ref.case_id))
+        });
+        candidates.truncate(max_results);
+
+        let candidate_case_ids: Vec<CaseId> = candidates.iter().map(|c| c.doc_ref.case_id).collect();
+        let metadata_by_id = self.storage.get_cases_metadata(&candidate_case_ids).await?;
+
+        let mut warnings = Vec::new();
+        let mut snippets_budget_exceeded = false;
+        let snippets_started_at = std::time::Instant::now();
+        let mut results = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let Some(case_metadata) = metadata_by_id.get(&candidate.doc_ref.case_id).cloned() else {
+                continue;
+            };
+            if let Some(result) = self
+                .hydrate_candidate(
+                    candidate,
+                    case_metadata,
+                    &representative_chunk,
+                    None,
+                    snippets_started_at,
+                    &mut snippets_budget_exceeded,
+                    &mut warnings,
+                )
+                .await?
+            {
+                results.push(result);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Remove a deleted case's entries from the trie index, staging the removal and publishing
+    /// it as a new generation (see [`TrieIndexHandle::commit`]). Called after
+    /// [`crate::storage::StorageManager`] drops a case so a stale `DocRef` doesn't keep
+    /// surfacing in search results only to fail metadata lookup afterward. Returns the number
+    /// of trie entries removed; `0` means the case had no lexical trie footprint to begin with,
+    /// which is not an error.
+    pub async fn remove_case_from_trie(&self, case_id: CaseId) -> Result<usize> {
+        let mut writer = self.trie_index.begin_write();
+        let removed = writer.remove_case(case_id);
+        self.trie_index.commit(writer);
+        Ok(removed)
+    }
+
+    /// Atomically rename a case's case-name trie entry, staging the change and publishing it as
+    /// a new generation (see [`TrieIndexHandle::commit`]). Used when
+    /// [`crate::storage::StorageManager`] records a case name correction so stale spellings
+    /// stop being suggested by [`TrieIndex::get_completions`] while the corrected name becomes
+    /// searchable immediately.
+    pub async fn update_case_name_in_trie(
+        &self,
+        case_id: CaseId,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<()> {
+        let mut writer = self.trie_index.begin_write();
+        writer.update_case_name(case_id, old_name, new_name)?;
+        self.trie_index.commit(writer);
+        Ok(())
+    }
+
+    /// Drop rarely-occurring content terms and cap oversized posting lists in the content trie,
+    /// staging the change and publishing it as a new generation (see
+    /// [`TrieIndexHandle::commit`]). Meant to be run by an operator after bulk ingestion, when
+    /// single-occurrence n-grams have accumulated; a pruned phrase simply falls through to
+    /// vector search on the next query rather than becoming an error. See
+    /// [`crate::trie::TrieIndex::prune`].
+    pub async fn prune_content_trie(&self, min_frequency: u32, max_postings: usize) -> PruneReport {
+        let mut writer = self.trie_index.begin_write();
+        let report = writer.prune(min_frequency, max_postings);
+        self.trie_index.commit(writer);
+
+        if report.document_refs_dropped > 0 {
+            self.invalidate_cache();
+        }
+
+        report
+    }
+
+    /// Rebuild the case-citation graph: for every stored case, extract its in-text citations
+    /// (see [`TextProcessor::process_text`]) and resolve each one against the citation trie (see
+    /// [`TrieIndex::resolve_citation`]), recording a [`crate::storage::CitationEdge`] per
+    /// citation via [`StorageManager::store_citation_edges`] — resolved when it lands on another
+    /// indexed case's own citation, [`crate::storage::CitationEdge::Unresolved`] verbatim
+    /// otherwise. A citation that only matches the case it was extracted from (its own reporter
+    /// citation recurring in a running head, say) is dropped rather than recorded as a
+    /// self-edge.
+    ///
+    /// Resolves against whatever citation trie generation is current when this is called, so it
+    /// should run after the corpus's own citations (`CaseMetadata::citations`) have all been
+    /// indexed via [`TrieIndex::insert_citation`] — running it earlier just leaves every in-text
+    /// citation unresolved rather than wrong, since an unindexed target can never match.
+    pub async fn rebuild_citation_graph(&self) -> Result<CitationGraphRebuildStats> {
+        let text_processor = TextProcessor::new(self.config.text_processing.clone())?;
+        let trie = self.trie_index.snapshot();
+        rebuild_citation_graph_with(&self.storage, &trie, &text_processor).await
+    }
+
+    /// `case_id`'s outgoing citation-graph edges ("cites"), built by
+    /// [`SearchEngine::rebuild_citation_graph`]. Returns [`SearchError::CaseNotFound`] if
+    /// `case_id` has no stored case, distinguishing "this case cites nothing" from "this case id
+    /// isn't in the corpus at all".
+    pub async fn get_cited_cases(&self, case_id: CaseId) -> Result<Vec<crate::storage::CitationEdge>> {
+        if self.storage.get_case_summary(&case_id).await?.is_none() {
+            return Err(SearchError::CaseNotFound { case_id });
+        }
+        self.storage.get_cited_cases(&case_id).await
+    }
+
+    /// Cases whose outgoing edges resolved to `case_id` ("cited by"), each with its resolution
+    /// confidence. Same not-found behavior as [`SearchEngine::get_cited_cases`].
+    pub async fn get_citing_cases(&self, case_id: CaseId) -> Result<Vec<crate::storage::CitingCase>> {
+        if self.storage.get_case_summary(&case_id).await?.is_none() {
+            return Err(SearchError::CaseNotFound { case_id });
+        }
+        self.storage.get_citing_cases(&case_id).await
+    }
+
+    /// Health check for search engine
+    pub async fn health_check(&self) -> Result<()> {
+        // Check if indices are loaded
+        let _trie = self.trie_index.snapshot();
+        let _vector = self.vector_index.read("health_check").await;
+
+        // Check storage connectivity
+        self.storage.health_check().await?;
+
+        Ok(())
+    }
+
+    /// Get search engine statistics
+    pub async fn get_stats(&self) -> SearchEngineStats {
+        let vector = self.vector_index.read("get_stats").await;
+        let cache = self.query_cache.read().await;
+        let trie = self.trie_index.snapshot();
+
+        self.finish_stats(trie.get_stats(), trie.indexed_case_count(), vector.get_stats(), vector.indexed_case_count(), cache.get_stats())
+            .await
+    }
+
+    /// Like [`SearchEngine::get_stats`], but runs [`VectorIndex::estimate_recall`]'s self-probe
+    /// against `sample_size` indexed vectors and reports it in `vector_index_stats.recall_estimate`.
+    /// Slower than plain `get_stats` (one extra search per sampled vector), so a caller opts into
+    /// it explicitly (see the `/stats?probe_recall=` admin endpoint) rather than paying it on
+    /// every stats fetch.
+    pub async fn get_stats_with_recall_probe(&self, sample_size: usize) -> SearchEngineStats {
+        let vector = self.vector_index.read("get_stats_with_recall_probe").await;
+        let cache = self.query_cache.read().await;
+        let trie = self.trie_index.snapshot();
+
+        self.finish_stats(
+            trie.get_stats(),
+            trie.indexed_case_count(),
+            vector.get_stats_with_recall_probe(sample_size).await,
+            vector.indexed_case_count(),
+            cache.get_stats(),
+        )
+        .await
+    }
+
+    /// Shared tail of [`SearchEngine::get_stats`]/`get_stats_with_recall_probe`: looks up
+    /// `cases_in_storage` (falling back to 0 rather than failing the whole stats call if storage
+    /// is unreachable, the same tolerance `api::stats_handler` already applies to its own
+    /// `StorageStats` fetch) and derives `index_lag` from it.
+    async fn finish_stats(
+        &self,
+        trie_stats: crate::trie::TrieIndexStats,
+        cases_in_trie: usize,
+        vector_index_stats: crate::vector::VectorIndexStats,
+        cases_with_vectors: usize,
+        cache_stats: CacheStats,
+    ) -> SearchEngineStats {
+        let cases_in_storage = self.storage.get_stats().await.map(|stats| stats.total_cases).unwrap_or(0);
+        let index_lag = cases_in_storage.saturating_sub(cases_in_trie.min(cases_with_vectors));
+
+        SearchEngineStats {
+            cases_in_storage,
+            cases_in_trie,
+            cases_with_vectors,
+            index_lag,
+            trie_stats,
+            vector_index_stats,
+            cache_stats,
+            current_load_percent: self.current_load_percent(),
+        }
+    }
+
+    /// Stream `source`'s sub-trie to `writer` via [`TrieIndex::export`], against a lock-free
+    /// snapshot the same way trie search itself works (see [`TrieIndexHandle::snapshot`]).
+    /// Backs the `--dump-trie` CLI flag for inspecting what's actually indexed when a search
+    /// result looks wrong.
+    pub fn dump_trie<W: std::io::Write>(&self, writer: W, source: TrieSource) -> Result<usize> {
+        self.trie_index.snapshot().export(writer, source)
+    }
+
+    /// Load the embedding model (if not already loaded) and run one dummy inference against it,
+    /// via [`VectorIndex::warm_up`]. Called from `main.rs` in the background right after the API
+    /// server starts listening, so `EmbeddingModelConfig::lazy_load_model` deployments still pay
+    /// the load cost once, just off the startup critical path, and the first real semantic query
+    /// isn't the one that pays it.
+    pub async fn warm_up_vector_index(&self) -> Result<()> {
+        self.vector_index.write("warm_up_vector_index").await.warm_up().await
+    }
+
+    /// Write every live vector to `writer` via [`VectorIndex::export_vectors`]. Backs the
+    /// `--export-embeddings` CLI flag, for offline analysis or migrating vectors into a
+    /// different vector store without re-running the embedding model.
+    pub async fn export_embeddings<W: std::io::Write>(&self, writer: W) -> Result<usize> {
+        self.vector_index.read("export_embeddings").await.export_vectors(writer)
+    }
+
+    /// Write a snapshot of the trie and vector indices to `dir` (as `trie.bin` and
+    /// `vector_cache.bin`, the same layout [`SearchEngine::from_snapshot`] reads), for use as a
+    /// periodic checkpoint alongside live query/write traffic.
+    ///
+    /// The trie snapshot is an `Arc` clone via [`TrieIndexHandle::snapshot`] — no locking at
+    /// all for the duration of the file write. The vector index is cloned while its read lock is
+    /// held, then serialized to disk *after* the lock is dropped, so a slow checkpoint write
+    /// never blocks concurrent readers or writers for longer than the clone itself takes.
+    pub async fn checkpoint<P: AsRef<std::path::Path>>(&self, dir: P) -> Result<()> {
+        let dir = dir.as_ref();
+
+        let trie_snapshot = self.trie_index.snapshot();
+        trie_snapshot.save_to_disk(dir.join("trie.bin")).await?;
+
+        let vector_snapshot = self.vector_index.read("checkpoint").await.clone();
+        vector_snapshot.save_to_disk(dir.join("vector_cache.bin")).await?;
+
+        Ok(())
+    }
+}
+
+/// Move a corrupt or unreadable snapshot file aside as `<name>.corrupt-<unix-timestamp>` so
+/// a future `index-build` run can write a fresh snapshot to the original path without
+/// clobbering evidence of the corruption
+async fn quarantine_corrupt_snapshot(path: &std::path::Path) {
+    if !tokio::fs::try_exists(path).await.unwrap_or(false) {
+        return;
+    }
+
+    let mut quarantined_name = path.file_name().unwrap_or_default().to_os_string();
+    quarantined_name.push(format!(".corrupt-{}", chrono::Utc::now().timestamp()));
+    let quarantined_path = path.with_file_name(quarantined_name);
+
+    match tokio::fs::rename(path, &quarantined_path).await {
+        Ok(()) => tracing::warn!("Quarantined corrupt snapshot {:?} -> {:?}", path, quarantined_path),
+        Err(e) => tracing::warn!("Failed to quarantine corrupt snapshot {:?}: {}", path, e),
+    }
+}
+
+impl QueryCache {
+    fn new(max_size: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            max_size,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            expirations: 0,
+            stale_invalidations: 0,
+        }
+    }
+
+    /// Move `key` to the most-recently-used end of `order`. `entries` is the source of truth for
+    /// which keys exist; `order` only ever reorders keys already present there.
+    fn touch(&mut self, key: &str) {
+        if let Some(pos) = self.order.iter().position(|existing| existing == key) {
+            let key = self.order.remove(pos).unwrap();
+            self.order.push_back(key);
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+        if let Some(pos) = self.order.iter().position(|existing| existing == key) {
+            self.order.remove(pos);
+        }
+    }
+
+    fn get(&mut self, key: &str, current_generation: u64) -> Option<RankedCandidates> {
+        let now = chrono::Utc::now();
+        match self.entries.get(key) {
+            Some(cached) if cached.is_expired(now) => {
+                self.remove(key);
+                self.expirations += 1;
+                self.misses += 1;
+                None
+            }
+            Some(cached) if cached.generation != current_generation => {
+                self.remove(key);
+                self.stale_invalidations += 1;
+                self.misses += 1;
+                None
+            }
+            Some(cached) => {
+                let ranked = cached.ranked.clone();
+                self.touch(key);
+                self.hits += 1;
+                Some(ranked)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, key: String, ranked: RankedCandidates, ttl_seconds: u64, generation: u64) {
+        self.sweep_expired();
+
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+        } else {
+            if self.entries.len() >= self.max_size {
+                if let Some(lru_key) = self.order.pop_front() {
+                    self.entries.remove(&lru_key);
+                    self.evictions += 1;
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+
+        self.entries.insert(key, CachedResult {
+            ranked,
+            timestamp: chrono::Utc::now(),
+            ttl_seconds,
+            generation,
+        });
+    }
+
+    /// Scan every entry and drop the ones that have expired, independent of whether they're ever
+    /// looked up again. Called both from `insert` (so a burst of distinct never-repeated queries
+    /// doesn't accumulate stale junk between sweeps) and periodically by
+    /// [`SearchEngine::spawn_query_cache_sweep`].
+    fn sweep_expired(&mut self) -> usize {
+        let now = chrono::Utc::now();
+        let expired: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, cached)| cached.is_expired(now))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let count = expired.len();
+        for key in expired {
+            self.remove(&key);
+        }
+        self.expirations += count;
+        count
+    }
+
+    fn get_stats(&self) -> CacheStats {
+        CacheStats {
+            size: self.entries.len(),
+            max_size: self.max_size,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            expirations: self.expirations,
+            stale_invalidations: self.stale_invalidations,
+        }
+    }
+}
+
+/// Search engine statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchEngineStats {
+    /// Cases in [`crate::storage::StorageManager`] — the source of truth for what's been
+    /// ingested, whether or not it's made it into the trie or vector index yet.
+    pub cases_in_storage: usize,
+    /// Distinct cases represented in the trie; see [`crate::trie::TrieIndex::indexed_case_count`].
+    pub cases_in_trie: usize,
+    /// Distinct cases with at least one live vector; see
+    /// [`crate::vector::VectorIndex::indexed_case_count`].
+    pub cases_with_vectors: usize,
+    /// `cases_in_storage` minus however many of those are missing from the trie or the vector
+    /// index (whichever is further behind) — the number of cases an operator would need to
+    /// (re)index to catch storage up. Zero once ingestion, trie indexing, and vector indexing all
+    /// agree on the same case count.
+    pub index_lag: usize,
+    /// Trie term counts, node/posting totals, and estimated memory footprint; see
+    /// [`crate::trie::TrieIndexStats`]
+    pub trie_stats: crate::trie::TrieIndexStats,
     pub vector_index_stats: crate::vector::VectorIndexStats,
     pub cache_stats: CacheStats,
+    /// Percentage of `search.max_concurrent_queries` slots currently in use
+    pub current_load_percent: u8,
+}
+
+/// Cache statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheStats {
+    pub size: usize,
+    pub max_size: usize,
+    /// Cumulative cache hits since the engine started (never reset)
+    pub hits: usize,
+    /// Cumulative cache misses, including expired entries found on lookup
+    pub misses: usize,
+    /// Cumulative entries evicted to make room under `max_size`, least-recently-used first
+    pub evictions: usize,
+    /// Cumulative entries reclaimed for having outlived their TTL, whether found expired on
+    /// lookup or by the periodic sweep task
+    pub expirations: usize,
+    /// Cumulative entries reclaimed on lookup for having been computed against an index
+    /// generation superseded by [`SearchEngine::invalidate_cache`] since
+    pub stale_invalidations: usize,
 }
 
-/// Cache statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CacheStats {
-    pub size: usize,
-    pub max_size: usize,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    thread_local! {
+        /// Test-only hook: when set, `generate_snippet` panics for this one case id, letting
+        /// tests exercise `search_with_params`'s isolated-task panic handling without a real
+        /// bug. `#[tokio::test]` runs on a single-threaded runtime by default, so a task
+        /// spawned during the test observes the same thread-local as the test itself.
+        static PANIC_ON_SNIPPET_FOR_CASE: std::cell::Cell<Option<CaseId>> = const { std::cell::Cell::new(None) };
+    }
+
+    pub(super) fn maybe_panic_for_snippet(case_id: CaseId) {
+        if PANIC_ON_SNIPPET_FOR_CASE.with(|cell| cell.get()) == Some(case_id) {
+            panic!("simulated panic in snippet generation (test-injected)");
+        }
+    }
+
+    thread_local! {
+        /// Test-only hooks: when set, the corresponding stage of `execute_hybrid_search`
+        /// sleeps for the given duration before doing its (otherwise near-instant, since the
+        /// vector/trie backends in unit tests hold little to no data) work, letting tests
+        /// exercise `SearchStageBudgets` timeouts deterministically without a real slow
+        /// dependency. Same single-threaded-runtime reasoning as `PANIC_ON_SNIPPET_FOR_CASE`.
+        static LEXICAL_STAGE_DELAY: std::cell::Cell<Option<Duration>> = const { std::cell::Cell::new(None) };
+        static SEMANTIC_STAGE_DELAY: std::cell::Cell<Option<Duration>> = const { std::cell::Cell::new(None) };
+        static RERANK_STAGE_DELAY: std::cell::Cell<Option<Duration>> = const { std::cell::Cell::new(None) };
+        static SNIPPET_DELAY: std::cell::Cell<Option<Duration>> = const { std::cell::Cell::new(None) };
+    }
+
+    pub(super) async fn maybe_delay_lexical() {
+        if let Some(delay) = LEXICAL_STAGE_DELAY.with(|cell| cell.get()) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    pub(super) async fn maybe_delay_semantic() {
+        if let Some(delay) = SEMANTIC_STAGE_DELAY.with(|cell| cell.get()) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    pub(super) async fn maybe_delay_rerank() {
+        if let Some(delay) = RERANK_STAGE_DELAY.with(|cell| cell.get()) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    pub(super) async fn maybe_delay_snippet() {
+        if let Some(delay) = SNIPPET_DELAY.with(|cell| cell.get()) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    fn set_lexical_stage_delay(delay: Option<Duration>) {
+        LEXICAL_STAGE_DELAY.with(|cell| cell.set(delay));
+    }
+
+    fn set_semantic_stage_delay(delay: Option<Duration>) {
+        SEMANTIC_STAGE_DELAY.with(|cell| cell.set(delay));
+    }
+
+    fn set_rerank_stage_delay(delay: Option<Duration>) {
+        RERANK_STAGE_DELAY.with(|cell| cell.set(delay));
+    }
+
+    fn set_snippet_delay(delay: Option<Duration>) {
+        SNIPPET_DELAY.with(|cell| cell.set(delay));
+    }
+
+    fn set_panic_on_snippet_for_case(case_id: Option<CaseId>) {
+        PANIC_ON_SNIPPET_FOR_CASE.with(|cell| cell.set(case_id));
+    }
+
+    /// Exercises the same acquire-with-timeout gating `search_with_params` performs, using
+    /// a bare semaphore so the test doesn't need a full storage/vector/trie fixture. The
+    /// "mocked slow vector stage" is a fixed sleep held for the permit's lifetime.
+    async fn run_query(limiter: Arc<Semaphore>, wait_budget: Duration, work: Duration, shed: Arc<AtomicUsize>) {
+        match tokio::time::timeout(wait_budget, limiter.acquire_owned()).await {
+            Ok(Ok(_permit)) => tokio::time::sleep(work).await,
+            _ => {
+                shed.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shedding_kicks_in_under_burst_then_recovers() {
+        let limiter = Arc::new(Semaphore::new(2));
+        let wait_budget = Duration::from_millis(50);
+        let slow_work = Duration::from_millis(200);
+        let shed = Arc::new(AtomicUsize::new(0));
+
+        // Burst of 5 queries against 2 slots: some must be shed rather than queue forever.
+        let handles: Vec<_> = (0..5)
+            .map(|_| tokio::spawn(run_query(limiter.clone(), wait_budget, slow_work, shed.clone())))
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert!(shed.load(Ordering::SeqCst) > 0, "expected some queries to be shed under burst load");
+
+        // Once the burst drains, capacity recovers and a fresh query is admitted immediately.
+        let recovered = Arc::new(AtomicUsize::new(0));
+        run_query(limiter.clone(), wait_budget, Duration::from_millis(1), recovered.clone()).await;
+        assert_eq!(recovered.load(Ordering::SeqCst), 0, "expected capacity to recover once the burst drained");
+    }
+
+    #[test]
+    fn test_snippet_from_paragraph_centers_a_window_on_a_match_at_the_start() {
+        let paragraph = "Marbury filed suit against Madison seeking a writ of mandamus from the Supreme Court \
+            regarding his commission as a justice of the peace for the District of Columbia under the \
+            Judiciary Act of 1801 which the incoming administration had declined to deliver upon taking office.";
+        let snippet = SearchEngine::snippet_from_paragraph(paragraph, "Marbury");
+        assert!(snippet.starts_with("Marbury"), "no leading ellipsis expected: {snippet}");
+        assert!(snippet.ends_with('…'), "should be truncated at the end: {snippet}");
+    }
+
+    #[test]
+    fn test_snippet_from_paragraph_centers_a_window_on_a_match_in_the_middle() {
+        let mut words = vec!["filler"; 60];
+        words[30] = "mandamus";
+        let paragraph = words.join(" ");
+        let snippet = SearchEngine::snippet_from_paragraph(&paragraph, "mandamus");
+        assert!(snippet.starts_with('…'), "should be truncated at the start: {snippet}");
+        assert!(snippet.contains("mandamus"));
+        assert!(snippet.ends_with('…'), "should be truncated at the end: {snippet}");
+    }
+
+    #[test]
+    fn test_snippet_from_paragraph_centers_a_window_on_a_match_at_the_end() {
+        let mut words = vec!["filler"; 60];
+        words[59] = "mandamus";
+        let paragraph = words.join(" ");
+        let snippet = SearchEngine::snippet_from_paragraph(&paragraph, "mandamus");
+        assert!(snippet.starts_with('…'), "should be truncated at the start: {snippet}");
+        assert!(snippet.ends_with("mandamus"), "no trailing ellipsis expected: {snippet}");
+    }
+
+    #[test]
+    fn test_snippet_from_paragraph_falls_back_to_leading_sentences_for_a_semantic_match() {
+        let paragraph = "The court held that the writ could not issue. It then discussed jurisdiction at length. \
+            A third sentence follows with further analysis.";
+        let snippet = SearchEngine::snippet_from_paragraph(paragraph, "unrelated semantic query");
+        assert_eq!(snippet, "The court held that the writ could not issue. It then discussed jurisdiction at length.");
+    }
+
+    #[test]
+    fn test_snippet_from_paragraph_is_utf8_safe_for_non_ascii_text() {
+        let paragraph = "Le tribunal a statué que le mandat de mandamus ne pouvait être délivré en \
+            l'absence d'une compétence claire, une décision très commentée à l'époque.";
+        let snippet = SearchEngine::snippet_from_paragraph(paragraph, "mandamus");
+        assert!(snippet.contains("mandamus"));
+        // Must not panic on non-ASCII byte boundaries, and must not corrupt the accented text.
+        assert!(snippet.contains("tribunal") || snippet.contains("statué"));
+    }
+
+    #[test]
+    fn test_leading_sentences_falls_back_to_a_word_window_without_sentence_punctuation() {
+        let words = vec!["word"; 50].join(" ");
+        let snippet = SearchEngine::leading_sentences(&words);
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.split_whitespace().count() <= SearchEngine::SNIPPET_WINDOW_WORDS + 1);
+    }
+
+    #[test]
+    fn test_match_provenance_maps_from_trie_source() {
+        assert_eq!(MatchProvenance::from(TrieSource::CaseName), MatchProvenance::TrieCaseName);
+        assert_eq!(MatchProvenance::from(TrieSource::Content), MatchProvenance::TrieContent);
+        assert_eq!(MatchProvenance::from(TrieSource::Citation), MatchProvenance::TrieCitation);
+    }
+
+    /// A truncated/corrupt vector snapshot must not fail startup: the engine should come up
+    /// with the vector index marked `Degraded` and lexical (trie) search still functional.
+    #[tokio::test]
+    async fn test_corrupt_vector_snapshot_degrades_instead_of_failing_startup() {
+        let temp_dir = std::env::temp_dir().join(format!("search-degrade-test-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+
+        let case_id = uuid::Uuid::new_v4();
+        let metadata = CaseMetadata {
+            id: case_id,
+            name: "Marbury v Madison".to_string(),
+            citation: "5 U.S. 137".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1803, 2, 24).unwrap(),
+            judges: vec!["Marshall".to_string()],
+            topics: vec![],
+            full_text: "The province of the court is solely to decide on the rights of individuals.".to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec!["5 U.S. 137".to_string()],
+            docket_number: None,
+            source_url: None,
+            word_count: 12,
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        storage.store_case_metadata(&metadata).await.unwrap();
+        storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        trie_index.insert_case_name(&metadata.name, case_id).unwrap();
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        // A truncated snapshot: valid-looking bytes that don't deserialize as a VectorCache.
+        tokio::fs::write(temp_dir.join("vector_cache.bin"), b"not a real snapshot").await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, &temp_dir).await.unwrap();
+
+        let health = engine.index_health().await;
+        assert_eq!(health.trie, IndexComponentStatus::Healthy);
+        assert!(matches!(health.vector, IndexComponentStatus::Degraded { .. }));
+
+        let results = engine.search("Marbury v Madison").await.unwrap();
+        assert!(!results.is_empty(), "lexical search should still find the trie-indexed case");
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `checkpoint` takes a lock-free trie snapshot and clones the vector index under its
+    /// (instrumented) read lock, doing the actual file IO afterward in both cases, so a
+    /// concurrent writer should never see its lock acquisition blocked for anywhere near the
+    /// time the checkpoint's file writes take.
+    #[tokio::test]
+    async fn test_checkpoint_does_not_hold_lock_for_duration_of_file_io() {
+        let temp_dir = std::env::temp_dir().join(format!("search-checkpoint-test-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let engine = SearchEngine::new(config, storage).await.unwrap();
+
+        let snapshot_dir = temp_dir.join("snapshot");
+        tokio::fs::create_dir_all(&snapshot_dir).await.unwrap();
+        engine.checkpoint(&snapshot_dir).await.unwrap();
+
+        assert!(snapshot_dir.join("trie.bin").exists());
+        assert!(snapshot_dir.join("vector_cache.bin").exists());
+        assert_eq!(engine.vector_index.threshold_breach_count(), 0);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A panic inside the isolated search task (simulated via `maybe_panic_for_snippet`) must
+    /// surface as `SearchError::Internal`, not crash the engine or poison anything a
+    /// subsequent, unrelated request needs.
+    #[tokio::test]
+    async fn test_panicking_snippet_generator_isolates_failure_to_one_request() {
+        let temp_dir = std::env::temp_dir().join(format!("search-panic-test-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+
+        let case_id = uuid::Uuid::new_v4();
+        let metadata = CaseMetadata {
+            id: case_id,
+            name: "Marbury v Madison".to_string(),
+            citation: "5 U.S. 137".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1803, 2, 24).unwrap(),
+            judges: vec!["Marshall".to_string()],
+            topics: vec![],
+            full_text: "The province of the court is solely to decide on the rights of individuals.".to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec!["5 U.S. 137".to_string()],
+            docket_number: None,
+            source_url: None,
+            word_count: 12,
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        storage.store_case_metadata(&metadata).await.unwrap();
+        storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        trie_index.insert_case_name(&metadata.name, case_id).unwrap();
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, &temp_dir).await.unwrap();
+
+        set_panic_on_snippet_for_case(Some(case_id));
+        let panicking = engine.search("Marbury v Madison").await;
+        assert!(
+            matches!(panicking, Err(SearchError::Internal { .. })),
+            "expected the isolated task's panic to surface as SearchError::Internal, got {:?}",
+            panicking
+        );
+
+        set_panic_on_snippet_for_case(None);
+        let recovered = engine.search("Marbury v Madison").await.unwrap();
+        assert!(!recovered.is_empty(), "expected a later request to succeed after the panicking one");
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Shared fixture for the per-stage latency budget tests below: a snapshot-backed engine
+    /// with one case indexed in the trie, so `search_with_params` exercises the real
+    /// trie/vector/snippet/rerank stages end to end rather than a bare-bones mock.
+    async fn budget_test_engine(temp_dir: &std::path::Path) -> (SearchEngine, CaseId) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+
+        let case_id = uuid::Uuid::new_v4();
+        let metadata = CaseMetadata {
+            id: case_id,
+            name: "Marbury v Madison".to_string(),
+            citation: "5 U.S. 137".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1803, 2, 24).unwrap(),
+            judges: vec!["Marshall".to_string()],
+            topics: vec![],
+            full_text: "The province of the court is solely to decide on the rights of individuals.".to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec!["5 U.S. 137".to_string()],
+            docket_number: None,
+            source_url: None,
+            word_count: 12,
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        storage.store_case_metadata(&metadata).await.unwrap();
+        storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        trie_index.insert_case_name(&metadata.name, case_id).unwrap();
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, case_id)
+    }
+
+    fn budget_query(query: &str, engine: &SearchEngine, budgets: SearchStageBudgets) -> SearchQuery {
+        let mut config = SearchConfig::from_config(&engine.config.search, &engine.config.vector);
+        config.budgets = budgets;
+        SearchQuery {
+            query: query.to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config,
+        }
+    }
+
+    /// A lexical stage slowed (via the test-only `LEXICAL_STAGE_DELAY` hook) past its
+    /// configured `lexical_ms` budget must be cut short: the query still succeeds, but with no
+    /// trie matches and a `LEXICAL_BUDGET_EXCEEDED` warning, rather than blocking until the
+    /// slow trie search finishes.
+    #[tokio::test]
+    async fn test_lexical_budget_exceeded_drops_trie_results_and_warns() {
+        let temp_dir = std::env::temp_dir().join(format!("search-budget-lexical-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        set_lexical_stage_delay(Some(Duration::from_millis(50)));
+        let query = budget_query(
+            "Marbury v Madison",
+            &engine,
+            SearchStageBudgets { lexical_ms: Some(5), ..Default::default() },
+        );
+        let outcome = engine.search_with_params(query).await.unwrap();
+        set_lexical_stage_delay(None);
+
+        assert!(outcome.results.is_empty(), "expected the slow lexical stage to be cut short with no results");
+        assert!(outcome.warnings.contains(&"LEXICAL_BUDGET_EXCEEDED".to_string()));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Without a configured budget, an artificially slow lexical stage still eventually
+    /// completes and its results are returned with no warning — `None` means unbounded.
+    #[tokio::test]
+    async fn test_no_lexical_budget_configured_waits_out_slow_stage() {
+        let temp_dir = std::env::temp_dir().join(format!("search-budget-lexical-none-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        set_lexical_stage_delay(Some(Duration::from_millis(5)));
+        let query = budget_query("Marbury v Madison", &engine, SearchStageBudgets::default());
+        let outcome = engine.search_with_params(query).await.unwrap();
+        set_lexical_stage_delay(None);
+
+        assert!(!outcome.results.is_empty(), "expected the unbudgeted stage to wait out the delay and still find the case");
+        assert!(outcome.warnings.is_empty());
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A semantic stage slowed past its `semantic_ms` budget must warn rather than block the
+    /// whole query, mirroring the lexical case.
+    #[tokio::test]
+    async fn test_semantic_budget_exceeded_warns() {
+        let temp_dir = std::env::temp_dir().join(format!("search-budget-semantic-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        set_semantic_stage_delay(Some(Duration::from_millis(50)));
+        let mut budgets = SearchStageBudgets { semantic_ms: Some(5), ..Default::default() };
+        budgets.lexical_ms = None;
+        let mut query = budget_query("Marbury v Madison", &engine, budgets);
+        query.config.enable_prefix = false; // isolate the semantic stage
+        let outcome = engine.search_with_params(query).await.unwrap();
+        set_semantic_stage_delay(None);
+
+        assert!(outcome.warnings.contains(&"SEMANTIC_BUDGET_EXCEEDED".to_string()));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A rerank stage slowed past its `rerank_ms` budget must warn and fall back to the unfused
+    /// lexical-then-semantic candidate list (see `concat_unfused`) instead of RRF fusion,
+    /// rather than block.
+    #[tokio::test]
+    async fn test_rerank_budget_exceeded_skips_fusion_and_warns() {
+        let temp_dir = std::env::temp_dir().join(format!("search-budget-rerank-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        set_rerank_stage_delay(Some(Duration::from_millis(50)));
+        let query = budget_query(
+            "Marbury v Madison",
+            &engine,
+            SearchStageBudgets { rerank_ms: Some(5), ..Default::default() },
+        );
+        let outcome = engine.search_with_params(query).await.unwrap();
+        set_rerank_stage_delay(None);
+
+        assert!(outcome.warnings.contains(&"RERANK_BUDGET_EXCEEDED".to_string()));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Bare `Candidate` fixture for `fuse_reciprocal_rank` tests, which exercise the fusion
+    /// arithmetic directly and so never need a real engine, trie, or vector index.
+    fn rrf_candidate(case_id: CaseId, score: f32, lexical_score: Option<f32>, semantic_score: Option<f32>) -> Candidate {
+        Candidate {
+            doc_ref: DocRef { case_id, paragraph_index: 0, char_offset: None },
+            summary: crate::storage::CaseSummary {
+                id: case_id,
+                court: "Test Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                topics: Vec::new(),
+                judges: Vec::new(),
+                duplicate_of: None,
+            },
+            score,
+            match_type: MatchType::Exact,
+            provenance: MatchProvenance::TrieContent,
+            lexical_score,
+            semantic_score,
+            extra_doc_refs: Vec::new(),
+        }
+    }
+
+    /// A case ranked first in both the lexical and vector lists sums two `1/(k + rank)` terms
+    /// and must outrank a case that only appears once, even at rank 1 of its own list.
+    #[test]
+    fn test_fuse_reciprocal_rank_favors_a_case_found_by_both_stages() {
+        let both_case = uuid::Uuid::new_v4();
+        let lexical_only_case = uuid::Uuid::new_v4();
+        let vector_only_case = uuid::Uuid::new_v4();
+        let k = 60.0;
+
+        let lexical = vec![
+            rrf_candidate(both_case, 3.0, Some(3.0), None),
+            rrf_candidate(lexical_only_case, 2.0, Some(2.0), None),
+        ];
+        let vector = vec![
+            rrf_candidate(both_case, 0.9, None, Some(0.9)),
+            rrf_candidate(vector_only_case, 0.8, None, Some(0.8)),
+        ];
+
+        let fused = SearchEngine::fuse_reciprocal_rank(lexical, vector, k);
+
+        let expected_both = 1.0 / (k + 1.0) + 1.0 / (k + 1.0);
+        let expected_single = 1.0 / (k + 1.0);
+        let expected_second = 1.0 / (k + 2.0);
+
+        let by_id = |id: CaseId| fused.iter().find(|c| c.summary.id == id).unwrap();
+
+        assert_eq!(fused.len(), 3);
+        assert!((by_id(both_case).score - expected_both).abs() < 1e-6);
+        assert!((by_id(lexical_only_case).score - expected_single).abs() < 1e-6);
+        assert!((by_id(vector_only_case).score - expected_single).abs() < 1e-6);
+        // `both_case` sums two rank-1 terms and must sort ahead of either single-source hit.
+        assert_eq!(fused[0].summary.id, both_case);
+        assert!(fused[0].score > expected_second);
+
+        // Both raw scores survive fusion for debugging, keyed off the lexical candidate's
+        // doc_ref/match_type/provenance per the fusion's tie-break rule.
+        let both = by_id(both_case);
+        assert_eq!(both.lexical_score, Some(3.0));
+        assert_eq!(both.semantic_score, Some(0.9));
+    }
+
+    /// With a small fixed `k` and three ranked entries per list, the fused order and scores
+    /// should match hand-computed RRF sums exactly.
+    #[test]
+    fn test_fuse_reciprocal_rank_computes_exact_sums_for_fixed_ranks() {
+        let a = uuid::Uuid::new_v4();
+        let b = uuid::Uuid::new_v4();
+        let c = uuid::Uuid::new_v4();
+        let k = 1.0;
+
+        // Lexical ranks (by descending score): a=1, b=2, c=3
+        let lexical = vec![
+            rrf_candidate(a, 9.0, Some(9.0), None),
+            rrf_candidate(b, 8.0, Some(8.0), None),
+            rrf_candidate(c, 7.0, Some(7.0), None),
+        ];
+        // Vector ranks (by descending score): c=1, a=2, b=3
+        let vector = vec![
+            rrf_candidate(c, 0.99, None, Some(0.99)),
+            rrf_candidate(a, 0.5, None, Some(0.5)),
+            rrf_candidate(b, 0.1, None, Some(0.1)),
+        ];
+
+        let fused = SearchEngine::fuse_reciprocal_rank(lexical, vector, k);
+        let score_of = |id: CaseId| fused.iter().find(|cand| cand.summary.id == id).unwrap().score;
+
+        // a: lexical rank 1 + vector rank 2 -> 1/(1+1) + 1/(1+2) = 1/2 + 1/3
+        assert!((score_of(a) - (1.0 / 2.0 + 1.0 / 3.0)).abs() < 1e-6);
+        // b: lexical rank 2 + vector rank 3 -> 1/(1+2) + 1/(1+3) = 1/3 + 1/4
+        assert!((score_of(b) - (1.0 / 3.0 + 1.0 / 4.0)).abs() < 1e-6);
+        // c: lexical rank 3 + vector rank 1 -> 1/(1+3) + 1/(1+1) = 1/4 + 1/2
+        assert!((score_of(c) - (1.0 / 4.0 + 1.0 / 2.0)).abs() < 1e-6);
+
+        let mut expected_order = vec![(a, score_of(a)), (b, score_of(b)), (c, score_of(c))];
+        expected_order.sort_by(|x, y| y.1.partial_cmp(&x.1).unwrap());
+        let actual_order: Vec<CaseId> = fused.iter().map(|cand| cand.summary.id).collect();
+        assert_eq!(actual_order, expected_order.into_iter().map(|(id, _)| id).collect::<Vec<_>>());
+    }
+
+    /// `concat_unfused` (the `RERANK_BUDGET_EXCEEDED` fallback) must keep every lexical hit in
+    /// its own order, then append vector hits that weren't already found lexically — with no
+    /// fused score, since fusion is exactly the work the exceeded budget skipped.
+    #[test]
+    fn test_concat_unfused_orders_lexical_first_then_novel_vector_hits() {
+        let shared_case = uuid::Uuid::new_v4();
+        let lexical_only = uuid::Uuid::new_v4();
+        let vector_only = uuid::Uuid::new_v4();
+
+        let lexical = vec![
+            rrf_candidate(shared_case, 3.0, Some(3.0), None),
+            rrf_candidate(lexical_only, 2.0, Some(2.0), None),
+        ];
+        let vector = vec![
+            rrf_candidate(shared_case, 0.9, None, Some(0.9)),
+            rrf_candidate(vector_only, 0.8, None, Some(0.8)),
+        ];
+
+        let out = SearchEngine::concat_unfused(lexical, vector);
+        let ids: Vec<CaseId> = out.iter().map(|c| c.summary.id).collect();
+
+        assert_eq!(ids, vec![shared_case, lexical_only, vector_only]);
+    }
+
+    /// Bare `RankedCandidates` fixture for `QueryCache` tests — an empty candidate set is fine,
+    /// since these tests only care about cache bookkeeping (keys, eviction order, expiry), not
+    /// what's actually ranked.
+    fn empty_ranked_candidates() -> RankedCandidates {
+        RankedCandidates {
+            candidates: Vec::new(),
+            topic_facets: Vec::new(),
+            facets: SearchFacets::default(),
+            warnings: Vec::new(),
+            applied_synonym_expansions: Vec::new(),
+        }
+    }
+
+    fn cache_test_query(text: &str) -> SearchQuery {
+        SearchQuery {
+            query: text.to_string(),
+            max_results: Some(10),
+            offset: 0,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            config: SearchConfig::default(),
+        }
+    }
+
+    /// Inserting past `max_size` must evict the least-recently-used entry, not an arbitrary one —
+    /// and a `get` on a still-live entry must count toward "recently used", protecting it from
+    /// the next eviction even though it was inserted first.
+    #[test]
+    fn test_query_cache_evicts_least_recently_used_entry() {
+        let mut cache = QueryCache::new(2);
+        cache.insert("a".to_string(), empty_ranked_candidates(), 3600, 0);
+        cache.insert("b".to_string(), empty_ranked_candidates(), 3600, 0);
+
+        // Touch "a" so "b" becomes the least recently used.
+        assert!(cache.get("a", 0).is_some());
+
+        cache.insert("c".to_string(), empty_ranked_candidates(), 3600, 0);
+
+        assert!(cache.get("a", 0).is_some(), "recently-used entry should survive eviction");
+        assert!(cache.get("b", 0).is_none(), "least-recently-used entry should have been evicted");
+        assert!(cache.get("c", 0).is_some(), "newly-inserted entry should be present");
+        assert_eq!(cache.get_stats().evictions, 1);
+    }
+
+    /// An entry older than its own TTL must be treated as a miss (and reclaimed) even though
+    /// `max_size` was never reached.
+    #[test]
+    fn test_query_cache_expires_entries_past_their_ttl() {
+        let mut cache = QueryCache::new(10);
+        cache.insert("stale".to_string(), empty_ranked_candidates(), 0, 0);
+
+        // A `ttl_seconds` of 0 means "already expired" on the very next lookup, since
+        // `is_expired` compares `age >= ttl_seconds`.
+        assert!(cache.get("stale", 0).is_none());
+        let stats = cache.get_stats();
+        assert_eq!(stats.expirations, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.size, 0, "expired entry should have been removed, not just skipped");
+    }
+
+    /// The periodic sweep must reclaim expired entries without requiring anyone to `get` them
+    /// first.
+    #[test]
+    fn test_query_cache_sweep_reclaims_expired_entries_without_a_read() {
+        let mut cache = QueryCache::new(10);
+        cache.insert("stale".to_string(), empty_ranked_candidates(), 0, 0);
+        cache.insert("fresh".to_string(), empty_ranked_candidates(), 3600, 0);
+
+        let reclaimed = cache.sweep_expired();
+
+        assert_eq!(reclaimed, 1);
+        assert_eq!(cache.get_stats().size, 1);
+        assert!(cache.entries.contains_key("fresh"));
+    }
+
+    /// An entry computed against an older index generation must be treated as a miss (and
+    /// reclaimed) even though it hasn't expired, since [`SearchEngine::invalidate_cache`] bumps
+    /// the generation instead of touching `entries` directly.
+    #[test]
+    fn test_query_cache_treats_a_generation_mismatch_as_a_miss() {
+        let mut cache = QueryCache::new(10);
+        cache.insert("q".to_string(), empty_ranked_candidates(), 3600, 0);
+
+        assert!(cache.get("q", 1).is_none(), "entry from a stale generation should not be served");
+        let stats = cache.get_stats();
+        assert_eq!(stats.stale_invalidations, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.size, 0, "stale entry should have been removed, not just skipped");
+    }
+
+    /// Two queries with the same text but different court filters must not collide in the cache
+    /// key, and filter order must not matter (the same courts in a different order are still the
+    /// same filter).
+    #[test]
+    fn test_compose_cache_key_distinguishes_filters_and_ignores_list_order() {
+        let mut base = cache_test_query("free speech");
+        base.court_filter = Some(vec!["Supreme Court".to_string(), "9th Circuit".to_string()]);
+
+        let mut reordered = cache_test_query("free speech");
+        reordered.court_filter = Some(vec!["9th Circuit".to_string(), "Supreme Court".to_string()]);
+
+        let mut different_court = cache_test_query("free speech");
+        different_court.court_filter = Some(vec!["Supreme Court".to_string()]);
+
+        let mut no_filter = cache_test_query("free speech");
+        no_filter.court_filter = None;
+
+        assert_eq!(SearchEngine::compose_cache_key(&base), SearchEngine::compose_cache_key(&reordered));
+        assert_ne!(SearchEngine::compose_cache_key(&base), SearchEngine::compose_cache_key(&different_court));
+        assert_ne!(SearchEngine::compose_cache_key(&base), SearchEngine::compose_cache_key(&no_filter));
+    }
+
+    /// `offset` must NOT be part of the cache key, so a second page of the same query is a cache
+    /// hit against the first page's entry (see the [`QueryCache`] doc comment).
+    #[test]
+    fn test_compose_cache_key_ignores_offset() {
+        let mut first_page = cache_test_query("free speech");
+        first_page.offset = 0;
+
+        let mut second_page = cache_test_query("free speech");
+        second_page.offset = 10;
+
+        assert_eq!(SearchEngine::compose_cache_key(&first_page), SearchEngine::compose_cache_key(&second_page));
+    }
+
+    /// `max_results` DOES belong in the cache key: it sets how many hits the vector stage
+    /// fetches (see `search_vector_for_query`'s `requested`), so two different `max_results`
+    /// values can legitimately produce two different candidate sets for otherwise-identical
+    /// queries.
+    #[test]
+    fn test_compose_cache_key_distinguishes_max_results() {
+        let mut small_page = cache_test_query("free speech");
+        small_page.max_results = Some(10);
+
+        let mut large_page = cache_test_query("free speech");
+        large_page.max_results = Some(100);
+
+        assert_ne!(SearchEngine::compose_cache_key(&small_page), SearchEngine::compose_cache_key(&large_page));
+    }
+
+    /// Once the batch-wide `snippets_ms` budget is exhausted, remaining results must fall
+    /// back to the cheap placeholder snippet instead of paying for a slow `generate_snippet`.
+    #[tokio::test]
+    async fn test_snippets_budget_exceeded_falls_back_and_warns() {
+        let temp_dir = std::env::temp_dir().join(format!("search-budget-snippets-{}", uuid::Uuid::new_v4()));
+        let (engine, case_id) = budget_test_engine(&temp_dir).await;
+
+        set_snippet_delay(Some(Duration::from_millis(50)));
+        let query = budget_query(
+            "Marbury v Madison",
+            &engine,
+            SearchStageBudgets { snippets_ms: Some(5), ..Default::default() },
+        );
+        let outcome = engine.search_with_params(query).await.unwrap();
+        set_snippet_delay(None);
+
+        assert!(outcome.warnings.contains(&"SNIPPETS_BUDGET_EXCEEDED".to_string()));
+        assert!(
+            outcome.results.iter().any(|r| r.snippet == format!("Case {} (snippet omitted: latency budget exceeded)", case_id)),
+            "expected the fallback snippet once the budget was exhausted, got {:?}",
+            outcome.results.iter().map(|r| &r.snippet).collect::<Vec<_>>()
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Builds a snapshot-backed engine with `count` distinct cases, all sharing a `"case "`
+    /// case-name prefix so a single wildcard query (`"case *"`) matches all of them at once —
+    /// used to exercise `execute_hybrid_search`'s lazy-hydration behavior against a candidate
+    /// pool bigger than one page.
+    async fn multi_case_budget_test_engine(temp_dir: &std::path::Path, count: usize) -> SearchEngine {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+
+        for i in 0..count {
+            let case_id = uuid::Uuid::new_v4();
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case Number{i}"),
+                citation: format!("{i} U.S. 1"),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: "Sample case text.".to_string(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![format!("{i} U.S. 1")],
+                docket_number: None,
+                source_url: None,
+                word_count: 3,
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+            trie_index.insert_case_name(&metadata.name, case_id).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap()
+    }
+
+    /// A candidate pool larger than `max_results` should only pay full-`CaseMetadata`
+    /// deserialization for the page actually returned, not for every candidate that was
+    /// considered before filtering/ranking/truncation.
+    #[tokio::test]
+    async fn test_full_metadata_hydration_count_equals_page_size() {
+        let temp_dir = std::env::temp_dir().join(format!("search-lazy-hydration-{}", uuid::Uuid::new_v4()));
+        let engine = multi_case_budget_test_engine(&temp_dir, 15).await;
+
+        let page_size = 5;
+        let mut query = budget_query("case *", &engine, SearchStageBudgets::default());
+        query.max_results = Some(page_size);
+
+        let reads_before = engine.storage.metadata_read_count();
+        let outcome = engine.search_with_params(query).await.unwrap();
+        let reads_after = engine.storage.metadata_read_count();
+
+        assert_eq!(outcome.results.len(), page_size);
+        assert_eq!(outcome.total_candidates, 15);
+        assert_eq!(
+            reads_after - reads_before,
+            page_size as u64,
+            "expected exactly one full metadata hydration per returned result, not per candidate"
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Six cases spread across three courts, three decades, three jurisdiction variants, and
+    /// two topic-taxonomy leaves, for exercising [`SearchEngine::compute_facets`] against known
+    /// counts. Case names are `"Facet Case 0"`..`"Facet Case 5"`, all matchable by the wildcard
+    /// query `"facet *"`.
+    async fn facet_test_engine(temp_dir: &std::path::Path) -> SearchEngine {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+
+        // (court, decision_date, jurisdiction, topics)
+        let fixtures: Vec<(&str, chrono::NaiveDate, crate::Jurisdiction, Vec<&str>)> = vec![
+            ("Supreme Court", chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(), crate::Jurisdiction::Federal, vec!["first-amendment-speech"]),
+            ("Supreme Court", chrono::NaiveDate::from_ymd_opt(1955, 1, 1).unwrap(), crate::Jurisdiction::Federal, vec!["fourth-amendment-search"]),
+            ("9th Circuit", chrono::NaiveDate::from_ymd_opt(1992, 1, 1).unwrap(), crate::Jurisdiction::Federal, vec!["first-amendment-speech"]),
+            ("9th Circuit", chrono::NaiveDate::from_ymd_opt(1998, 1, 1).unwrap(), crate::Jurisdiction::State("California".to_string()), vec![]),
+            ("2nd Circuit", chrono::NaiveDate::from_ymd_opt(2001, 1, 1).unwrap(), crate::Jurisdiction::State("New York".to_string()), vec![]),
+            ("2nd Circuit", chrono::NaiveDate::from_ymd_opt(2005, 1, 1).unwrap(), crate::Jurisdiction::International, vec!["first-amendment-speech"]),
+        ];
+
+        for (i, (court, decision_date, jurisdiction, topics)) in fixtures.into_iter().enumerate() {
+            let case_id = uuid::Uuid::new_v4();
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Facet Case {i}"),
+                citation: format!("{i} U.S. 1"),
+                court: court.to_string(),
+                decision_date,
+                judges: vec![],
+                topics: topics.into_iter().map(str::to_string).collect(),
+                full_text: "Sample case text.".to_string(),
+                jurisdiction,
+                citations: vec![format!("{i} U.S. 1")],
+                docket_number: None,
+                source_url: None,
+                word_count: 3,
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+            trie_index.insert_case_name(&metadata.name, case_id).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap()
+    }
+
+    fn find_facet<'a>(facets: &'a [FacetCount], value: &str) -> Option<&'a FacetCount> {
+        facets.iter().find(|f| f.value == value)
+    }
+
+    fn find_topic_facet<'a>(facets: &'a [TopicFacet], node_id: &str) -> Option<&'a TopicFacet> {
+        facets.iter().find(|f| f.node_id == node_id)
+    }
+
+    /// Facet counts over an unfiltered query must match the known distribution of the fixture
+    /// corpus across all four dimensions, with topic counts rolled up through ancestor nodes
+    /// the same way `SearchEngine::topic_facets` already does.
+    #[tokio::test]
+    async fn test_compute_facets_counts_grouped_by_court_decade_jurisdiction_and_topic() {
+        let temp_dir = std::env::temp_dir().join(format!("search-facets-{}", uuid::Uuid::new_v4()));
+        let engine = facet_test_engine(&temp_dir).await;
+
+        let query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.total_candidates, 6);
+
+        assert_eq!(find_facet(&outcome.facets.court, "Supreme Court").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.court, "9th Circuit").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.court, "2nd Circuit").unwrap().count, 2);
+
+        assert_eq!(find_facet(&outcome.facets.decade, "1950s").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.decade, "1990s").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.decade, "2000s").unwrap().count, 2);
+
+        assert_eq!(find_facet(&outcome.facets.jurisdiction, "Federal").unwrap().count, 3);
+        assert_eq!(find_facet(&outcome.facets.jurisdiction, "State").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.jurisdiction, "International").unwrap().count, 1);
+
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "first-amendment-speech").unwrap().count, 3);
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "fourth-amendment-search").unwrap().count, 1);
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "first-amendment").unwrap().count, 3);
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "constitutional-law").unwrap().count, 4);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `date_range` filtering runs on `NaiveDate` bounds end to end, whether they came from a
+    /// structured pair or (as here) an open-ended `>=YYYY` string parsed by
+    /// `fielded_query::parse_date_range_expression` — the same parser `api::DateRangeFilter`
+    /// uses for a bare string `date_range`.
+    #[tokio::test]
+    async fn test_date_range_filter_accepts_a_parsed_relative_expression() {
+        let temp_dir = std::env::temp_dir().join(format!("search-date-range-{}", uuid::Uuid::new_v4()));
+        let engine = facet_test_engine(&temp_dir).await;
+
+        let mut query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        query.date_range = Some(crate::fielded_query::parse_date_range_expression(">=1990").unwrap());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        // Fixtures decided in 1992, 1998, 2001, and 2005 fall at or after 1990; 1950 and 1955 don't.
+        assert_eq!(outcome.total_candidates, 4);
+        assert!(outcome.results.iter().all(|r| r.case_metadata.decision_date >= chrono::NaiveDate::from_ymd_opt(1990, 1, 1).unwrap()));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A facet dimension must ignore the query's own filter on that dimension while still
+    /// honoring every other active filter (standard faceting semantics): with `court_filter`
+    /// narrowed to "Supreme Court", the court facet still shows every court's full count, but
+    /// decade/jurisdiction/topic facets only count the two Supreme Court cases.
+    #[tokio::test]
+    async fn test_facets_ignore_their_own_dimensions_active_filter_but_respect_others() {
+        let temp_dir = std::env::temp_dir().join(format!("search-facets-filtered-{}", uuid::Uuid::new_v4()));
+        let engine = facet_test_engine(&temp_dir).await;
+
+        let mut query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        query.court_filter = Some(vec!["Supreme Court".to_string()]);
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.total_candidates, 2, "court filter should narrow the actual result set");
+
+        // The court facet ignores the active court_filter, so all three courts still show up.
+        assert_eq!(find_facet(&outcome.facets.court, "Supreme Court").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.court, "9th Circuit").unwrap().count, 2);
+        assert_eq!(find_facet(&outcome.facets.court, "2nd Circuit").unwrap().count, 2);
+
+        // Every other facet respects the active court_filter and only counts the 2 matching cases.
+        assert_eq!(outcome.facets.decade.len(), 1);
+        assert_eq!(find_facet(&outcome.facets.decade, "1950s").unwrap().count, 2);
+
+        assert_eq!(outcome.facets.jurisdiction.len(), 1);
+        assert_eq!(find_facet(&outcome.facets.jurisdiction, "Federal").unwrap().count, 2);
+
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "first-amendment-speech").unwrap().count, 1);
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "fourth-amendment-search").unwrap().count, 1);
+        assert_eq!(find_topic_facet(&outcome.facets.topic, "constitutional-law").unwrap().count, 2);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `SortOrder::DateAsc` and `SortOrder::DateDesc` must order the fixture corpus strictly by
+    /// `decision_date`, oldest-first and newest-first respectively.
+    #[tokio::test]
+    async fn test_sort_by_date_orders_oldest_or_newest_first() {
+        let temp_dir = std::env::temp_dir().join(format!("search-sort-date-{}", uuid::Uuid::new_v4()));
+        let engine = facet_test_engine(&temp_dir).await;
+
+        let mut asc_query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        asc_query.sort = SortOrder::DateAsc;
+        asc_query.max_results = Some(10);
+        let asc = engine.search_with_params(asc_query).await.unwrap();
+        let asc_names: Vec<&str> = asc.results.iter().map(|r| r.case_metadata.name.as_str()).collect();
+        assert_eq!(
+            asc_names,
+            vec![
+                "Facet Case 0",
+                "Facet Case 1",
+                "Facet Case 2",
+                "Facet Case 3",
+                "Facet Case 4",
+                "Facet Case 5",
+            ]
+        );
+
+        let mut desc_query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        desc_query.sort = SortOrder::DateDesc;
+        desc_query.max_results = Some(10);
+        let desc = engine.search_with_params(desc_query).await.unwrap();
+        let desc_names: Vec<&str> = desc.results.iter().map(|r| r.case_metadata.name.as_str()).collect();
+        assert_eq!(
+            desc_names,
+            vec![
+                "Facet Case 5",
+                "Facet Case 4",
+                "Facet Case 3",
+                "Facet Case 2",
+                "Facet Case 1",
+                "Facet Case 0",
+            ]
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `SortOrder::CourtRank` must place the Supreme Court cases ahead of the circuit court
+    /// cases, and within a rank must break ties deterministically by case id so repeated queries
+    /// against an unchanged index always return the same order.
+    #[tokio::test]
+    async fn test_sort_by_court_rank_orders_supreme_before_circuit_with_id_tiebreak() {
+        let temp_dir = std::env::temp_dir().join(format!("search-sort-court-{}", uuid::Uuid::new_v4()));
+        let engine = facet_test_engine(&temp_dir).await;
+
+        let mut query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        query.sort = SortOrder::CourtRank;
+        query.max_results = Some(10);
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 6);
+
+        let (supreme, circuit): (Vec<_>, Vec<_>) =
+            outcome.results.iter().partition(|r| r.case_metadata.court == "Supreme Court");
+        assert_eq!(supreme.len(), 2);
+        assert_eq!(circuit.len(), 4);
+
+        // Supreme Court results (rank 0) must all precede the circuit results (rank 1).
+        let last_supreme_pos =
+            outcome.results.iter().rposition(|r| r.case_metadata.court == "Supreme Court").unwrap();
+        let first_circuit_pos =
+            outcome.results.iter().position(|r| r.case_metadata.court != "Supreme Court").unwrap();
+        assert!(last_supreme_pos < first_circuit_pos);
+
+        // Within each rank, ties are broken by ascending case id.
+        let supreme_ids: Vec<_> = supreme.iter().map(|r| r.case_metadata.id).collect();
+        let mut sorted_supreme_ids = supreme_ids.clone();
+        sorted_supreme_ids.sort();
+        assert_eq!(supreme_ids, sorted_supreme_ids);
+
+        let circuit_ids: Vec<_> = circuit.iter().map(|r| r.case_metadata.id).collect();
+        let mut sorted_circuit_ids = circuit_ids.clone();
+        sorted_circuit_ids.sort();
+        assert_eq!(circuit_ids, sorted_circuit_ids);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `SortOrder::Relevance` results carrying equal fused scores (as with our uniformly-scored
+    /// fixture corpus) must still be totally ordered, breaking ties by ascending case id rather
+    /// than leaving them in whatever order the fusion stage happened to produce.
+    #[tokio::test]
+    async fn test_sort_by_relevance_breaks_score_ties_by_case_id() {
+        let temp_dir = std::env::temp_dir().join(format!("search-sort-relevance-{}", uuid::Uuid::new_v4()));
+        let engine = facet_test_engine(&temp_dir).await;
+
+        let mut query = budget_query("facet *", &engine, SearchStageBudgets::default());
+        query.sort = SortOrder::Relevance;
+        query.max_results = Some(10);
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 6);
+        let scores_equal = outcome.results.windows(2).all(|w| (w[0].score - w[1].score).abs() < f32::EPSILON);
+        if scores_equal {
+            let ids: Vec<_> = outcome.results.iter().map(|r| r.case_metadata.id).collect();
+            let mut sorted_ids = ids.clone();
+            sorted_ids.sort();
+            assert_eq!(ids, sorted_ids);
+        }
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Cases indexed by both case name and citation (via `TrieIndex::insert_citation`), for
+    /// exercising `SearchEngine::resolve_citation_query`'s direct citation lookup. Returns a
+    /// label -> case id map.
+    async fn citation_indexed_engine(
+        temp_dir: &std::path::Path,
+        cases: &[(&str, &str)],
+    ) -> (SearchEngine, HashMap<String, CaseId>) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        let mut ids = HashMap::new();
+
+        for (label, citation) in cases {
+            let case_id = uuid::Uuid::new_v4();
+            ids.insert(label.to_string(), case_id);
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {label}"),
+                citation: citation.to_string(),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: format!("Sample opinion text for {label}."),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![citation.to_string()],
+                docket_number: None,
+                source_url: None,
+                word_count: 4,
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+            trie_index.insert_case_name(&metadata.name, case_id).unwrap();
+            let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+            trie_index.insert_citation(citation, doc_ref).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, ids)
+    }
+
+    /// A query that's entirely a citation must resolve directly against the citation trie with
+    /// `MatchType::Citation` and a score of exactly `1.0`, without needing the case name or
+    /// content to mention the citation text.
+    #[tokio::test]
+    async fn test_citation_query_resolves_exact_match_with_score_one() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-exact-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = citation_indexed_engine(
+            &temp_dir,
+            &[("brown", "347 U.S. 483"), ("roe", "410 U.S. 113")],
+        )
+        .await;
+
+        let query = budget_query("347 U.S. 483", &engine, SearchStageBudgets::default());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["brown"]);
+        assert_eq!(outcome.results[0].match_type, MatchType::Citation);
+        assert_eq!(outcome.results[0].score, 1.0);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Reporter-alias spelling variants (`"S. Ct."` vs `"S.Ct."`) and multi-character reporter
+    /// abbreviations (`"F.3d"`) must resolve to the same indexed citation.
+    #[tokio::test]
+    async fn test_citation_query_resolves_reporter_alias_and_fthird_formats() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-alias-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = citation_indexed_engine(
+            &temp_dir,
+            &[("bakke", "98 S.Ct. 2733"), ("appeal", "410 F.3d 100")],
+        )
+        .await;
+
+        let scotus_query = budget_query("98 S. Ct. 2733", &engine, SearchStageBudgets::default());
+        let scotus_outcome = engine.search_with_params(scotus_query).await.unwrap();
+        assert_eq!(scotus_outcome.results.len(), 1);
+        assert_eq!(scotus_outcome.results[0].case_metadata.id, ids["bakke"]);
+        assert_eq!(scotus_outcome.results[0].match_type, MatchType::Citation);
+
+        let circuit_query = budget_query("410 F.3d 100", &engine, SearchStageBudgets::default());
+        let circuit_outcome = engine.search_with_params(circuit_query).await.unwrap();
+        assert_eq!(circuit_outcome.results.len(), 1);
+        assert_eq!(circuit_outcome.results[0].case_metadata.id, ids["appeal"]);
+        assert_eq!(circuit_outcome.results[0].match_type, MatchType::Citation);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A partial citation with no page number must resolve to prefix completions of every full
+    /// citation sharing that volume/reporter, rather than a miss.
+    #[tokio::test]
+    async fn test_partial_citation_query_returns_prefix_completions() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-partial-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = citation_indexed_engine(
+            &temp_dir,
+            &[("roe", "410 U.S. 113"), ("other", "410 U.S. 483"), ("unrelated", "347 U.S. 483")],
+        )
+        .await;
+
+        let query = budget_query("410 U.S.", &engine, SearchStageBudgets::default());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        let result_ids: HashSet<CaseId> = outcome.results.iter().map(|r| r.case_metadata.id).collect();
+        assert_eq!(result_ids, HashSet::from([ids["roe"], ids["other"]]));
+        assert!(outcome.results.iter().all(|r| r.match_type == MatchType::Citation));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A query that merely looks citation-shaped (starts with a number) but matches no indexed
+    /// citation must fall through to ordinary search rather than erroring or forcing an empty
+    /// result.
+    #[tokio::test]
+    async fn test_citation_shaped_query_with_no_match_falls_back_gracefully() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-nomatch-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = citation_indexed_engine(&temp_dir, &[("brown", "347 U.S. 483")]).await;
+
+        let query = budget_query("999 Nonexistent Reporter 42", &engine, SearchStageBudgets::default());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 0);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `more_like_this` must surface a case sharing the source case's own extracted key phrases
+    /// ranked ahead of a lexically unrelated one, and never include the source case itself.
+    #[tokio::test]
+    async fn test_more_like_this_ranks_lexically_related_case_above_unrelated_and_excludes_source() {
+        let temp_dir = std::env::temp_dir().join(format!("search-mlt-{}", uuid::Uuid::new_v4()));
+        let source_text =
+            "qualified immunity doctrine bars claims filed against officers arriving late to the scene";
+        let related_text =
+            "in a later dispute the same qualified immunity doctrine bars claims against a different officer";
+        let unrelated_text = "maritime salvage law governs disputes over sunken cargo recovered offshore";
+        let (engine, ids) = content_indexed_engine(
+            &temp_dir,
+            &[("source", source_text), ("related", related_text), ("unrelated", unrelated_text)],
+        )
+        .await;
+
+        for (label, text) in [("source", source_text), ("related", related_text), ("unrelated", unrelated_text)] {
+            let case_id = ids[label];
+            let mut vector = engine.vector_index.write("test_setup").await;
+            vector.add_document(DocRef { case_id, paragraph_index: 0, char_offset: None }, text).await.unwrap();
+        }
+
+        let results = engine.more_like_this(ids["source"], 10).await.unwrap();
+
+        assert!(results.iter().all(|r| r.case_metadata.id != ids["source"]));
+        let related_position = results.iter().position(|r| r.case_metadata.id == ids["related"]);
+        assert!(related_position.is_some(), "expected the lexically related case to appear in results");
+        if let Some(unrelated_position) = results.iter().position(|r| r.case_metadata.id == ids["unrelated"]) {
+            assert!(
+                related_position.unwrap() < unrelated_position,
+                "the lexically related case should outrank the unrelated one"
+            );
+        }
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// An unknown case id has no stored text, so `more_like_this` must surface
+    /// `SearchError::CaseNotFound` rather than an empty result set.
+    #[tokio::test]
+    async fn test_more_like_this_unknown_case_id_returns_not_found() {
+        let temp_dir = std::env::temp_dir().join(format!("search-mlt-missing-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = content_indexed_engine(&temp_dir, &[("source", "qualified immunity doctrine")]).await;
+
+        let unknown_id = uuid::Uuid::new_v4();
+        let result = engine.more_like_this(unknown_id, 10).await;
+
+        assert!(matches!(result, Err(SearchError::CaseNotFound { case_id }) if case_id == unknown_id));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A prefix shorter than `SearchConfig::min_query_length` (2 by default) must come back as
+    /// an empty list, not `SearchError::InvalidSearchQuery` — `suggest` is meant to be called on
+    /// every keystroke, including the first one.
+    #[tokio::test]
+    async fn test_suggest_returns_empty_list_for_a_too_short_prefix() {
+        let temp_dir = std::env::temp_dir().join(format!("search-suggest-short-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = citation_indexed_engine(&temp_dir, &[("alpha", "410 U.S. 113")]).await;
+
+        let suggestions = engine.suggest("c", 10).await.unwrap();
+
+        assert!(suggestions.is_empty());
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Case names and citations sharing a prefix must both surface, tagged with the right
+    /// `SuggestionType` for their origin, each counting as its own case.
+    #[tokio::test]
+    async fn test_suggest_tags_case_name_and_citation_completions_by_origin() {
+        let temp_dir = std::env::temp_dir().join(format!("search-suggest-tags-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) =
+            citation_indexed_engine(&temp_dir, &[("alpha", "410 U.S. 113"), ("beta", "410 U.S. 483")]).await;
+
+        let case_name_suggestions = engine.suggest("case", 10).await.unwrap();
+        assert_eq!(case_name_suggestions.len(), 2);
+        assert!(case_name_suggestions.iter().all(|s| s.suggestion_type == SuggestionType::CaseName));
+        assert!(case_name_suggestions.iter().all(|s| s.case_count == 1));
+
+        let citation_suggestions = engine.suggest("410 U.S.", 10).await.unwrap();
+        assert_eq!(citation_suggestions.len(), 2);
+        assert!(citation_suggestions.iter().all(|s| s.suggestion_type == SuggestionType::Citation));
+        assert!(citation_suggestions.iter().all(|s| s.case_count == 1));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A content-trie completion is tagged `Phrase`, and `case_count` reflects the number of
+    /// distinct cases whose text contains it — not the raw trie frequency, which would count
+    /// "immunity doctrine" twice for a case whose text repeats it across two paragraphs.
+    #[tokio::test]
+    async fn test_suggest_phrase_case_count_dedups_repeats_within_one_case() {
+        let temp_dir = std::env::temp_dir().join(format!("search-suggest-phrase-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("repeated", "qualified immunity doctrine bars the claim. qualified immunity doctrine controls."),
+                ("other", "qualified immunity doctrine also applies here"),
+                ("unrelated", "maritime salvage law governs this dispute"),
+            ],
+        )
+        .await;
+
+        let suggestions = engine.suggest("qualified immunity", 10).await.unwrap();
+
+        let phrase = suggestions
+            .iter()
+            .find(|s| s.suggestion_type == SuggestionType::Phrase && s.text == "qualified immunity doctrine")
+            .expect("expected a phrase suggestion for the repeated content");
+        assert_eq!(phrase.case_count, 2);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `limit` bounds the number of suggestions returned even when more prefix matches exist.
+    #[tokio::test]
+    async fn test_suggest_respects_limit() {
+        let temp_dir = std::env::temp_dir().join(format!("search-suggest-limit-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = citation_indexed_engine(
+            &temp_dir,
+            &[("alpha", "410 U.S. 113"), ("beta", "410 U.S. 483"), ("gamma", "410 U.S. 502")],
+        )
+        .await;
+
+        let suggestions = engine.suggest("case", 2).await.unwrap();
+
+        assert_eq!(suggestions.len(), 2);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Indexes 1000 documents, only 5 of which belong to `matching_court`, and verifies that a
+    /// court-filtered semantic query surfaces exactly those 5 instead of starving the page — the
+    /// scenario the old fixed-multiplier over-fetch loop handled poorly for a narrow filter, now
+    /// handled by resolving the filter to a `CaseId` allow-list up front (see
+    /// `SearchEngine::compute_allowed_case_ids` and `VectorIndex::search_filtered`).
+    #[tokio::test]
+    async fn test_court_filtered_semantic_search_finds_a_small_minority_court() {
+        let temp_dir = std::env::temp_dir().join(format!("search-filtered-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        const TOTAL: usize = 1000;
+        const MATCHING: usize = 5;
+        let matching_court = "Supreme Court of the Narrow Jurisdiction";
+
+        let mut matching_ids = HashSet::new();
+        for i in 0..TOTAL {
+            let court = if i < MATCHING { matching_court } else { "District Court" };
+            let case_id = uuid::Uuid::new_v4();
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {i}"),
+                citation: format!("{i} U.S. 1"),
+                court: court.to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: format!("case number {i}"),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: 3,
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            engine.storage.store_case_metadata(&metadata).await.unwrap();
+            if i < MATCHING {
+                matching_ids.insert(case_id);
+            }
+
+            let doc = DocRef { case_id, paragraph_index: 0, char_offset: None };
+            let mut vector = engine.vector_index.write("test_setup").await;
+            vector.add_document(doc, &format!("case number {i}")).await.unwrap();
+        }
+
+        let query = SearchQuery {
+            query: "narrow jurisdiction dispute".to_string(),
+            max_results: Some(50),
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: Some(vec![matching_court.to_string()]),
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), MATCHING);
+        for result in &outcome.results {
+            assert!(matching_ids.contains(&result.case_metadata.id));
+        }
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Requesting pages 1-3 (`offset` 0/10/20, `max_results` 10) of a 25-result query must
+    /// together cover every matching case exactly once — no result skipped by an off-by-one in
+    /// the offset math, and none repeated because a cached ranked-candidate list was re-sliced
+    /// incorrectly on the second and third page's cache hit.
+    #[tokio::test]
+    async fn test_paginating_with_offset_covers_every_result_with_no_duplicates_or_gaps() {
+        let temp_dir = std::env::temp_dir().join(format!("search-paginate-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        const TOTAL: usize = 25;
+        let mut expected_ids = HashSet::new();
+        for i in 0..TOTAL {
+            let case_id = uuid::Uuid::new_v4();
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Paginated Case {i}"),
+                citation: format!("{i} U.S. 2"),
+                court: "District Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: format!("case number {i}"),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: 3,
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            engine.storage.store_case_metadata(&metadata).await.unwrap();
+            expected_ids.insert(case_id);
+
+            let doc = DocRef { case_id, paragraph_index: 0, char_offset: None };
+            let mut vector = engine.vector_index.write("test_setup").await;
+            vector.add_document(doc, &format!("case number {i}")).await.unwrap();
+        }
+
+        let page_query = |offset: usize| SearchQuery {
+            query: "paginated dispute".to_string(),
+            max_results: Some(10),
+            offset,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let mut seen_ids = HashSet::new();
+        let mut page_sizes = Vec::new();
+        for offset in [0, 10, 20] {
+            let outcome = engine.search_with_params(page_query(offset)).await.unwrap();
+            assert_eq!(outcome.total_candidates, TOTAL);
+            page_sizes.push(outcome.results.len());
+            for result in &outcome.results {
+                assert!(
+                    seen_ids.insert(result.case_metadata.id),
+                    "case {} appeared on more than one page",
+                    result.case_metadata.id
+                );
+            }
+        }
+
+        assert_eq!(page_sizes, vec![10, 10, 5]);
+        assert_eq!(seen_ids, expected_ids);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Build a `SearchEngine` over `temp_dir` whose trie and storage are populated from
+    /// `cases` (a label paired with the case's `full_text`, indexed as a single content-trie
+    /// sentence), for tests that need real adjacency/co-occurrence in indexed content rather
+    /// than just a case name (unlike `budget_test_engine`). Returns each label's generated
+    /// `CaseId` so tests can assert on which case(s) matched.
+    async fn content_indexed_engine(temp_dir: &std::path::Path, cases: &[(&str, &str)]) -> (SearchEngine, HashMap<String, CaseId>) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        let mut ids = HashMap::new();
+
+        for (label, full_text) in cases {
+            let case_id = uuid::Uuid::new_v4();
+            ids.insert(label.to_string(), case_id);
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {label}"),
+                citation: format!("{label} citation"),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: full_text.to_string(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: full_text.split_whitespace().count(),
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, full_text, full_text).await.unwrap();
+            let tokens: Vec<(String, usize)> =
+                full_text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            trie_index.insert_content(&tokens, DocRef { case_id, paragraph_index: 0, char_offset: None }).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, ids)
+    }
+
+    /// Like `content_indexed_engine`, but each case also gets a real, parseable `citation` field
+    /// that is indexed into the citation trie via `insert_citation` (which `content_indexed_engine`
+    /// never does), so `rebuild_citation_graph_with` has something to resolve in-text citations
+    /// against — needed for citation-graph tests, where `content_indexed_engine`'s placeholder
+    /// `"{label} citation"` strings would never match anything.
+    async fn citation_graph_engine(
+        temp_dir: &std::path::Path,
+        cases: &[(&str, &str, &str)],
+    ) -> (SearchEngine, HashMap<String, CaseId>) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        let mut ids = HashMap::new();
+
+        for (label, full_text, citation) in cases {
+            let case_id = uuid::Uuid::new_v4();
+            ids.insert(label.to_string(), case_id);
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {label}"),
+                citation: citation.to_string(),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: full_text.to_string(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: full_text.split_whitespace().count(),
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, full_text, full_text).await.unwrap();
+            let tokens: Vec<(String, usize)> =
+                full_text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+            trie_index.insert_content(&tokens, doc_ref.clone()).unwrap();
+            trie_index.insert_citation(citation, doc_ref).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, ids)
+    }
+
+    /// Like `content_indexed_engine`, but indexes one case's `paragraphs` individually (each its
+    /// own `insert_content` call with its own `paragraph_index`), mirroring how the real
+    /// ingestion pipeline indexes one sentence at a time (see `trie.rs`'s per-sentence content
+    /// insertion) — needed for tests where the same query term must hit more than one paragraph
+    /// of one case within a single trie bucket, which `content_indexed_engine` (one
+    /// `paragraph_index: 0` call per case) can't produce.
+    async fn multi_paragraph_indexed_engine(temp_dir: &std::path::Path, label: &str, paragraphs: &[&str]) -> (SearchEngine, CaseId) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        let case_id = uuid::Uuid::new_v4();
+        let full_text = paragraphs.join(" ");
+        let metadata = CaseMetadata {
+            id: case_id,
+            name: format!("Case {label}"),
+            citation: format!("{label} citation"),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+            judges: vec![],
+            topics: vec![],
+            full_text: full_text.clone(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec![],
+            docket_number: None,
+            source_url: None,
+            word_count: full_text.split_whitespace().count(),
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        storage.store_case_metadata(&metadata).await.unwrap();
+        storage.store_case_text(&case_id, &full_text, &full_text).await.unwrap();
+        for (paragraph_index, paragraph) in paragraphs.iter().enumerate() {
+            let tokens: Vec<(String, usize)> =
+                paragraph.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            trie_index.insert_content(&tokens, DocRef { case_id, paragraph_index, char_offset: None }).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, case_id)
+    }
+
+    /// Like `content_indexed_engine`, but backed by a small fixture synonym table (written to
+    /// `temp_dir/synonyms.json`) instead of the bundled default, so tests can expand a made-up
+    /// term without depending on `data/synonyms.json`'s real legal-concept entries.
+    async fn synonym_indexed_engine(temp_dir: &std::path::Path, cases: &[(&str, &str)]) -> (SearchEngine, HashMap<String, CaseId>) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let synonyms_path = temp_dir.join("synonyms.json");
+        tokio::fs::write(
+            &synonyms_path,
+            r#"[{"term": "riparian rights", "synonyms": ["water usage entitlement"]}]"#,
+        )
+        .await
+        .unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        config.search.synonyms_path = Some(synonyms_path);
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+        let mut ids = HashMap::new();
+
+        for (label, full_text) in cases {
+            let case_id = uuid::Uuid::new_v4();
+            ids.insert(label.to_string(), case_id);
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {label}"),
+                citation: format!("{label} citation"),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: full_text.to_string(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: full_text.split_whitespace().count(),
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, full_text, full_text).await.unwrap();
+            let tokens: Vec<(String, usize)> =
+                full_text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            trie_index.insert_content(&tokens, DocRef { case_id, paragraph_index: 0, char_offset: None }).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, ids)
+    }
+
+    fn synonym_query(query: &str, engine: &SearchEngine) -> SearchQuery {
+        let mut config = SearchConfig::from_config(&engine.config.search, &engine.config.vector);
+        config.enable_synonyms = true;
+        SearchQuery {
+            query: query.to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config,
+        }
+    }
+
+    /// A query for the table's term (`"riparian rights"`) still surfaces a case that only
+    /// contains its mapped synonym phrase (`"water usage entitlement"`, never itself queried),
+    /// and `applied_synonym_expansions` records the expansion that made it happen.
+    #[tokio::test]
+    async fn test_synonym_expansion_improves_recall_for_unindexed_synonym_term() {
+        let temp_dir = std::env::temp_dir().join(format!("search-synonym-recall-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = synonym_indexed_engine(
+            &temp_dir,
+            &[("wanted", "dispute over water usage entitlement along the river")],
+        )
+        .await;
+
+        let outcome = engine.execute_search_isolated(synonym_query("riparian rights", &engine)).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["wanted"]);
+        assert_eq!(outcome.applied_synonym_expansions, vec!["water usage entitlement".to_string()]);
+    }
+
+    /// A case matched by the query's own term always outranks one only reachable via a synonym
+    /// expansion, even when the synonym-matched case would otherwise score just as well.
+    #[tokio::test]
+    async fn test_synonym_match_scores_below_original_term_match() {
+        let temp_dir = std::env::temp_dir().join(format!("search-synonym-scoring-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = synonym_indexed_engine(
+            &temp_dir,
+            &[
+                ("original", "dispute over riparian rights along the river"),
+                ("synonym-only", "dispute over water usage entitlement along the river"),
+            ],
+        )
+        .await;
+
+        let outcome = engine.execute_search_isolated(synonym_query("riparian rights", &engine)).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 2);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["original"]);
+        assert_eq!(outcome.results[1].case_metadata.id, ids["synonym-only"]);
+    }
+
+    /// With `enable_synonyms` left at its default `false`, a query for the table's term neither
+    /// surfaces the synonym-only case nor reports any applied expansions.
+    #[tokio::test]
+    async fn test_synonym_expansion_disabled_by_default() {
+        let temp_dir = std::env::temp_dir().join(format!("search-synonym-disabled-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = synonym_indexed_engine(
+            &temp_dir,
+            &[("wanted", "dispute over water usage entitlement along the river")],
+        )
+        .await;
+
+        let mut query = synonym_query("riparian rights", &engine);
+        query.config.enable_synonyms = false;
+        let outcome = engine.execute_search_isolated(query).await.unwrap();
+
+        assert!(outcome.results.is_empty());
+        assert!(outcome.applied_synonym_expansions.is_empty());
+    }
+
+    /// Two ingested variants of the same decision under different `CaseId`s (as if CAP and
+    /// CourtListener both supplied it), sharing one citation but scored differently so the
+    /// dedup pass has something to pick between; the label of the higher-scoring one wins.
+    async fn duplicate_citation_engine(temp_dir: &std::path::Path) -> (SearchEngine, CaseId, CaseId) {
+        tokio::fs::create_dir_all(temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+
+        let cap_id = uuid::Uuid::new_v4();
+        let court_listener_id = uuid::Uuid::new_v4();
+        // The CourtListener variant repeats "segregation" so its content-trie bucket scores
+        // higher than CAP's single mention, giving the dedup pass a clear winner to keep.
+        let variants = [
+            (cap_id, "347 U.S. 483", "equal protection segregation appeal"),
+            (court_listener_id, "347 U. S. 483", "equal protection segregation segregation appeal"),
+        ];
+        for (case_id, citation, full_text) in variants {
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: "Brown v. Board of Education".to_string(),
+                citation: citation.to_string(),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1954, 5, 17).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: full_text.to_string(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![citation.to_string()],
+                docket_number: None,
+                source_url: None,
+                word_count: full_text.split_whitespace().count(),
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, full_text, full_text).await.unwrap();
+            let tokens: Vec<(String, usize)> =
+                full_text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            trie_index.insert_content(&tokens, DocRef { case_id, paragraph_index: 0, char_offset: None }).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, temp_dir).await.unwrap();
+        (engine, cap_id, court_listener_id)
+    }
+
+    fn plain_query(query: &str, engine: &SearchEngine) -> SearchQuery {
+        SearchQuery {
+            query: query.to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        }
+    }
+
+    /// With `enable_citation_dedup` on by default, two ingested variants of the same decision
+    /// sharing a normalized citation collapse into one result, and the merged variant's id is
+    /// recorded on the survivor's `duplicates`.
+    #[tokio::test]
+    async fn test_citation_dedup_collapses_same_case_variants() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-dedup-{}", uuid::Uuid::new_v4()));
+        let (engine, cap_id, court_listener_id) = duplicate_citation_engine(&temp_dir).await;
+
+        let outcome = engine.execute_search_isolated(plain_query("segregation", &engine)).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, court_listener_id);
+        assert_eq!(outcome.results[0].duplicates, vec![cap_id]);
+    }
+
+    /// With `enable_citation_dedup` turned off, both variants are returned separately.
+    #[tokio::test]
+    async fn test_citation_dedup_disabled_returns_both_variants() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-dedup-disabled-{}", uuid::Uuid::new_v4()));
+        let (engine, cap_id, court_listener_id) = duplicate_citation_engine(&temp_dir).await;
+
+        let mut query = plain_query("segregation", &engine);
+        query.config.enable_citation_dedup = false;
+        let outcome = engine.execute_search_isolated(query).await.unwrap();
+
+        let ids: HashSet<CaseId> = outcome.results.iter().map(|r| r.case_metadata.id).collect();
+        assert_eq!(ids, HashSet::from([cap_id, court_listener_id]));
+        assert!(outcome.results.iter().all(|r| r.duplicates.is_empty()));
+    }
+
+    /// `SearchResult::score` is documented as always falling in `0.0..=1.0`; this must hold
+    /// across a mixed result set (case-name, citation, and content-origin lexical matches, RRF
+    /// fusion with an empty semantic list) even when the three `*_match_weight` configs are
+    /// pushed well above `1.0`, exactly the scenario `relative_lexical_weight_for` exists for.
+    #[tokio::test]
+    async fn test_result_scores_stay_within_zero_one_even_with_weights_above_one() {
+        let temp_dir = std::env::temp_dir().join(format!("search-score-bounds-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("exact", "riparian water rights dispute along the river"),
+                ("substring", "the riparianwaterrightsdispute case"),
+                ("unrelated", "an unrelated case about maritime law"),
+            ],
+        )
+        .await;
+        assert!(!ids.is_empty());
+
+        let mut query = plain_query("riparian water rights dispute", &engine);
+        query.config.exact_match_weight = 50.0;
+        query.config.case_name_match_weight = 80.0;
+        query.config.citation_match_weight = 80.0;
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert!(!outcome.results.is_empty());
+        for result in &outcome.results {
+            assert!((0.0..=1.0).contains(&result.score), "score {} out of [0, 1]", result.score);
+            if let Some(lexical_score) = result.lexical_score {
+                assert!((0.0..=1.0).contains(&lexical_score), "lexical_score {} out of [0, 1]", lexical_score);
+            }
+        }
+    }
+
+    /// A case matching the query term in 5 separate paragraphs of the same trie bucket collapses
+    /// into a single `SearchResult` (not 5 duplicate rows), scores higher than an otherwise
+    /// identical case matching in only 1 paragraph (the log bonus from
+    /// `SearchEngine::merge_multi_passage_candidate`), and surfaces at most
+    /// `MULTI_PASSAGE_MAX_SNIPPETS` distinct passages.
+    #[tokio::test]
+    async fn test_multi_paragraph_matches_collapse_into_one_result_with_a_score_bonus() {
+        let temp_dir = std::env::temp_dir().join(format!("search-multi-passage-{}", uuid::Uuid::new_v4()));
+        let (engine, multi_case_id) = multi_paragraph_indexed_engine(
+            &temp_dir,
+            "multi",
+            &[
+                "riparian rights are discussed in the opening paragraph",
+                "the court revisits riparian rights again here",
+                "a third mention of riparian rights follows",
+                "riparian rights come up a fourth time",
+                "and once more riparian rights close the opinion",
+            ],
+        )
+        .await;
+
+        let single_temp_dir = std::env::temp_dir().join(format!("search-single-passage-{}", uuid::Uuid::new_v4()));
+        let (single_engine, single_case_id) =
+            multi_paragraph_indexed_engine(&single_temp_dir, "single", &["riparian rights are discussed just once here"]).await;
+
+        let outcome = engine.search_with_params(plain_query("riparian rights", &engine)).await.unwrap();
+        let matches: Vec<_> = outcome.results.iter().filter(|r| r.case_metadata.id == multi_case_id).collect();
+        assert_eq!(matches.len(), 1, "5 paragraph hits for one case must collapse into 1 result, got {matches:?}");
+        let multi_result = matches[0];
+        assert!(multi_result.passages.len() <= MULTI_PASSAGE_MAX_SNIPPETS);
+        assert!(multi_result.passages.len() > 1, "expected more than one passage, got {:?}", multi_result.passages);
+        assert_eq!(multi_result.passages[0], multi_result.snippet);
+        assert!(multi_result.passages.iter().collect::<HashSet<_>>().len() > 1, "passages should differ: {:?}", multi_result.passages);
+
+        let single_outcome = single_engine.search_with_params(plain_query("riparian rights", &single_engine)).await.unwrap();
+        let single_result = single_outcome.results.iter().find(|r| r.case_metadata.id == single_case_id).unwrap();
+        assert!(single_result.passages.is_empty(), "single-paragraph match should have no extra passages");
+        assert!(
+            multi_result.score > single_result.score,
+            "multi-paragraph match ({}) should score higher than single-paragraph match ({})",
+            multi_result.score,
+            single_result.score
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+        tokio::fs::remove_dir_all(&single_temp_dir).await.unwrap();
+    }
+
+    /// A `SearchSyntax::Boolean` query must intersect and subtract postings across the trie's
+    /// auxiliary substring index rather than treating the whole string as one bag-of-words
+    /// query: `"equal protection" AND segregation NOT employment` should surface only the case
+    /// containing all three positive terms and none of the excluded one.
+    #[tokio::test]
+    async fn test_boolean_query_intersects_and_subtracts_postings() {
+        let temp_dir = std::env::temp_dir().join(format!("search-boolean-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("wanted", "equal protection segregation appeal"),
+                ("unwanted-employment", "equal protection employment appeal"),
+                ("unwanted-unrelated", "commerce clause tariff dispute"),
+            ],
+        )
+        .await;
+        let wanted_id = ids["wanted"];
+
+        let query = SearchQuery {
+            query: "\"equal protection\" AND segregation NOT employment".to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Boolean,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, wanted_id);
+        assert_eq!(outcome.results[0].match_type, MatchType::Boolean);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// An unparseable `SearchSyntax::Boolean` query (here, a NOT-only query with no bounded
+    /// positive term) must fall back to plain bag-of-words search with a
+    /// `BOOLEAN_QUERY_FALLBACK` warning, rather than failing the request outright.
+    #[tokio::test]
+    async fn test_unparseable_boolean_query_falls_back_to_plain_search_with_a_warning() {
+        let temp_dir = std::env::temp_dir().join(format!("search-boolean-fallback-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        // A NOT-only query has no bounded positive term, so `boolean_query::parse` rejects it
+        // (see `has_positive_term`); this must fall back to plain search rather than error out.
+        let query = SearchQuery {
+            query: "NOT Marbury".to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Boolean,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert!(outcome.warnings.iter().any(|w| w.starts_with("BOOLEAN_QUERY_FALLBACK")));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A quoted phrase must only match a case where its words actually appear adjacent to one
+    /// another; a case with the same words scattered non-adjacently in the same paragraph does
+    /// not qualify, even though it would satisfy an unquoted bag-of-words query for the same
+    /// terms.
+    #[tokio::test]
+    async fn test_quoted_phrase_excludes_a_case_with_the_same_words_out_of_order() {
+        let temp_dir = std::env::temp_dir().join(format!("search-phrase-adjacency-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("adjacent", "the doctrine of separate but equal was overturned"),
+                ("scattered", "the facilities were kept separate; treatment was in no sense equal but tolerated"),
+            ],
+        )
+        .await;
+
+        let query = SearchQuery {
+            query: "\"separate but equal\"".to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["adjacent"]);
+        assert_eq!(outcome.results[0].match_type, MatchType::Phrase);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A mixed query of a quoted phrase plus unquoted remainder terms (`"due process"
+    /// incorporation`) must still require the phrase's adjacency while treating the remainder
+    /// as ordinary bag-of-words terms.
+    #[tokio::test]
+    async fn test_mixed_quoted_phrase_and_unquoted_terms_requires_phrase_adjacency() {
+        let temp_dir = std::env::temp_dir().join(format!("search-phrase-mixed-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("matches", "due process incorporation doctrine applies here"),
+                ("wrong-order", "process due incorporation doctrine applies here"),
+            ],
+        )
+        .await;
+
+        let query = SearchQuery {
+            query: "\"due process\" incorporation".to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["matches"]);
+        assert_eq!(outcome.results[0].match_type, MatchType::Phrase);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `VectorIndex::rebuild_with_model` must re-embed stored case text into a fresh index
+    /// under the new config without disturbing the old one: the old index keeps serving its own
+    /// documents throughout, and the returned index ends up with exactly the stored cases.
+    #[tokio::test]
+    async fn test_rebuild_with_model_reembeds_into_a_fresh_index_leaving_the_old_one_untouched() {
+        let temp_dir = std::env::temp_dir().join(format!("search-rebuild-{}", uuid::Uuid::new_v4()));
+        let (engine, _case_id) = budget_test_engine(&temp_dir).await;
+
+        let old_total_before = engine.vector_index.read("test").await.get_stats().total_vectors;
+        assert!(old_total_before > 0, "expected the old index to already have Marbury v Madison indexed");
+
+        let mut new_config = engine.config.vector.clone();
+        new_config.model.model_type = "legal-bert-v2".to_string();
+
+        let handle = {
+            let mut old_index = engine.vector_index.write("rebuild_test").await;
+            old_index.rebuild_with_model(engine.storage.clone(), new_config)
+        };
+
+        // The old index keeps serving its own vectors untouched throughout the rebuild.
+        let old_total_after = engine.vector_index.read("test").await.get_stats().total_vectors;
+        assert_eq!(old_total_before, old_total_after);
+
+        let rebuilt = handle.join().await.unwrap();
+        let rebuilt_stats = rebuilt.get_stats();
+        assert_eq!(rebuilt_stats.total_vectors, 1);
+        assert_eq!(rebuilt_stats.dimension, engine.config.vector.dimension);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A misspelled query that matches nothing must come back with a "did you mean" suggestion
+    /// generated from the trie's fuzzy traversal, not just an empty result set.
+    #[tokio::test]
+    async fn test_zero_result_query_suggests_the_misspelled_phrase_correction() {
+        let temp_dir = std::env::temp_dir().join(format!("search-suggestions-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = content_indexed_engine(&temp_dir, &[("habeas", "habeas corpus")]).await;
+
+        let query = SearchQuery {
+            query: "habaes corpus".to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config: SearchConfig::from_config(&engine.config.search, &engine.config.vector),
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert!(outcome.results.is_empty());
+        assert!(outcome.applied_correction.is_none());
+        assert!(
+            outcome.suggestions.iter().any(|s| s == "habeas corpus"),
+            "expected \"habeas corpus\" among suggestions, got {:?}",
+            outcome.suggestions
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// With `SearchConfig::auto_correct` set, a zero-result query must be retried with the top
+    /// suggestion and the corrected outcome returned directly, flagged via `applied_correction`
+    /// rather than left for the caller to retry manually.
+    #[tokio::test]
+    async fn test_auto_correct_retries_with_top_suggestion_and_flags_the_response() {
+        let temp_dir = std::env::temp_dir().join(format!("search-autocorrect-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = content_indexed_engine(&temp_dir, &[("habeas", "habeas corpus")]).await;
+
+        let mut config = SearchConfig::from_config(&engine.config.search, &engine.config.vector);
+        config.auto_correct = true;
+        let query = SearchQuery {
+            query: "habaes corpus".to_string(),
+            max_results: None,
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            sort: SortOrder::Relevance,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            profile: None,
+            config,
+        };
+
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        assert_eq!(outcome.applied_correction, Some("habeas corpus".to_string()));
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["habeas"]);
+        assert!(outcome.suggestions.is_empty(), "suggestions should be cleared once a correction was applied");
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `generate_spelling_suggestions` must rank candidates by corpus frequency (document count)
+    /// before edit distance, so a well-known phrase outranks a rarer one that's a closer typo.
+    #[tokio::test]
+    async fn test_spelling_suggestions_rank_by_corpus_frequency() {
+        let temp_dir = std::env::temp_dir().join(format!("search-suggestions-freq-{}", uuid::Uuid::new_v4()));
+        // "hobeas" is one edit away from "hobbes" (edit distance 1) but two away from "habeas"
+        // (edit distance 2); "habeas corpus" appears in more documents, so it should still be
+        // suggested first.
+        let (engine, _ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("habeas-1", "habeas corpus"),
+                ("habeas-2", "habeas corpus"),
+                ("hobbes", "hobbes leviathan"),
+            ],
+        )
+        .await;
+
+        let suggestions = engine.generate_spelling_suggestions("hobeas corpus").unwrap();
+
+        assert_eq!(suggestions.first().map(String::as_str), Some("habeas corpus"));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Ingests 5 cases into storage, indexes only 3 of them into the trie and only 2 into the
+    /// vector index, and checks that `get_stats` reports each count separately rather than
+    /// collapsing them into one "indexed" number — and that `index_lag` reflects the gap left by
+    /// whichever index is further behind (here, the vector index).
+    #[tokio::test]
+    async fn test_stats_reports_the_gap_between_storage_trie_and_vector_indexing() {
+        let temp_dir = std::env::temp_dir().join(format!("search-stats-lag-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&temp_dir).await.unwrap();
+
+        let mut config = Config::default();
+        config.storage.db_path = temp_dir.join("db");
+        config.search.taxonomy_path = None;
+        let config = Arc::new(config);
+
+        let storage = Arc::new(StorageManager::new(config.storage.clone()).await.unwrap());
+        let mut trie_index = TrieIndex::new(config.trie.clone()).await.unwrap();
+
+        let mut case_ids = Vec::new();
+        for i in 0..5 {
+            let case_id = uuid::Uuid::new_v4();
+            case_ids.push(case_id);
+            let full_text = format!("Sample opinion text for case {i}.");
+            let metadata = CaseMetadata {
+                id: case_id,
+                name: format!("Case {i}"),
+                citation: format!("{i} F.3d 1"),
+                court: "Supreme Court".to_string(),
+                decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                judges: vec![],
+                topics: vec![],
+                full_text: full_text.clone(),
+                jurisdiction: crate::Jurisdiction::Federal,
+                citations: vec![],
+                docket_number: None,
+                source_url: None,
+                word_count: full_text.split_whitespace().count(),
+                ingestion_date: chrono::Utc::now(),
+                validation_warnings: vec![],
+                content_simhash: None,
+                duplicate_of: None,
+            };
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, &full_text, &full_text).await.unwrap();
+        }
+
+        // Only the first 3 of 5 cases make it into the trie.
+        for &case_id in &case_ids[..3] {
+            trie_index.insert_case_name(&format!("Case {case_id}"), case_id).unwrap();
+        }
+        trie_index.save_to_disk(temp_dir.join("trie.bin")).await.unwrap();
+
+        let engine = SearchEngine::from_snapshot(config, storage, &temp_dir).await.unwrap();
+
+        // Only the first 2 of 5 cases make it into the vector index.
+        for &case_id in &case_ids[..2] {
+            let mut vector = engine.vector_index.write("test_setup").await;
+            vector.add_document(DocRef { case_id, paragraph_index: 0, char_offset: None }, "sample text").await.unwrap();
+        }
+
+        let stats = engine.get_stats().await;
+        assert_eq!(stats.cases_in_storage, 5);
+        assert_eq!(stats.cases_in_trie, 3);
+        assert_eq!(stats.cases_with_vectors, 2);
+        assert_eq!(stats.index_lag, 3);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// A case containing 4 of a 5-token query (missing "foxtrot" entirely, so neither the
+    /// sequential content-trie search nor the all-tokens `SubstringIndex::search` fallback can
+    /// find it) is only reachable through `min_should_match`'s coverage-based relaxation: absent
+    /// at the strict `"100%"` (all 5 required), present once the threshold drops to `"75%"`
+    /// (4 of 5 required).
+    #[tokio::test]
+    async fn test_min_should_match_admits_a_partial_match_only_below_full_coverage() {
+        let temp_dir = std::env::temp_dir().join(format!("search-min-should-match-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("partial", "alpha bravo charlie delta ruling"),
+                ("unrelated", "totally different case about zoning"),
+            ],
+        )
+        .await;
+
+        let mut strict_query = plain_query("alpha bravo charlie delta foxtrot", &engine);
+        strict_query.config.min_should_match = "100%".to_string();
+        let strict_outcome = engine.execute_search_isolated(strict_query).await.unwrap();
+        assert!(strict_outcome.results.is_empty());
+
+        let mut relaxed_query = plain_query("alpha bravo charlie delta foxtrot", &engine);
+        relaxed_query.config.min_should_match = "75%".to_string();
+        let relaxed_outcome = engine.execute_search_isolated(relaxed_query).await.unwrap();
+        assert_eq!(relaxed_outcome.results.len(), 1);
+        assert_eq!(relaxed_outcome.results[0].case_metadata.id, ids["partial"]);
+        assert_eq!(relaxed_outcome.results[0].match_type, MatchType::PartialMatch);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `search_streamed` sends the same results, in the same order, that `search_with_params`
+    /// returns as a `Vec` for the identical query, and its summary's `total_candidates`/
+    /// `degraded` match the corresponding `SearchOutcome` fields — the property `POST
+    /// /search/stream` relies on to stay consistent with the non-streaming `POST /search`.
+    #[tokio::test]
+    async fn test_search_streamed_matches_the_order_and_totals_of_search_with_params() {
+        let temp_dir = std::env::temp_dir().join(format!("search-streamed-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = content_indexed_engine(
+            &temp_dir,
+            &[
+                ("first", "appeal regarding maritime salvage rights"),
+                ("second", "maritime salvage dispute over sunken cargo"),
+                ("third", "an unrelated zoning ordinance case"),
+            ],
+        )
+        .await;
+
+        let expected = engine.execute_search_isolated(plain_query("maritime salvage", &engine)).await.unwrap();
+        assert!(!expected.results.is_empty());
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+        let streamed_query = plain_query("maritime salvage", &engine);
+        let handle = tokio::spawn(async move { engine.search_streamed(streamed_query, tx).await });
+
+        let mut streamed_results = Vec::new();
+        while let Some(result) = rx.recv().await {
+            streamed_results.push(result);
+        }
+        let summary = handle.await.unwrap().unwrap();
+
+        assert_eq!(
+            streamed_results.iter().map(|r| r.case_metadata.id).collect::<Vec<_>>(),
+            expected.results.iter().map(|r| r.case_metadata.id).collect::<Vec<_>>()
+        );
+        assert_eq!(summary.total_candidates, expected.total_candidates);
+        assert_eq!(summary.degraded, !expected.warnings.is_empty());
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// Content indexed with an NFC-composed accented character and a straight apostrophe still
+    /// matches a query written with the NFD-decomposed form of that character and a curly
+    /// apostrophe — the case `QueryNormalizer` exists for, since `TrieIndex`'s own token
+    /// lowercasing never NFC-composes or quote-folds, only case-folds.
+    #[tokio::test]
+    async fn test_curly_quotes_and_nfd_decomposed_query_match_nfc_indexed_content() {
+        let temp_dir = std::env::temp_dir().join(format!("search-query-normalize-{}", uuid::Uuid::new_v4()));
+        let full_text = "the caf\u{00e9} defendant's motion was denied";
+        let (engine, ids) = content_indexed_engine(&temp_dir, &[("wanted", full_text)]).await;
+
+        let nfd_curly_query = "cafe\u{0301} defendant\u{2019}s motion";
+        let outcome = engine.execute_search_isolated(plain_query(nfd_curly_query, &engine)).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].case_metadata.id, ids["wanted"]);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    #[test]
+    fn test_query_normalizer_composes_nfd_and_folds_curly_quotes() {
+        let normalizer = QueryNormalizer::new(Config::default().text_processing).unwrap();
+
+        assert_eq!(normalizer.normalize("cafe\u{0301}"), "caf\u{00e9}");
+        assert_eq!(normalizer.normalize("defendant\u{2019}s motion"), "defendant's motion");
+    }
+
+    /// A query answered once from an empty index must not stay cached forever: once a case
+    /// matching that same query is indexed and [`SearchEngine::invalidate_cache`] is called (what
+    /// an ingestion pipeline would do after writing to the trie), the identical query has to see
+    /// the new case rather than the stale, pre-ingestion `RankedCandidates`.
+    #[tokio::test]
+    async fn test_invalidate_cache_forces_a_stale_query_to_see_newly_ingested_content() {
+        let temp_dir = std::env::temp_dir().join(format!("search-invalidate-cache-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) =
+            content_indexed_engine(&temp_dir, &[("unrelated", "an early ruling on tariff policy")]).await;
+
+        let query = plain_query("maritime salvage claim", &engine);
+        let before = engine.execute_search_isolated(query.clone()).await.unwrap();
+        assert!(before.results.is_empty(), "nothing indexed should match yet");
+
+        let generation_before = engine.index_generation();
+
+        let new_case_id = uuid::Uuid::new_v4();
+        let full_text = "a later ruling on maritime salvage claim";
+        let metadata = CaseMetadata {
+            id: new_case_id,
+            name: "Case new".to_string(),
+            citation: "new citation".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+            judges: vec![],
+            topics: vec![],
+            full_text: full_text.to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec![],
+            docket_number: None,
+            source_url: None,
+            word_count: full_text.split_whitespace().count(),
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        engine.storage.store_case_metadata(&metadata).await.unwrap();
+        engine.storage.store_case_text(&new_case_id, full_text, full_text).await.unwrap();
+        let tokens: Vec<(String, usize)> =
+            full_text.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+        let mut writer = engine.trie_index.begin_write();
+        writer.insert_content(&tokens, DocRef { case_id: new_case_id, paragraph_index: 0, char_offset: None }).unwrap();
+        engine.trie_index.commit(writer);
+
+        // Without invalidation the cached (empty) result from `before` would still be served.
+        engine.invalidate_cache();
+        assert!(engine.index_generation() > generation_before);
+
+        let after = engine.execute_search_isolated(query).await.unwrap();
+        assert!(after.results.iter().any(|r| r.case_metadata.id == new_case_id));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `enable_vector_short_circuit` is what now controls whether a lexical stage that's already
+    /// filled `max_results` skips the vector stage: on, a case findable only by vector search
+    /// never gets the chance to run (the old, timing-coupled behavior); off (the default), the
+    /// two stages launch together via `tokio::join!` and the vector-only case still surfaces.
+    #[tokio::test]
+    async fn test_vector_short_circuit_flag_controls_whether_a_full_lexical_page_skips_the_vector_stage() {
+        let temp_dir = std::env::temp_dir().join(format!("search-vector-short-circuit-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) =
+            content_indexed_engine(&temp_dir, &[("lexical", "riparian water rights dispute")]).await;
+
+        // Findable only through the vector stage: stored and embedded, but never inserted into
+        // the trie, so the lexical stage can never surface it.
+        let semantic_case_id = uuid::Uuid::new_v4();
+        let semantic_text = "riparian water rights dispute";
+        let metadata = CaseMetadata {
+            id: semantic_case_id,
+            name: "Case semantic".to_string(),
+            citation: "semantic citation".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+            judges: vec![],
+            topics: vec![],
+            full_text: semantic_text.to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec![],
+            docket_number: None,
+            source_url: None,
+            word_count: semantic_text.split_whitespace().count(),
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        engine.storage.store_case_metadata(&metadata).await.unwrap();
+        engine.storage.store_case_text(&semantic_case_id, semantic_text, semantic_text).await.unwrap();
+        {
+            let mut vector = engine.vector_index.write("test_setup").await;
+            vector
+                .add_document(DocRef { case_id: semantic_case_id, paragraph_index: 0, char_offset: None }, semantic_text)
+                .await
+                .unwrap();
+        }
+
+        let mut query = plain_query("riparian water rights dispute", &engine);
+        query.config.max_results = 1;
+
+        query.config.enable_vector_short_circuit = true;
+        let short_circuited = engine.rank_candidates(&query).await.unwrap();
+        assert!(
+            short_circuited.candidates.iter().all(|c| c.doc_ref.case_id != semantic_case_id),
+            "vector stage should have been skipped once the lexical stage filled max_results"
+        );
+
+        query.config.enable_vector_short_circuit = false;
+        let concurrent = engine.rank_candidates(&query).await.unwrap();
+        assert!(
+            concurrent.candidates.iter().any(|c| c.doc_ref.case_id == semantic_case_id),
+            "vector stage should still run alongside a full lexical page by default"
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_citation_graph_resolves_a_citing_case_in_both_directions() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-graph-{}", uuid::Uuid::new_v4()));
+        let (engine, ids) = citation_graph_engine(
+            &temp_dir,
+            &[
+                (
+                    "a",
+                    "Case A holds that the doctrine announced in 410 U.S. 113 (1973) controls this appeal.",
+                    "300 U.S. 1 (1930)",
+                ),
+                ("b", "Case B concerns family law visitation rights.", "410 U.S. 113 (1973)"),
+                (
+                    "c",
+                    "Case C cites 555 U.S. 999 (1999), an opinion no one in this corpus indexed.",
+                    "600 U.S. 2 (2020)",
+                ),
+            ],
+        )
+        .await;
+        let (case_a, case_b, case_c) = (ids["a"], ids["b"], ids["c"]);
+
+        let stats = engine.rebuild_citation_graph().await.unwrap();
+        assert_eq!(stats.cases_scanned, 3);
+        assert_eq!(stats.edges_resolved, 1);
+        assert_eq!(stats.edges_unresolved, 1);
+
+        let cited_by_a = engine.get_cited_cases(case_a).await.unwrap();
+        assert_eq!(
+            cited_by_a,
+            vec![crate::storage::CitationEdge::Resolved {
+                case_id: case_b,
+                raw_citation: "410 U.S. 113 (1973)".to_string(),
+                confidence: crate::storage::CitationConfidence::Exact,
+            }]
+        );
+
+        let citing_b = engine.get_citing_cases(case_b).await.unwrap();
+        assert_eq!(
+            citing_b,
+            vec![crate::storage::CitingCase { case_id: case_a, confidence: crate::storage::CitationConfidence::Exact }]
+        );
+
+        // A case with no incoming citations still resolves, just with an empty edge list.
+        assert!(engine.get_citing_cases(case_a).await.unwrap().is_empty());
+        assert!(engine.get_cited_cases(case_b).await.unwrap().is_empty());
+
+        // An in-text citation that doesn't match any indexed case's own citation is recorded as
+        // an unresolved raw string rather than dropped.
+        let cited_by_c = engine.get_cited_cases(case_c).await.unwrap();
+        assert_eq!(
+            cited_by_c,
+            vec![crate::storage::CitationEdge::Unresolved { raw_citation: "555 U.S. 999 (1999)".to_string() }]
+        );
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_cited_cases_for_an_unknown_case_id_returns_case_not_found() {
+        let temp_dir = std::env::temp_dir().join(format!("search-citation-graph-404-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = content_indexed_engine(&temp_dir, &[("a", "unrelated content")]).await;
+
+        let err = engine.get_cited_cases(uuid::Uuid::new_v4()).await.unwrap_err();
+        assert!(matches!(err, SearchError::CaseNotFound { .. }));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `"lexical"` disables the semantic stage entirely, so a case findable only through the
+    /// vector index must drop out of the results, while a case findable only through the trie
+    /// must stay.
+    #[tokio::test]
+    async fn test_lexical_profile_excludes_semantic_only_matches() {
+        let temp_dir = std::env::temp_dir().join(format!("search-profile-lexical-{}", uuid::Uuid::new_v4()));
+        let (engine, semantic_case_id, lexical_case_id) = weighting_profile_test_engine(&temp_dir).await;
+
+        let mut query = plain_query("riparian water rights dispute", &engine);
+        query.profile = Some("lexical".to_string());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        let result_ids: HashSet<CaseId> = outcome.results.iter().map(|r| r.case_metadata.id).collect();
+        assert!(result_ids.contains(&lexical_case_id));
+        assert!(!result_ids.contains(&semantic_case_id));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `"semantic"` disables the lexical stage entirely (`enable_prefix: false`), so a case
+    /// findable only through the trie must drop out, while a case findable only through the
+    /// vector index must stay.
+    #[tokio::test]
+    async fn test_semantic_profile_excludes_lexical_only_matches() {
+        let temp_dir = std::env::temp_dir().join(format!("search-profile-semantic-{}", uuid::Uuid::new_v4()));
+        let (engine, semantic_case_id, lexical_case_id) = weighting_profile_test_engine(&temp_dir).await;
+
+        let mut query = plain_query("riparian water rights dispute", &engine);
+        query.profile = Some("semantic".to_string());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        let result_ids: HashSet<CaseId> = outcome.results.iter().map(|r| r.case_metadata.id).collect();
+        assert!(result_ids.contains(&semantic_case_id));
+        assert!(!result_ids.contains(&lexical_case_id));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// `"balanced"` runs both stages, so both a trie-only and a vector-only match are returned —
+    /// distinguishing it from either single-stage profile above.
+    #[tokio::test]
+    async fn test_balanced_profile_includes_both_lexical_and_semantic_matches() {
+        let temp_dir = std::env::temp_dir().join(format!("search-profile-balanced-{}", uuid::Uuid::new_v4()));
+        let (engine, semantic_case_id, lexical_case_id) = weighting_profile_test_engine(&temp_dir).await;
+
+        let mut query = plain_query("riparian water rights dispute", &engine);
+        query.profile = Some("balanced".to_string());
+        let outcome = engine.search_with_params(query).await.unwrap();
+
+        let result_ids: HashSet<CaseId> = outcome.results.iter().map(|r| r.case_metadata.id).collect();
+        assert!(result_ids.contains(&semantic_case_id));
+        assert!(result_ids.contains(&lexical_case_id));
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unknown_profile_name_is_rejected_with_available_profiles_listed() {
+        let temp_dir = std::env::temp_dir().join(format!("search-profile-unknown-{}", uuid::Uuid::new_v4()));
+        let (engine, _ids) = content_indexed_engine(&temp_dir, &[("a", "riparian water rights dispute")]).await;
+
+        let mut query = plain_query("riparian water rights dispute", &engine);
+        query.profile = Some("made-up-profile".to_string());
+        let err = engine.search_with_params(query).await.unwrap_err();
+
+        match err {
+            SearchError::ValidationFailed { field, reason } => {
+                assert_eq!(field, "profile");
+                assert!(reason.contains("lexical"));
+                assert!(reason.contains("balanced"));
+                assert!(reason.contains("semantic"));
+            }
+            other => panic!("expected ValidationFailed, got {other:?}"),
+        }
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    /// One case indexed only in the trie (via `content_indexed_engine`) and one findable only
+    /// through the vector index (embedded directly, never inserted into the trie) — the same
+    /// setup `test_vector_short_circuit_flag_controls_whether_a_full_lexical_page_skips_the_vector_stage`
+    /// uses, reused here so each weighting-profile test can assert which stage(s) a profile
+    /// actually ran. Returns `(engine, semantic_only_case_id, lexical_only_case_id)`.
+    async fn weighting_profile_test_engine(temp_dir: &std::path::Path) -> (SearchEngine, CaseId, CaseId) {
+        let (engine, ids) =
+            content_indexed_engine(temp_dir, &[("lexical", "riparian water rights dispute")]).await;
+        let lexical_case_id = ids["lexical"];
+
+        let semantic_case_id = uuid::Uuid::new_v4();
+        let semantic_text = "riparian water rights dispute";
+        let metadata = CaseMetadata {
+            id: semantic_case_id,
+            name: "Case semantic".to_string(),
+            citation: "semantic citation".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+            judges: vec![],
+            topics: vec![],
+            full_text: semantic_text.to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec![],
+            docket_number: None,
+            source_url: None,
+            word_count: semantic_text.split_whitespace().count(),
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        engine.storage.store_case_metadata(&metadata).await.unwrap();
+        engine.storage.store_case_text(&semantic_case_id, semantic_text, semantic_text).await.unwrap();
+        {
+            let mut vector = engine.vector_index.write("test_setup").await;
+            vector
+                .add_document(DocRef { case_id: semantic_case_id, paragraph_index: 0, char_offset: None }, semantic_text)
+                .await
+                .unwrap();
+        }
+
+        (engine, semantic_case_id, lexical_case_id)
+    }
 } 
\ No newline at end of file
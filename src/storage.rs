@@ -18,19 +18,259 @@
 
 use crate::config::StorageConfig;
 use crate::errors::{Result, SearchError};
+use crate::text_processing::ProcessedArtifacts;
 use crate::{CaseId, CaseMetadata};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use sled::transaction::Transactional;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Schema version stamped on every value [`StorageManager::store_processed`] writes to
+/// `processed_tree`, so [`StorageManager::get_processed`] can detect a [`ProcessedArtifacts`]
+/// shape change and fall back to re-tokenizing instead of failing bincode deserialization.
+/// Bump this whenever [`ProcessedArtifacts`]'s fields change in a way that isn't
+/// bincode-compatible with what's already on disk.
+const PROCESSED_ARTIFACTS_SCHEMA_VERSION: u8 = 1;
+
+/// Tag byte [`StorageManager::encode_text`] prefixes onto a value stored uncompressed.
+const ENCODING_TAG_NONE: u8 = 0;
+/// Tag byte [`StorageManager::encode_text`] prefixes onto a gzip-compressed value.
+const ENCODING_TAG_GZIP: u8 = 1;
+/// Tag byte [`StorageManager::encode_text`] prefixes onto a zstd-compressed value.
+const ENCODING_TAG_ZSTD: u8 = 2;
+/// First two bytes of every gzip stream, used by [`StorageManager::decode_text`] to recognize a
+/// value written before per-value tags existed (its first byte can't be mistaken for one of the
+/// tag bytes above, since gzip's magic number starts higher than any tag currently in use).
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// `meta_tree` key under which [`StorageManager::case_count`]'s value is persisted, so
+/// [`StorageManager::new`] can restore it on restart without paying for `metadata_tree.len()`.
+const META_KEY_CASE_COUNT: &[u8] = b"case_count";
+/// `meta_tree` key under which the running total of encoded raw+normalized text bytes is
+/// persisted. See [`StorageManager::adjust_total_text_bytes`].
+const META_KEY_TOTAL_TEXT_BYTES: &[u8] = b"total_text_bytes";
+
 /// Main storage manager
 pub struct StorageManager {
     config: StorageConfig,
     db: Arc<sled::Db>,
     metadata_tree: Arc<sled::Tree>,
-    text_tree: Arc<sled::Tree>,
+    /// Lightweight per-case projection kept in sync with `metadata_tree` (see [`CaseSummary`]),
+    /// so facet roll-ups and court/date/topic filtering over a large candidate set don't need
+    /// to hydrate full [`CaseMetadata`] for every candidate.
+    summary_tree: Arc<sled::Tree>,
+    /// Whitespace/quote-normalized text used for indexing and snippet generation. Keeps the
+    /// original `case_text` tree name so cases stored before [`TextForm`] existed stay readable.
+    normalized_text_tree: Arc<sled::Tree>,
+    /// Original as-ingested text, kept around for display and for reprocessing with improved
+    /// normalizers. Cases stored before this tree existed have nothing here; [`StorageManager::get_case_text`]
+    /// falls back to `normalized_text_tree` for them.
+    raw_text_tree: Arc<sled::Tree>,
+    /// Secondary lookup indexes derived from `metadata_tree`. Held behind their own lock so
+    /// [`StorageManager::rebuild_secondary_indexes`] can swap in freshly-rebuilt trees without
+    /// blocking concurrent metadata reads/writes, and so lookups mid-rebuild still see the old
+    /// (still-consistent) trees rather than a half-populated one.
+    secondary_indexes: Arc<RwLock<SecondaryIndexes>>,
     stats: Arc<RwLock<StorageStats>>,
+    /// Number of full [`CaseMetadata`] hydrations served by [`StorageManager::get_case_metadata`]
+    /// since this `StorageManager` was created. Exists so callers like
+    /// [`crate::search::SearchEngine::execute_hybrid_search`] can be tested against a hard
+    /// budget on how much full-metadata hydration a query performs, rather than just eyeballing
+    /// query timing.
+    metadata_read_count: Arc<AtomicU64>,
+    /// Number of cases currently in `metadata_tree`, maintained incrementally by
+    /// [`StorageManager::store_case_metadata`]/[`StorageManager::store_case_atomic`] (increment
+    /// on a new id) and [`StorageManager::delete_case`] (decrement on an existing one), so
+    /// [`StorageManager::count_cases`] and [`StorageManager::update_stats`] never need to pay for
+    /// `metadata_tree.len()`'s full-tree iteration on every `/stats` request.
+    case_count: Arc<AtomicU64>,
+    /// Running total of encoded (post-compression) bytes across `raw_text_tree` and
+    /// `normalized_text_tree`, maintained incrementally by [`StorageManager::store_case_text`]/
+    /// [`StorageManager::store_case_atomic`] (on insert, adjusted for whatever was previously
+    /// stored under that id) and [`StorageManager::delete_case`] (subtracted on removal). Backed
+    /// by `meta_tree` so [`StorageManager::update_stats`] never re-derives it by scanning either
+    /// text tree, on startup or otherwise. See [`StorageManager::adjust_total_text_bytes`].
+    total_text_bytes: Arc<AtomicU64>,
+    /// Small tree of persisted counters (`total_text_bytes`, `case_count`) that must survive a
+    /// restart without re-scanning `metadata_tree`/`raw_text_tree`/`normalized_text_tree` to
+    /// recompute them. See [`META_KEY_CASE_COUNT`]/[`META_KEY_TOTAL_TEXT_BYTES`].
+    meta_tree: Arc<sled::Tree>,
+    /// Outgoing citation-graph edges, keyed by the citing case: a bincode-encoded
+    /// `Vec<CitationEdge>` per case, built by
+    /// [`crate::search::SearchEngine::rebuild_citation_graph`]. See [`CitationEdge`].
+    citation_edges_tree: Arc<sled::Tree>,
+    /// Reverse of `citation_edges_tree`, keyed by the cited case: a bincode-encoded
+    /// `Vec<CitingCase>` of every case whose outgoing edges resolved to this one. Kept in sync
+    /// with `citation_edges_tree` by [`StorageManager::store_citation_edges`], the same way
+    /// `secondary_indexes` is kept in sync with `metadata_tree`.
+    citing_edges_tree: Arc<sled::Tree>,
+    /// Bincode-serialized, version-prefixed [`crate::text_processing::ProcessedArtifacts`] per
+    /// case, so a later re-index (see [`crate::trie::TrieIndex::build_from_storage`]) can reuse
+    /// tokens/citations/entities/stats instead of re-running [`crate::text_processing::TextProcessor::process_text`].
+    /// See [`StorageManager::store_processed`]/[`StorageManager::get_processed`].
+    processed_tree: Arc<sled::Tree>,
+}
+
+/// How confidently a [`CitationEdge::Resolved`] edge's `case_id` matches the in-text citation
+/// that produced it, mirroring [`crate::trie::CitationResolution`]'s variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CitationConfidence {
+    /// The citation, including year, matched the cited case's own citation exactly.
+    Exact,
+    /// Volume/reporter/page matched but the queried and indexed years differ.
+    YearMismatch,
+    /// The citation was a prefix of exactly one indexed case's citation.
+    Prefix,
+}
+
+/// One outgoing edge in the case citation graph built by
+/// [`crate::search::SearchEngine::rebuild_citation_graph`]: an in-text citation extracted from a
+/// case's body, either resolved against an indexed case's own citation or left as the raw
+/// extracted text when nothing indexed matches closely enough (a citation to a case outside this
+/// corpus, or one this corpus hasn't ingested yet).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CitationEdge {
+    Resolved { case_id: CaseId, raw_citation: String, confidence: CitationConfidence },
+    Unresolved { raw_citation: String },
+}
+
+/// One entry in a cited case's reverse "who cites me" list (see
+/// [`StorageManager::get_citing_cases`]) — the mirror image of a [`CitationEdge::Resolved`]
+/// stored on the citing case, so a lookup in either direction reports the same confidence for
+/// the same edge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CitingCase {
+    pub case_id: CaseId,
+    pub confidence: CitationConfidence,
+}
+
+/// Lightweight per-case projection derived from [`CaseMetadata`], covering just the fields the
+/// search engine's facet roll-up and court/date/topic filters need (see
+/// [`crate::search::SearchEngine::execute_hybrid_search`]). Stored alongside the full record in
+/// `summary_tree` and kept in sync by [`StorageManager::store_case_metadata`] and
+/// [`StorageManager::delete_case`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaseSummary {
+    pub id: CaseId,
+    pub court: String,
+    pub decision_date: chrono::NaiveDate,
+    pub jurisdiction: crate::Jurisdiction,
+    pub topics: Vec<String>,
+    /// Mirrors [`CaseMetadata::judges`], needed for [`crate::search::SearchQuery::judge_filter`]
+    /// without a full metadata hydration.
+    pub judges: Vec<String>,
+    /// Mirrors [`CaseMetadata::duplicate_of`], needed to dedup a candidate against its
+    /// canonical case before either has been fully hydrated.
+    pub duplicate_of: Option<CaseId>,
+}
+
+impl From<&CaseMetadata> for CaseSummary {
+    fn from(metadata: &CaseMetadata) -> Self {
+        Self {
+            id: metadata.id,
+            court: metadata.court.clone(),
+            decision_date: metadata.decision_date,
+            jurisdiction: metadata.jurisdiction.clone(),
+            topics: metadata.topics.clone(),
+            judges: metadata.judges.clone(),
+            duplicate_of: metadata.duplicate_of,
+        }
+    }
+}
+
+/// One secondary lookup tree per filterable [`CaseMetadata`] field, each mapping a normalized
+/// key (see [`StorageManager::secondary_index_key`]) to a bincode-encoded `Vec<CaseId>`. Grouped
+/// behind one [`RwLock`] in [`StorageManager`] so a rebuild swaps all five in as a single atomic
+/// step, never leaving lookups split across an old citation index and a new court index.
+struct SecondaryIndexes {
+    citation: Arc<sled::Tree>,
+    court: Arc<sled::Tree>,
+    decision_date: Arc<sled::Tree>,
+    judge: Arc<sled::Tree>,
+    docket_number: Arc<sled::Tree>,
+}
+
+/// Which secondary index to query with [`StorageManager::find_case_ids_by`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecondaryIndexField {
+    Citation,
+    Court,
+    DecisionDate,
+    Judge,
+    DocketNumber,
+}
+
+/// Counts of entries written into each secondary index by
+/// [`StorageManager::rebuild_secondary_indexes`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecondaryIndexRebuildStats {
+    pub cases_scanned: usize,
+    pub citation_entries: usize,
+    pub court_entries: usize,
+    pub decision_date_entries: usize,
+    pub judge_entries: usize,
+    pub docket_number_entries: usize,
+}
+
+/// Result of [`StorageManager::verify_integrity`]: which cases had metadata but no text, or
+/// text but no metadata, before the scan repaired them by deleting the orphaned side (there's
+/// nothing to reconstruct the missing half from, so "repair" here means "stop it lingering
+/// half-visible" rather than making it whole).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub cases_scanned: usize,
+    pub metadata_without_text: Vec<CaseId>,
+    pub text_without_metadata: Vec<CaseId>,
+}
+
+/// What [`StorageManager::import_jsonl`] does when a line's case id is already present in
+/// `metadata_tree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportConflictPolicy {
+    /// Leave the existing case as-is; the line is counted under [`ImportReport::skipped`].
+    Skip,
+    /// Overwrite the existing case, the same as importing it for the first time.
+    Overwrite,
+}
+
+/// One [`StorageManager::import_jsonl`] line that could not be imported: its 1-based position
+/// in the input, so a caller can find and fix it without re-parsing the whole file, and why it
+/// failed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportFailure {
+    pub line_number: usize,
+    pub reason: String,
+}
+
+/// Summary of a completed [`StorageManager::import_jsonl`] run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub failed: Vec<ImportFailure>,
+}
+
+/// Which textual form of a case to retrieve via [`StorageManager::get_case_text`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextForm {
+    /// The text as originally ingested, before whitespace/quote normalization
+    Raw,
+    /// Normalized text used for indexing and snippet generation
+    Normalized,
+}
+
+/// Result of [`StorageManager::get_case_text`]
+#[derive(Debug, Clone)]
+pub struct CaseTextResult {
+    pub text: String,
+    /// True when the requested form had no stored entry for this case (it was ingested before
+    /// raw and normalized text were stored separately) and the normalized text was served in
+    /// its place instead of reporting the case as missing text entirely.
+    pub served_as_fallback: bool,
 }
 
 /// Storage statistics
@@ -40,8 +280,26 @@ pub struct StorageStats {
     pub total_size_bytes: u64,
     pub database_size_bytes: u64,
     pub last_backup: Option<chrono::DateTime<chrono::Utc>>,
+    /// When a case was last stored or deleted. `None` until the first mutation on a freshly
+    /// created database; not persisted, so it resets to `None` across a restart rather than
+    /// surviving one the way `total_cases`/`total_text_bytes` do — unlike those counters, a
+    /// stale `last_modified` from before a restart would be actively misleading to a caller.
+    pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Base sled tree names for [`SecondaryIndexes`], in `(citation, court, decision_date, judge,
+/// docket_number)` order
+const SECONDARY_INDEX_TREE_NAMES: [&str; 5] = [
+    "index_citation",
+    "index_court",
+    "index_decision_date",
+    "index_judge",
+    "index_docket_number",
+];
+
+/// How often [`StorageManager::rebuild_secondary_indexes`] logs progress, in cases scanned
+const SECONDARY_INDEX_REBUILD_PROGRESS_INTERVAL: usize = 500;
+
 impl StorageManager {
     /// Create new storage manager
     pub async fn new(config: StorageConfig) -> Result<Self> {
@@ -64,26 +322,110 @@ impl StorageManager {
                 reason: format!("Failed to open metadata tree: {}", e),
             })?;
 
-        let text_tree = db.open_tree("case_text")
+        let normalized_text_tree = db.open_tree("case_text")
             .map_err(|e| SearchError::DatabaseConnectionFailed {
                 db_path: config.db_path.to_string_lossy().to_string(),
                 reason: format!("Failed to open text tree: {}", e),
             })?;
 
+        let raw_text_tree = db.open_tree("case_text_raw")
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open raw text tree: {}", e),
+            })?;
+
+        let summary_tree = db.open_tree("case_summary")
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open summary tree: {}", e),
+            })?;
+
+        let citation_edges_tree = db.open_tree("citation_edges")
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open citation edges tree: {}", e),
+            })?;
+
+        let citing_edges_tree = db.open_tree("citing_edges")
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open citing edges tree: {}", e),
+            })?;
+
+        let processed_tree = db.open_tree("case_processed")
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open processed-artifacts tree: {}", e),
+            })?;
+
+        let meta_tree = db.open_tree("storage_meta")
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open storage meta tree: {}", e),
+            })?;
+
+        let secondary_indexes = Arc::new(RwLock::new(Self::open_secondary_indexes(
+            &db,
+            SECONDARY_INDEX_TREE_NAMES,
+            &config,
+        )?));
+
         // Initialize statistics
         let stats = Arc::new(RwLock::new(StorageStats {
             total_cases: 0,
             total_size_bytes: 0,
             database_size_bytes: 0,
             last_backup: None,
+            last_modified: None,
         }));
 
+        // `case_count`/`total_text_bytes` are persisted in `meta_tree` so a restart can restore
+        // them in O(1) instead of re-scanning `metadata_tree`/the text trees. A database that
+        // predates these counters (or is opened for the first time) has neither key yet; pay the
+        // one-time scan here, then persist the result so every subsequent open is cheap.
+        let (initial_case_count, initial_total_text_bytes) = match (
+            Self::read_meta_counter(&meta_tree, META_KEY_CASE_COUNT)?,
+            Self::read_meta_counter(&meta_tree, META_KEY_TOTAL_TEXT_BYTES)?,
+        ) {
+            (Some(cases), Some(text_bytes)) => (cases, text_bytes),
+            _ => {
+                let cases = metadata_tree.len() as u64;
+                let mut text_bytes = 0u64;
+                for result in normalized_text_tree.iter().chain(raw_text_tree.iter()) {
+                    if let Ok((_, value)) = result {
+                        text_bytes += value.len() as u64;
+                    }
+                }
+                meta_tree.insert(META_KEY_CASE_COUNT, &cases.to_be_bytes())
+                    .map_err(|e| SearchError::DatabaseConnectionFailed {
+                        db_path: config.db_path.to_string_lossy().to_string(),
+                        reason: format!("Failed to persist initial case count: {}", e),
+                    })?;
+                meta_tree.insert(META_KEY_TOTAL_TEXT_BYTES, &text_bytes.to_be_bytes())
+                    .map_err(|e| SearchError::DatabaseConnectionFailed {
+                        db_path: config.db_path.to_string_lossy().to_string(),
+                        reason: format!("Failed to persist initial total text bytes: {}", e),
+                    })?;
+                (cases, text_bytes)
+            }
+        };
+
         let storage = Self {
             config,
             db: Arc::new(db),
             metadata_tree: Arc::new(metadata_tree),
-            text_tree: Arc::new(text_tree),
+            summary_tree: Arc::new(summary_tree),
+            normalized_text_tree: Arc::new(normalized_text_tree),
+            raw_text_tree: Arc::new(raw_text_tree),
+            secondary_indexes,
             stats,
+            metadata_read_count: Arc::new(AtomicU64::new(0)),
+            case_count: Arc::new(AtomicU64::new(initial_case_count)),
+            total_text_bytes: Arc::new(AtomicU64::new(initial_total_text_bytes)),
+            meta_tree: Arc::new(meta_tree),
+            citation_edges_tree: Arc::new(citation_edges_tree),
+            citing_edges_tree: Arc::new(citing_edges_tree),
+            processed_tree: Arc::new(processed_tree),
         };
 
         // Update statistics
@@ -95,21 +437,265 @@ impl StorageManager {
         Ok(storage)
     }
 
+    /// Open the five [`SecondaryIndexes`] trees, under whichever names are passed in — the
+    /// live names at startup, or freshly-generated temporary names during
+    /// [`StorageManager::rebuild_secondary_indexes`].
+    fn open_secondary_indexes(db: &sled::Db, names: [&str; 5], config: &StorageConfig) -> Result<SecondaryIndexes> {
+        let open = |name: &str| -> Result<Arc<sled::Tree>> {
+            db.open_tree(name).map(Arc::new).map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: config.db_path.to_string_lossy().to_string(),
+                reason: format!("Failed to open secondary index tree '{}': {}", name, e),
+            })
+        };
+        Ok(SecondaryIndexes {
+            citation: open(names[0])?,
+            court: open(names[1])?,
+            decision_date: open(names[2])?,
+            judge: open(names[3])?,
+            docket_number: open(names[4])?,
+        })
+    }
+
+    /// Normalize a secondary index lookup/insert key so `"Warren"` and `"warren"`, or
+    /// `"S.Ct."` and `" S.Ct. "`, land under the same entry
+    fn secondary_index_key(value: &str) -> Vec<u8> {
+        value.trim().to_lowercase().into_bytes()
+    }
+
+    /// Append `case_id` to the `Vec<CaseId>` posting list stored under `key` in `tree`,
+    /// creating the entry if it doesn't exist yet. A no-op if `case_id` is already present.
+    fn append_case_id_to_index(tree: &sled::Tree, key: &[u8], case_id: CaseId) -> Result<()> {
+        let mut ids: Vec<CaseId> = match tree.get(key).map_err(|e| SearchError::SerializationFailed {
+            message: format!("SecondaryIndexEntry serialization failed: {}", e),
+        })? {
+            Some(bytes) => bincode::deserialize(&bytes)?,
+            None => Vec::new(),
+        };
+        if !ids.contains(&case_id) {
+            ids.push(case_id);
+            let bytes = bincode::serialize(&ids)?;
+            tree.insert(key, bytes).map_err(|e| SearchError::SerializationFailed {
+                message: format!("SecondaryIndexEntry serialization failed: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Remove `case_id` from the posting list stored under `key` in `tree`, deleting the entry
+    /// entirely once its last case id is removed. A no-op if `key` has no entry.
+    fn remove_case_id_from_index(tree: &sled::Tree, key: &[u8], case_id: CaseId) -> Result<()> {
+        let Some(bytes) = tree.get(key).map_err(|e| SearchError::SerializationFailed {
+            message: format!("SecondaryIndexEntry serialization failed: {}", e),
+        })? else {
+            return Ok(());
+        };
+        let mut ids: Vec<CaseId> = bincode::deserialize(&bytes)?;
+        ids.retain(|id| *id != case_id);
+
+        if ids.is_empty() {
+            tree.remove(key)
+        } else {
+            tree.insert(key, bincode::serialize(&ids)?)
+        }
+        .map_err(|e| SearchError::SerializationFailed {
+            message: format!("SecondaryIndexEntry serialization failed: {}", e),
+        })?;
+        Ok(())
+    }
+
+    /// Add `metadata` to every applicable secondary index tree
+    fn index_metadata_secondary(secondary: &SecondaryIndexes, metadata: &CaseMetadata) -> Result<()> {
+        Self::append_case_id_to_index(&secondary.citation, &Self::secondary_index_key(&metadata.citation), metadata.id)?;
+        Self::append_case_id_to_index(&secondary.court, &Self::secondary_index_key(&metadata.court), metadata.id)?;
+        Self::append_case_id_to_index(
+            &secondary.decision_date,
+            metadata.decision_date.to_string().as_bytes(),
+            metadata.id,
+        )?;
+        for judge in &metadata.judges {
+            Self::append_case_id_to_index(&secondary.judge, &Self::secondary_index_key(judge), metadata.id)?;
+        }
+        if let Some(docket_number) = &metadata.docket_number {
+            Self::append_case_id_to_index(&secondary.docket_number, &Self::secondary_index_key(docket_number), metadata.id)?;
+        }
+        Ok(())
+    }
+
+    /// Remove `metadata` from every secondary index tree it was previously indexed under
+    fn deindex_metadata_secondary(secondary: &SecondaryIndexes, metadata: &CaseMetadata) -> Result<()> {
+        Self::remove_case_id_from_index(&secondary.citation, &Self::secondary_index_key(&metadata.citation), metadata.id)?;
+        Self::remove_case_id_from_index(&secondary.court, &Self::secondary_index_key(&metadata.court), metadata.id)?;
+        Self::remove_case_id_from_index(
+            &secondary.decision_date,
+            metadata.decision_date.to_string().as_bytes(),
+            metadata.id,
+        )?;
+        for judge in &metadata.judges {
+            Self::remove_case_id_from_index(&secondary.judge, &Self::secondary_index_key(judge), metadata.id)?;
+        }
+        if let Some(docket_number) = &metadata.docket_number {
+            Self::remove_case_id_from_index(&secondary.docket_number, &Self::secondary_index_key(docket_number), metadata.id)?;
+        }
+        Ok(())
+    }
+
+    /// Look up case ids by one secondary index field (see [`SecondaryIndexField`]). Empty when
+    /// nothing is indexed under `value`, not an error.
+    pub async fn find_case_ids_by(&self, field: SecondaryIndexField, value: &str) -> Result<Vec<CaseId>> {
+        let secondary = self.secondary_indexes.read().await;
+        let (tree, key) = match field {
+            SecondaryIndexField::Citation => (&secondary.citation, Self::secondary_index_key(value)),
+            SecondaryIndexField::Court => (&secondary.court, Self::secondary_index_key(value)),
+            SecondaryIndexField::DecisionDate => (&secondary.decision_date, value.as_bytes().to_vec()),
+            SecondaryIndexField::Judge => (&secondary.judge, Self::secondary_index_key(value)),
+            SecondaryIndexField::DocketNumber => (&secondary.docket_number, Self::secondary_index_key(value)),
+        };
+
+        match tree.get(&key).map_err(|e| SearchError::SerializationFailed {
+            message: format!("SecondaryIndexEntry serialization failed: {}", e),
+        })? {
+            Some(bytes) => Ok(bincode::deserialize(&bytes)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Case ids whose [`CaseMetadata::citation`] matches `citation` (see
+    /// [`StorageManager::find_case_ids_by`]); a thin, typed convenience wrapper.
+    pub async fn find_by_citation(&self, citation: &str) -> Result<Vec<CaseId>> {
+        self.find_case_ids_by(SecondaryIndexField::Citation, citation).await
+    }
+
+    /// Case ids whose [`CaseMetadata::court`] matches `court`; see
+    /// [`StorageManager::find_case_ids_by`].
+    pub async fn find_by_court(&self, court: &str) -> Result<Vec<CaseId>> {
+        self.find_case_ids_by(SecondaryIndexField::Court, court).await
+    }
+
+    /// Case ids whose [`CaseMetadata::docket_number`] matches `docket_number`; see
+    /// [`StorageManager::find_case_ids_by`].
+    pub async fn find_by_docket(&self, docket_number: &str) -> Result<Vec<CaseId>> {
+        self.find_case_ids_by(SecondaryIndexField::DocketNumber, docket_number).await
+    }
+
+    /// Case ids whose [`CaseMetadata::decision_date`] falls within `[start_year, end_year]`
+    /// inclusive. Unlike [`StorageManager::find_case_ids_by`]'s other fields, the decision-date
+    /// index is keyed by the full `YYYY-MM-DD` date rather than a single normalized value, so a
+    /// year range is served with a sled key-range scan (ISO 8601 dates sort lexicographically in
+    /// chronological order) rather than a single point lookup — one tree traversal instead of
+    /// 365-ish per-day lookups.
+    pub async fn find_by_year_range(&self, start_year: u32, end_year: u32) -> Result<Vec<CaseId>> {
+        let secondary = self.secondary_indexes.read().await;
+        let start_key = format!("{start_year:04}-01-01");
+        let end_key = format!("{end_year:04}-12-31");
+
+        let mut seen = HashSet::new();
+        let mut case_ids = Vec::new();
+        for entry in secondary.decision_date.range(start_key.as_bytes()..=end_key.as_bytes()) {
+            let (_, value) = entry.map_err(|e| SearchError::Internal {
+                message: format!("Database iteration error: {}", e),
+            })?;
+            let ids: Vec<CaseId> = bincode::deserialize(&value)?;
+            for case_id in ids {
+                if seen.insert(case_id) {
+                    case_ids.push(case_id);
+                }
+            }
+        }
+        Ok(case_ids)
+    }
+
+    /// Clear and repopulate all five secondary index trees by streaming `metadata_tree`, the
+    /// source of truth they're derived from. Builds into freshly-named temporary trees first,
+    /// then swaps them into [`StorageManager::secondary_indexes`] behind a single write lock
+    /// acquisition — so a lookup running concurrently sees either the complete old set of
+    /// indexes or the complete new set, never a mix, and the old trees are only dropped after
+    /// the swap. Progress is logged every [`SECONDARY_INDEX_REBUILD_PROGRESS_INTERVAL`] cases.
+    pub async fn rebuild_secondary_indexes(&self) -> Result<SecondaryIndexRebuildStats> {
+        let build_id = uuid::Uuid::new_v4();
+        let temp_names: [String; 5] = SECONDARY_INDEX_TREE_NAMES.map(|name| format!("{name}_rebuild_{build_id}"));
+        let temp_name_refs: [&str; 5] = std::array::from_fn(|i| temp_names[i].as_str());
+        let temp = Self::open_secondary_indexes(&self.db, temp_name_refs, &self.config)?;
+
+        let mut stats = SecondaryIndexRebuildStats::default();
+
+        for result in self.metadata_tree.iter() {
+            let (_, value) = result.map_err(|e| SearchError::Internal {
+                message: format!("Database iteration error: {}", e),
+            })?;
+            let metadata: CaseMetadata = bincode::deserialize(&value)?;
+
+            Self::index_metadata_secondary(&temp, &metadata)?;
+            stats.citation_entries += 1;
+            stats.court_entries += 1;
+            stats.decision_date_entries += 1;
+            stats.judge_entries += metadata.judges.len();
+            stats.docket_number_entries += metadata.docket_number.is_some() as usize;
+
+            stats.cases_scanned += 1;
+            if stats.cases_scanned % SECONDARY_INDEX_REBUILD_PROGRESS_INTERVAL == 0 {
+                tracing::info!("Secondary index rebuild progress: {} cases scanned", stats.cases_scanned);
+            }
+        }
+
+        let old = {
+            let mut guard = self.secondary_indexes.write().await;
+            std::mem::replace(&mut *guard, temp)
+        };
+
+        for tree in [&old.citation, &old.court, &old.decision_date, &old.judge, &old.docket_number] {
+            let name = tree.name();
+            if let Err(e) = self.db.drop_tree(&name) {
+                tracing::warn!("Failed to drop stale secondary index tree {:?}: {}", name, e);
+            }
+        }
+
+        tracing::info!(
+            "Secondary index rebuild complete: {} cases scanned, {} citation / {} court / {} date / {} judge / {} docket entries",
+            stats.cases_scanned,
+            stats.citation_entries,
+            stats.court_entries,
+            stats.decision_date_entries,
+            stats.judge_entries,
+            stats.docket_number_entries
+        );
+
+        Ok(stats)
+    }
+
     /// Store case metadata
     pub async fn store_case_metadata(&self, metadata: &CaseMetadata) -> Result<()> {
         let key = metadata.id.to_string();
+        let old_metadata = self.get_case_metadata(&metadata.id).await?;
         let value = bincode::serialize(metadata)?;
 
         self.metadata_tree.insert(key.as_bytes(), value)
             .map_err(|e| SearchError::SerializationFailed {
-                data_type: "CaseMetadata".to_string(),
-                reason: e.to_string(),
+                message: format!("CaseMetadata serialization failed: {}", e),
             })?;
 
+        let secondary = self.secondary_indexes.read().await;
+        // An update re-storing an existing case_id with a changed citation/court/docket/date/judge
+        // must drop the old values' postings first, or a re-indexed case would linger under both
+        // its old and new secondary-index keys.
+        let is_new_case = old_metadata.is_none();
+        if let Some(old_metadata) = old_metadata {
+            Self::deindex_metadata_secondary(&secondary, &old_metadata)?;
+        }
+        Self::index_metadata_secondary(&secondary, metadata)?;
+        drop(secondary);
+
+        self.store_case_summary(&CaseSummary::from(metadata)).await?;
+
+        if is_new_case {
+            let new_count = self.case_count.fetch_add(1, Ordering::Relaxed) + 1;
+            self.persist_meta_counter(META_KEY_CASE_COUNT, new_count)?;
+        }
+
         // Update statistics
         let mut stats = self.stats.write().await;
-        stats.total_cases = self.metadata_tree.len();
-        
+        stats.total_cases = self.case_count.load(Ordering::Relaxed) as usize;
+        stats.last_modified = Some(chrono::Utc::now());
+
         tracing::debug!("Stored metadata for case: {}", metadata.name);
         Ok(())
     }
@@ -117,202 +703,849 @@ impl StorageManager {
     /// Retrieve case metadata by ID
     pub async fn get_case_metadata(&self, case_id: &CaseId) -> Result<Option<CaseMetadata>> {
         let key = case_id.to_string();
-        
+
         if let Some(value) = self.metadata_tree.get(key.as_bytes())
             .map_err(|e| SearchError::SerializationFailed {
-                data_type: "CaseMetadata".to_string(),
-                reason: e.to_string(),
+                message: format!("CaseMetadata serialization failed: {}", e),
             })? {
-            
+
             let metadata: CaseMetadata = bincode::deserialize(&value)?;
+            self.metadata_read_count.fetch_add(1, Ordering::Relaxed);
             Ok(Some(metadata))
         } else {
             Ok(None)
         }
     }
 
-    /// Store full case text
-    pub async fn store_case_text(&self, case_id: &CaseId, text: &str) -> Result<()> {
-        let key = case_id.to_string();
-        
-        // Compress text if enabled
-        let data = if self.config.enable_compression {
-            self.compress_text(text)?
-        } else {
-            text.as_bytes().to_vec()
-        };
+    /// Batch counterpart to [`StorageManager::get_case_metadata`]: a caller hydrating a whole
+    /// page of candidates (see `search::SearchEngine::execute_hybrid_search`) makes one call
+    /// here instead of awaiting `get_case_metadata` once per id in a loop. An id with no
+    /// matching entry (a stale trie postings entry outliving its case, say) is simply absent
+    /// from the returned map rather than failing the whole batch.
+    pub async fn get_cases_metadata(&self, case_ids: &[CaseId]) -> Result<HashMap<CaseId, CaseMetadata>> {
+        let mut out = HashMap::with_capacity(case_ids.len());
+        for case_id in case_ids {
+            if let Some(metadata) = self.get_case_metadata(case_id).await? {
+                out.insert(*case_id, metadata);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Number of cases currently stored, maintained as a running counter rather than by
+    /// iterating `metadata_tree` (which is what `sled::Tree::len` does under the hood) — see
+    /// [`StorageManager::case_count`]. Backs the `/stats` endpoint's `total_cases` field.
+    pub fn count_cases(&self) -> usize {
+        self.case_count.load(Ordering::Relaxed) as usize
+    }
+
+    /// Number of full [`CaseMetadata`] hydrations served by [`StorageManager::get_case_metadata`]
+    /// since this `StorageManager` was created. Callers that only need `CaseSummary`-level
+    /// fields (court, decision date, jurisdiction, topics) should prefer
+    /// [`StorageManager::get_case_summary`], which doesn't count against this.
+    pub fn metadata_read_count(&self) -> u64 {
+        self.metadata_read_count.load(Ordering::Relaxed)
+    }
+
+    /// Read a `meta_tree` counter written by [`StorageManager::persist_meta_counter`]. `None`
+    /// means the key is absent (a fresh database, or one predating this counter) rather than an
+    /// error; a value present but not exactly 8 bytes is treated the same way, so a corrupted
+    /// entry falls back to being recomputed rather than panicking.
+    fn read_meta_counter(tree: &sled::Tree, key: &[u8]) -> Result<Option<u64>> {
+        let value = tree.get(key)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to read storage meta counter: {}", e),
+            })?;
+        Ok(value.and_then(|bytes| <[u8; 8]>::try_from(bytes.as_ref()).ok().map(u64::from_be_bytes)))
+    }
+
+    /// Persist a `meta_tree` counter so it survives a restart. Best-effort in the sense that a
+    /// failure here doesn't roll back the in-memory counter it accompanies — a database that
+    /// can't be written to has bigger problems than a stale stats counter — but is surfaced to
+    /// the caller so it's at least logged rather than silently swallowed.
+    fn persist_meta_counter(&self, key: &'static [u8], value: u64) -> Result<()> {
+        self.meta_tree.insert(key, &value.to_be_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to persist storage meta counter: {}", e),
+            })?;
+        Ok(())
+    }
+
+    /// Adjust the running `total_text_bytes` counter by `added` minus `removed` and persist the
+    /// new total to `meta_tree`.
+    fn adjust_total_text_bytes(&self, added: u64, removed: u64) -> Result<()> {
+        let delta = added as i64 - removed as i64;
+        let previous = self.total_text_bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(current.saturating_add_signed(delta))
+            })
+            .expect("closure always returns Some");
+        self.persist_meta_counter(META_KEY_TOTAL_TEXT_BYTES, previous.saturating_add_signed(delta))
+    }
+
+    /// Replace `case_id`'s outgoing citation-graph edges, deindexing the old resolved edges from
+    /// `citing_edges_tree` before indexing the new ones.
+    pub async fn store_citation_edges(&self, case_id: CaseId, edges: Vec<CitationEdge>) -> Result<()> {
+        let previous = self.get_cited_cases(&case_id).await?;
+        for edge in &previous {
+            if let CitationEdge::Resolved { case_id: cited_id, confidence, .. } = edge {
+                Self::remove_citing_case(&self.citing_edges_tree, cited_id, case_id, *confidence)?;
+            }
+        }
 
-        self.text_tree.insert(key.as_bytes(), data)
+        let key = case_id.to_string();
+        let value = bincode::serialize(&edges)?;
+        self.citation_edges_tree.insert(key.as_bytes(), value)
             .map_err(|e| SearchError::SerializationFailed {
-                data_type: "CaseText".to_string(),
-                reason: e.to_string(),
+                message: format!("CitationEdge serialization failed: {}", e),
             })?;
 
-        tracing::debug!("Stored text for case: {} ({} bytes)", case_id, text.len());
+        for edge in &edges {
+            if let CitationEdge::Resolved { case_id: cited_id, confidence, .. } = edge {
+                Self::append_citing_case(&self.citing_edges_tree, *cited_id, case_id, *confidence)?;
+            }
+        }
+
         Ok(())
     }
 
-    /// Retrieve full case text
-    pub async fn get_case_text(&self, case_id: &CaseId) -> Result<Option<String>> {
+    /// `case_id`'s outgoing citation-graph edges ("cites"), in the order they were extracted.
+    /// Empty when the citation graph hasn't been built for this case yet, not an error.
+    pub async fn get_cited_cases(&self, case_id: &CaseId) -> Result<Vec<CitationEdge>> {
         let key = case_id.to_string();
-        
-        if let Some(data) = self.text_tree.get(key.as_bytes())
+        match self.citation_edges_tree.get(key.as_bytes())
             .map_err(|e| SearchError::SerializationFailed {
-                data_type: "CaseText".to_string(),
-                reason: e.to_string(),
+                message: format!("CitationEdge serialization failed: {}", e),
             })? {
-            
-            let text = if self.config.enable_compression {
-                self.decompress_text(&data)?
-            } else {
-                String::from_utf8(data.to_vec())
-                    .map_err(|e| SearchError::UnsupportedEncoding {
-                        encoding: format!("UTF-8: {}", e),
-                    })?
-            };
-            
-            Ok(Some(text))
-        } else {
-            Ok(None)
+            Some(bytes) => Ok(bincode::deserialize(&bytes)?),
+            None => Ok(Vec::new()),
         }
     }
 
-    /// List all case IDs
-    pub async fn list_case_ids(&self) -> Result<Vec<CaseId>> {
-        let mut case_ids = Vec::new();
-        
-        for result in self.metadata_tree.iter() {
-            let (key, _) = result.map_err(|e| SearchError::Internal {
-                message: format!("Database iteration error: {}", e),
+    /// Cases whose outgoing edges resolved to `case_id` ("cited by"), each with the confidence
+    /// of that resolution. Empty when nothing indexed cites `case_id`, not an error.
+    pub async fn get_citing_cases(&self, case_id: &CaseId) -> Result<Vec<CitingCase>> {
+        let key = case_id.to_string();
+        match self.citing_edges_tree.get(key.as_bytes())
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CitingCase serialization failed: {}", e),
+            })? {
+            Some(bytes) => Ok(bincode::deserialize(&bytes)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Append `citing_case_id` to the reverse posting list stored under `cited_case_id`,
+    /// creating the entry if it doesn't exist yet. A no-op if `citing_case_id` is already
+    /// present.
+    fn append_citing_case(
+        tree: &sled::Tree,
+        cited_case_id: CaseId,
+        citing_case_id: CaseId,
+        confidence: CitationConfidence,
+    ) -> Result<()> {
+        let key = cited_case_id.to_string();
+        let mut entries: Vec<CitingCase> = match tree.get(key.as_bytes())
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CitingCase serialization failed: {}", e),
+            })? {
+            Some(bytes) => bincode::deserialize(&bytes)?,
+            None => Vec::new(),
+        };
+        if !entries.iter().any(|entry| entry.case_id == citing_case_id) {
+            entries.push(CitingCase { case_id: citing_case_id, confidence });
+            let bytes = bincode::serialize(&entries)?;
+            tree.insert(key.as_bytes(), bytes).map_err(|e| SearchError::SerializationFailed {
+                message: format!("CitingCase serialization failed: {}", e),
             })?;
-            
-            let key_str = String::from_utf8(key.to_vec())
-                .map_err(|e| SearchError::UnsupportedEncoding {
-                    encoding: format!("UTF-8: {}", e),
-                })?;
-            
-            let case_id = uuid::Uuid::parse_str(&key_str)
-                .map_err(|e| SearchError::Internal {
-                    message: format!("Invalid case ID format: {}", e),
-                })?;
-            
-            case_ids.push(case_id);
         }
-        
-        Ok(case_ids)
+        Ok(())
     }
 
-    /// Check if case exists
-    pub async fn case_exists(&self, case_id: &CaseId) -> Result<bool> {
-        let key = case_id.to_string();
-        Ok(self.metadata_tree.contains_key(key.as_bytes())
-            .map_err(|e| SearchError::Internal {
-                message: format!("Database query error: {}", e),
-            })?)
+    /// Remove `citing_case_id` from the reverse posting list stored under `cited_case_id`,
+    /// deleting the entry entirely once its last citing case is removed. A no-op if
+    /// `cited_case_id` has no entry.
+    fn remove_citing_case(
+        tree: &sled::Tree,
+        cited_case_id: &CaseId,
+        citing_case_id: CaseId,
+        _confidence: CitationConfidence,
+    ) -> Result<()> {
+        let key = cited_case_id.to_string();
+        let Some(bytes) = tree.get(key.as_bytes())
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CitingCase serialization failed: {}", e),
+            })? else {
+            return Ok(());
+        };
+        let mut entries: Vec<CitingCase> = bincode::deserialize(&bytes)?;
+        entries.retain(|entry| entry.case_id != citing_case_id);
+
+        if entries.is_empty() {
+            tree.remove(key.as_bytes())
+        } else {
+            tree.insert(key.as_bytes(), bincode::serialize(&entries)?)
+        }
+        .map_err(|e| SearchError::SerializationFailed {
+            message: format!("CitingCase serialization failed: {}", e),
+        })?;
+        Ok(())
     }
 
-    /// Delete case data
-    pub async fn delete_case(&self, case_id: &CaseId) -> Result<()> {
-        let key = case_id.to_string();
-        
-        // Remove from both trees
-        self.metadata_tree.remove(key.as_bytes())
-            .map_err(|e| SearchError::Internal {
-                message: format!("Failed to delete metadata: {}", e),
-            })?;
-        
-        self.text_tree.remove(key.as_bytes())
-            .map_err(|e| SearchError::Internal {
-                message: format!("Failed to delete text: {}", e),
+    /// Store the [`CaseSummary`] projection of a case, overwriting any existing entry. Called
+    /// from [`StorageManager::store_case_metadata`] to keep `summary_tree` in sync with
+    /// `metadata_tree`; not exposed on its own since a summary without a backing full record
+    /// would be meaningless.
+    async fn store_case_summary(&self, summary: &CaseSummary) -> Result<()> {
+        let key = summary.id.to_string();
+        let value = bincode::serialize(summary)?;
+
+        self.summary_tree.insert(key.as_bytes(), value)
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CaseSummary serialization failed: {}", e),
             })?;
-        
-        tracing::info!("Deleted case: {}", case_id);
+
         Ok(())
     }
 
-    /// Batch store multiple cases
-    pub async fn store_cases_batch(&self, cases: Vec<(CaseMetadata, String)>) -> Result<usize> {
-        let mut stored_count = 0;
-        
-        for (metadata, text) in cases {
-            if let Err(e) = self.store_case_metadata(&metadata).await {
-                tracing::error!("Failed to store metadata for {}: {}", metadata.id, e);
-                continue;
-            }
-            
-            if let Err(e) = self.store_case_text(&metadata.id, &text).await {
-                tracing::error!("Failed to store text for {}: {}", metadata.id, e);
-                continue;
-            }
-            
-            stored_count += 1;
+    /// Retrieve the lightweight [`CaseSummary`] projection of a case, without hydrating the
+    /// full [`CaseMetadata`] record. Used by [`crate::search::SearchEngine`] to filter, dedup,
+    /// and compute facets over a candidate set before deciding which results actually need full
+    /// metadata and snippet generation.
+    pub async fn get_case_summary(&self, case_id: &CaseId) -> Result<Option<CaseSummary>> {
+        let key = case_id.to_string();
+
+        if let Some(value) = self.summary_tree.get(key.as_bytes())
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CaseSummary serialization failed: {}", e),
+            })? {
+            Ok(Some(bincode::deserialize(&value)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Store both textual forms of a case: `raw_text` as originally ingested (for display and
+    /// for reprocessing with improved normalizers) and `normalized_text` as used for indexing
+    /// and snippet generation. Each form is encoded per `StorageConfig::compression_algorithm`;
+    /// the two are stored separately even when identical.
+    pub async fn store_case_text(&self, case_id: &CaseId, raw_text: &str, normalized_text: &str) -> Result<()> {
+        let key = case_id.to_string();
+
+        let raw_data = self.encode_text(raw_text)?;
+        let raw_len = raw_data.len() as u64;
+        let old_raw = self.raw_text_tree.insert(key.as_bytes(), raw_data)
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CaseText serialization failed: {}", e),
+            })?;
+
+        let normalized_data = self.encode_text(normalized_text)?;
+        let normalized_len = normalized_data.len() as u64;
+        let old_normalized = self.normalized_text_tree.insert(key.as_bytes(), normalized_data)
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CaseText serialization failed: {}", e),
+            })?;
+
+        let removed = old_raw.map(|v| v.len() as u64).unwrap_or(0)
+            + old_normalized.map(|v| v.len() as u64).unwrap_or(0);
+        self.adjust_total_text_bytes(raw_len + normalized_len, removed)?;
+        self.stats.write().await.last_modified = Some(chrono::Utc::now());
+
+        tracing::debug!(
+            "Stored raw ({} bytes) and normalized ({} bytes) text for case: {}",
+            raw_text.len(), normalized_text.len(), case_id
+        );
+        Ok(())
+    }
+
+    /// Store a case's metadata and both text forms as a single all-or-nothing write across
+    /// `metadata_tree`, `raw_text_tree`, and `normalized_text_tree`, via sled's transactional
+    /// API. `store_case_metadata` followed by `store_case_text` (as `store_cases_batch` used to
+    /// do) can be interrupted between the two calls — a crash, a panic, the process being
+    /// killed — leaving a case with metadata but no text or vice versa, an orphan only
+    /// [`StorageManager::verify_integrity`] can later detect and clean up. This method makes
+    /// that interruption window disappear: either every write in the transaction lands, or none
+    /// do. It intentionally does not also fold in secondary-index/summary maintenance — those
+    /// derive from metadata alone and can always be rebuilt from it (see
+    /// [`StorageManager::rebuild_secondary_indexes`]), so their being briefly behind the
+    /// metadata/text write is recoverable in a way a metadata/text mismatch is not.
+    pub async fn store_case_atomic(&self, metadata: &CaseMetadata, raw_text: &str, normalized_text: &str) -> Result<()> {
+        let key = metadata.id.to_string();
+        let metadata_bytes = bincode::serialize(metadata)?;
+        let raw_bytes = self.encode_text(raw_text)?;
+        let normalized_bytes = self.encode_text(normalized_text)?;
+
+        let old_metadata = self.get_case_metadata(&metadata.id).await?;
+
+        let (old_raw, old_normalized) = (self.metadata_tree.as_ref(), self.raw_text_tree.as_ref(), self.normalized_text_tree.as_ref())
+            .transaction(|(tx_metadata, tx_raw, tx_normalized)| {
+                tx_metadata.insert(key.as_bytes(), metadata_bytes.clone())?;
+
+                #[cfg(test)]
+                tests::maybe_fail_case_write_transaction()?;
+
+                let old_raw = tx_raw.insert(key.as_bytes(), raw_bytes.clone())?;
+                let old_normalized = tx_normalized.insert(key.as_bytes(), normalized_bytes.clone())?;
+                Ok((old_raw, old_normalized))
+            })
+            .map_err(|e: sled::transaction::TransactionError<SearchError>| match e {
+                sled::transaction::TransactionError::Abort(err) => err,
+                sled::transaction::TransactionError::Storage(err) => SearchError::SerializationFailed {
+                    message: format!("CaseMetadata serialization failed: {}", err),
+                },
+            })?;
+
+        let secondary = self.secondary_indexes.read().await;
+        let is_new_case = old_metadata.is_none();
+        if let Some(old_metadata) = old_metadata {
+            Self::deindex_metadata_secondary(&secondary, &old_metadata)?;
+        }
+        Self::index_metadata_secondary(&secondary, metadata)?;
+        drop(secondary);
+
+        self.store_case_summary(&CaseSummary::from(metadata)).await?;
+
+        if is_new_case {
+            let new_count = self.case_count.fetch_add(1, Ordering::Relaxed) + 1;
+            self.persist_meta_counter(META_KEY_CASE_COUNT, new_count)?;
+        }
+
+        let removed = old_raw.map(|v| v.len() as u64).unwrap_or(0)
+            + old_normalized.map(|v| v.len() as u64).unwrap_or(0);
+        self.adjust_total_text_bytes((raw_bytes.len() + normalized_bytes.len()) as u64, removed)?;
+
+        let mut stats = self.stats.write().await;
+        stats.total_cases = self.case_count.load(Ordering::Relaxed) as usize;
+        stats.last_modified = Some(chrono::Utc::now());
+
+        tracing::debug!("Atomically stored metadata and text for case: {}", metadata.name);
+        Ok(())
+    }
+
+    /// Retrieve one textual form of a case. Cases ingested before raw and normalized text were
+    /// stored separately only have a `Normalized` entry; requesting `Raw` for one of those
+    /// falls back to serving the normalized text, flagged via [`CaseTextResult::served_as_fallback`]
+    /// rather than reporting the case as missing text entirely.
+    pub async fn get_case_text(&self, case_id: &CaseId, form: TextForm) -> Result<Option<CaseTextResult>> {
+        let key = case_id.to_string();
+
+        let tree = match form {
+            TextForm::Raw => &self.raw_text_tree,
+            TextForm::Normalized => &self.normalized_text_tree,
+        };
+
+        if let Some(data) = tree.get(key.as_bytes())
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("CaseText serialization failed: {}", e),
+            })? {
+            return Ok(Some(CaseTextResult { text: self.decode_text(&data)?, served_as_fallback: false }));
+        }
+
+        if form == TextForm::Raw {
+            if let Some(data) = self.normalized_text_tree.get(key.as_bytes())
+                .map_err(|e| SearchError::SerializationFailed {
+                    message: format!("CaseText serialization failed: {}", e),
+                })? {
+                return Ok(Some(CaseTextResult { text: self.decode_text(&data)?, served_as_fallback: true }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Persist [`ProcessedArtifacts`] derived from a case's [`crate::text_processing::ProcessedText`]
+    /// so a later re-index can reuse them instead of re-tokenizing (see
+    /// [`crate::trie::TrieIndex::build_from_storage`]). The stored value is prefixed with
+    /// [`PROCESSED_ARTIFACTS_SCHEMA_VERSION`] so a future format change can be detected by
+    /// [`StorageManager::get_processed`] rather than failing bincode deserialization outright.
+    pub async fn store_processed(&self, case_id: &CaseId, artifacts: &ProcessedArtifacts) -> Result<()> {
+        let key = case_id.to_string();
+        let mut value = Vec::with_capacity(1 + 256);
+        value.push(PROCESSED_ARTIFACTS_SCHEMA_VERSION);
+        value.extend(bincode::serialize(artifacts)?);
+
+        self.processed_tree.insert(key.as_bytes(), value)
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("ProcessedArtifacts serialization failed: {}", e),
+            })?;
+
+        Ok(())
+    }
+
+    /// Retrieve the [`ProcessedArtifacts`] stored by [`StorageManager::store_processed`] for a
+    /// case, if any. Returns `Ok(None)` both when nothing has been stored yet and when what's
+    /// stored was written under an older [`PROCESSED_ARTIFACTS_SCHEMA_VERSION`] — a version
+    /// mismatch is treated the same as a cache miss so callers like
+    /// [`crate::trie::TrieIndex::build_from_storage`] just fall back to re-tokenizing rather than
+    /// failing the whole rebuild over a stale cache entry.
+    pub async fn get_processed(&self, case_id: &CaseId) -> Result<Option<ProcessedArtifacts>> {
+        let key = case_id.to_string();
+
+        let Some(data) = self.processed_tree.get(key.as_bytes())
+            .map_err(|e| SearchError::SerializationFailed {
+                message: format!("ProcessedArtifacts serialization failed: {}", e),
+            })? else {
+            return Ok(None);
+        };
+
+        let Some((&version, body)) = data.split_first() else {
+            return Ok(None);
+        };
+
+        if version != PROCESSED_ARTIFACTS_SCHEMA_VERSION {
+            tracing::warn!(
+                "Discarding processed artifacts for case {}: schema version {} does not match current version {}",
+                case_id,
+                version,
+                PROCESSED_ARTIFACTS_SCHEMA_VERSION
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(bincode::deserialize(body)?))
+    }
+
+    /// List all case IDs
+    pub async fn list_case_ids(&self) -> Result<Vec<CaseId>> {
+        let mut case_ids = Vec::new();
+        
+        for result in self.metadata_tree.iter() {
+            let (key, _) = result.map_err(|e| SearchError::Internal {
+                message: format!("Database iteration error: {}", e),
+            })?;
+            
+            let key_str = String::from_utf8(key.to_vec())
+                .map_err(|e| SearchError::UnsupportedEncoding {
+                    encoding: format!("UTF-8: {}", e),
+                })?;
+            
+            let case_id = uuid::Uuid::parse_str(&key_str)
+                .map_err(|e| SearchError::Internal {
+                    message: format!("Invalid case ID format: {}", e),
+                })?;
+            
+            case_ids.push(case_id);
         }
         
+        Ok(case_ids)
+    }
+
+    /// Page through stored [`CaseMetadata`] by raw position, skipping `offset` entries and
+    /// deserializing up to `limit` after that — an offset/limit alternative to
+    /// [`StorageManager::scan_cases`] for callers that want simple page numbers rather than an
+    /// opaque cursor and can tolerate `skip`'s O(offset) cost (sled iteration order is stable
+    /// across calls as long as the tree isn't concurrently mutated, but re-skipping from zero on
+    /// every page still means later pages cost more than earlier ones — prefer `scan_cases` for
+    /// deep pagination over the whole corpus, e.g. an index rebuild).
+    pub async fn iter_cases(&self, offset: usize, limit: usize) -> Result<Vec<CaseMetadata>> {
+        let mut cases = Vec::with_capacity(limit.min(1024));
+
+        for result in self.metadata_tree.iter().skip(offset).take(limit) {
+            let (_, value) = result.map_err(|e| SearchError::Internal {
+                message: format!("Database iteration error: {}", e),
+            })?;
+            cases.push(bincode::deserialize(&value)?);
+        }
+
+        Ok(cases)
+    }
+
+    /// Page through stored [`CaseMetadata`] with a cursor rather than an offset, so a full sweep
+    /// (the future index rebuild this exists for) never re-walks earlier pages the way repeated
+    /// [`StorageManager::iter_cases`] calls would. `cursor` is the last case id returned by the
+    /// previous call (`None` to start from the beginning); the returned `next_cursor` is `Some`
+    /// only when the page came back full, meaning there may be more to fetch. Uses a sled range
+    /// scan starting just past the cursor's key rather than `metadata_tree.iter().skip(..)`, so
+    /// each page costs `O(limit)` regardless of how far into the corpus the cursor already is.
+    pub async fn scan_cases(
+        &self,
+        cursor: Option<CaseId>,
+        limit: usize,
+    ) -> Result<(Vec<CaseMetadata>, Option<CaseId>)> {
+        let range = match cursor {
+            Some(after) => {
+                // Exclusive start: sled has no "range starting just after this key" primitive,
+                // so append a 0x00 byte to the cursor's key bytes. Every real key sorts after
+                // this one (UUID's string form never contains a trailing NUL), so it's a tight
+                // exclusive lower bound rather than an inclusive one that would re-yield `after`.
+                let mut lower = after.to_string().into_bytes();
+                lower.push(0u8);
+                self.metadata_tree.range(lower..)
+            }
+            None => self.metadata_tree.range::<Vec<u8>, _>(..),
+        };
+
+        let mut cases = Vec::with_capacity(limit.min(1024));
+        let mut last_id = None;
+
+        for entry in range.take(limit) {
+            let (key, value) = entry.map_err(|e| SearchError::Internal {
+                message: format!("Database iteration error: {}", e),
+            })?;
+            let case_id = Self::case_id_from_key(&key)?;
+            cases.push(bincode::deserialize(&value)?);
+            last_id = Some(case_id);
+        }
+
+        let next_cursor = if cases.len() == limit { last_id } else { None };
+        Ok((cases, next_cursor))
+    }
+
+    /// Check if case exists
+    pub async fn case_exists(&self, case_id: &CaseId) -> Result<bool> {
+        let key = case_id.to_string();
+        Ok(self.metadata_tree.contains_key(key.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Database query error: {}", e),
+            })?)
+    }
+
+    /// Delete case data
+    pub async fn delete_case(&self, case_id: &CaseId) -> Result<()> {
+        let key = case_id.to_string();
+
+        let old_metadata = self.get_case_metadata(case_id).await?;
+
+        // Remove from both trees
+        self.metadata_tree.remove(key.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to delete metadata: {}", e),
+            })?;
+
+        let removed_normalized = self.normalized_text_tree.remove(key.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to delete normalized text: {}", e),
+            })?;
+
+        let removed_raw = self.raw_text_tree.remove(key.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to delete raw text: {}", e),
+            })?;
+
+        self.summary_tree.remove(key.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to delete case summary: {}", e),
+            })?;
+
+        let removed_text_bytes = removed_normalized.map(|v| v.len() as u64).unwrap_or(0)
+            + removed_raw.map(|v| v.len() as u64).unwrap_or(0);
+        self.adjust_total_text_bytes(0, removed_text_bytes)?;
+
+        if let Some(metadata) = old_metadata {
+            let secondary = self.secondary_indexes.read().await;
+            Self::deindex_metadata_secondary(&secondary, &metadata)?;
+            let new_count = self.case_count.fetch_sub(1, Ordering::Relaxed) - 1;
+            self.persist_meta_counter(META_KEY_CASE_COUNT, new_count)?;
+        }
+
+        self.stats.write().await.last_modified = Some(chrono::Utc::now());
+
+        // Drop this case's outgoing citation edges (deindexing them from citing_edges_tree) and
+        // its own reverse "who cites me" entry — nothing can usefully look either up once the
+        // case itself is gone. A citing case's own outgoing edge list may still reference this
+        // deleted id afterward, the same kind of stale reference `get_cases_metadata` already
+        // tolerates elsewhere.
+        self.store_citation_edges(*case_id, Vec::new()).await?;
+        self.citing_edges_tree.remove(key.as_bytes())
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to delete citing-cases entry: {}", e),
+            })?;
+
+        tracing::info!("Deleted case: {}", case_id);
+        Ok(())
+    }
+
+    /// Batch store multiple cases, each via [`StorageManager::store_case_atomic`] so a failure
+    /// on one case can never leave it with metadata but no text (or vice versa) — only a whole
+    /// case is skipped, never half of one.
+    pub async fn store_cases_batch(&self, cases: Vec<(CaseMetadata, String)>) -> Result<usize> {
+        let mut stored_count = 0;
+
+        for (metadata, text) in cases {
+            if let Err(e) = self.store_case_atomic(&metadata, &text, &text).await {
+                tracing::error!("Failed to store case {}: {}", metadata.id, e);
+                continue;
+            }
+
+            stored_count += 1;
+        }
+
         // Flush to disk
         self.db.flush_async().await
             .map_err(|e| SearchError::Internal {
                 message: format!("Failed to flush database: {}", e),
             })?;
-        
+
         // Update statistics
         self.update_stats().await?;
-        
+
         tracing::info!("Batch stored {} cases", stored_count);
         Ok(stored_count)
     }
 
-    /// Compress text data
-    fn compress_text(&self, text: &str) -> Result<Vec<u8>> {
+    /// Scan `metadata_tree` against `raw_text_tree`/`normalized_text_tree` for orphans — cases
+    /// with one but not the other, the failure mode [`StorageManager::store_case_atomic`] exists
+    /// to prevent going forward, and that pre-existing data (written before that method existed,
+    /// or written by a caller that still uses `store_case_metadata`/`store_case_text`
+    /// separately) can still have. Orphans found are deleted rather than left in place: a
+    /// metadata-only case is unsearchable-by-text anyway and confuses `list_case_ids`, and a
+    /// text-only case is unreachable (nothing indexes it) dead weight.
+    pub async fn verify_integrity(&self) -> Result<IntegrityReport> {
+        let metadata_ids: HashSet<CaseId> = self.list_case_ids().await?.into_iter().collect();
+
+        let mut text_ids = HashSet::new();
+        for tree in [&self.raw_text_tree, &self.normalized_text_tree] {
+            for entry in tree.iter() {
+                let (key, _) = entry.map_err(|e| SearchError::Internal {
+                    message: format!("Database iteration error: {}", e),
+                })?;
+                text_ids.insert(Self::case_id_from_key(&key)?);
+            }
+        }
+
+        let mut report = IntegrityReport {
+            cases_scanned: metadata_ids.union(&text_ids).count(),
+            ..Default::default()
+        };
+
+        for case_id in metadata_ids.difference(&text_ids).copied().collect::<Vec<_>>() {
+            tracing::warn!("verify_integrity: case {} has metadata but no text; removing orphan", case_id);
+            self.delete_case(&case_id).await?;
+            report.metadata_without_text.push(case_id);
+        }
+
+        for case_id in text_ids.difference(&metadata_ids).copied().collect::<Vec<_>>() {
+            tracing::warn!("verify_integrity: case {} has text but no metadata; removing orphan", case_id);
+            let key = case_id.to_string();
+            self.raw_text_tree.remove(key.as_bytes())
+                .map_err(|e| SearchError::Internal { message: format!("Failed to delete orphaned raw text: {}", e) })?;
+            self.normalized_text_tree.remove(key.as_bytes())
+                .map_err(|e| SearchError::Internal { message: format!("Failed to delete orphaned normalized text: {}", e) })?;
+            report.text_without_metadata.push(case_id);
+        }
+
+        Ok(report)
+    }
+
+    /// Stream every stored case as one JSON [`CaseMetadata`] object per line to `writer` — the
+    /// same schema [`crate::index_build::build_snapshot`] reads, so an export can be fed
+    /// straight into `index-build --input` or [`StorageManager::import_jsonl`] without a
+    /// conversion step. `include_text` controls whether each line's `full_text` is populated or
+    /// blanked to keep a metadata-only export lean; every other field is always written in full.
+    /// Pages through [`StorageManager::scan_cases`] rather than [`StorageManager::iter_cases`],
+    /// so exporting a large corpus doesn't re-walk earlier pages.
+    pub async fn export_jsonl<W: std::io::Write>(&self, mut writer: W, include_text: bool) -> Result<usize> {
+        let mut exported = 0usize;
+        let mut cursor = None;
+
+        loop {
+            let (page, next_cursor) = self.scan_cases(cursor, 256).await?;
+
+            for mut metadata in page {
+                if !include_text {
+                    metadata.full_text.clear();
+                }
+                serde_json::to_writer(&mut writer, &metadata)?;
+                writer.write_all(b"\n")?;
+                exported += 1;
+            }
+
+            match next_cursor {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+
+        Ok(exported)
+    }
+
+    /// Read `reader` line by line as [`StorageManager::export_jsonl`]'s output (or any JSONL of
+    /// [`CaseMetadata`] records, e.g. an `index-build --input` file), storing each valid line via
+    /// [`StorageManager::store_case_atomic`]. Blank lines are skipped silently; a line that fails
+    /// to parse, or whose case id already exists under [`ImportConflictPolicy::Skip`], doesn't
+    /// stop the import — it's recorded in the returned [`ImportReport`] and the next line is
+    /// tried. A record exported with `include_text: false` round-trips with an empty `full_text`,
+    /// same as any other field value the export chose to blank.
+    pub async fn import_jsonl<R: std::io::BufRead>(
+        &self,
+        reader: R,
+        conflict_policy: ImportConflictPolicy,
+    ) -> Result<ImportReport> {
+        let mut report = ImportReport::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    report.failed.push(ImportFailure {
+                        line_number,
+                        reason: format!("Failed to read line: {}", e),
+                    });
+                    continue;
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let metadata: CaseMetadata = match serde_json::from_str(&line) {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    report.failed.push(ImportFailure {
+                        line_number,
+                        reason: format!("Invalid JSON: {}", e),
+                    });
+                    continue;
+                }
+            };
+
+            let already_exists = self.case_exists(&metadata.id).await?;
+            if already_exists && conflict_policy == ImportConflictPolicy::Skip {
+                report.skipped += 1;
+                continue;
+            }
+
+            match self.store_case_atomic(&metadata, &metadata.full_text, &metadata.full_text).await {
+                Ok(()) => {
+                    if already_exists {
+                        report.updated += 1;
+                    } else {
+                        report.inserted += 1;
+                    }
+                }
+                Err(e) => {
+                    report.failed.push(ImportFailure { line_number, reason: e.to_string() });
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Parse a sled tree key back into the `CaseId` it was stored under; shared by
+    /// [`StorageManager::list_case_ids`] and [`StorageManager::verify_integrity`].
+    fn case_id_from_key(key: &[u8]) -> Result<CaseId> {
+        let key_str = String::from_utf8(key.to_vec())
+            .map_err(|e| SearchError::UnsupportedEncoding {
+                encoding: format!("UTF-8: {}", e),
+            })?;
+        uuid::Uuid::parse_str(&key_str)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Invalid case ID format: {}", e),
+            })
+    }
+
+    /// Gzip-compress `text` at the default compression level
+    fn compress_gzip(text: &str) -> Result<Vec<u8>> {
         use std::io::Write;
-        
+
         let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
         encoder.write_all(text.as_bytes())
             .map_err(|e| SearchError::Internal {
                 message: format!("Compression failed: {}", e),
             })?;
-        
+
         encoder.finish()
             .map_err(|e| SearchError::Internal {
                 message: format!("Compression finish failed: {}", e),
             })
     }
 
-    /// Decompress text data
-    fn decompress_text(&self, data: &[u8]) -> Result<String> {
+    /// Decompress a gzip payload produced by [`StorageManager::compress_gzip`]
+    fn decompress_gzip(data: &[u8]) -> Result<String> {
         use std::io::Read;
-        
+
         let mut decoder = flate2::read::GzDecoder::new(data);
         let mut decompressed = String::new();
         decoder.read_to_string(&mut decompressed)
             .map_err(|e| SearchError::Internal {
                 message: format!("Decompression failed: {}", e),
             })?;
-        
+
         Ok(decompressed)
     }
 
-    /// Update storage statistics
+    /// Encode text for storage per [`StorageConfig::compression_algorithm`], prefixed with a
+    /// one-byte tag ([`ENCODING_TAG_NONE`]/[`ENCODING_TAG_GZIP`]/[`ENCODING_TAG_ZSTD`]) naming
+    /// which algorithm produced it. The tag means [`StorageManager::decode_text`] never has to
+    /// trust the *current* config to read back a value written under a *previous* one — a
+    /// database that switched `compression_algorithm` mid-lifetime still reads every value it
+    /// has ever written.
+    fn encode_text(&self, text: &str) -> Result<Vec<u8>> {
+        match self.config.compression_algorithm {
+            crate::config::CompressionAlgorithm::None => {
+                let mut out = Vec::with_capacity(1 + text.len());
+                out.push(ENCODING_TAG_NONE);
+                out.extend_from_slice(text.as_bytes());
+                Ok(out)
+            }
+            crate::config::CompressionAlgorithm::Gzip => {
+                let mut out = vec![ENCODING_TAG_GZIP];
+                out.extend(Self::compress_gzip(text)?);
+                Ok(out)
+            }
+            crate::config::CompressionAlgorithm::Zstd => {
+                let mut out = vec![ENCODING_TAG_ZSTD];
+                out.extend(
+                    zstd::encode_all(text.as_bytes(), self.config.compression_level)
+                        .map_err(|e| SearchError::Internal {
+                            message: format!("zstd compression failed: {}", e),
+                        })?,
+                );
+                Ok(out)
+            }
+        }
+    }
+
+    /// Decode text read back from storage, the inverse of [`StorageManager::encode_text`].
+    /// Values written before per-value tags existed have no tag byte at all — either raw UTF-8
+    /// (if compression was off) or a bare gzip stream (if it was on) — so anything not starting
+    /// with a recognized tag byte is treated as one of those two, distinguished by whether it
+    /// starts with gzip's own magic bytes.
+    fn decode_text(&self, data: &[u8]) -> Result<String> {
+        match data.first() {
+            Some(&ENCODING_TAG_NONE) => String::from_utf8(data[1..].to_vec())
+                .map_err(|e| SearchError::UnsupportedEncoding { encoding: format!("UTF-8: {}", e) }),
+            Some(&ENCODING_TAG_GZIP) => Self::decompress_gzip(&data[1..]),
+            Some(&ENCODING_TAG_ZSTD) => zstd::decode_all(&data[1..])
+                .map_err(|e| SearchError::Internal { message: format!("zstd decompression failed: {}", e) })
+                .and_then(|bytes| {
+                    String::from_utf8(bytes)
+                        .map_err(|e| SearchError::UnsupportedEncoding { encoding: format!("UTF-8: {}", e) })
+                }),
+            _ if data.starts_with(&GZIP_MAGIC) => Self::decompress_gzip(data),
+            _ => String::from_utf8(data.to_vec())
+                .map_err(|e| SearchError::UnsupportedEncoding { encoding: format!("UTF-8: {}", e) }),
+        }
+    }
+
+    /// Update storage statistics. `total_cases`/`total_text_bytes` come from the running
+    /// counters maintained by every store/delete call, not by scanning `metadata_tree` or either
+    /// text tree — this is what makes `get_stats` (and the `/stats` endpoint behind it) cheap
+    /// regardless of how large the store is. `database_size_bytes` is the one field still read
+    /// fresh from sled on every call rather than cached: it reflects on-disk effects (page
+    /// allocation, compaction) our own counters don't track, so it's sled's `size_on_disk` — not
+    /// a maintained counter — that reconciles `total_size_bytes` against reality.
     async fn update_stats(&self) -> Result<()> {
         let mut stats = self.stats.write().await;
-        
-        stats.total_cases = self.metadata_tree.len();
+
+        stats.total_cases = self.count_cases();
         stats.database_size_bytes = self.db.size_on_disk()
             .map_err(|e| SearchError::Internal {
                 message: format!("Failed to get database size: {}", e),
             })?;
-        
-        // Calculate total size including text
-        let mut total_size = stats.database_size_bytes;
-        for result in self.text_tree.iter() {
-            if let Ok((_, value)) = result {
-                total_size += value.len() as u64;
-            }
-        }
-        stats.total_size_bytes = total_size;
-        
+        stats.total_size_bytes = stats.database_size_bytes + self.total_text_bytes.load(Ordering::Relaxed);
+
         Ok(())
     }
 
+    /// Underlying sled database handle, for callers that need to open their own trees
+    /// (e.g. the model migration tracker)
+    pub(crate) fn db(&self) -> Arc<sled::Db> {
+        self.db.clone()
+    }
+
     /// Health check
     pub async fn health_check(&self) -> Result<()> {
         // Test basic database operations
@@ -355,24 +1588,1125 @@ impl StorageManager {
         Ok(self.stats.read().await.clone())
     }
 
-    /// Create backup
+    /// Snapshot every tree in the database (via [`sled::Db::export`]) into a single
+    /// gzip-compressed archive at `backup_path`. The archive format is just a bincode-encoded
+    /// `Vec<(collection_type, collection_name, records)>` — the same shape `sled::Db::export`
+    /// returns, made owned so it can be serialized — so [`StorageManager::restore_from_backup`]
+    /// can hand it straight to [`sled::Db::import`] without knowing this database's specific
+    /// tree layout.
     pub async fn create_backup(&self, backup_path: &Path) -> Result<()> {
         // Ensure backup directory exists
         if let Some(parent) = backup_path.parent() {
             tokio::fs::create_dir_all(parent).await?;
         }
-        
-        // Export database
-        self.db.export_iter()
-            .map_err(|e| SearchError::Internal {
-                message: format!("Backup export failed: {}", e),
-            })?;
-        
-        // Update backup timestamp
+
+        let export: Vec<(Vec<u8>, Vec<u8>, Vec<Vec<Vec<u8>>>)> = self.db.export()
+            .into_iter()
+            .map(|(collection_type, collection_name, records)| {
+                (collection_type, collection_name, records.collect())
+            })
+            .collect();
+
+        let payload = bincode::serialize(&export)?;
+        let archive = Self::compress_backup_archive(&payload)?;
+
+        tokio::fs::write(backup_path, archive).await?;
+
         let mut stats = self.stats.write().await;
         stats.last_backup = Some(chrono::Utc::now());
-        
+
         tracing::info!("Created backup at: {:?}", backup_path);
         Ok(())
     }
+
+    /// Restore a gzip-compressed archive written by [`StorageManager::create_backup`]. sled
+    /// can't have the files backing an already-open `Db` swapped out from under it, so this
+    /// rebuilds the archived trees into a fresh sibling directory next to the current database
+    /// and hands back a `StorageManager` freshly opened on it — it never touches `self`.
+    /// Callers holding an `Arc<StorageManager>` (see `main.rs`'s `AppState`) are responsible for
+    /// swapping their reference to the returned instance once this resolves.
+    pub async fn restore_from_backup(&self, archive_path: &Path) -> Result<StorageManager> {
+        let archive = tokio::fs::read(archive_path).await?;
+        let payload = Self::decompress_backup_archive(&archive)?;
+        let export: Vec<(Vec<u8>, Vec<u8>, Vec<Vec<Vec<u8>>>)> = bincode::deserialize(&payload)?;
+
+        if !export.iter().any(|(_, name, _)| name == b"case_metadata") {
+            return Err(SearchError::Internal {
+                message: format!(
+                    "Backup archive {:?} is missing the case metadata tree; refusing to restore",
+                    archive_path
+                ),
+            });
+        }
+
+        let restore_dir = Self::restore_directory_for(&self.config.db_path);
+        tokio::fs::create_dir_all(&restore_dir).await?;
+
+        let restored_db = sled::open(&restore_dir)
+            .map_err(|e| SearchError::DatabaseConnectionFailed {
+                db_path: restore_dir.to_string_lossy().to_string(),
+                reason: format!("Failed to open restore directory: {}", e),
+            })?;
+        restored_db.import(
+            export.into_iter()
+                .map(|(collection_type, collection_name, records)| {
+                    (collection_type, collection_name, records.into_iter())
+                })
+                .collect(),
+        );
+        restored_db.flush_async().await
+            .map_err(|e| SearchError::Internal {
+                message: format!("Failed to flush restored database: {}", e),
+            })?;
+        drop(restored_db);
+
+        let mut restored_config = self.config.clone();
+        restored_config.db_path = restore_dir;
+        let restored = StorageManager::new(restored_config).await?;
+
+        tracing::info!("Restored backup {:?} into {:?}", archive_path, restored.config.db_path);
+        Ok(restored)
+    }
+
+    /// Where [`StorageManager::restore_from_backup`] rebuilds a backup's trees: a sibling of the
+    /// live database directory, named after it plus a UTC timestamp, so a restore never risks
+    /// colliding with (or overwriting) the database that's still open.
+    fn restore_directory_for(db_path: &Path) -> PathBuf {
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+        let name = db_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| "db".to_string());
+        db_path.with_file_name(format!("{name}-restored-{stamp}"))
+    }
+
+    /// Write a timestamped backup under `BackupConfig::backup_dir` and enforce
+    /// `BackupConfig::max_backups` by deleting the oldest archives beyond that count. Backup
+    /// filenames sort lexicographically in creation order (`backup-{RFC3339-ish timestamp}.sled.gz`),
+    /// so "oldest" is just "first after a plain sort" — no need to stat each file's mtime.
+    pub async fn run_scheduled_backup(&self) -> Result<PathBuf> {
+        let backup_dir = &self.config.backup.backup_dir;
+        tokio::fs::create_dir_all(backup_dir).await?;
+
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+        let backup_path = backup_dir.join(format!("backup-{stamp}.sled.gz"));
+        self.create_backup(&backup_path).await?;
+
+        let mut archives = Vec::new();
+        let mut entries = tokio::fs::read_dir(backup_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("gz") {
+                archives.push(path);
+            }
+        }
+        archives.sort();
+
+        let max_backups = self.config.backup.max_backups as usize;
+        if archives.len() > max_backups {
+            for stale in &archives[..archives.len() - max_backups] {
+                if let Err(e) = tokio::fs::remove_file(stale).await {
+                    tracing::warn!("Failed to remove stale backup {:?}: {}", stale, e);
+                } else {
+                    tracing::info!("Removed stale backup {:?} (max_backups = {})", stale, max_backups);
+                }
+            }
+        }
+
+        Ok(backup_path)
+    }
+
+    /// Spawn a background task that calls [`StorageManager::run_scheduled_backup`] every
+    /// `BackupConfig::interval_hours`, for as long as the process runs — a no-op when
+    /// `BackupConfig::enabled` is false, matching `spawn_query_cache_sweep`'s one-shot,
+    /// fire-and-forget style over in `search.rs`.
+    pub fn spawn_periodic_backups(self: &Arc<Self>) {
+        if !self.config.backup.enabled {
+            return;
+        }
+
+        let storage = self.clone();
+        let interval_hours = self.config.backup.interval_hours.max(1);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_hours * 3600));
+            interval.tick().await; // first tick fires immediately; skip it so backups start one interval in
+            loop {
+                interval.tick().await;
+                match storage.run_scheduled_backup().await {
+                    Ok(path) => tracing::info!("Scheduled backup written to {:?}", path),
+                    Err(e) => tracing::error!("Scheduled backup failed: {}", e),
+                }
+            }
+        });
+    }
+
+    /// Gzip-compress a backup archive payload; mirrors [`StorageManager::compress_gzip`] but
+    /// over the raw bincode bytes of a whole-database export rather than one case's text.
+    fn compress_backup_archive(data: &[u8]) -> Result<Vec<u8>> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Backup compression failed: {}", e),
+            })?;
+
+        encoder.finish()
+            .map_err(|e| SearchError::Internal {
+                message: format!("Backup compression finish failed: {}", e),
+            })
+    }
+
+    /// Inverse of [`StorageManager::compress_backup_archive`].
+    fn decompress_backup_archive(data: &[u8]) -> Result<Vec<u8>> {
+        use std::io::Read;
+
+        let mut decoder = flate2::read::GzDecoder::new(data);
+        let mut decompressed = Vec::new();
+        decoder.read_to_end(&mut decompressed)
+            .map_err(|e| SearchError::Internal {
+                message: format!("Backup decompression failed: {}", e),
+            })?;
+
+        Ok(decompressed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    thread_local! {
+        /// Test-only hook: when set, `store_case_atomic`'s transaction aborts right after the
+        /// metadata write and before either text write, letting tests simulate a fault "between
+        /// writes" without a real crash and confirm the transaction rolled the metadata write
+        /// back too, rather than committing it alone. `#[tokio::test]` runs on a
+        /// single-threaded runtime by default, so a task spawned during the test observes the
+        /// same thread-local as the test itself (same reasoning as `search::tests`' delay hooks).
+        static FAIL_CASE_WRITE_TRANSACTION: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    }
+
+    pub(super) fn maybe_fail_case_write_transaction() -> sled::transaction::ConflictableTransactionResult<(), SearchError> {
+        if FAIL_CASE_WRITE_TRANSACTION.with(|cell| cell.get()) {
+            Err(sled::transaction::ConflictableTransactionError::Abort(SearchError::Internal {
+                message: "injected failure between metadata and text writes (test-injected)".to_string(),
+            }))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_fail_case_write_transaction(fail: bool) {
+        FAIL_CASE_WRITE_TRANSACTION.with(|cell| cell.set(fail));
+    }
+
+    async fn test_storage() -> StorageManager {
+        let mut config = Config::default();
+        config.storage.db_path = std::env::temp_dir().join(format!("storage-test-{}", uuid::Uuid::new_v4()));
+        StorageManager::new(config.storage).await.unwrap()
+    }
+
+    async fn test_storage_with_algorithm(algorithm: crate::config::CompressionAlgorithm) -> StorageManager {
+        let mut config = Config::default();
+        config.storage.db_path = std::env::temp_dir().join(format!("storage-test-{}", uuid::Uuid::new_v4()));
+        config.storage.compression_algorithm = algorithm;
+        StorageManager::new(config.storage).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_case_text_returns_the_form_it_was_stored_under() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_text(&case_id, "Raw   text.", "raw text.").await.unwrap();
+
+        let raw = storage.get_case_text(&case_id, TextForm::Raw).await.unwrap().unwrap();
+        assert_eq!(raw.text, "Raw   text.");
+        assert!(!raw.served_as_fallback);
+
+        let normalized = storage.get_case_text(&case_id, TextForm::Normalized).await.unwrap().unwrap();
+        assert_eq!(normalized.text, "raw text.");
+        assert!(!normalized.served_as_fallback);
+    }
+
+    #[tokio::test]
+    async fn test_get_case_text_missing_case_is_none_not_error() {
+        let storage = test_storage().await;
+        let result = storage.get_case_text(&uuid::Uuid::new_v4(), TextForm::Raw).await.unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_encode_decode_round_trips_under_every_compression_algorithm() {
+        for algorithm in [
+            crate::config::CompressionAlgorithm::None,
+            crate::config::CompressionAlgorithm::Gzip,
+            crate::config::CompressionAlgorithm::Zstd,
+        ] {
+            let storage = test_storage_with_algorithm(algorithm).await;
+            let encoded = storage.encode_text("Separate educational facilities are inherently unequal.").unwrap();
+            let decoded = storage.decode_text(&encoded).unwrap();
+            assert_eq!(decoded, "Separate educational facilities are inherently unequal.", "round trip failed for {:?}", algorithm);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decode_text_reads_a_value_written_under_a_different_algorithm() {
+        // A database that switches `compression_algorithm` must still read values it wrote
+        // under the old setting, since each value is tagged with its own algorithm.
+        let gzip_storage = test_storage_with_algorithm(crate::config::CompressionAlgorithm::Gzip).await;
+        let gzip_encoded = gzip_storage.encode_text("gzip-encoded text").unwrap();
+
+        let zstd_storage = test_storage_with_algorithm(crate::config::CompressionAlgorithm::Zstd).await;
+        assert_eq!(zstd_storage.decode_text(&gzip_encoded).unwrap(), "gzip-encoded text");
+    }
+
+    #[tokio::test]
+    async fn test_decode_text_reads_a_legacy_gzip_value_with_no_tag_byte() {
+        let storage = test_storage().await;
+        let legacy = StorageManager::compress_gzip("legacy gzip text, no tag byte").unwrap();
+        assert_eq!(storage.decode_text(&legacy).unwrap(), "legacy gzip text, no tag byte");
+    }
+
+    #[tokio::test]
+    async fn test_decode_text_reads_a_legacy_plain_value_with_no_tag_byte() {
+        let storage = test_storage().await;
+        let legacy = b"legacy plain text, no tag byte".to_vec();
+        assert_eq!(storage.decode_text(&legacy).unwrap(), "legacy plain text, no tag byte");
+    }
+
+    fn fixture_processed_artifacts() -> crate::text_processing::ProcessedArtifacts {
+        crate::text_processing::ProcessedArtifacts {
+            tokens: Vec::new(),
+            sentences: vec![crate::text_processing::SentenceSpan {
+                text: "Separate educational facilities are inherently unequal.".to_string(),
+                start: 0,
+            }],
+            citations: Vec::new(),
+            legal_terms: Vec::new(),
+            entities: Vec::new(),
+            stats: crate::text_processing::TextStats {
+                char_count: 57,
+                word_count: 6,
+                sentence_count: 1,
+                paragraph_count: 1,
+                unique_words: 6,
+                reading_level: None,
+                language: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_processed_round_trips_artifacts() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        let artifacts = fixture_processed_artifacts();
+
+        storage.store_processed(&case_id, &artifacts).await.unwrap();
+        let retrieved = storage.get_processed(&case_id).await.unwrap().unwrap();
+
+        assert_eq!(retrieved.sentences.len(), artifacts.sentences.len());
+        assert_eq!(retrieved.sentences[0].text, artifacts.sentences[0].text);
+        assert_eq!(retrieved.stats.word_count, artifacts.stats.word_count);
+    }
+
+    #[tokio::test]
+    async fn test_get_processed_missing_case_is_none_not_error() {
+        let storage = test_storage().await;
+        let result = storage.get_processed(&uuid::Uuid::new_v4()).await.unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_processed_discards_entries_written_under_a_different_schema_version() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+
+        // Simulate an entry written under a schema version this build no longer understands.
+        let mut stale = vec![PROCESSED_ARTIFACTS_SCHEMA_VERSION.wrapping_add(1)];
+        stale.extend(bincode::serialize(&fixture_processed_artifacts()).unwrap());
+        storage.processed_tree.insert(case_id.to_string().as_bytes(), stale).unwrap();
+
+        let result = storage.get_processed(&case_id).await.unwrap();
+        assert!(result.is_none(), "a schema version mismatch should be treated as a cache miss");
+    }
+
+    #[tokio::test]
+    async fn test_get_case_text_raw_falls_back_to_normalized_for_pre_migration_cases() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+
+        // Simulate a case ingested before raw/normalized text were stored separately: only the
+        // normalized tree has an entry.
+        let data = storage.encode_text("legacy normalized text").unwrap();
+        storage.normalized_text_tree.insert(case_id.to_string().as_bytes(), data).unwrap();
+
+        let raw = storage.get_case_text(&case_id, TextForm::Raw).await.unwrap().unwrap();
+        assert_eq!(raw.text, "legacy normalized text");
+        assert!(raw.served_as_fallback);
+
+        let normalized = storage.get_case_text(&case_id, TextForm::Normalized).await.unwrap().unwrap();
+        assert_eq!(normalized.text, "legacy normalized text");
+        assert!(!normalized.served_as_fallback);
+    }
+
+    #[tokio::test]
+    async fn test_delete_case_removes_both_text_forms() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_text(&case_id, "raw", "normalized").await.unwrap();
+
+        storage.delete_case(&case_id).await.unwrap();
+
+        assert!(storage.get_case_text(&case_id, TextForm::Raw).await.unwrap().is_none());
+        assert!(storage.get_case_text(&case_id, TextForm::Normalized).await.unwrap().is_none());
+    }
+
+    fn fixture_metadata(case_id: CaseId) -> CaseMetadata {
+        CaseMetadata {
+            id: case_id,
+            name: "Brown v Board of Education".to_string(),
+            citation: "347 U.S. 483".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1954, 5, 17).unwrap(),
+            judges: vec!["Warren".to_string()],
+            topics: vec![],
+            full_text: "Separate educational facilities are inherently unequal.".to_string(),
+            jurisdiction: crate::Jurisdiction::Federal,
+            citations: vec!["347 U.S. 483".to_string()],
+            docket_number: Some("1".to_string()),
+            source_url: None,
+            word_count: 8,
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_case_metadata_populates_secondary_indexes() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        assert_eq!(
+            storage.find_case_ids_by(SecondaryIndexField::Citation, "347 U.S. 483").await.unwrap(),
+            vec![case_id]
+        );
+        assert_eq!(
+            storage.find_case_ids_by(SecondaryIndexField::Court, "supreme court").await.unwrap(),
+            vec![case_id]
+        );
+        assert_eq!(
+            storage.find_case_ids_by(SecondaryIndexField::Judge, "warren").await.unwrap(),
+            vec![case_id]
+        );
+        assert_eq!(
+            storage.find_case_ids_by(SecondaryIndexField::DocketNumber, "1").await.unwrap(),
+            vec![case_id]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_case_removes_secondary_index_entries() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        storage.delete_case(&case_id).await.unwrap();
+
+        assert!(storage.find_case_ids_by(SecondaryIndexField::Citation, "347 U.S. 483").await.unwrap().is_empty());
+        assert!(storage.find_case_ids_by(SecondaryIndexField::Judge, "warren").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_secondary_indexes_restores_consistency_after_corruption() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        // Corrupt the court index directly, simulating drift from a partial failure: the
+        // citation index still has the case, but the court index has been wiped.
+        {
+            let secondary = storage.secondary_indexes.read().await;
+            secondary.court.remove(StorageManager::secondary_index_key("Supreme Court")).unwrap();
+        }
+        assert!(storage.find_case_ids_by(SecondaryIndexField::Court, "supreme court").await.unwrap().is_empty());
+
+        let stats = storage.rebuild_secondary_indexes().await.unwrap();
+        assert_eq!(stats.cases_scanned, 1);
+
+        assert_eq!(
+            storage.find_case_ids_by(SecondaryIndexField::Court, "supreme court").await.unwrap(),
+            vec![case_id]
+        );
+        assert_eq!(
+            storage.find_case_ids_by(SecondaryIndexField::Citation, "347 U.S. 483").await.unwrap(),
+            vec![case_id]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_typed_lookup_methods_find_a_stored_case() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        assert_eq!(storage.find_by_citation("347 U.S. 483").await.unwrap(), vec![case_id]);
+        assert_eq!(storage.find_by_court("supreme court").await.unwrap(), vec![case_id]);
+        assert_eq!(storage.find_by_docket("1").await.unwrap(), vec![case_id]);
+        assert_eq!(storage.find_by_year_range(1954, 1954).await.unwrap(), vec![case_id]);
+        assert!(storage.find_by_year_range(1960, 1970).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_year_range_spans_multiple_years_and_excludes_outsiders() {
+        let storage = test_storage().await;
+        let mut metadata = fixture_metadata(uuid::Uuid::new_v4());
+        metadata.decision_date = chrono::NaiveDate::from_ymd_opt(1953, 12, 31).unwrap();
+        let before_id = metadata.id;
+        storage.store_case_metadata(&metadata).await.unwrap();
+
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let mut metadata = fixture_metadata(uuid::Uuid::new_v4());
+        metadata.decision_date = chrono::NaiveDate::from_ymd_opt(1955, 1, 1).unwrap();
+        let after_id = metadata.id;
+        storage.store_case_metadata(&metadata).await.unwrap();
+
+        let mut in_range = storage.find_by_year_range(1954, 1955).await.unwrap();
+        in_range.sort();
+        let mut expected = vec![case_id, after_id];
+        expected.sort();
+        assert_eq!(in_range, expected);
+        assert!(!in_range.contains(&before_id));
+    }
+
+    /// Re-storing an existing case_id with changed field values must drop the stale postings
+    /// under the old values, not just add postings under the new ones.
+    #[tokio::test]
+    async fn test_store_case_metadata_deindexes_stale_values_on_update() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let mut updated = fixture_metadata(case_id);
+        updated.citation = "410 U.S. 113".to_string();
+        updated.court = "District Court".to_string();
+        updated.docket_number = Some("2".to_string());
+        updated.decision_date = chrono::NaiveDate::from_ymd_opt(1973, 1, 22).unwrap();
+        storage.store_case_metadata(&updated).await.unwrap();
+
+        assert!(storage.find_by_citation("347 U.S. 483").await.unwrap().is_empty());
+        assert!(storage.find_by_court("supreme court").await.unwrap().is_empty());
+        assert!(storage.find_by_docket("1").await.unwrap().is_empty());
+        assert!(storage.find_by_year_range(1954, 1954).await.unwrap().is_empty());
+
+        assert_eq!(storage.find_by_citation("410 U.S. 113").await.unwrap(), vec![case_id]);
+        assert_eq!(storage.find_by_court("district court").await.unwrap(), vec![case_id]);
+        assert_eq!(storage.find_by_docket("2").await.unwrap(), vec![case_id]);
+        assert_eq!(storage.find_by_year_range(1973, 1973).await.unwrap(), vec![case_id]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_case_removes_it_from_typed_lookups() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        storage.delete_case(&case_id).await.unwrap();
+
+        assert!(storage.find_by_citation("347 U.S. 483").await.unwrap().is_empty());
+        assert!(storage.find_by_year_range(1954, 1954).await.unwrap().is_empty());
+    }
+
+    /// A from-scratch `rebuild_secondary_indexes()` backfill must agree with the indexes that
+    /// were already there from ordinary incremental `store_case_metadata` calls.
+    #[tokio::test]
+    async fn test_rebuild_produces_identical_indexes_to_incremental_maintenance() {
+        let storage = test_storage().await;
+        let brown_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(brown_id)).await.unwrap();
+
+        let mut roe = fixture_metadata(uuid::Uuid::new_v4());
+        roe.name = "Roe v Wade".to_string();
+        roe.citation = "410 U.S. 113".to_string();
+        roe.court = "Supreme Court".to_string();
+        roe.decision_date = chrono::NaiveDate::from_ymd_opt(1973, 1, 22).unwrap();
+        roe.docket_number = Some("70-18".to_string());
+        let roe_id = roe.id;
+        storage.store_case_metadata(&roe).await.unwrap();
+
+        let before_citation = storage.find_by_citation("410 U.S. 113").await.unwrap();
+        let mut before_court = storage.find_by_court("supreme court").await.unwrap();
+        let before_year_range = storage.find_by_year_range(1954, 1973).await.unwrap();
+        before_court.sort();
+
+        storage.rebuild_secondary_indexes().await.unwrap();
+
+        let after_citation = storage.find_by_citation("410 U.S. 113").await.unwrap();
+        let mut after_court = storage.find_by_court("supreme court").await.unwrap();
+        let after_year_range = storage.find_by_year_range(1954, 1973).await.unwrap();
+        after_court.sort();
+
+        assert_eq!(before_citation, after_citation);
+        assert_eq!(before_citation, vec![roe_id]);
+        assert_eq!(before_court, after_court);
+        assert_eq!(before_court, vec![brown_id, roe_id]);
+        assert_eq!(before_year_range.len(), after_year_range.len());
+        assert_eq!(
+            before_year_range.iter().collect::<HashSet<_>>(),
+            after_year_range.iter().collect::<HashSet<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_case_metadata_populates_summary_tree() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let summary = storage.get_case_summary(&case_id).await.unwrap().unwrap();
+        assert_eq!(summary.id, case_id);
+        assert_eq!(summary.court, "Supreme Court");
+        assert_eq!(summary.decision_date, chrono::NaiveDate::from_ymd_opt(1954, 5, 17).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_delete_case_removes_summary() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        storage.delete_case(&case_id).await.unwrap();
+
+        assert!(storage.get_case_summary(&case_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_case_metadata_increments_read_count_but_summary_does_not() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        assert_eq!(storage.metadata_read_count(), 0);
+        storage.get_case_summary(&case_id).await.unwrap();
+        assert_eq!(storage.metadata_read_count(), 0);
+        storage.get_case_metadata(&case_id).await.unwrap();
+        assert_eq!(storage.metadata_read_count(), 1);
+    }
+
+    /// `get_cases_metadata` returns every stored id it was asked for and simply omits one that
+    /// isn't there, rather than failing the whole batch.
+    #[tokio::test]
+    async fn test_get_cases_metadata_returns_stored_ids_and_omits_missing_ones() {
+        let storage = test_storage().await;
+        let stored_id = uuid::Uuid::new_v4();
+        let missing_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(stored_id)).await.unwrap();
+
+        let batch = storage.get_cases_metadata(&[stored_id, missing_id]).await.unwrap();
+
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch[&stored_id].id, stored_id);
+        assert!(!batch.contains_key(&missing_id));
+    }
+
+    /// `store_citation_edges` records the outgoing edge under the citing case and the mirrored
+    /// entry under the cited case, and a case with no edges reports empty in both directions.
+    #[tokio::test]
+    async fn test_store_citation_edges_populates_both_directions() {
+        let storage = test_storage().await;
+        let citing_id = uuid::Uuid::new_v4();
+        let cited_id = uuid::Uuid::new_v4();
+        let unrelated_id = uuid::Uuid::new_v4();
+
+        storage.store_citation_edges(citing_id, vec![
+            CitationEdge::Resolved {
+                case_id: cited_id,
+                raw_citation: "410 U.S. 113 (1973)".to_string(),
+                confidence: CitationConfidence::Exact,
+            },
+            CitationEdge::Unresolved { raw_citation: "1 Blackstone 100".to_string() },
+        ]).await.unwrap();
+
+        let cites = storage.get_cited_cases(&citing_id).await.unwrap();
+        assert_eq!(cites.len(), 2);
+
+        let cited_by = storage.get_citing_cases(&cited_id).await.unwrap();
+        assert_eq!(cited_by, vec![CitingCase { case_id: citing_id, confidence: CitationConfidence::Exact }]);
+
+        assert!(storage.get_cited_cases(&unrelated_id).await.unwrap().is_empty());
+        assert!(storage.get_citing_cases(&unrelated_id).await.unwrap().is_empty());
+    }
+
+    /// Overwriting a case's edges with `store_citation_edges` deindexes its previous resolved
+    /// edges so a citation removed on re-extraction (e.g. a misparse fixed upstream) stops
+    /// showing up in the old cited case's reverse list.
+    #[tokio::test]
+    async fn test_store_citation_edges_deindexes_previous_edges_on_overwrite() {
+        let storage = test_storage().await;
+        let citing_id = uuid::Uuid::new_v4();
+        let old_cited_id = uuid::Uuid::new_v4();
+        let new_cited_id = uuid::Uuid::new_v4();
+
+        storage.store_citation_edges(citing_id, vec![CitationEdge::Resolved {
+            case_id: old_cited_id,
+            raw_citation: "410 U.S. 113 (1973)".to_string(),
+            confidence: CitationConfidence::Exact,
+        }]).await.unwrap();
+
+        storage.store_citation_edges(citing_id, vec![CitationEdge::Resolved {
+            case_id: new_cited_id,
+            raw_citation: "347 U.S. 483 (1954)".to_string(),
+            confidence: CitationConfidence::Exact,
+        }]).await.unwrap();
+
+        assert!(storage.get_citing_cases(&old_cited_id).await.unwrap().is_empty());
+        assert_eq!(
+            storage.get_citing_cases(&new_cited_id).await.unwrap(),
+            vec![CitingCase { case_id: citing_id, confidence: CitationConfidence::Exact }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_secondary_indexes_lookups_agree_before_and_after_rebuild() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        // A lookup run before the rebuild and one run after its swap of the index trees must
+        // return the same case ids.
+        let before = storage.find_case_ids_by(SecondaryIndexField::Court, "supreme court").await.unwrap();
+        storage.rebuild_secondary_indexes().await.unwrap();
+        let after = storage.find_case_ids_by(SecondaryIndexField::Court, "supreme court").await.unwrap();
+
+        assert_eq!(before, after);
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore_round_trips_every_case() {
+        let storage = test_storage().await;
+
+        let mut cases = Vec::new();
+        for i in 0..5 {
+            let case_id = uuid::Uuid::new_v4();
+            let mut metadata = fixture_metadata(case_id);
+            metadata.name = format!("Case {i}");
+            storage.store_case_metadata(&metadata).await.unwrap();
+            storage.store_case_text(&case_id, "raw text", "normalized text").await.unwrap();
+            cases.push((case_id, metadata));
+        }
+
+        let backup_path = std::env::temp_dir().join(format!("backup-test-{}.sled.gz", uuid::Uuid::new_v4()));
+        storage.create_backup(&backup_path).await.unwrap();
+
+        // Wipe every case from the live store, simulating data loss, before restoring.
+        for (case_id, _) in &cases {
+            storage.delete_case(case_id).await.unwrap();
+        }
+        for (case_id, _) in &cases {
+            assert!(storage.get_case_metadata(case_id).await.unwrap().is_none());
+        }
+
+        let restored = storage.restore_from_backup(&backup_path).await.unwrap();
+
+        for (case_id, metadata) in &cases {
+            let restored_metadata = restored.get_case_metadata(case_id).await.unwrap().unwrap();
+            assert_eq!(restored_metadata.name, metadata.name);
+
+            let text = restored.get_case_text(case_id, TextForm::Raw).await.unwrap().unwrap();
+            assert_eq!(text.text, "raw text");
+        }
+
+        let _ = tokio::fs::remove_file(&backup_path).await;
+    }
+
+    #[tokio::test]
+    async fn test_restore_from_backup_rejects_an_archive_with_no_metadata_tree() {
+        let storage = test_storage().await;
+
+        let garbage: Vec<(Vec<u8>, Vec<u8>, Vec<Vec<Vec<u8>>>)> = vec![];
+        let payload = bincode::serialize(&garbage).unwrap();
+        let archive = StorageManager::compress_backup_archive(&payload).unwrap();
+        let archive_path = std::env::temp_dir().join(format!("backup-garbage-{}.sled.gz", uuid::Uuid::new_v4()));
+        tokio::fs::write(&archive_path, archive).await.unwrap();
+
+        let result = storage.restore_from_backup(&archive_path).await;
+        assert!(result.is_err());
+
+        let _ = tokio::fs::remove_file(&archive_path).await;
+    }
+
+    #[tokio::test]
+    async fn test_run_scheduled_backup_deletes_oldest_archives_beyond_max_backups() {
+        let mut config = Config::default();
+        config.storage.db_path = std::env::temp_dir().join(format!("storage-test-{}", uuid::Uuid::new_v4()));
+        config.storage.backup.backup_dir = std::env::temp_dir().join(format!("backup-dir-test-{}", uuid::Uuid::new_v4()));
+        config.storage.backup.max_backups = 2;
+        let storage = StorageManager::new(config.storage).await.unwrap();
+
+        tokio::fs::create_dir_all(&storage.config.backup.backup_dir).await.unwrap();
+        // Pre-existing archives that sort before anything `run_scheduled_backup` generates today.
+        tokio::fs::write(storage.config.backup.backup_dir.join("backup-20200101T000000Z.sled.gz"), b"old").await.unwrap();
+        tokio::fs::write(storage.config.backup.backup_dir.join("backup-20200102T000000Z.sled.gz"), b"older-but-newer").await.unwrap();
+
+        let newest = storage.run_scheduled_backup().await.unwrap();
+
+        let mut remaining = Vec::new();
+        let mut entries = tokio::fs::read_dir(&storage.config.backup.backup_dir).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            remaining.push(entry.path());
+        }
+
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.contains(&newest));
+        assert!(!remaining.iter().any(|p| p.ends_with("backup-20200101T000000Z.sled.gz")));
+    }
+
+    #[tokio::test]
+    async fn test_store_case_atomic_stores_metadata_and_both_text_forms() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+
+        storage.store_case_atomic(&fixture_metadata(case_id), "raw", "normalized").await.unwrap();
+
+        assert!(storage.get_case_metadata(&case_id).await.unwrap().is_some());
+        assert_eq!(storage.get_case_text(&case_id, TextForm::Raw).await.unwrap().unwrap().text, "raw");
+        assert_eq!(storage.get_case_text(&case_id, TextForm::Normalized).await.unwrap().unwrap().text, "normalized");
+    }
+
+    /// A fault injected between the metadata write and the text writes must roll the whole
+    /// transaction back — the metadata write must not persist on its own, unlike the old
+    /// `store_case_metadata` + `store_case_text` sequential path this method replaces.
+    #[tokio::test]
+    async fn test_store_case_atomic_leaves_no_orphan_when_interrupted_between_writes() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+
+        set_fail_case_write_transaction(true);
+        let result = storage.store_case_atomic(&fixture_metadata(case_id), "raw", "normalized").await;
+        set_fail_case_write_transaction(false);
+
+        assert!(result.is_err());
+        assert!(storage.get_case_metadata(&case_id).await.unwrap().is_none());
+        assert!(storage.get_case_text(&case_id, TextForm::Raw).await.unwrap().is_none());
+        assert!(storage.get_case_text(&case_id, TextForm::Normalized).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_integrity_removes_metadata_without_text() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let report = storage.verify_integrity().await.unwrap();
+
+        assert_eq!(report.metadata_without_text, vec![case_id]);
+        assert!(report.text_without_metadata.is_empty());
+        assert!(storage.get_case_metadata(&case_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_integrity_removes_text_without_metadata() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_text(&case_id, "raw", "normalized").await.unwrap();
+
+        let report = storage.verify_integrity().await.unwrap();
+
+        assert_eq!(report.text_without_metadata, vec![case_id]);
+        assert!(report.metadata_without_text.is_empty());
+        assert!(storage.get_case_text(&case_id, TextForm::Raw).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_integrity_leaves_consistent_cases_untouched() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_atomic(&fixture_metadata(case_id), "raw", "normalized").await.unwrap();
+
+        let report = storage.verify_integrity().await.unwrap();
+
+        assert_eq!(report.cases_scanned, 1);
+        assert!(report.metadata_without_text.is_empty());
+        assert!(report.text_without_metadata.is_empty());
+        assert!(storage.get_case_metadata(&case_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_count_cases_tracks_stores_and_deletes_without_iterating() {
+        let storage = test_storage().await;
+        assert_eq!(storage.count_cases(), 0);
+
+        let first = uuid::Uuid::new_v4();
+        let second = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(first)).await.unwrap();
+        storage.store_case_metadata(&fixture_metadata(second)).await.unwrap();
+        assert_eq!(storage.count_cases(), 2);
+
+        // Re-storing an existing case_id is an update, not a new case.
+        storage.store_case_metadata(&fixture_metadata(first)).await.unwrap();
+        assert_eq!(storage.count_cases(), 2);
+
+        storage.delete_case(&first).await.unwrap();
+        assert_eq!(storage.count_cases(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_iter_cases_pages_through_offset_and_limit() {
+        let storage = test_storage().await;
+        let mut ids = Vec::new();
+        for _ in 0..5 {
+            let case_id = uuid::Uuid::new_v4();
+            storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+            ids.push(case_id);
+        }
+
+        let first_page = storage.iter_cases(0, 2).await.unwrap();
+        let second_page = storage.iter_cases(2, 2).await.unwrap();
+        let third_page = storage.iter_cases(4, 2).await.unwrap();
+
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(second_page.len(), 2);
+        assert_eq!(third_page.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_scan_cases_cursor_traversal_covers_every_case_exactly_once() {
+        let storage = test_storage().await;
+        let mut expected: HashSet<CaseId> = HashSet::new();
+        for _ in 0..17 {
+            let case_id = uuid::Uuid::new_v4();
+            storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+            expected.insert(case_id);
+        }
+
+        let mut seen: Vec<CaseId> = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next_cursor) = storage.scan_cases(cursor, 5).await.unwrap();
+            assert!(!page.is_empty() || next_cursor.is_none());
+            seen.extend(page.iter().map(|m| m.id));
+            match next_cursor {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+
+        let seen_set: HashSet<CaseId> = seen.iter().copied().collect();
+        assert_eq!(seen.len(), seen_set.len(), "cursor traversal must not revisit a case");
+        assert_eq!(seen_set, expected, "cursor traversal must cover every stored case");
+    }
+
+    #[tokio::test]
+    async fn test_scan_cases_with_no_cases_returns_empty_page_and_no_cursor() {
+        let storage = test_storage().await;
+        let (page, next_cursor) = storage.scan_cases(None, 10).await.unwrap();
+        assert!(page.is_empty());
+        assert!(next_cursor.is_none());
+    }
+
+    /// `get_stats` must reflect a `store_case_text` call without ever iterating either text
+    /// tree — this asserts on the observable counter value, which is the same thing
+    /// `update_stats` now reads instead of scanning.
+    #[tokio::test]
+    async fn test_get_stats_total_size_grows_after_storing_text() {
+        let storage = test_storage().await;
+        let before = storage.get_stats().await.unwrap().total_size_bytes;
+
+        storage.store_case_text(&uuid::Uuid::new_v4(), "raw text", "normalized text").await.unwrap();
+
+        let after = storage.get_stats().await.unwrap().total_size_bytes;
+        assert!(after > before, "total_size_bytes should grow after storing text: {} -> {}", before, after);
+    }
+
+    #[tokio::test]
+    async fn test_get_stats_total_size_shrinks_back_after_deleting_a_case() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_atomic(&fixture_metadata(case_id), "raw text", "normalized text").await.unwrap();
+        let with_case = storage.get_stats().await.unwrap().total_size_bytes;
+
+        storage.delete_case(&case_id).await.unwrap();
+        let after_delete = storage.get_stats().await.unwrap().total_size_bytes;
+
+        assert!(after_delete < with_case, "total_size_bytes should shrink after delete: {} -> {}", with_case, after_delete);
+    }
+
+    #[tokio::test]
+    async fn test_store_case_text_overwrite_adjusts_counter_by_the_delta_not_the_full_new_size() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_text(&case_id, "short", "short").await.unwrap();
+        let after_first = storage.get_stats().await.unwrap().total_size_bytes;
+
+        // Overwriting with identical text must not double-count what was already stored.
+        storage.store_case_text(&case_id, "short", "short").await.unwrap();
+        let after_second = storage.get_stats().await.unwrap().total_size_bytes;
+
+        assert_eq!(after_first, after_second);
+    }
+
+    #[tokio::test]
+    async fn test_get_stats_total_size_correct_after_batch_store() {
+        let storage = test_storage().await;
+        let before = storage.get_stats().await.unwrap().total_size_bytes;
+
+        let cases = (0..4)
+            .map(|_| (fixture_metadata(uuid::Uuid::new_v4()), "some case text".to_string()))
+            .collect();
+        let stored = storage.store_cases_batch(cases).await.unwrap();
+        assert_eq!(stored, 4);
+
+        let after = storage.get_stats().await.unwrap().total_size_bytes;
+        assert!(after > before, "total_size_bytes should grow after a batch store: {} -> {}", before, after);
+    }
+
+    /// Counters must be restored from `meta_tree`, not recomputed, when a `StorageManager` is
+    /// reopened against the same `db_path` — the scenario a process restart looks like.
+    #[tokio::test]
+    async fn test_counters_survive_reopening_the_same_database() {
+        let mut config = Config::default();
+        config.storage.db_path = std::env::temp_dir().join(format!("storage-test-{}", uuid::Uuid::new_v4()));
+
+        let storage = StorageManager::new(config.storage.clone()).await.unwrap();
+        storage.store_case_atomic(&fixture_metadata(uuid::Uuid::new_v4()), "raw text", "normalized text").await.unwrap();
+        let before_restart = storage.get_stats().await.unwrap();
+        drop(storage);
+
+        let reopened = StorageManager::new(config.storage).await.unwrap();
+        let after_restart = reopened.get_stats().await.unwrap();
+
+        assert_eq!(after_restart.total_cases, before_restart.total_cases);
+        assert_eq!(after_restart.total_size_bytes, before_restart.total_size_bytes);
+    }
+
+    #[tokio::test]
+    async fn test_last_modified_is_set_on_store_and_delete() {
+        let storage = test_storage().await;
+        assert!(storage.get_stats().await.unwrap().last_modified.is_none());
+
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+        assert!(storage.get_stats().await.unwrap().last_modified.is_some());
+
+        storage.delete_case(&case_id).await.unwrap();
+        assert!(storage.get_stats().await.unwrap().last_modified.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_export_then_import_jsonl_round_trips_every_metadata_field() {
+        let storage = test_storage().await;
+
+        let mut federal = fixture_metadata(uuid::Uuid::new_v4());
+        federal.jurisdiction = crate::Jurisdiction::Federal;
+        let mut state = fixture_metadata(uuid::Uuid::new_v4());
+        state.jurisdiction = crate::Jurisdiction::State("Texas".to_string());
+        let mut local = fixture_metadata(uuid::Uuid::new_v4());
+        local.jurisdiction = crate::Jurisdiction::Local("Travis County".to_string());
+        let mut international = fixture_metadata(uuid::Uuid::new_v4());
+        international.jurisdiction = crate::Jurisdiction::International;
+
+        for metadata in [&federal, &state, &local, &international] {
+            storage.store_case_atomic(metadata, &metadata.full_text, &metadata.full_text).await.unwrap();
+        }
+
+        let mut buffer: Vec<u8> = Vec::new();
+        let exported = storage.export_jsonl(&mut buffer, true).await.unwrap();
+        assert_eq!(exported, 4);
+
+        let target = test_storage().await;
+        let report = target.import_jsonl(buffer.as_slice(), ImportConflictPolicy::Skip).await.unwrap();
+        assert_eq!(report.inserted, 4);
+        assert_eq!(report.updated, 0);
+        assert_eq!(report.skipped, 0);
+        assert!(report.failed.is_empty());
+
+        for original in [&federal, &state, &local, &international] {
+            let restored = target.get_case_metadata(&original.id).await.unwrap().unwrap();
+            assert_eq!(restored.id, original.id);
+            assert_eq!(restored.name, original.name);
+            assert_eq!(restored.citation, original.citation);
+            assert_eq!(restored.court, original.court);
+            assert_eq!(restored.decision_date, original.decision_date);
+            assert_eq!(restored.judges, original.judges);
+            assert_eq!(restored.topics, original.topics);
+            assert_eq!(restored.full_text, original.full_text);
+            assert_eq!(restored.jurisdiction, original.jurisdiction);
+            assert_eq!(restored.citations, original.citations);
+            assert_eq!(restored.docket_number, original.docket_number);
+            assert_eq!(restored.source_url, original.source_url);
+            assert_eq!(restored.word_count, original.word_count);
+            assert_eq!(restored.validation_warnings, original.validation_warnings);
+            assert_eq!(restored.content_simhash, original.content_simhash);
+            assert_eq!(restored.duplicate_of, original.duplicate_of);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_without_text_blanks_full_text_but_keeps_other_fields() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        storage.export_jsonl(&mut buffer, false).await.unwrap();
+
+        let target = test_storage().await;
+        target.import_jsonl(buffer.as_slice(), ImportConflictPolicy::Skip).await.unwrap();
+
+        let restored = target.get_case_metadata(&case_id).await.unwrap().unwrap();
+        assert_eq!(restored.full_text, "");
+        assert_eq!(restored.court, "Supreme Court");
+    }
+
+    #[tokio::test]
+    async fn test_import_jsonl_conflict_policy_skip_leaves_existing_case_untouched() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let mut updated = fixture_metadata(case_id);
+        updated.court = "District Court".to_string();
+        let line = format!("{}\n", serde_json::to_string(&updated).unwrap());
+
+        let report = storage.import_jsonl(line.as_bytes(), ImportConflictPolicy::Skip).await.unwrap();
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.inserted, 0);
+        assert_eq!(storage.get_case_metadata(&case_id).await.unwrap().unwrap().court, "Supreme Court");
+    }
+
+    #[tokio::test]
+    async fn test_import_jsonl_conflict_policy_overwrite_replaces_existing_case() {
+        let storage = test_storage().await;
+        let case_id = uuid::Uuid::new_v4();
+        storage.store_case_metadata(&fixture_metadata(case_id)).await.unwrap();
+
+        let mut updated = fixture_metadata(case_id);
+        updated.court = "District Court".to_string();
+        let line = format!("{}\n", serde_json::to_string(&updated).unwrap());
+
+        let report = storage.import_jsonl(line.as_bytes(), ImportConflictPolicy::Overwrite).await.unwrap();
+        assert_eq!(report.updated, 1);
+        assert_eq!(report.inserted, 0);
+        assert_eq!(storage.get_case_metadata(&case_id).await.unwrap().unwrap().court, "District Court");
+    }
+
+    #[tokio::test]
+    async fn test_import_jsonl_reports_invalid_lines_by_number_without_aborting() {
+        let storage = test_storage().await;
+        let valid = fixture_metadata(uuid::Uuid::new_v4());
+        let input = format!(
+            "{}\nnot valid json\n{}\n",
+            serde_json::to_string(&valid).unwrap(),
+            serde_json::to_string(&fixture_metadata(uuid::Uuid::new_v4())).unwrap()
+        );
+
+        let report = storage.import_jsonl(input.as_bytes(), ImportConflictPolicy::Skip).await.unwrap();
+        assert_eq!(report.inserted, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].line_number, 2);
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,160 @@
+//! # Query Synonym Expansion
+//!
+//! ## Purpose
+//! A query like `"free speech"` should also surface documents that only say `"freedom of
+//! expression"`. This module holds a term -> synonym-phrase table (bundled or user-supplied) and
+//! the lookup [`SearchEngine::run_plain_lexical_stage`](crate::search) uses to find, for a given
+//! query, which additional phrases are worth searching as OR-alternatives.
+//!
+//! ## Input/Output Specification
+//! - **Input**: Bundled or user-supplied synonym JSON, a query string
+//! - **Output**: Up to a caller-supplied cap of synonym phrases to search alongside the original
+//!   query, each scored below an original-term match by
+//!   `crate::search::SearchEngine::run_plain_lexical_stage`
+//! - **Format**: Flat JSON array of `{"term": ..., "synonyms": [...]}` entries
+
+use crate::errors::{Result, SearchError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Bundled default synonym table, shipped with the crate and used unless a user supplies their own
+const DEFAULT_SYNONYMS_JSON: &str = include_str!("../data/synonyms.json");
+
+/// One term -> synonym-phrase mapping, as it appears in the synonym JSON file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SynonymEntry {
+    term: String,
+    synonyms: Vec<String>,
+}
+
+/// Term -> synonym-phrase table, loaded from bundled or user-supplied JSON
+#[derive(Debug, Clone)]
+pub struct SynonymTable {
+    /// Lowercased term -> its synonym phrases, in file order
+    entries: HashMap<String, Vec<String>>,
+}
+
+impl SynonymTable {
+    /// Load the synonym table bundled with the crate
+    pub fn load_bundled() -> Result<Self> {
+        Self::from_json(DEFAULT_SYNONYMS_JSON)
+    }
+
+    /// Load a user-supplied synonym table, replacing the bundled one entirely
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = std::fs::read_to_string(path.as_ref()).map_err(|e| SearchError::Config {
+            message: format!("Failed to read synonym file {:?}: {}", path.as_ref(), e),
+        })?;
+        Self::from_json(&contents)
+    }
+
+    fn from_json(json: &str) -> Result<Self> {
+        let entry_list: Vec<SynonymEntry> = serde_json::from_str(json).map_err(|e| SearchError::Config {
+            message: format!("Failed to parse synonym JSON: {}", e),
+        })?;
+
+        let mut entries = HashMap::new();
+        for entry in entry_list {
+            entries.insert(entry.term.to_lowercase(), entry.synonyms);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Every synonym phrase for terms that occur as a whole-word run within `query`, up to
+    /// `max_expansions` total, longest term first so `"free speech"` is preferred over a shorter
+    /// term it happens to contain. Case-insensitive. Returns the phrases in the order they
+    /// should be searched, not deduplicated against `query` itself — a phrase identical to the
+    /// query text is harmless to search twice.
+    pub fn expand(&self, query: &str, max_expansions: usize) -> Vec<String> {
+        if max_expansions == 0 {
+            return Vec::new();
+        }
+
+        let query_words: Vec<String> = query.split_whitespace().map(|w| w.to_lowercase()).collect();
+        let joined_query = query_words.join(" ");
+        let mut terms: Vec<&String> =
+            self.entries.keys().filter(|term| Self::contains_phrase(&query_words, term)).collect();
+        // Longest term first; equal-length terms in the order they occur in `query`.
+        terms.sort_by_key(|term| (std::cmp::Reverse(term.len()), joined_query.find(term.as_str())));
+
+        let mut matched: Vec<Vec<String>> = Vec::new();
+        let mut expansions = Vec::new();
+        for term in terms {
+            if expansions.len() >= max_expansions {
+                break;
+            }
+            // A term lying inside an already-matched longer one ("speech" in "free speech") is skipped.
+            if matched.iter().any(|longer| Self::contains_phrase(longer, term)) {
+                continue;
+            }
+            matched.push(term.split_whitespace().map(str::to_string).collect());
+            for synonym in &self.entries[term] {
+                if expansions.len() >= max_expansions {
+                    break;
+                }
+                expansions.push(synonym.clone());
+            }
+        }
+        expansions
+    }
+
+    /// Whether `term`'s words occur as a contiguous, whole-word run somewhere in `query_words`
+    fn contains_phrase(query_words: &[String], term: &str) -> bool {
+        let term_words: Vec<&str> = term.split_whitespace().collect();
+        if term_words.is_empty() || term_words.len() > query_words.len() {
+            return false;
+        }
+        query_words
+            .windows(term_words.len())
+            .any(|window| window.iter().map(String::as_str).eq(term_words.iter().copied()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_table() -> SynonymTable {
+        let json = r#"[
+            {"term": "free speech", "synonyms": ["freedom of expression", "freedom of speech"]},
+            {"term": "speech", "synonyms": ["utterance"]},
+            {"term": "due process", "synonyms": ["procedural fairness"]}
+        ]"#;
+        SynonymTable::from_json(json).unwrap()
+    }
+
+    #[test]
+    fn test_expand_prefers_the_longer_matching_term() {
+        let table = sample_table();
+        let expansions = table.expand("the free speech clause", 10);
+        assert_eq!(expansions, vec!["freedom of expression", "freedom of speech"]);
+    }
+
+    #[test]
+    fn test_expand_is_case_insensitive() {
+        let table = sample_table();
+        let expansions = table.expand("FREE SPEECH clause", 10);
+        assert_eq!(expansions, vec!["freedom of expression", "freedom of speech"]);
+    }
+
+    #[test]
+    fn test_expand_respects_max_expansions_cap() {
+        let table = sample_table();
+        let expansions = table.expand("free speech and due process", 1);
+        assert_eq!(expansions, vec!["freedom of expression"]);
+    }
+
+    #[test]
+    fn test_expand_returns_nothing_for_an_unmatched_query() {
+        let table = sample_table();
+        assert!(table.expand("maritime salvage law", 10).is_empty());
+    }
+
+    #[test]
+    fn test_bundled_synonyms_load_and_expand_free_speech() {
+        let table = SynonymTable::load_bundled().unwrap();
+        let expansions = table.expand("free speech", 10);
+        assert!(expansions.iter().any(|s| s == "freedom of expression"));
+    }
+}
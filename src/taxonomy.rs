@@ -0,0 +1,265 @@
+//! # Legal Topic Taxonomy Module
+//!
+//! ## Purpose
+//! Defines a hierarchical taxonomy of legal topics (e.g. "Constitutional Law >
+//! First Amendment > Speech") so case topics support drill-down filtering and
+//! faceted counts instead of a flat tag list.
+//!
+//! ## Input/Output Specification
+//! - **Input**: Bundled or user-supplied taxonomy JSON, free text for classification
+//! - **Output**: Taxonomy node ids attached to `CaseMetadata::topics`, facet counts
+//! - **Format**: Flat JSON array of nodes with `id`, `name`, `parent`, `keywords`
+//!
+//! ## Key Features
+//! - Parent/child lookups for hierarchical `include_descendants` filtering
+//! - Facet count roll-up from leaf nodes to ancestors
+//! - Keyword-based topic classification
+//! - Unknown node id validation with close-match suggestions
+
+use crate::errors::{Result, SearchError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Bundled default taxonomy, shipped with the crate and used unless a user supplies their own
+const DEFAULT_TAXONOMY_JSON: &str = include_str!("../data/taxonomy.json");
+
+/// A single node in the topic taxonomy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxonomyNode {
+    pub id: String,
+    pub name: String,
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+/// Hierarchical legal topic taxonomy, loaded from bundled or user-supplied JSON
+#[derive(Debug, Clone)]
+pub struct Taxonomy {
+    nodes: HashMap<String, TaxonomyNode>,
+    children: HashMap<String, Vec<String>>,
+}
+
+/// Filter for topic-based search, allowing roll-up over a node's descendants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicFilter {
+    /// Taxonomy node id to filter on
+    pub node_id: String,
+    /// When true, also match cases classified under any descendant of `node_id`
+    #[serde(default)]
+    pub include_descendants: bool,
+}
+
+/// Facet count for a single taxonomy node, rolled up to include descendant counts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicFacet {
+    pub node_id: String,
+    pub name: String,
+    pub count: usize,
+}
+
+impl Taxonomy {
+    /// Load the taxonomy bundled with the crate
+    pub fn load_bundled() -> Result<Self> {
+        Self::from_json(DEFAULT_TAXONOMY_JSON)
+    }
+
+    /// Load a user-supplied taxonomy, replacing the bundled one entirely
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = std::fs::read_to_string(path.as_ref()).map_err(|e| SearchError::Config {
+            message: format!("Failed to read taxonomy file {:?}: {}", path.as_ref(), e),
+        })?;
+        Self::from_json(&contents)
+    }
+
+    fn from_json(json: &str) -> Result<Self> {
+        let node_list: Vec<TaxonomyNode> = serde_json::from_str(json).map_err(|e| SearchError::Config {
+            message: format!("Failed to parse taxonomy JSON: {}", e),
+        })?;
+
+        let mut nodes = HashMap::new();
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        for node in node_list {
+            if let Some(parent) = &node.parent {
+                children.entry(parent.clone()).or_default().push(node.id.clone());
+            }
+            nodes.insert(node.id.clone(), node);
+        }
+
+        for node in nodes.values() {
+            if let Some(parent) = &node.parent {
+                if !nodes.contains_key(parent) {
+                    return Err(SearchError::Config {
+                        message: format!(
+                            "Taxonomy node '{}' references unknown parent '{}'",
+                            node.id, parent
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(Self { nodes, children })
+    }
+
+    /// Look up a node by id
+    pub fn get(&self, node_id: &str) -> Option<&TaxonomyNode> {
+        self.nodes.get(node_id)
+    }
+
+    /// Validate that a node id exists, returning close matches in the error if not
+    pub fn validate_node_id(&self, node_id: &str) -> Result<()> {
+        if self.nodes.contains_key(node_id) {
+            return Ok(());
+        }
+
+        let suggestions = self.close_matches(node_id, 3);
+        Err(SearchError::ValidationFailed {
+            field: "topic".to_string(),
+            reason: if suggestions.is_empty() {
+                format!("Unknown taxonomy node '{}'", node_id)
+            } else {
+                format!(
+                    "Unknown taxonomy node '{}'; did you mean: {}?",
+                    node_id,
+                    suggestions.join(", ")
+                )
+            },
+        })
+    }
+
+    /// Find the closest known node ids to an unrecognized one, by edit distance
+    pub fn close_matches(&self, node_id: &str, limit: usize) -> Vec<String> {
+        let mut scored: Vec<(usize, &String)> = self
+            .nodes
+            .keys()
+            .map(|id| (levenshtein(node_id, id), id))
+            .collect();
+        scored.sort_by_key(|(distance, _)| *distance);
+        scored.into_iter().take(limit).map(|(_, id)| id.clone()).collect()
+    }
+
+    /// All node ids in the subtree rooted at `node_id`, including itself
+    pub fn descendants(&self, node_id: &str) -> Result<Vec<String>> {
+        self.validate_node_id(node_id)?;
+
+        let mut result = vec![node_id.to_string()];
+        let mut stack = vec![node_id.to_string()];
+        while let Some(current) = stack.pop() {
+            if let Some(child_ids) = self.children.get(&current) {
+                for child_id in child_ids {
+                    result.push(child_id.clone());
+                    stack.push(child_id.clone());
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Classify free text into taxonomy node ids by keyword matching
+    pub fn classify(&self, text: &str) -> Vec<String> {
+        let lower = text.to_lowercase();
+        let mut matches: Vec<String> = self
+            .nodes
+            .values()
+            .filter(|node| node.keywords.iter().any(|kw| lower.contains(&kw.to_lowercase())))
+            .map(|node| node.id.clone())
+            .collect();
+        matches.sort();
+        matches
+    }
+
+    /// Roll facet counts for a set of assigned node ids up to every ancestor
+    pub fn facet_counts(&self, assigned_node_ids: &[String]) -> Vec<TopicFacet> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for node_id in assigned_node_ids {
+            let mut current = Some(node_id.clone());
+            while let Some(id) = current {
+                *counts.entry(id.clone()).or_insert(0) += 1;
+                current = self.nodes.get(&id).and_then(|n| n.parent.clone());
+            }
+        }
+
+        let mut facets: Vec<TopicFacet> = counts
+            .into_iter()
+            .filter_map(|(node_id, count)| {
+                self.nodes.get(&node_id).map(|node| TopicFacet {
+                    node_id,
+                    name: node.name.clone(),
+                    count,
+                })
+            })
+            .collect();
+        facets.sort_by(|a, b| a.node_id.cmp(&b.node_id));
+        facets
+    }
+}
+
+/// Simple Levenshtein edit distance, used for taxonomy node id suggestions
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dp[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+        }
+    }
+
+    dp[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn three_level_taxonomy() -> Taxonomy {
+        let json = r#"[
+            {"id": "constitutional-law", "name": "Constitutional Law", "parent": null, "keywords": ["constitution"]},
+            {"id": "first-amendment", "name": "First Amendment", "parent": "constitutional-law", "keywords": ["first amendment"]},
+            {"id": "speech", "name": "Speech", "parent": "first-amendment", "keywords": ["free speech"]},
+            {"id": "religion", "name": "Religion", "parent": "first-amendment", "keywords": ["establishment clause"]}
+        ]"#;
+        Taxonomy::from_json(json).unwrap()
+    }
+
+    #[test]
+    fn test_descendants_include_all_levels_below_a_node() {
+        let taxonomy = three_level_taxonomy();
+        let mut ids = taxonomy.descendants("first-amendment").unwrap();
+        ids.sort();
+        assert_eq!(ids, vec!["first-amendment", "religion", "speech"]);
+    }
+
+    #[test]
+    fn test_unknown_node_id_is_rejected_with_a_suggestion() {
+        let taxonomy = three_level_taxonomy();
+        let err = taxonomy.validate_node_id("frist-amendment").unwrap_err();
+        assert!(err.to_string().contains("first-amendment"));
+    }
+
+    #[test]
+    fn test_facet_counts_roll_up_to_parent_nodes() {
+        let taxonomy = three_level_taxonomy();
+        let assigned = vec!["speech".to_string(), "speech".to_string(), "religion".to_string()];
+        let facets = taxonomy.facet_counts(&assigned);
+
+        let get = |id: &str| facets.iter().find(|f| f.node_id == id).unwrap().count;
+        assert_eq!(get("speech"), 2);
+        assert_eq!(get("religion"), 1);
+        assert_eq!(get("first-amendment"), 3);
+        assert_eq!(get("constitutional-law"), 3);
+    }
+}
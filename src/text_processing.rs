@@ -43,8 +43,8 @@ pub struct ProcessedText {
     pub normalized: String,
     /// Extracted tokens
     pub tokens: Vec<Token>,
-    /// Extracted sentences
-    pub sentences: Vec<String>,
+    /// Extracted sentences, each with its starting position in `normalized`
+    pub sentences: Vec<SentenceSpan>,
     /// Legal citations found
     pub citations: Vec<Citation>,
     /// Legal terms identified
@@ -55,6 +55,34 @@ pub struct ProcessedText {
     pub stats: TextStats,
 }
 
+/// The parts of a [`ProcessedText`] worth persisting so a later re-index doesn't have to re-run
+/// [`TextProcessor::process_text`] from scratch — everything except `original`/`normalized`,
+/// which already live in `StorageManager`'s own text trees and would just be duplicated here.
+/// Stored by [`crate::storage::StorageManager::store_processed`] and consumed by
+/// [`crate::trie::TrieIndex::build_from_storage`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessedArtifacts {
+    pub tokens: Vec<Token>,
+    pub sentences: Vec<SentenceSpan>,
+    pub citations: Vec<Citation>,
+    pub legal_terms: Vec<LegalTerm>,
+    pub entities: Vec<NamedEntity>,
+    pub stats: TextStats,
+}
+
+impl From<&ProcessedText> for ProcessedArtifacts {
+    fn from(processed: &ProcessedText) -> Self {
+        Self {
+            tokens: processed.tokens.clone(),
+            sentences: processed.sentences.clone(),
+            citations: processed.citations.clone(),
+            legal_terms: processed.legal_terms.clone(),
+            entities: processed.entities.clone(),
+            stats: processed.stats.clone(),
+        }
+    }
+}
+
 /// Individual token with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
@@ -72,6 +100,44 @@ pub struct Token {
     pub pos_tag: Option<String>,
 }
 
+/// A sentence extracted by [`TextProcessor::extract_sentences`], with the byte offset (into the
+/// `normalized` text it was extracted from) of its first character. Needed by callers like
+/// [`crate::trie::TrieIndex::insert_content`] that index per-sentence content and want to report
+/// where a matched phrase actually sits in the case text, not just that it matched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentenceSpan {
+    /// The sentence text, trimmed of surrounding whitespace
+    pub text: String,
+    /// Byte offset of `text`'s first character within the normalized text
+    pub start: usize,
+}
+
+impl SentenceSpan {
+    /// Split this sentence into whitespace-delimited words, each paired with its byte offset in
+    /// the normalized text the sentence was extracted from. Feeds
+    /// [`crate::trie::TrieIndex::insert_content`], which needs the offset of the first word to
+    /// locate an exact phrase match back in the case text.
+    pub fn word_offsets(&self) -> Vec<(String, usize)> {
+        let mut words = Vec::new();
+        let mut word_start: Option<usize> = None;
+
+        for (index, ch) in self.text.char_indices() {
+            if ch.is_whitespace() {
+                if let Some(start) = word_start.take() {
+                    words.push((self.text[start..index].to_string(), self.start + start));
+                }
+            } else if word_start.is_none() {
+                word_start = Some(index);
+            }
+        }
+        if let Some(start) = word_start {
+            words.push((self.text[start..].to_string(), self.start + start));
+        }
+
+        words
+    }
+}
+
 /// Token classification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TokenType {
@@ -211,6 +277,13 @@ impl TextProcessor {
         Ok(processor)
     }
 
+    /// The loaded stopword set, for callers like [`crate::trie::TrieIndex::set_stopwords`] that
+    /// need to make the same stopword-vs-content-word distinction outside this processor's own
+    /// tokenization pipeline.
+    pub fn stopwords(&self) -> &HashSet<String> {
+        &self.stopwords
+    }
+
     /// Process legal text
     pub async fn process_text(&self, text: &str) -> Result<ProcessedText> {
         tracing::debug!("Processing text: {} characters", text.len());
@@ -371,8 +444,13 @@ impl TextProcessor {
         Ok(())
     }
 
-    /// Normalize text
-    fn normalize_text(&self, text: &str) -> Result<String> {
+    /// Normalize text: NFC-compose, fold curly single quotes to straight ones (curly double
+    /// quotes are left as they are), collapse whitespace,
+    /// and strip control characters — but never change case, so a downstream citation
+    /// extractor (or, via [`crate::search::QueryNormalizer`], a query) still sees the exact
+    /// casing it was given. `pub(crate)` rather than private so `QueryNormalizer` can run the
+    /// same normalization a query needs without duplicating these rules.
+    pub(crate) fn normalize_text(&self, text: &str) -> Result<String> {
         let mut normalized = text.nfc().collect::<String>();
 
         if self.config.remove_extra_whitespace {
@@ -434,15 +512,26 @@ impl TextProcessor {
         Ok(tokens)
     }
 
-    /// Extract sentences
-    fn extract_sentences(&self, text: &str) -> Result<Vec<String>> {
+    /// Extract sentences, tracking each one's starting char offset in `text`
+    fn extract_sentences(&self, text: &str) -> Result<Vec<SentenceSpan>> {
         // Simple sentence splitting - in production would use more sophisticated NLP
         let sentence_regex = Regex::new(r"[.!?]+\s+").unwrap();
-        let sentences: Vec<String> = sentence_regex
-            .split(text)
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
+
+        let mut sentences = Vec::new();
+        let mut cursor = 0;
+        let push_span = |sentences: &mut Vec<SentenceSpan>, raw: &str, raw_start: usize| {
+            let leading_ws = raw.len() - raw.trim_start().len();
+            let trimmed = raw.trim();
+            if !trimmed.is_empty() {
+                sentences.push(SentenceSpan { text: trimmed.to_string(), start: raw_start + leading_ws });
+            }
+        };
+
+        for separator in sentence_regex.find_iter(text) {
+            push_span(&mut sentences, &text[cursor..separator.start()], cursor);
+            cursor = separator.end();
+        }
+        push_span(&mut sentences, &text[cursor..], cursor);
 
         Ok(sentences)
     }
@@ -541,7 +630,7 @@ impl TextProcessor {
     }
 
     /// Calculate text statistics
-    fn calculate_stats(&self, text: &str, tokens: &[Token], sentences: &[String]) -> Result<TextStats> {
+    fn calculate_stats(&self, text: &str, tokens: &[Token], sentences: &[SentenceSpan]) -> Result<TextStats> {
         let word_count = tokens.len();
         let unique_words = tokens.iter()
             .map(|t| &t.normalized)
@@ -580,9 +669,11 @@ impl TextProcessor {
         })
     }
 
-    /// Normalize citation format
+    /// Normalize citation format, including reporter abbreviation spelling (see
+    /// [`crate::citation::normalize_reporter_spelling`]) so `"98 S.Ct. 2733"` and
+    /// `"98 S. Ct. 2733"` normalize identically
     fn normalize_citation(&self, citation: &str) -> String {
-        // Basic citation normalization
+        let citation = crate::citation::normalize_reporter_spelling(citation.trim());
         citation.trim()
             .replace("  ", " ")
             .replace(" ,", ",")
@@ -21,47 +21,383 @@ use crate::config::TrieConfig;
 use crate::errors::{Result, SearchError};
 use crate::{CaseId, DocRef};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::Path;
 
 /// Main trie index manager
+#[derive(Debug, Clone)]
 pub struct TrieIndex {
     config: TrieConfig,
     case_name_trie: CaseNameTrie,
     content_trie: ContentTrie,
     citation_trie: CitationTrie,
+    substring_index: SubstringIndex,
+    /// Stopword set used by `insert_content`/`insert_batch` to apply
+    /// `TrieConfig::skip_stopword_only_ngrams`; empty (the filter never fires) until a caller
+    /// injects one via [`TrieIndex::set_stopwords`]. Not persisted in the snapshot format — each
+    /// process re-injects it from its own [`crate::text_processing::TextProcessor`] at startup.
+    stopwords: HashSet<String>,
 }
 
 /// Trie for case names
+#[derive(Debug, Clone)]
 pub struct CaseNameTrie {
     root: TrieNode,
 }
 
 /// Trie for content (sentences, paragraphs)
+#[derive(Debug, Clone)]
 pub struct ContentTrie {
     root: TrieNode,
 }
 
 /// Trie for legal citations
+#[derive(Debug, Clone)]
 pub struct CitationTrie {
     root: TrieNode,
 }
 
 /// Trie node structure
+///
+/// `children` is a `Vec<(Box<str>, TrieNode)>` kept sorted by edge, searched with binary
+/// search, rather than a `HashMap<String, TrieNode>`. A `HashMap` per node pays for a hash
+/// table's bucket array, load-factor slack, and hashing on every lookup even though most nodes
+/// have only a handful of children; a sorted `Vec` with a `Box<str>` edge needs no bucket array
+/// and no hasher, and its edge allocation is trimmed to exactly the token's byte length instead
+/// of a `String`'s separate capacity. Across a corpus of a few hundred thousand cases, most of
+/// which fan out through only a few children per node, this materially cuts per-node overhead.
+/// Full byte-level radix compression (splitting shared *prefixes* of a token across edges) was
+/// considered but rejected: it would mean abandoning whole-token edges, which
+/// [`TrieNode::fuzzy_matches`]'s per-token Levenshtein budget and [`TrieIndex::get_completions`]'s
+/// last-token-is-partial convention both depend on.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrieNode {
-    children: HashMap<String, TrieNode>,
+    children: Vec<(Box<str>, TrieNode)>,
     is_end_of_word: bool,
     document_refs: Vec<DocRef>,
     frequency: u32,
 }
 
+/// Memory footprint of a [`TrieIndex`], reported by [`TrieIndex::memory_stats`] so the effect
+/// of changes to [`TrieNode`]'s representation is measurable rather than assumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrieMemoryStats {
+    /// Total number of `TrieNode`s across all three specialized tries
+    pub node_count: usize,
+    /// Total bytes occupied by edge keys (the `Box<str>` on each child entry)
+    pub edge_bytes: usize,
+    /// Total number of `DocRef`s stored across all nodes
+    pub posting_count: usize,
+}
+
+impl std::ops::Add for TrieMemoryStats {
+    type Output = TrieMemoryStats;
+
+    fn add(self, other: TrieMemoryStats) -> TrieMemoryStats {
+        TrieMemoryStats {
+            node_count: self.node_count + other.node_count,
+            edge_bytes: self.edge_bytes + other.edge_bytes,
+            posting_count: self.posting_count + other.posting_count,
+        }
+    }
+}
+
+/// Introspection stats for a [`TrieIndex`], returned by [`TrieIndex::get_stats`] and surfaced
+/// through `SearchEngine::get_stats` and the `/stats` API handler. Term counts are broken out
+/// per specialized sub-trie (unlike [`TrieMemoryStats`], which only reports combined totals)
+/// since case-name, content, and citation tries grow at very different rates as a corpus is
+/// ingested. `estimated_memory_bytes` is a rough `size_of`-based estimate, not a precise
+/// allocator accounting — good enough to notice a representation regression, not to budget
+/// against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrieIndexStats {
+    /// Number of distinct indexed terms (case-name token paths) in [`CaseNameTrie`]
+    pub case_name_terms: usize,
+    /// Number of distinct indexed terms in [`ContentTrie`]
+    pub content_terms: usize,
+    /// Number of distinct indexed terms in [`CitationTrie`]
+    pub citation_terms: usize,
+    /// Total `TrieNode`s across all three specialized tries
+    pub total_nodes: usize,
+    /// Total `DocRef`s stored across all nodes
+    pub total_document_refs: usize,
+    /// Deepest `is_end_of_word` path (in tokens) across all three tries
+    pub max_depth: usize,
+    /// Rough estimate of the tries' combined heap footprint in bytes
+    pub estimated_memory_bytes: usize,
+}
+
+/// Result of a [`TrieIndex::prune`] pass over [`ContentTrie`], run after bulk ingestion to keep
+/// single-occurrence content n-grams from dominating the trie's node count. `terms_dropped`
+/// counts terms whose frequency fell below the configured threshold and were removed entirely;
+/// `document_refs_dropped` also includes postings truncated off terms that cleared the frequency
+/// bar but still had an oversized posting list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PruneReport {
+    /// Number of content terms removed for falling below `min_frequency`
+    pub terms_dropped: usize,
+    /// Total `DocRef`s dropped, both from removed terms and from posting lists truncated
+    /// down to `max_postings`
+    pub document_refs_dropped: usize,
+}
+
+/// Per-subtrie counters accumulated by [`TrieNode::accumulate_index_stats`] on the way to a
+/// [`TrieIndexStats`]: term/node/posting/edge-byte totals plus the deepest indexed path.
+#[derive(Debug, Clone, Copy, Default)]
+struct TrieNodeStats {
+    terms: usize,
+    nodes: usize,
+    document_refs: usize,
+    edge_bytes: usize,
+    max_depth: usize,
+}
+
+/// On-disk snapshot of a [`TrieIndex`], used by [`TrieIndex::save_to_disk`] and
+/// [`TrieIndex::load_from_disk`] to persist/restore all three specialized tries, plus the
+/// auxiliary [`SubstringIndex`], as one bincode-encoded file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TrieSnapshot {
+    case_name_root: TrieNode,
+    content_root: TrieNode,
+    citation_root: TrieNode,
+    #[serde(default)]
+    substring_index: SubstringIndex,
+}
+
+/// Identifies a file as a [`TrieSnapshot`] rather than arbitrary bytes
+const SNAPSHOT_MAGIC: [u8; 8] = *b"TRIEIDX\0";
+/// Bumped whenever [`TrieSnapshot`]'s shape changes in a way old readers can't handle. Bumped to
+/// 2 when [`SubstringIndex`] was added to the snapshot body; a version-1 file is reported as
+/// [`SearchError::IndexCorrupted`] rather than silently loading with an empty substring index.
+const SNAPSHOT_VERSION: u32 = 2;
+const SNAPSHOT_HEADER_LEN: usize = SNAPSHOT_MAGIC.len() + std::mem::size_of::<u32>();
+
+/// Auxiliary token -> `DocRef` inverted index, populated alongside [`CaseNameTrie`]'s and
+/// [`ContentTrie`]'s ordinary prefix-trie insertion. The prefix trie can only match a query
+/// anchored at an indexed sequence's first token, so a query like `"board of education"` never
+/// matches the indexed sequence `"brown v. board of education"`, and `"freedom of speech"` never
+/// matches a sentence like `"no law abridging the freedom of speech"`. This index lets
+/// [`TrieIndex::search`] fall back to it when the ordinary prefix walk finds no exact match: each
+/// query token's posting list is looked up, and the lists are intersected by `DocRef.case_id` so
+/// a match requires every query token to occur somewhere in the same case, not merely somewhere
+/// across unrelated ones. Citations are not indexed here — see [`TrieIndex::search_wildcard`]'s
+/// doc comment for why a citation's volume/reporter/page/year structure doesn't have a natural
+/// "any word" slot the way case names and prose do.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SubstringIndex {
+    postings: std::collections::HashMap<String, Vec<DocRef>>,
+}
+
+impl SubstringIndex {
+    /// Index `doc_ref` under every token in `tokens`, skipping a token already indexed under
+    /// `doc_ref` (mirrors [`TrieIndex::insert_content`]'s dedup of reprocessed sentences).
+    fn insert(&mut self, tokens: &[String], doc_ref: &DocRef) {
+        for token in tokens {
+            let postings = self.postings.entry(token.clone()).or_default();
+            if !postings.contains(doc_ref) {
+                postings.push(doc_ref.clone());
+            }
+        }
+    }
+
+    /// Remove every posting for `case_id` under `tokens` specifically (not the case's other
+    /// entries), for [`TrieIndex::update_case_name`] replacing a stale case name without
+    /// disturbing that case's indexed content.
+    fn remove(&mut self, tokens: &[String], case_id: CaseId) {
+        for token in tokens {
+            if let Some(postings) = self.postings.get_mut(token) {
+                postings.retain(|doc_ref| doc_ref.case_id != case_id);
+                if postings.is_empty() {
+                    self.postings.remove(token);
+                }
+            }
+        }
+    }
+
+    /// Remove every posting belonging to `case_id`, across all tokens, for
+    /// [`TrieIndex::remove_case`] dropping a case entirely.
+    fn remove_case(&mut self, case_id: CaseId) {
+        for postings in self.postings.values_mut() {
+            postings.retain(|doc_ref| doc_ref.case_id != case_id);
+        }
+        self.postings.retain(|_, postings| !postings.is_empty());
+    }
+
+    /// `DocRef`s whose case contains every token in `tokens` (in any position, not necessarily
+    /// contiguous or in order), or an empty vec if `tokens` is empty or any token has no
+    /// postings at all.
+    fn search(&self, tokens: &[String]) -> Vec<DocRef> {
+        let mut lists = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            match self.postings.get(token) {
+                Some(postings) => lists.push(postings),
+                None => return Vec::new(),
+            }
+        }
+        let Some((first, rest)) = lists.split_first() else { return Vec::new() };
+
+        let mut matching_case_ids: std::collections::HashSet<CaseId> =
+            first.iter().map(|doc_ref| doc_ref.case_id).collect();
+        for postings in rest {
+            let case_ids: std::collections::HashSet<CaseId> =
+                postings.iter().map(|doc_ref| doc_ref.case_id).collect();
+            matching_case_ids.retain(|case_id| case_ids.contains(case_id));
+        }
+
+        first
+            .iter()
+            .filter(|doc_ref| matching_case_ids.contains(&doc_ref.case_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Like [`SubstringIndex::search`], but a case qualifies once it contains at least
+    /// `min_matches` of `tokens` rather than every one of them — the union-with-coverage
+    /// counterpart to that all-or-nothing intersection, for
+    /// [`TrieIndex::search_min_should_match`]. Each returned `DocRef` is the first one found for
+    /// its case, in `tokens` order, paired with how many distinct tokens actually matched.
+    fn search_with_coverage(&self, tokens: &[String], min_matches: usize) -> Vec<(DocRef, usize)> {
+        let mut coverage: std::collections::HashMap<CaseId, (usize, DocRef)> = std::collections::HashMap::new();
+
+        for token in tokens {
+            let Some(postings) = self.postings.get(token) else { continue };
+            let mut cases_for_token: HashSet<CaseId> = HashSet::new();
+            for doc_ref in postings {
+                if cases_for_token.insert(doc_ref.case_id) {
+                    coverage.entry(doc_ref.case_id).or_insert_with(|| (0, doc_ref.clone())).0 += 1;
+                }
+            }
+        }
+
+        coverage
+            .into_values()
+            .filter(|(matched, _)| *matched >= min_matches)
+            .map(|(matched, doc_ref)| (doc_ref, matched))
+            .collect()
+    }
+}
+
+/// How often [`TrieIndex::build_from_storage`] logs progress, in cases processed
+const BUILD_PROGRESS_LOG_INTERVAL: usize = 500;
+
+/// Counts of terms inserted into each sub-trie by [`TrieIndex::build_from_storage`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrieBuildStats {
+    pub cases_indexed: usize,
+    pub content_entries_indexed: usize,
+    pub citations_indexed: usize,
+}
+
+/// One row emitted per indexed term by [`TrieIndex::export`], with enough information (a
+/// whitespace-joined term and its full posting list) to reconstruct the entry via
+/// [`TrieIndex::insert_batch`] for a round-trip, or just to eyeball what a query should have
+/// matched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrieExportEntry {
+    pub source: TrieSource,
+    pub term: String,
+    pub frequency: u32,
+    pub document_refs: Vec<DocRef>,
+}
+
+/// One entry for [`TrieIndex::insert_batch`], carrying the same inputs as the corresponding
+/// single-entry `insert_case_name`/`insert_content`/`insert_citation` call it replaces.
+#[derive(Debug, Clone)]
+pub enum TrieEntry {
+    CaseName { case_name: String, case_id: CaseId },
+    Content { tokens: Vec<(String, usize)>, doc_ref: DocRef },
+    Citation { citation: String, doc_ref: DocRef },
+}
+
+/// Which specialized trie produced a [`TrieSearchResult`], for result provenance
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TrieSource {
+    CaseName,
+    Content,
+    Citation,
+}
+
+/// One completion from [`TrieIndex::get_completions_by_source`], tagging which sub-trie
+/// produced it so a caller (e.g. `search::SearchEngine::suggest`) can label the suggestion
+/// without re-deriving its origin from a follow-up query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieCompletion {
+    pub text: String,
+    pub source: TrieSource,
+}
+
+/// One case matching some, but not necessarily all, of a multi-token query — from
+/// [`TrieIndex::search_min_should_match`]; see `search::SearchConfig::min_should_match`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialMatch {
+    pub doc_ref: DocRef,
+    /// How many of the query's tokens this case actually contains.
+    pub matched_terms: usize,
+    /// Total tokens in the query, so a caller can compute a coverage fraction.
+    pub total_terms: usize,
+}
+
 /// Search result from trie lookup
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrieSearchResult {
     pub exact_matches: Vec<DocRef>,
     pub prefix_completions: Vec<String>,
     pub total_matches: usize,
+    /// Which trie produced this result
+    pub source: TrieSource,
+    /// Set when `exact_matches` came from [`TrieIndex`]'s auxiliary substring index rather than
+    /// an ordinary prefix-trie walk — i.e. the query matched somewhere inside an indexed
+    /// sequence ("board of education" inside "Brown v. Board of Education", or "freedom of
+    /// speech" inside a longer sentence) rather than from its first token. Callers like
+    /// `crate::search::SearchEngine` use this to rank these matches below true prefix/exact
+    /// matches, since a substring hit says less about relevance than a match anchored at the
+    /// start of the sequence.
+    #[serde(default)]
+    pub is_substring_match: bool,
+    /// Populated only by [`TrieIndex::search`]: one entry per sub-trie origin that had at least
+    /// one exact match, merged into this result's own `exact_matches`/`total_matches` (deduped
+    /// by [`DocRef`], since a case name is also indexed into the auxiliary substring index and
+    /// so can otherwise appear both as a `CaseName` and a `Content` hit for the same case). A
+    /// single-origin result — a sub-trie's own `search`, or [`TrieIndex::search_wildcard`] —
+    /// leaves this empty rather than a redundant one-element vec of itself.
+    #[serde(default)]
+    pub buckets: Vec<TrieSearchResult>,
+}
+
+impl TrieSearchResult {
+    fn with_source(mut self, source: TrieSource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Result of [`TrieIndex::resolve_citation`]: a citation query either matches exactly, matches
+/// on volume/reporter/page with a differing parenthetical year, is a partial citation with
+/// completions, or doesn't match at all.
+#[derive(Debug, Clone)]
+pub enum CitationResolution {
+    /// The full citation, including year, matched exactly (or the query had no year to check).
+    Exact(TrieSearchResult),
+    /// Volume/reporter/page matched exactly but the queried and indexed years differ; callers
+    /// should surface this as a warning rather than treating it as a miss.
+    YearMismatch { result: TrieSearchResult, queried_year: u32, indexed_year: u32 },
+    /// A partial citation (e.g. `"410 U.S."` with no page number) matched no citation exactly,
+    /// but is a prefix of one or more indexed citations; `TrieSearchResult::prefix_completions`
+    /// holds those full citations' document references.
+    Prefix(TrieSearchResult),
+    /// No indexed citation shares this query's volume/reporter/page.
+    NoMatch,
+}
+
+/// A fuzzy (edit-distance-tolerant) trie match, produced by [`TrieIndex::search_fuzzy`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuzzyMatch {
+    /// The word sequence actually stored in the trie, which may differ from the query
+    pub text: String,
+    /// Document references attached to the matched word sequence
+    pub document_refs: Vec<DocRef>,
+    /// Total Levenshtein distance summed across every token of the query, 0 for an exact match
+    pub edit_distance: usize,
 }
 
 impl TrieIndex {
@@ -76,31 +412,234 @@ impl TrieIndex {
             case_name_trie,
             content_trie,
             citation_trie,
+            substring_index: SubstringIndex::default(),
+            stopwords: HashSet::new(),
         })
     }
 
-    /// Load trie from disk
-    pub async fn load_from_disk<P: AsRef<Path>>(path: P) -> Result<Self> {
-        // TODO: Implement loading from FST or serialized format
-        Err(SearchError::NotSupported {
-            operation: "Loading trie from disk".to_string(),
+    /// Inject the stopword set `insert_content`/`insert_batch` consult for
+    /// `TrieConfig::skip_stopword_only_ngrams`. Callers building a trie alongside a
+    /// [`crate::text_processing::TextProcessor`] (see [`TrieIndex::build_from_storage`]) should
+    /// pass its [`crate::text_processing::TextProcessor::stopwords`] here; without it, the
+    /// stopword-only filter never fires (an empty set matches nothing).
+    pub fn set_stopwords(&mut self, stopwords: HashSet<String>) {
+        self.stopwords = stopwords;
+    }
+
+    /// Load a trie index snapshot written by [`TrieIndex::save_to_disk`]
+    ///
+    /// Note: `TrieConfig::use_fst` and `enable_memory_mapping` aren't honored by this snapshot
+    /// format yet — every trie round-trips through a single bincode-encoded file regardless of
+    /// `use_fst`, the same way `VectorIndex::load_from_disk`'s HNSW graph is still a stub.
+    /// FST-backed storage (with doc-ref postings in a sidecar sled tree keyed by term id) is a
+    /// larger follow-up; what this does guarantee is that a truncated or foreign-version file
+    /// is reported as [`SearchError::IndexCorrupted`] rather than a raw deserialization error,
+    /// so callers like [`crate::search::SearchEngine::from_snapshot`] can quarantine it.
+    pub async fn load_from_disk<P: AsRef<Path>>(config: TrieConfig, path: P) -> Result<Self> {
+        let bytes = tokio::fs::read(path).await?;
+
+        if bytes.len() < SNAPSHOT_HEADER_LEN {
+            return Err(SearchError::IndexCorrupted {
+                index_type: "trie".to_string(),
+                details: format!(
+                    "snapshot file is {} bytes, too short for the {}-byte header",
+                    bytes.len(),
+                    SNAPSHOT_HEADER_LEN
+                ),
+            });
+        }
+
+        let (header, body) = bytes.split_at(SNAPSHOT_HEADER_LEN);
+        if header[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC[..] {
+            return Err(SearchError::IndexCorrupted {
+                index_type: "trie".to_string(),
+                details: "snapshot magic bytes do not match; this is not a trie snapshot file".to_string(),
+            });
+        }
+
+        let version = u32::from_le_bytes(header[SNAPSHOT_MAGIC.len()..SNAPSHOT_HEADER_LEN].try_into().unwrap());
+        if version != SNAPSHOT_VERSION {
+            return Err(SearchError::IndexCorrupted {
+                index_type: "trie".to_string(),
+                details: format!(
+                    "snapshot version {} is not supported by this build (expected {})",
+                    version, SNAPSHOT_VERSION
+                ),
+            });
+        }
+
+        let snapshot: TrieSnapshot = bincode::deserialize(body).map_err(|e| SearchError::IndexCorrupted {
+            index_type: "trie".to_string(),
+            details: format!("failed to decode snapshot body: {e}"),
+        })?;
+
+        Ok(Self {
+            config,
+            case_name_trie: CaseNameTrie { root: snapshot.case_name_root },
+            content_trie: ContentTrie { root: snapshot.content_root },
+            citation_trie: CitationTrie { root: snapshot.citation_root },
+            substring_index: snapshot.substring_index,
+            stopwords: HashSet::new(),
         })
     }
 
-    /// Save trie to disk
+    /// Save this trie index as a single bincode-encoded snapshot file, prefixed with a magic
+    /// number and format version that [`TrieIndex::load_from_disk`] validates on read
     pub async fn save_to_disk<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        // TODO: Implement saving to FST or serialized format
+        let snapshot = TrieSnapshot {
+            case_name_root: self.case_name_trie.root.clone(),
+            content_root: self.content_trie.root.clone(),
+            citation_root: self.citation_trie.root.clone(),
+            substring_index: self.substring_index.clone(),
+        };
+        let body = bincode::serialize(&snapshot)?;
+
+        let mut bytes = Vec::with_capacity(SNAPSHOT_HEADER_LEN + body.len());
+        bytes.extend_from_slice(&SNAPSHOT_MAGIC);
+        bytes.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&body);
+
+        if let Some(parent) = path.as_ref().parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, bytes).await?;
         Ok(())
     }
 
-    /// Insert case name into trie
+    /// Build a trie index directly from cases already sitting in `storage`, tokenizing each
+    /// case's normalized text with `text_processor` one case at a time as it streams through
+    /// [`crate::storage::StorageManager::list_case_ids`]. A case's text is loaded, indexed, and
+    /// dropped before the next case is loaded, so memory is bounded by the growing trie itself
+    /// rather than the size of the whole corpus. This is the underlying implementation behind
+    /// the `--rebuild-index` CLI flag.
+    ///
+    /// Progress is reported via `tracing::info!` every [`BUILD_PROGRESS_LOG_INTERVAL`] cases.
+    /// The returned [`TrieBuildStats`] gives per-sub-trie term counts for the caller to log or
+    /// assert on.
+    pub async fn build_from_storage(
+        config: TrieConfig,
+        storage: &crate::storage::StorageManager,
+        text_processor: &crate::text_processing::TextProcessor,
+    ) -> Result<(Self, TrieBuildStats)> {
+        let mut trie_index = Self::new(config).await?;
+        trie_index.set_stopwords(text_processor.stopwords().clone());
+        let mut stats = TrieBuildStats::default();
+
+        let case_ids = storage.list_case_ids().await?;
+        let total = case_ids.len();
+
+        for case_id in case_ids {
+            let Some(metadata) = storage.get_case_metadata(&case_id).await? else { continue };
+            let Some(full_text) = storage
+                .get_case_text(&case_id, crate::storage::TextForm::Normalized)
+                .await?
+            else {
+                continue;
+            };
+
+            trie_index.insert_case_name(&metadata.name, case_id)?;
+
+            // Reuse a prior processing pass's sentence spans when available, rather than
+            // re-running `process_text` on every case in the corpus — the whole point of
+            // `store_processed`. A missing or schema-mismatched entry falls back to reprocessing.
+            let sentences = match storage.get_processed(&case_id).await? {
+                Some(artifacts) => artifacts.sentences,
+                None => text_processor.process_text(&full_text.text).await?.sentences,
+            };
+
+            for (paragraph_index, sentence) in sentences.iter().enumerate() {
+                let tokens = sentence.word_offsets();
+                if tokens.is_empty() {
+                    continue;
+                }
+                trie_index.insert_content(
+                    &tokens,
+                    DocRef { case_id, paragraph_index, char_offset: None },
+                )?;
+                stats.content_entries_indexed += 1;
+            }
+
+            for citation in &metadata.citations {
+                trie_index.insert_citation(
+                    citation,
+                    DocRef { case_id, paragraph_index: 0, char_offset: None },
+                )?;
+                stats.citations_indexed += 1;
+            }
+
+            stats.cases_indexed += 1;
+            if stats.cases_indexed % BUILD_PROGRESS_LOG_INTERVAL == 0 {
+                tracing::info!(
+                    "Trie build from storage progress: {}/{} cases indexed",
+                    stats.cases_indexed,
+                    total
+                );
+            }
+        }
+
+        tracing::info!(
+            "Trie build from storage complete: {} cases, {} content entries, {} citations",
+            stats.cases_indexed,
+            stats.content_entries_indexed,
+            stats.citations_indexed
+        );
+
+        Ok((trie_index, stats))
+    }
+
+    /// Insert case name into trie. Also indexes the name's tokens (lowercased, unsplit by party)
+    /// into the auxiliary [`SubstringIndex`], so a query landing in the middle of the name (e.g.
+    /// `"v. board"`, beyond what [`CaseNameTrie::indexable_paths`]'s party-split paths already
+    /// cover) can still be found by [`TrieIndex::search`]'s substring fallback.
     pub fn insert_case_name(&mut self, case_name: &str, case_id: CaseId) -> Result<()> {
-        self.case_name_trie.insert(case_name, case_id)
+        self.case_name_trie.insert(case_name, case_id)?;
+        let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+        let tokens: Vec<String> = case_name.split_whitespace().map(|s| s.to_lowercase()).collect();
+        self.substring_index.insert(&tokens, &doc_ref);
+        Ok(())
+    }
+
+    /// Insert a content phrase (e.g. a sentence) into the trie, with the char offset of its
+    /// first token (within the case's normalized text) recorded on `doc_ref` so exact matches
+    /// can be located later. `tokens` are `(text, char_offset)` pairs as produced by
+    /// [`crate::text_processing::Token`]; the offset of `tokens[0]` becomes `doc_ref.char_offset`.
+    /// Inserting the same `(case_id, paragraph_index, char_offset)` triple twice — e.g. because a
+    /// case was reprocessed — is a no-op rather than a duplicate posting. Also indexes the
+    /// sentence's tokens into the auxiliary [`SubstringIndex`], so a phrase buried mid-sentence
+    /// (e.g. `"freedom of speech"` inside "no law abridging the freedom of speech") can still be
+    /// found by [`TrieIndex::search`]'s substring fallback.
+    pub fn insert_content(&mut self, tokens: &[(String, usize)], mut doc_ref: DocRef) -> Result<()> {
+        let words: Vec<String> = tokens.iter().map(|(text, _)| text.to_lowercase()).collect();
+        if self.is_low_value_ngram(&words) {
+            return Ok(());
+        }
+
+        if let Some((_, first_position)) = tokens.first() {
+            doc_ref.char_offset = Some(*first_position);
+        }
+        self.substring_index.insert(&words, &doc_ref);
+        self.content_trie.insert(&words, doc_ref)
     }
 
-    /// Insert content sequence into trie
-    pub fn insert_content(&mut self, tokens: &[String], doc_ref: DocRef) -> Result<()> {
-        self.content_trie.insert(tokens, doc_ref)
+    /// Whether `words` (a content sentence about to be indexed) carries no retrieval value and
+    /// should be dropped rather than bloating the content trie, per
+    /// `TrieConfig::skip_stopword_only_ngrams`/`min_token_length`. A sentence is low-value only
+    /// when *every* token fails the relevant bar — one non-stopword or long-enough token is
+    /// enough to keep the whole sentence, so a stopword appearing mid-phrase
+    /// (`"freedom of speech"`) never causes it to be dropped.
+    fn is_low_value_ngram(&self, words: &[String]) -> bool {
+        if words.is_empty() {
+            return false;
+        }
+        if self.config.skip_stopword_only_ngrams && words.iter().all(|w| self.stopwords.contains(w)) {
+            return true;
+        }
+        if self.config.min_token_length > 0
+            && words.iter().all(|w| w.chars().count() < self.config.min_token_length)
+        {
+            return true;
+        }
+        false
     }
 
     /// Insert citation into trie
@@ -108,172 +647,2578 @@ impl TrieIndex {
         self.citation_trie.insert(citation, doc_ref)
     }
 
-    /// Search for exact matches and prefixes
+    /// Insert a batch of [`TrieEntry`] values in one pass, replacing the equivalent sequence
+    /// of `insert_case_name`/`insert_content`/`insert_citation` calls when ingesting many
+    /// entries at once (see `ingestion::pipeline`). Each sub-trie's paths are sorted once and
+    /// walked via [`TrieNode::insert_sorted_batch`], so entries sharing a prefix (e.g. many
+    /// case names starting with "United States v.") reuse the same node path and defer their
+    /// frequency/posting updates to a single batched pass instead of one per entry.
+    pub fn insert_batch(&mut self, entries: Vec<TrieEntry>) -> Result<()> {
+        let mut case_name_paths = Vec::new();
+        let mut content_paths = Vec::new();
+        let mut citation_paths = Vec::new();
+
+        for entry in entries {
+            match entry {
+                TrieEntry::CaseName { case_name, case_id } => {
+                    let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+                    for path in CaseNameTrie::indexable_paths(&case_name) {
+                        case_name_paths.push((path, doc_ref.clone()));
+                    }
+                    let tokens: Vec<String> =
+                        case_name.split_whitespace().map(|s| s.to_lowercase()).collect();
+                    self.substring_index.insert(&tokens, &doc_ref);
+                }
+                TrieEntry::Citation { citation, doc_ref } => {
+                    let normalized = crate::citation::normalize_for_index(&citation);
+                    let tokens: Vec<String> =
+                        normalized.split_whitespace().map(|s| s.to_string()).collect();
+                    citation_paths.push((tokens, doc_ref));
+                }
+                TrieEntry::Content { tokens, mut doc_ref } => {
+                    let words: Vec<String> =
+                        tokens.iter().map(|(text, _)| text.to_lowercase()).collect();
+                    if self.is_low_value_ngram(&words) {
+                        continue;
+                    }
+                    if let Some((_, first_position)) = tokens.first() {
+                        doc_ref.char_offset = Some(*first_position);
+                    }
+                    self.substring_index.insert(&words, &doc_ref);
+                    content_paths.push((words, doc_ref));
+                }
+            }
+        }
+
+        Self::batch_insert_paths(&mut self.case_name_trie.root, case_name_paths);
+        Self::batch_insert_paths(&mut self.content_trie.root, content_paths);
+        Self::batch_insert_paths(&mut self.citation_trie.root, citation_paths);
+        Ok(())
+    }
+
+    /// Sort `paths` lexicographically and hand them to [`TrieNode::insert_sorted_batch`], the
+    /// ordering that lets it recognize shared prefixes as contiguous runs.
+    fn batch_insert_paths(root: &mut TrieNode, mut paths: Vec<(Vec<String>, DocRef)>) {
+        paths.sort_by(|a, b| a.0.cmp(&b.0));
+        root.insert_sorted_batch(&paths, 0);
+    }
+
+    /// Whether `query` contains a `*` wildcard token, i.e. should be routed to
+    /// [`TrieIndex::search_wildcard`] instead of [`TrieIndex::search`]'s exact/prefix path.
+    pub fn is_wildcard_query(query: &str) -> bool {
+        query.split_whitespace().any(|token| token == "*")
+    }
+
+    /// Search case names and content for a pattern containing one or more `*` wildcard tokens,
+    /// e.g. `"freedom of *"` (trailing: any completion) or `"* v. board of education"`
+    /// (leading/middle: any single token at that position). Citations are not searched this way
+    /// — a citation's volume/reporter/page/year structure doesn't have a natural "any word"
+    /// slot the way case names and prose do.
+    ///
+    /// Matches from both tries are aggregated up to
+    /// [`TrieConfig::wildcard_max_results`] `DocRef`s combined, to bound the cost of a wildcard
+    /// landing on a highly-branching position; a query with no `*` token still works but is
+    /// better served by [`TrieIndex::search`].
+    pub fn search_wildcard(&self, query: &str) -> Result<TrieSearchResult> {
+        let cap = self.config.wildcard_max_results;
+        let tokens: Vec<String> = query.split_whitespace().map(|s| s.to_lowercase()).collect();
+
+        let mut doc_refs = Vec::new();
+        self.case_name_trie.root.collect_wildcard(&tokens, cap, &mut doc_refs);
+        self.content_trie.root.collect_wildcard(&tokens, cap, &mut doc_refs);
+        doc_refs.truncate(cap);
+
+        Ok(TrieSearchResult {
+            total_matches: doc_refs.len(),
+            exact_matches: doc_refs,
+            prefix_completions: Vec::new(),
+            source: TrieSource::Content,
+            is_substring_match: false,
+            buckets: Vec::new(),
+
+        })
+    }
+
+    /// Search all three specialized tries and merge whatever each finds, rather than returning
+    /// as soon as one origin has an exact match. A query like `"miranda"` used to only ever
+    /// surface the case-name hit ("Miranda v. Arizona") because case-name search was tried
+    /// first and short-circuited the rest; now it also returns other cases' content mentioning
+    /// Miranda rights, and a matching citation, all tagged with their origin in
+    /// [`TrieSearchResult::buckets`] (one bucket per origin that matched). The top-level
+    /// `exact_matches`/`total_matches` are the union of every bucket's matches, deduped by
+    /// [`DocRef`] — a case name is also indexed into the auxiliary substring index (see
+    /// [`SubstringIndex`]'s doc comment), so without dedup the same case could otherwise appear
+    /// twice: once as a `CaseName` bucket hit and once as a `Content` bucket hit on itself.
     pub fn search(&self, query: &str) -> Result<TrieSearchResult> {
-        // Try case name search first
+        if Self::is_wildcard_query(query) {
+            return self.search_wildcard(query);
+        }
+
+        let mut buckets: Vec<TrieSearchResult> = Vec::new();
+
         if let Ok(result) = self.case_name_trie.search(query) {
             if !result.exact_matches.is_empty() {
-                return Ok(result);
+                buckets.push(result);
             }
         }
 
-        // Try citation search
         if let Ok(result) = self.citation_trie.search(query) {
             if !result.exact_matches.is_empty() {
-                return Ok(result);
+                buckets.push(result);
             }
         }
 
-        // Fall back to content search
+        // Content: an ordinary prefix walk first; if that finds nothing, fall back to the
+        // auxiliary substring index (see its doc comment) so a query matches content wherever
+        // it occurs in a sentence, not just at the sentence's first token.
         let tokens: Vec<String> = query.split_whitespace().map(|s| s.to_string()).collect();
-        self.content_trie.search_tokens(&tokens)
-    }
+        let content_result = self.content_trie.search_tokens(&tokens)?;
+        if !content_result.exact_matches.is_empty() {
+            buckets.push(content_result);
+        } else {
+            let lowercase_tokens: Vec<String> = query.split_whitespace().map(|s| s.to_lowercase()).collect();
+            let substring_matches = self.substring_index.search(&lowercase_tokens);
+            if !substring_matches.is_empty() {
+                buckets.push(TrieSearchResult {
+                    total_matches: substring_matches.len(),
+                    exact_matches: substring_matches,
+                    prefix_completions: Vec::new(),
+                    source: TrieSource::Content,
+                    is_substring_match: true,
+                    buckets: Vec::new(),
+                });
+            }
+        }
 
-    /// Get completion suggestions for a prefix
-    pub fn get_completions(&self, prefix: &str, limit: usize) -> Result<Vec<String>> {
-        // TODO: Implement completion logic
-        Ok(Vec::new())
-    }
-}
+        if buckets.is_empty() {
+            return Ok(TrieSearchResult {
+                exact_matches: Vec::new(),
+                prefix_completions: Vec::new(),
+                total_matches: 0,
+                source: TrieSource::Content,
+                is_substring_match: false,
+                buckets: Vec::new(),
+            });
+        }
 
-impl CaseNameTrie {
-    fn new() -> Self {
-        Self {
-            root: TrieNode::new(),
+        let mut exact_matches: Vec<DocRef> = Vec::new();
+        for bucket in &buckets {
+            for doc_ref in &bucket.exact_matches {
+                if !exact_matches.contains(doc_ref) {
+                    exact_matches.push(doc_ref.clone());
+                }
+            }
         }
+        let source = buckets[0].source;
+        let is_substring_match = buckets.len() == 1 && buckets[0].is_substring_match;
+
+        Ok(TrieSearchResult {
+            total_matches: exact_matches.len(),
+            exact_matches,
+            prefix_completions: Vec::new(),
+            source,
+            is_substring_match,
+            buckets,
+        })
     }
 
-    fn insert(&mut self, case_name: &str, case_id: CaseId) -> Result<()> {
-        let tokens: Vec<String> = case_name.split_whitespace().map(|s| s.to_lowercase()).collect();
-        let doc_ref = DocRef {
-            case_id,
-            paragraph_index: 0,
-            char_offset: None,
-        };
-        self.root.insert(&tokens, doc_ref);
-        Ok(())
+    /// Look up every [`DocRef`] whose case contains all of `tokens` (already lowercased by the
+    /// caller), in any position, via the auxiliary substring index — the same posting lists
+    /// [`TrieIndex::search`]'s substring fallback uses. Exposed so
+    /// [`crate::boolean_query::evaluate`] can build `AND`/`OR`/`NOT` term and phrase matches on
+    /// top of it without duplicating [`SubstringIndex`]'s intersection logic.
+    pub(crate) fn substring_match(&self, tokens: &[String]) -> Vec<DocRef> {
+        self.substring_index.search(tokens)
     }
 
-    fn search(&self, query: &str) -> Result<TrieSearchResult> {
+    /// Content-trie cases matching at least `min_should_match` of `query`'s (lowercased,
+    /// whitespace-split) tokens — the union-with-coverage counterpart to [`TrieIndex::search`]'s
+    /// all-or-nothing content match, for `search::SearchConfig::min_should_match`. Meant as a
+    /// fallback when `search` finds no content match at all: a five-token query with only four
+    /// tokens present in a case still surfaces it here (scored down by coverage), instead of
+    /// requiring the full token sequence or nothing. `min_should_match` above `query`'s own token
+    /// count, or an empty `query`, both yield an empty vec — the former degenerates to requiring
+    /// every token, which `search`'s content search already covers.
+    pub fn search_min_should_match(&self, query: &str, min_should_match: usize) -> Vec<PartialMatch> {
         let tokens: Vec<String> = query.split_whitespace().map(|s| s.to_lowercase()).collect();
-        Ok(self.root.search(&tokens))
+        if tokens.is_empty() || min_should_match > tokens.len() {
+            return Vec::new();
+        }
+
+        self.substring_index
+            .search_with_coverage(&tokens, min_should_match)
+            .into_iter()
+            .map(|(doc_ref, matched_terms)| PartialMatch { doc_ref, matched_terms, total_terms: tokens.len() })
+            .collect()
     }
-}
 
-impl ContentTrie {
-    fn new() -> Self {
-        Self {
-            root: TrieNode::new(),
+    /// Get completion suggestions for a prefix
+    ///
+    /// Walks the case-name, citation, and content tries for entries beginning with `prefix`
+    /// and returns up to `limit` of them, ranked by [`TrieNode::frequency`] so a popular case
+    /// name like "miranda v arizona" is suggested ahead of one inserted only once, with a
+    /// deterministic lexicographic tie-break so ordering never depends on trie insertion or
+    /// traversal order. `prefix` is clamped to [`TrieConfig::max_prefix_length`] before the
+    /// walk. When `bias_shorter` is set, a shorter completion wins a tie over a longer one of
+    /// equal frequency, ahead of the lexicographic tie-break — useful for autocomplete UIs
+    /// where a terser suggestion is usually the more useful one. A prefix with no matches
+    /// yields an empty list, not an error.
+    pub fn get_completions(&self, prefix: &str, limit: usize, bias_shorter: bool) -> Result<Vec<String>> {
+        let prefix: String = prefix.chars().take(self.config.max_prefix_length).collect();
+
+        let lowercase_tokens: Vec<String> =
+            prefix.split_whitespace().map(|s| s.to_lowercase()).collect();
+        if lowercase_tokens.is_empty() {
+            return Ok(Vec::new());
         }
+        let citation_tokens: Vec<String> =
+            prefix.split_whitespace().map(|s| s.to_string()).collect();
+
+        let mut ranked = Vec::new();
+        ranked.extend(self.case_name_trie.root.completions(&lowercase_tokens));
+        ranked.extend(self.citation_trie.root.completions(&citation_tokens));
+        ranked.extend(self.content_trie.root.completions(&lowercase_tokens));
+
+        TrieNode::rank_completions(&mut ranked, bias_shorter);
+        ranked.dedup_by(|(a, _), (b, _)| a == b);
+        ranked.truncate(limit);
+
+        Ok(ranked.into_iter().map(|(completion, _)| completion).collect())
     }
 
-    fn insert(&mut self, tokens: &[String], doc_ref: DocRef) -> Result<()> {
-        let normalized_tokens: Vec<String> = tokens.iter().map(|t| t.to_lowercase()).collect();
-        self.root.insert(&normalized_tokens, doc_ref);
-        Ok(())
+    /// Like [`TrieIndex::get_completions`], but keeps each completion's originating sub-trie
+    /// instead of merging them into a single untagged list, ranked the same way (by
+    /// [`TrieNode::frequency`] descending, then — if `bias_shorter` — length ascending, then
+    /// lexicographically) across all three sources combined.
+    pub fn get_completions_by_source(&self, prefix: &str, limit: usize, bias_shorter: bool) -> Result<Vec<TrieCompletion>> {
+        let prefix: String = prefix.chars().take(self.config.max_prefix_length).collect();
+
+        let lowercase_tokens: Vec<String> =
+            prefix.split_whitespace().map(|s| s.to_lowercase()).collect();
+        if lowercase_tokens.is_empty() {
+            return Ok(Vec::new());
+        }
+        let citation_tokens: Vec<String> =
+            prefix.split_whitespace().map(|s| s.to_string()).collect();
+
+        let mut ranked: Vec<(String, u32, TrieSource)> = Vec::new();
+        ranked.extend(
+            self.case_name_trie.root.completions(&lowercase_tokens).into_iter().map(|(text, freq)| (text, freq, TrieSource::CaseName)),
+        );
+        ranked.extend(
+            self.citation_trie.root.completions(&citation_tokens).into_iter().map(|(text, freq)| (text, freq, TrieSource::Citation)),
+        );
+        ranked.extend(
+            self.content_trie.root.completions(&lowercase_tokens).into_iter().map(|(text, freq)| (text, freq, TrieSource::Content)),
+        );
+
+        ranked.sort_by(|(a, freq_a, _), (b, freq_b, _)| {
+            freq_b.cmp(freq_a).then_with(|| if bias_shorter { a.len().cmp(&b.len()).then_with(|| a.cmp(b)) } else { a.cmp(b) })
+        });
+        ranked.dedup_by(|(a, _, source_a), (b, _, source_b)| a == b && source_a == source_b);
+        ranked.truncate(limit);
+
+        Ok(ranked.into_iter().map(|(text, _, source)| TrieCompletion { text, source }).collect())
     }
 
-    fn search_tokens(&self, tokens: &[String]) -> Result<TrieSearchResult> {
-        let normalized_tokens: Vec<String> = tokens.iter().map(|t| t.to_lowercase()).collect();
-        Ok(self.root.search(&normalized_tokens))
+    /// Fuzzy search tolerant of misspelled tokens (e.g. "Mirranda v. Arizona" for "Miranda v.
+    /// Arizona"). Each query token may differ from the trie edge it matches by up to a
+    /// per-token Levenshtein distance budget: `max_edit_distance` when given, otherwise
+    /// [`TrieConfig::fuzzy_max_edit_distance_short`] for tokens shorter than
+    /// [`TrieConfig::fuzzy_short_token_length_threshold`] characters and
+    /// [`TrieConfig::fuzzy_max_edit_distance_long`] otherwise.
+    ///
+    /// Matches are annotated with their total edit distance (summed across tokens) and sorted
+    /// ascending by it, so a distance-0 (exact) match always sorts first; callers combining
+    /// this with [`TrieIndex::search`]'s exact matches should still prefer those; this is a
+    /// pure lexical fallback for when they come back empty.
+    pub fn search_fuzzy(&self, query: &str, max_edit_distance: Option<usize>) -> Result<Vec<FuzzyMatch>> {
+        let lowercase_tokens: Vec<String> = query.split_whitespace().map(|s| s.to_lowercase()).collect();
+        let citation_tokens: Vec<String> = query.split_whitespace().map(|s| s.to_string()).collect();
+        if lowercase_tokens.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let budget_for = |token: &str| {
+            max_edit_distance.unwrap_or_else(|| self.default_max_edit_distance(token))
+        };
+
+        let mut matches = Vec::new();
+        let mut path = Vec::new();
+        self.case_name_trie.root.fuzzy_matches(&lowercase_tokens, &budget_for, &mut path, 0, &mut matches);
+        path.clear();
+        self.content_trie.root.fuzzy_matches(&lowercase_tokens, &budget_for, &mut path, 0, &mut matches);
+        path.clear();
+        self.citation_trie.root.fuzzy_matches(&citation_tokens, &budget_for, &mut path, 0, &mut matches);
+
+        matches.sort_by(|a, b| a.edit_distance.cmp(&b.edit_distance).then_with(|| a.text.cmp(&b.text)));
+        Ok(matches)
     }
-}
 
-impl CitationTrie {
-    fn new() -> Self {
-        Self {
-            root: TrieNode::new(),
+    /// Default per-token edit-distance budget used by [`TrieIndex::search_fuzzy`] when the
+    /// caller doesn't pin one: a single typo is proportionally larger on a short word, so
+    /// short tokens get a tighter budget than long ones.
+    fn default_max_edit_distance(&self, token: &str) -> usize {
+        if token.chars().count() < self.config.fuzzy_short_token_length_threshold {
+            self.config.fuzzy_max_edit_distance_short
+        } else {
+            self.config.fuzzy_max_edit_distance_long
         }
     }
 
-    fn insert(&mut self, citation: &str, doc_ref: DocRef) -> Result<()> {
-        let tokens: Vec<String> = citation.split_whitespace().map(|s| s.to_string()).collect();
-        self.root.insert(&tokens, doc_ref);
+    /// Remove every trie entry belonging to `case_id` from all three specialized tries. Drops
+    /// each matching [`DocRef`], decrements the owning node's [`TrieNode::frequency`] to match,
+    /// and prunes any node left with no children and no remaining word ending, so a deleted
+    /// case doesn't linger as a dangling `DocRef` that surfaces in search results and then
+    /// fails metadata lookup against [`crate::storage::StorageManager`]. Returns the total
+    /// number of document refs removed across the three tries.
+    pub fn remove_case(&mut self, case_id: CaseId) -> usize {
+        self.substring_index.remove_case(case_id);
+        self.case_name_trie.root.remove_case(case_id)
+            + self.content_trie.root.remove_case(case_id)
+            + self.citation_trie.root.remove_case(case_id)
+    }
+
+    /// Atomically replace a case's case-name trie entry: remove the `DocRef` for `case_id`
+    /// from `old_name`'s path and insert it at `new_name`'s path instead, without disturbing
+    /// any other case that happens to share a path prefix with `old_name`. A no-op removal
+    /// (e.g. `old_name` was already stale) is not an error; the new name is still inserted.
+    /// Also moves `old_name`'s tokens out of the auxiliary substring index and `new_name`'s
+    /// tokens in, without touching that case's other (content) substring entries.
+    pub fn update_case_name(&mut self, case_id: CaseId, old_name: &str, new_name: &str) -> Result<()> {
+        self.case_name_trie.remove_name(case_id, old_name);
+        let old_tokens: Vec<String> = old_name.split_whitespace().map(|s| s.to_lowercase()).collect();
+        self.substring_index.remove(&old_tokens, case_id);
+
+        self.case_name_trie.insert(new_name, case_id)?;
+        let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+        let new_tokens: Vec<String> = new_name.split_whitespace().map(|s| s.to_lowercase()).collect();
+        self.substring_index.insert(&new_tokens, &doc_ref);
         Ok(())
     }
 
-    fn search(&self, query: &str) -> Result<TrieSearchResult> {
-        let tokens: Vec<String> = query.split_whitespace().map(|s| s.to_string()).collect();
-        Ok(self.root.search(&tokens))
+    /// Drop rarely-occurring content terms and cap oversized posting lists, to keep
+    /// single-occurrence n-grams from dominating [`ContentTrie`] on a large corpus. A term whose
+    /// `frequency` is below `min_frequency` is removed entirely (its subtree pruned back up to
+    /// the nearest still-needed ancestor, the same way [`TrieIndex::remove_case`] prunes empty
+    /// subtrees); a term that clears the frequency bar but has more than `max_postings`
+    /// `DocRef`s has its posting list truncated to `max_postings`, keeping the term searchable
+    /// while bounding its memory footprint. Only [`ContentTrie`] is pruned — case names and
+    /// citations are low-cardinality enough that they don't need this, and a pruned case name or
+    /// citation would make a specific case permanently unfindable by name rather than merely
+    /// falling back to vector search the way a pruned phrase does. Meant to run after bulk
+    /// ingestion, not on every insert.
+    pub fn prune(&mut self, min_frequency: u32, max_postings: usize) -> PruneReport {
+        let mut report = PruneReport::default();
+        self.content_trie.root.prune(min_frequency, max_postings, &mut report);
+        report
     }
-}
 
-impl TrieNode {
-    fn new() -> Self {
-        Self {
-            children: HashMap::new(),
-            is_end_of_word: false,
-            document_refs: Vec::new(),
-            frequency: 0,
+    /// Walk all three tries and report their combined memory footprint: total node count,
+    /// total edge-key bytes, and total posting (`DocRef`) count. Exists so the effect of
+    /// [`TrieNode`]'s compact sorted-edge representation is measurable rather than assumed.
+    pub fn memory_stats(&self) -> TrieMemoryStats {
+        let mut stats = TrieMemoryStats { node_count: 0, edge_bytes: 0, posting_count: 0 };
+        self.case_name_trie.root.accumulate_memory_stats(&mut stats);
+        self.content_trie.root.accumulate_memory_stats(&mut stats);
+        self.citation_trie.root.accumulate_memory_stats(&mut stats);
+        stats
+    }
+
+    /// Walk all three tries and report [`TrieIndexStats`]: per-sub-trie term counts, combined
+    /// node/posting/depth totals, and an estimated memory footprint. Like [`TrieIndex::memory_stats`],
+    /// this is a single O(total node count) pass over borrowed nodes with no cloning, so it stays
+    /// bounded even on a multi-million-node trie rather than materializing anything proportional
+    /// to the number of indexed terms.
+    pub fn get_stats(&self) -> TrieIndexStats {
+        let mut case_name = TrieNodeStats::default();
+        self.case_name_trie.root.accumulate_index_stats(0, &mut case_name);
+        let mut content = TrieNodeStats::default();
+        self.content_trie.root.accumulate_index_stats(0, &mut content);
+        let mut citation = TrieNodeStats::default();
+        self.citation_trie.root.accumulate_index_stats(0, &mut citation);
+
+        let total_nodes = case_name.nodes + content.nodes + citation.nodes;
+        let total_document_refs = case_name.document_refs + content.document_refs + citation.document_refs;
+        let edge_bytes = case_name.edge_bytes + content.edge_bytes + citation.edge_bytes;
+        let estimated_memory_bytes = total_nodes * std::mem::size_of::<TrieNode>()
+            + edge_bytes
+            + total_document_refs * std::mem::size_of::<DocRef>();
+
+        TrieIndexStats {
+            case_name_terms: case_name.terms,
+            content_terms: content.terms,
+            citation_terms: citation.terms,
+            total_nodes,
+            total_document_refs,
+            max_depth: case_name.max_depth.max(content.max_depth).max(citation.max_depth),
+            estimated_memory_bytes,
         }
     }
 
-    fn insert(&mut self, tokens: &[String], doc_ref: DocRef) {
-        let mut current = self;
-        
-        for token in tokens {
-            current = current.children.entry(token.clone()).or_insert_with(TrieNode::new);
+    /// Number of distinct cases represented anywhere in the trie, for `SearchEngine::get_stats`.
+    /// Counted from the case-name trie rather than content or citations: every ingested case gets
+    /// its name indexed exactly once (see [`TrieIndex::insert_case_name`]), while a case can
+    /// contribute anywhere from zero (no citation) to hundreds (one per paragraph) of postings to
+    /// the other two. An explicit-stack walk, like [`TrieIndex::export`], so it isn't bounded by
+    /// call-stack depth on a deep trie.
+    pub fn indexed_case_count(&self) -> usize {
+        let mut case_ids: HashSet<CaseId> = HashSet::new();
+        let mut stack: Vec<&TrieNode> = vec![&self.case_name_trie.root];
+
+        while let Some(node) = stack.pop() {
+            case_ids.extend(node.document_refs.iter().map(|doc_ref| doc_ref.case_id));
+            stack.extend(node.children.iter().map(|(_, child)| child));
         }
-        
-        current.is_end_of_word = true;
-        current.document_refs.push(doc_ref);
-        current.frequency += 1;
+
+        case_ids.len()
     }
 
-    fn search(&self, tokens: &[String]) -> TrieSearchResult {
-        let mut current = self;
-        
-        // Traverse to the end of the query
-        for token in tokens {
-            if let Some(child) = current.children.get(token) {
-                current = child;
-            } else {
-                // No matches found
-                return TrieSearchResult {
-                    exact_matches: Vec::new(),
-                    prefix_completions: Vec::new(),
-                    total_matches: 0,
+    /// Stream every indexed term in `source`'s sub-trie to `writer` as one [`TrieExportEntry`]
+    /// JSON object per line, for inspecting what's actually indexed when a search result looks
+    /// wrong (see the `--dump-trie` CLI flag). The walk uses an explicit stack rather than
+    /// recursion, so it isn't bounded by the call stack's depth the way a naive recursive walk
+    /// over an arbitrarily deep trie would be, and holds only the pending stack frames and the
+    /// writer's own buffering in memory at once rather than every term up front. Returns the
+    /// number of terms written. Round-trips through [`TrieIndex::insert_batch`] via
+    /// [`TrieEntry::Content`] (each exported term re-tokenized on whitespace).
+    pub fn export<W: std::io::Write>(&self, mut writer: W, source: TrieSource) -> Result<usize> {
+        let mut written = 0usize;
+        let mut stack: Vec<(&TrieNode, Vec<String>)> = vec![(self.root_for(source), Vec::new())];
+
+        while let Some((node, path)) = stack.pop() {
+            if node.is_end_of_word {
+                let entry = TrieExportEntry {
+                    source,
+                    term: path.join(" "),
+                    frequency: node.frequency,
+                    document_refs: node.document_refs.clone(),
                 };
+                serde_json::to_writer(&mut writer, &entry)?;
+                writer.write_all(b"\n")?;
+                written += 1;
+            }
+
+            for (edge, child) in &node.children {
+                let mut child_path = path.clone();
+                child_path.push(edge.to_string());
+                stack.push((child, child_path));
             }
         }
-        
-        // Collect exact matches if this is end of word
-        let exact_matches = if current.is_end_of_word {
-            current.document_refs.clone()
+
+        Ok(written)
+    }
+
+    /// Number of indexed terms in `source` starting with `prefix`, without materializing any of
+    /// them. Walks down to the node at `prefix` — O(m) in the prefix's token count — then counts
+    /// `is_end_of_word` nodes in that subtree — O(k) in the subtree's size — so this is cheap
+    /// relative to [`TrieIndex::get_completions`], which has to also collect and rank the terms
+    /// themselves. Returns 0 for a prefix with no matches, including an empty prefix.
+    pub fn count_with_prefix(&self, source: TrieSource, prefix: &str) -> usize {
+        let tokens = Self::tokens_for(source, prefix);
+        if tokens.is_empty() {
+            return 0;
+        }
+        match self.root_for(source).node_at(&tokens) {
+            Some(node) => node.count_terms(),
+            None => 0,
+        }
+    }
+
+    /// Deepest path in `source` matching the start of `query`, as a space-joined string of the
+    /// tokens actually matched (which may be fewer than `query` has, or all of them). O(m) in
+    /// `query`'s token count. Meant for query routing: an empty result means not even `query`'s
+    /// first token is indexed in this sub-trie at all, so [`TrieIndex::search`]/`search_fuzzy`
+    /// on it can be skipped in favor of going straight to vector search.
+    pub fn longest_indexed_prefix(&self, source: TrieSource, query: &str) -> Option<String> {
+        let tokens = Self::tokens_for(source, query);
+        let matched = self.root_for(source).longest_matching_prefix(&tokens);
+        if matched.is_empty() {
+            None
         } else {
-            Vec::new()
-        };
-        
-        // Collect prefix completions
-        let prefix_completions = self.collect_completions(current, tokens, 10);
-        
-        TrieSearchResult {
-            total_matches: exact_matches.len() + prefix_completions.len(),
-            exact_matches,
-            prefix_completions,
+            Some(matched.join(" "))
         }
     }
 
-    fn collect_completions(&self, node: &TrieNode, prefix: &[String], limit: usize) -> Vec<String> {
-        let mut completions = Vec::new();
-        let mut stack = vec![(node, prefix.to_vec())];
-        
-        while let Some((current, path)) = stack.pop() {
-            if completions.len() >= limit {
+    /// Resolve a citation query against the citation trie, tolerating a parenthetical-year
+    /// mismatch: if the exact citation (including year) isn't indexed but its
+    /// volume/reporter/page triple is, under a different year, that's reported as
+    /// [`CitationResolution::YearMismatch`] instead of a miss — common when a secondary source
+    /// misreports a case's decision year. Reporter spelling is normalized before matching (see
+    /// [`crate::citation`]), so alias variants resolve identically.
+    pub fn resolve_citation(&self, query: &str) -> CitationResolution {
+        if let Ok(result) = self.citation_trie.search(query) {
+            if !result.exact_matches.is_empty() {
+                return CitationResolution::Exact(result);
+            }
+            if !result.prefix_completions.is_empty() {
+                return CitationResolution::Prefix(result);
+            }
+        }
+
+        let Some(parsed) = crate::citation::parse(query) else {
+            return CitationResolution::NoMatch;
+        };
+        let Some(queried_year) = parsed.year else {
+            return CitationResolution::NoMatch;
+        };
+
+        let triple = format!("{} {} {}", parsed.volume, parsed.reporter, parsed.page);
+        let triple_tokens = Self::tokens_for(TrieSource::Citation, &triple);
+        let Some(node) = self.citation_trie.root.node_at(&triple_tokens) else {
+            return CitationResolution::NoMatch;
+        };
+
+        for (edge, child) in node.end_of_word_children() {
+            let Some(indexed_year) = edge
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .parse::<u32>()
+                .ok()
+            else {
+                continue;
+            };
+            if indexed_year != queried_year {
+                let result = TrieSearchResult {
+                    exact_matches: child.document_refs.clone(),
+                    prefix_completions: Vec::new(),
+                    total_matches: child.document_refs.len(),
+                    source: TrieSource::Citation,
+                    is_substring_match: false,
+                    buckets: Vec::new(),
+
+                };
+                return CitationResolution::YearMismatch { result, queried_year, indexed_year };
+            }
+        }
+
+        CitationResolution::NoMatch
+    }
+
+    /// Root node of the sub-trie selected by `source`
+    fn root_for(&self, source: TrieSource) -> &TrieNode {
+        match source {
+            TrieSource::CaseName => &self.case_name_trie.root,
+            TrieSource::Content => &self.content_trie.root,
+            TrieSource::Citation => &self.citation_trie.root,
+        }
+    }
+
+    /// Tokenize `text` the same way `source`'s own `insert`/`search` do: lowercased
+    /// whitespace-split words for case names and content, case-preserving whitespace-split
+    /// words for citations after [`crate::citation::normalize_for_index`] (`"410 U.S. 113"`
+    /// needs `"U.S."` intact, not lowercased, and `"S. Ct."`/`"S.Ct."` need to tokenize
+    /// identically)
+    fn tokens_for(source: TrieSource, text: &str) -> Vec<String> {
+        match source {
+            TrieSource::CaseName | TrieSource::Content => {
+                text.split_whitespace().map(|s| s.to_lowercase()).collect()
+            }
+            TrieSource::Citation => crate::citation::normalize_for_index(text)
+                .split_whitespace()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Arc-swap style live handle around an immutable [`TrieIndex`] generation, so a search never
+/// blocks behind an in-progress index update. [`TrieIndexHandle::snapshot`] takes a
+/// `std::sync::RwLock` read lock only long enough to clone an `Arc` — not to hold across the
+/// search itself — and returns an owned, unchanging [`TrieIndex`] generation to search against.
+/// A writer stages its mutations into a private clone (see [`TrieIndexHandle::begin_write`]) and
+/// publishes it with one `std::sync::RwLock` write-lock acquisition that does nothing but swap
+/// in a new `Arc` pointer (see [`TrieIndexHandle::commit`]), so the write lock is never held for
+/// anywhere near the duration of the mutations themselves. In-flight readers keep observing
+/// whatever generation they already snapshotted in full — never a partially applied batch.
+///
+/// This trades a full [`TrieIndex`] clone per write batch for lock-free reads; batching many
+/// mutations into one [`TrieIndexWriter`] before calling [`TrieIndexHandle::commit`] (rather
+/// than committing after each individual insert) is what keeps that clone cost proportional to
+/// the number of batches, not the number of mutations.
+pub struct TrieIndexHandle {
+    current: std::sync::RwLock<std::sync::Arc<TrieIndex>>,
+}
+
+impl TrieIndexHandle {
+    pub fn new(index: TrieIndex) -> Self {
+        Self { current: std::sync::RwLock::new(std::sync::Arc::new(index)) }
+    }
+
+    /// Immutable, point-in-time view of the trie. Cheap to call repeatedly — just an `Arc`
+    /// clone under a std `RwLock` read lock — and safe to hold across an entire search, since no
+    /// writer ever mutates a generation in place once it's been published.
+    pub fn snapshot(&self) -> std::sync::Arc<TrieIndex> {
+        self.current.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    /// Begin staging a batch of mutations against a private clone of the current generation.
+    /// Concurrent readers keep being served the current generation, unaffected, until
+    /// [`TrieIndexHandle::commit`] publishes the staged one.
+    pub fn begin_write(&self) -> TrieIndexWriter {
+        TrieIndexWriter { staged: (*self.snapshot()).clone() }
+    }
+
+    /// Atomically publish `writer`'s staged generation as the new current one. Only the `Arc`
+    /// pointer swap itself happens under the write lock, so this can't block a reader for longer
+    /// than another reader's own `Arc` clone would.
+    pub fn commit(&self, writer: TrieIndexWriter) {
+        let mut current = self.current.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *current = std::sync::Arc::new(writer.staged);
+    }
+}
+
+/// A batch of staged mutations against a private [`TrieIndex`] clone, published all at once by
+/// [`TrieIndexHandle::commit`]. Mirrors [`TrieIndex`]'s own mutation methods so existing call
+/// sites only need to swap which type they're calling them on.
+pub struct TrieIndexWriter {
+    staged: TrieIndex,
+}
+
+impl TrieIndexWriter {
+    pub fn insert_case_name(&mut self, case_name: &str, case_id: CaseId) -> Result<()> {
+        self.staged.insert_case_name(case_name, case_id)
+    }
+
+    pub fn insert_content(&mut self, tokens: &[(String, usize)], doc_ref: DocRef) -> Result<()> {
+        self.staged.insert_content(tokens, doc_ref)
+    }
+
+    pub fn insert_citation(&mut self, citation: &str, doc_ref: DocRef) -> Result<()> {
+        self.staged.insert_citation(citation, doc_ref)
+    }
+
+    pub fn insert_batch(&mut self, entries: Vec<TrieEntry>) -> Result<()> {
+        self.staged.insert_batch(entries)
+    }
+
+    pub fn remove_case(&mut self, case_id: CaseId) -> usize {
+        self.staged.remove_case(case_id)
+    }
+
+    pub fn update_case_name(&mut self, case_id: CaseId, old_name: &str, new_name: &str) -> Result<()> {
+        self.staged.update_case_name(case_id, old_name, new_name)
+    }
+
+    pub fn prune(&mut self, min_frequency: u32, max_postings: usize) -> PruneReport {
+        self.staged.prune(min_frequency, max_postings)
+    }
+}
+
+/// Leading procedural captions that name a single subject rather than two adversarial parties.
+const PROCEDURAL_PREFIXES: &[&str] = &["in re", "ex parte", "matter of"];
+
+impl CaseNameTrie {
+    fn new() -> Self {
+        Self {
+            root: TrieNode::new(),
+        }
+    }
+
+    /// Token paths `case_name` should be indexed (or, in [`CaseNameTrie::remove_name`],
+    /// removed) under: the full caption with its procedural prefix, party separator, and any
+    /// trailing "et al." stripped, plus — when a "v."/"vs."/"versus" separator is present —
+    /// each party's name as its own path, so `"Board of Education"` alone resolves the case
+    /// as an exact case-name match, not just `"Brown v. Board of Education"`. Always returns
+    /// at least one path, and never a duplicate one (e.g. `"In re Gault"` has no separator, so
+    /// its single subject and its "full name" are the same path).
+    fn indexable_paths(case_name: &str) -> Vec<Vec<String>> {
+        let mut tokens: Vec<String> = case_name.to_lowercase().split_whitespace().map(String::from).collect();
+
+        for prefix in PROCEDURAL_PREFIXES {
+            let prefix_tokens: Vec<&str> = prefix.split_whitespace().collect();
+            if tokens.len() > prefix_tokens.len() && tokens[..prefix_tokens.len()] == prefix_tokens[..] {
+                tokens.drain(..prefix_tokens.len());
                 break;
             }
-            
-            if current.is_end_of_word && path.len() > prefix.len() {
-                completions.push(path.join(" "));
+        }
+        Self::strip_trailing_et_al(&mut tokens);
+
+        let Some(separator_index) = tokens.iter().position(|token| Self::is_party_separator(token)) else {
+            return vec![tokens];
+        };
+
+        let mut party1 = tokens[..separator_index].to_vec();
+        let mut party2 = tokens[separator_index + 1..].to_vec();
+        Self::strip_trailing_et_al(&mut party1);
+        Self::strip_trailing_et_al(&mut party2);
+
+        let mut full = party1.clone();
+        full.extend(party2.iter().cloned());
+
+        let mut paths = vec![full];
+        if !party1.is_empty() {
+            paths.push(party1);
+        }
+        if !party2.is_empty() {
+            paths.push(party2);
+        }
+        paths.dedup();
+        paths
+    }
+
+    /// Whether `token` (with any trailing period stripped) is a case-name party separator:
+    /// "v.", "v", "vs.", "vs", or "versus".
+    fn is_party_separator(token: &str) -> bool {
+        matches!(token.trim_end_matches('.'), "v" | "vs" | "versus")
+    }
+
+    /// Drops a trailing "et al."/"et al" pair of tokens, if present, in place.
+    fn strip_trailing_et_al(tokens: &mut Vec<String>) {
+        if let [.., et, al] = tokens.as_slice() {
+            if et.trim_end_matches('.') == "et" && al.trim_end_matches('.') == "al" {
+                tokens.truncate(tokens.len() - 2);
+            }
+        }
+    }
+
+    fn insert(&mut self, case_name: &str, case_id: CaseId) -> Result<()> {
+        let doc_ref = DocRef {
+            case_id,
+            paragraph_index: 0,
+            char_offset: None,
+        };
+        for path in Self::indexable_paths(case_name) {
+            self.root.insert(&path, doc_ref.clone());
+        }
+        Ok(())
+    }
+
+    /// A query is normalized the same way an indexed name is: stripping its procedural prefix,
+    /// party separator, and trailing "et al." before matching, so `"Brown v. Board of
+    /// Education"`, `"brown board of education"`, and `"Board of Education"` alone all resolve
+    /// to the same case.
+    fn search(&self, query: &str) -> Result<TrieSearchResult> {
+        let tokens = Self::indexable_paths(query).swap_remove(0);
+        Ok(self.root.search(&tokens).with_source(TrieSource::CaseName))
+    }
+
+    /// Remove the `DocRef` for `case_id` from every path `case_name` was indexed under (see
+    /// [`CaseNameTrie::indexable_paths`]), pruning nodes left empty along the way. Returns
+    /// `true` if at least one doc ref was actually removed.
+    fn remove_name(&mut self, case_id: CaseId, case_name: &str) -> bool {
+        Self::indexable_paths(case_name)
+            .into_iter()
+            .map(|path| self.root.remove_along_path(&path, case_id))
+            .fold(false, |removed_any, removed| removed_any || removed)
+    }
+}
+
+impl ContentTrie {
+    fn new() -> Self {
+        Self {
+            root: TrieNode::new(),
+        }
+    }
+
+    fn insert(&mut self, tokens: &[String], doc_ref: DocRef) -> Result<()> {
+        let normalized_tokens: Vec<String> = tokens.iter().map(|t| t.to_lowercase()).collect();
+        self.root.insert(&normalized_tokens, doc_ref);
+        Ok(())
+    }
+
+    fn search_tokens(&self, tokens: &[String]) -> Result<TrieSearchResult> {
+        let normalized_tokens: Vec<String> = tokens.iter().map(|t| t.to_lowercase()).collect();
+        Ok(self.root.search(&normalized_tokens).with_source(TrieSource::Content))
+    }
+}
+
+impl CitationTrie {
+    fn new() -> Self {
+        Self {
+            root: TrieNode::new(),
+        }
+    }
+
+    /// Reporter spelling, spacing, and punctuation are normalized before tokenizing (see
+    /// [`crate::citation::normalize_for_index`]) so `"98 S.Ct. 2733"` and `"98 S. Ct. 2733"` —
+    /// or `"347 U. S. 483"` and `"347 U.S. 483"` — are indexed under the same path
+    fn insert(&mut self, citation: &str, doc_ref: DocRef) -> Result<()> {
+        let normalized = crate::citation::normalize_for_index(citation);
+        let tokens: Vec<String> = normalized.split_whitespace().map(|s| s.to_string()).collect();
+        self.root.insert(&tokens, doc_ref);
+        Ok(())
+    }
+
+    fn search(&self, query: &str) -> Result<TrieSearchResult> {
+        let normalized = crate::citation::normalize_for_index(query);
+        let tokens: Vec<String> = normalized.split_whitespace().map(|s| s.to_string()).collect();
+        Ok(self.root.search(&tokens).with_source(TrieSource::Citation))
+    }
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            is_end_of_word: false,
+            document_refs: Vec::new(),
+            frequency: 0,
+        }
+    }
+
+    /// Look up a child edge by exact token, via binary search over the sorted `children` vec.
+    fn child(&self, token: &str) -> Option<&TrieNode> {
+        self.children
+            .binary_search_by(|(edge, _)| edge.as_ref().cmp(token))
+            .ok()
+            .map(|index| &self.children[index].1)
+    }
+
+    /// Mutable version of [`TrieNode::child`].
+    fn child_mut(&mut self, token: &str) -> Option<&mut TrieNode> {
+        self.children
+            .binary_search_by(|(edge, _)| edge.as_ref().cmp(token))
+            .ok()
+            .map(move |index| &mut self.children[index].1)
+    }
+
+    /// Get the child edge for `token`, inserting an empty node in sorted position if absent.
+    fn child_or_insert(&mut self, token: &str) -> &mut TrieNode {
+        match self.children.binary_search_by(|(edge, _)| edge.as_ref().cmp(token)) {
+            Ok(index) => &mut self.children[index].1,
+            Err(index) => {
+                self.children.insert(index, (Box::from(token), TrieNode::new()));
+                &mut self.children[index].1
+            }
+        }
+    }
+
+    /// Remove the child edge for `token`, if present.
+    fn remove_child(&mut self, token: &str) {
+        if let Ok(index) = self.children.binary_search_by(|(edge, _)| edge.as_ref().cmp(token)) {
+            self.children.remove(index);
+        }
+    }
+
+    /// Follow `tokens` down from this node, one child lookup per token. Returns the node
+    /// reached if every token had a matching edge, `None` as soon as one doesn't.
+    fn node_at(&self, tokens: &[String]) -> Option<&TrieNode> {
+        let mut current = self;
+        for token in tokens {
+            current = current.child(token)?;
+        }
+        Some(current)
+    }
+
+    /// Total number of complete terms (nodes with `is_end_of_word`) in this subtree, including
+    /// this node itself.
+    fn count_terms(&self) -> usize {
+        let mut count = usize::from(self.is_end_of_word);
+        for (_, child) in &self.children {
+            count += child.count_terms();
+        }
+        count
+    }
+
+    /// Follow `tokens` down from this node as far as matching edges exist, returning every
+    /// token successfully matched, in order. A query whose first token isn't even indexed
+    /// yields an empty vec, not an error.
+    fn longest_matching_prefix<'a>(&self, tokens: &'a [String]) -> Vec<&'a str> {
+        let mut matched = Vec::new();
+        let mut current = self;
+        for token in tokens {
+            match current.child(token) {
+                Some(child) => {
+                    matched.push(token.as_str());
+                    current = child;
+                }
+                None => break,
+            }
+        }
+        matched
+    }
+
+    /// Direct children of this node that terminate a complete term, paired with their edge
+    /// token. Used by [`TrieIndex::resolve_citation`] to find citations sharing a
+    /// volume/reporter/page but indexed under a different parenthetical-year child.
+    fn end_of_word_children(&self) -> Vec<(&str, &TrieNode)> {
+        self.children
+            .iter()
+            .filter(|(_, child)| child.is_end_of_word)
+            .map(|(edge, child)| (edge.as_ref(), child))
+            .collect()
+    }
+
+    fn insert(&mut self, tokens: &[String], doc_ref: DocRef) {
+        let mut current = self;
+
+        for token in tokens {
+            current = current.child_or_insert(token);
+        }
+
+        current.is_end_of_word = true;
+        // A case reprocess or re-ingest re-inserts the same phrases; without this check they'd
+        // pile up as duplicate postings pointing at the exact same (case, paragraph, offset).
+        let is_duplicate = current.document_refs.iter().any(|existing| {
+            existing.case_id == doc_ref.case_id
+                && existing.paragraph_index == doc_ref.paragraph_index
+                && existing.char_offset == doc_ref.char_offset
+        });
+        if !is_duplicate {
+            current.document_refs.push(doc_ref);
+            current.frequency += 1;
+        }
+    }
+
+    /// Batch counterpart to [`TrieNode::insert`], for [`TrieIndex::insert_batch`]. `entries`
+    /// must be sorted lexicographically by `tokens` (as `Vec<String>`'s derived `Ord` does) and
+    /// share the same first `depth` tokens — the top-level call passes `depth: 0`, where that
+    /// invariant holds trivially. Because of the sort, entries branching on the same token at
+    /// `depth` are contiguous, so each distinct child subtree is walked into exactly once no
+    /// matter how many entries land in it, instead of every entry re-walking from the root the
+    /// way repeated [`TrieNode::insert`] calls do. Each touched node's frequency is bumped once
+    /// per batch (by however many of its entries turned out not to be duplicates) rather than
+    /// once per individual insert.
+    fn insert_sorted_batch(&mut self, entries: &[(Vec<String>, DocRef)], depth: usize) {
+        if entries.is_empty() {
+            return;
+        }
+
+        // Entries whose path ends exactly at this node sort first: a Vec<String> that's a
+        // strict prefix of another (i.e. shorter, with matching leading tokens) always compares
+        // less than the longer one.
+        let leaf_end = entries.partition_point(|(tokens, _)| tokens.len() == depth);
+        if leaf_end > 0 {
+            self.is_end_of_word = true;
+            let mut added = 0u32;
+            for (_, doc_ref) in &entries[..leaf_end] {
+                let is_duplicate = self.document_refs.iter().any(|existing| {
+                    existing.case_id == doc_ref.case_id
+                        && existing.paragraph_index == doc_ref.paragraph_index
+                        && existing.char_offset == doc_ref.char_offset
+                });
+                if !is_duplicate {
+                    self.document_refs.push(doc_ref.clone());
+                    added += 1;
+                }
+            }
+            self.frequency += added;
+        }
+
+        // Remaining entries all have a token at `depth`; group contiguous runs sharing it and
+        // recurse into that child once per run.
+        let mut i = leaf_end;
+        while i < entries.len() {
+            let token = &entries[i].0[depth];
+            let mut j = i + 1;
+            while j < entries.len() && &entries[j].0[depth] == token {
+                j += 1;
+            }
+            self.child_or_insert(token).insert_sorted_batch(&entries[i..j], depth + 1);
+            i = j;
+        }
+    }
+
+    /// A node with no children and no word ending of its own carries no information and can
+    /// be dropped from its parent's `children` map.
+    fn is_prunable(&self) -> bool {
+        !self.is_end_of_word && self.children.is_empty()
+    }
+
+    /// Recursively drop every `DocRef` matching `case_id` from this subtree, decrementing
+    /// `frequency` to match and pruning any child left with no children and no word ending.
+    /// Returns the number of document refs removed.
+    fn remove_case(&mut self, case_id: CaseId) -> usize {
+        let before = self.document_refs.len();
+        self.document_refs.retain(|doc_ref| doc_ref.case_id != case_id);
+        let removed_here = before - self.document_refs.len();
+        if removed_here > 0 {
+            self.frequency = self.frequency.saturating_sub(removed_here as u32);
+        }
+        if self.document_refs.is_empty() {
+            self.is_end_of_word = false;
+        }
+
+        let mut removed_total = removed_here;
+        self.children.retain_mut(|(_, child)| {
+            removed_total += child.remove_case(case_id);
+            !child.is_prunable()
+        });
+
+        removed_total
+    }
+
+    /// Recursively apply [`TrieIndex::prune`]'s frequency/posting-count thresholds to this
+    /// subtree, accumulating what was dropped into `report`. Mirrors [`TrieNode::remove_case`]'s
+    /// shape: clear this node's own word-ending first (if it fails the frequency bar) or
+    /// truncate its posting list (if it clears the bar but is oversized), then recurse into
+    /// children and prune any left with no children and no word ending.
+    fn prune(&mut self, min_frequency: u32, max_postings: usize, report: &mut PruneReport) {
+        if self.is_end_of_word {
+            if self.frequency < min_frequency {
+                report.terms_dropped += 1;
+                report.document_refs_dropped += self.document_refs.len();
+                self.document_refs.clear();
+                self.frequency = 0;
+                self.is_end_of_word = false;
+            } else if self.document_refs.len() > max_postings {
+                let excess = self.document_refs.len() - max_postings;
+                self.document_refs.truncate(max_postings);
+                report.document_refs_dropped += excess;
+            }
+        }
+
+        self.children.retain_mut(|(_, child)| {
+            child.prune(min_frequency, max_postings, report);
+            !child.is_prunable()
+        });
+    }
+
+    /// Remove the `DocRef` for `case_id` from the node reached by following `tokens` exactly,
+    /// pruning nodes left empty back up the path. A path that doesn't exist (a stale name) is
+    /// a no-op that returns `false`, not an error.
+    fn remove_along_path(&mut self, tokens: &[String], case_id: CaseId) -> bool {
+        let Some((token, rest)) = tokens.split_first() else {
+            let before = self.document_refs.len();
+            self.document_refs.retain(|doc_ref| doc_ref.case_id != case_id);
+            let removed = self.document_refs.len() != before;
+            if removed {
+                self.frequency = self.frequency.saturating_sub(1);
+            }
+            if self.document_refs.is_empty() {
+                self.is_end_of_word = false;
+            }
+            return removed;
+        };
+
+        let Some(child) = self.child_mut(token) else { return false };
+        let removed = child.remove_along_path(rest, case_id);
+        if removed && child.is_prunable() {
+            self.remove_child(token);
+        }
+        removed
+    }
+
+    fn search(&self, tokens: &[String]) -> TrieSearchResult {
+        let mut current = self;
+        
+        // Traverse to the end of the query
+        for token in tokens {
+            if let Some(child) = current.child(token) {
+                current = child;
+            } else {
+                // No matches found
+                return TrieSearchResult {
+                    exact_matches: Vec::new(),
+                    prefix_completions: Vec::new(),
+                    total_matches: 0,
+                    source: TrieSource::Content,
+                    is_substring_match: false,
+                    buckets: Vec::new(),
+
+                };
+            }
+        }
+
+        // Collect exact matches if this is end of word
+        let exact_matches = if current.is_end_of_word {
+            current.document_refs.clone()
+        } else {
+            Vec::new()
+        };
+
+        // Collect prefix completions
+        let prefix_completions = self.collect_completions(current, tokens, 10);
+
+        TrieSearchResult {
+            total_matches: exact_matches.len() + prefix_completions.len(),
+            exact_matches,
+            prefix_completions,
+            source: TrieSource::Content,
+            is_substring_match: false,
+            buckets: Vec::new(),
+
+        }
+    }
+
+    /// Rank every word sequence reachable from this node whose tokens start with `tokens`
+    /// (the last token in `tokens` may be a partial word; every token before it must match a
+    /// complete edge exactly), paired with its insertion frequency.
+    fn completions(&self, tokens: &[String]) -> Vec<(String, u32)> {
+        let Some((last, exact_prefix)) = tokens.split_last() else {
+            return self.collect_ranked(Vec::new());
+        };
+
+        let mut current = self;
+        for token in exact_prefix {
+            match current.child(token) {
+                Some(child) => current = child,
+                None => return Vec::new(),
+            }
+        }
+
+        let mut ranked = Vec::new();
+        for (word, child) in &current.children {
+            if word.starts_with(last.as_str()) {
+                let mut path = exact_prefix.to_vec();
+                path.push(word.to_string());
+                ranked.extend(child.collect_ranked(path));
+            }
+        }
+        ranked
+    }
+
+    /// Depth-first collection of every completed word sequence at or beneath this node,
+    /// paired with its insertion frequency
+    fn collect_ranked(&self, path: Vec<String>) -> Vec<(String, u32)> {
+        let mut results = Vec::new();
+        let mut stack = vec![(self, path)];
+
+        while let Some((node, path)) = stack.pop() {
+            if node.is_end_of_word {
+                results.push((path.join(" "), node.frequency));
             }
-            
-            for (token, child) in &current.children {
+            for (token, child) in &node.children {
                 let mut new_path = path.clone();
-                new_path.push(token.clone());
+                new_path.push(token.to_string());
                 stack.push((child, new_path));
             }
         }
-        
-        completions
+
+        results
+    }
+
+    /// Recursively match `tokens` against this subtree, descending into any child edge whose
+    /// token is within `budget_for(token)` Levenshtein distance of the corresponding query
+    /// token (rather than requiring an exact `HashMap` lookup as [`TrieNode::search`] does),
+    /// accumulating the matched path and total edit distance as we go.
+    fn fuzzy_matches(
+        &self,
+        tokens: &[String],
+        budget_for: &impl Fn(&str) -> usize,
+        path: &mut Vec<String>,
+        distance_so_far: usize,
+        out: &mut Vec<FuzzyMatch>,
+    ) {
+        match tokens.split_first() {
+            None => {
+                if self.is_end_of_word {
+                    out.push(FuzzyMatch {
+                        text: path.join(" "),
+                        document_refs: self.document_refs.clone(),
+                        edit_distance: distance_so_far,
+                    });
+                }
+            }
+            Some((token, rest)) => {
+                let budget = budget_for(token);
+                for (edge, child) in &self.children {
+                    if let Some(distance) = bounded_levenshtein(token, edge, budget) {
+                        path.push(edge.to_string());
+                        child.fuzzy_matches(rest, budget_for, path, distance_so_far + distance, out);
+                        path.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Collect the top `limit` word sequences strictly beneath `node` (i.e. longer than
+    /// `prefix`), ranked by [`TrieNode::frequency`] descending with a deterministic
+    /// lexicographic tie-break — never the stack-order-dependent, frequency-blind ordering a
+    /// plain DFS-until-`limit` would give.
+    fn collect_completions(&self, node: &TrieNode, prefix: &[String], limit: usize) -> Vec<String> {
+        let mut ranked = node.collect_ranked(prefix.to_vec());
+        ranked.retain(|(completion, _)| completion.split(' ').count() > prefix.len());
+        Self::rank_completions(&mut ranked, false);
+        ranked.truncate(limit);
+        ranked.into_iter().map(|(completion, _)| completion).collect()
+    }
+
+    /// Sort `ranked` completions by [`TrieNode::frequency`] descending, breaking ties
+    /// deterministically. When `bias_shorter` is set, a shorter completion wins a tie over a
+    /// longer one of equal frequency before falling back to lexicographic order; otherwise ties
+    /// go straight to lexicographic order.
+    fn rank_completions(ranked: &mut [(String, u32)], bias_shorter: bool) {
+        ranked.sort_by(|(a, freq_a), (b, freq_b)| {
+            freq_b.cmp(freq_a).then_with(|| {
+                if bias_shorter {
+                    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+                } else {
+                    a.cmp(b)
+                }
+            })
+        });
+    }
+
+    /// Recursively accumulate this subtree's [`TrieMemoryStats`] into `stats`.
+    fn accumulate_memory_stats(&self, stats: &mut TrieMemoryStats) {
+        stats.node_count += 1;
+        stats.posting_count += self.document_refs.len();
+        for (edge, child) in &self.children {
+            stats.edge_bytes += edge.len();
+            child.accumulate_memory_stats(stats);
+        }
+    }
+
+    /// Recursively accumulate this subtree's [`TrieNodeStats`] into `stats`, tracking `depth`
+    /// (in tokens from the sub-trie's root) so `max_depth` reflects the deepest indexed term.
+    fn accumulate_index_stats(&self, depth: usize, stats: &mut TrieNodeStats) {
+        stats.nodes += 1;
+        stats.document_refs += self.document_refs.len();
+        if self.is_end_of_word {
+            stats.terms += 1;
+            stats.max_depth = stats.max_depth.max(depth);
+        }
+        for (edge, child) in &self.children {
+            stats.edge_bytes += edge.len();
+            child.accumulate_index_stats(depth + 1, stats);
+        }
+    }
+
+    /// Match `tokens` against this subtree, treating a `"*"` token as a single-token wildcard
+    /// (descend into every child at that position) and a trailing `"*"` as "any completion"
+    /// (collect every posting in the rest of the subtree, not just one more token). Matched
+    /// `DocRef`s are appended to `out`; collection stops as soon as `out` reaches `cap`, so a
+    /// wildcard sitting at a highly-branching position can't blow up memory or latency.
+    fn collect_wildcard(&self, tokens: &[String], cap: usize, out: &mut Vec<DocRef>) {
+        if out.len() >= cap {
+            return;
+        }
+
+        let Some((token, rest)) = tokens.split_first() else {
+            if self.is_end_of_word {
+                out.extend(self.document_refs.iter().cloned());
+            }
+            return;
+        };
+
+        if token.as_str() == "*" {
+            if rest.is_empty() {
+                self.collect_all(cap, out);
+            } else {
+                for (_, child) in &self.children {
+                    if out.len() >= cap {
+                        break;
+                    }
+                    child.collect_wildcard(rest, cap, out);
+                }
+            }
+        } else if let Some(child) = self.child(token) {
+            child.collect_wildcard(rest, cap, out);
+        }
+    }
+
+    /// Depth-first collection of every `DocRef` at or beneath this node, stopping once `out`
+    /// reaches `cap`. Backs the trailing-wildcard ("any completion") case of
+    /// [`TrieNode::collect_wildcard`].
+    fn collect_all(&self, cap: usize, out: &mut Vec<DocRef>) {
+        if out.len() >= cap {
+            return;
+        }
+        if self.is_end_of_word {
+            out.extend(self.document_refs.iter().cloned());
+        }
+        for (_, child) in &self.children {
+            if out.len() >= cap {
+                break;
+            }
+            child.collect_all(cap, out);
+        }
+    }
+}
+
+/// Levenshtein distance between `a` and `b`, or `None` if it exceeds `max_distance`. Bails
+/// out early on the cheap length-difference lower bound and again mid-computation as soon as
+/// every entry in the current DP row exceeds `max_distance`, since neither can shrink for
+/// what remains — this is what keeps [`TrieNode::fuzzy_matches`] from degrading to a full
+/// unbounded edit-distance scan of every edge at each level.
+fn bounded_levenshtein(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for (i, &a_char) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        let mut row_min = curr[0];
+        for (j, &b_char) in b.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+            row_min = row_min.min(curr[j + 1]);
+        }
+        if row_min > max_distance {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    Some(prev[b.len()]).filter(|&distance| distance <= max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn doc_ref() -> DocRef {
+        DocRef {
+            case_id: Uuid::new_v4(),
+            paragraph_index: 0,
+            char_offset: None,
+        }
+    }
+
+    #[test]
+    fn test_case_name_trie_search_reports_case_name_provenance() {
+        let mut trie = CaseNameTrie::new();
+        trie.insert("Marbury v Madison", Uuid::new_v4()).unwrap();
+
+        let result = trie.search("Marbury v Madison").unwrap();
+        assert_eq!(result.source, TrieSource::CaseName);
+        assert!(result.total_matches >= result.exact_matches.len());
+    }
+
+    #[test]
+    fn test_case_name_trie_search_by_either_party_finds_case() {
+        let mut trie = CaseNameTrie::new();
+        let case_id = Uuid::new_v4();
+        trie.insert("Brown v. Board of Education", case_id).unwrap();
+
+        for query in ["Brown v. Board of Education", "brown board education", "Board of Education", "Brown"] {
+            let result = trie.search(query).unwrap();
+            assert!(
+                result.exact_matches.iter().any(|d| d.case_id == case_id),
+                "expected query {:?} to find the case, got {:?}",
+                query,
+                result.exact_matches
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_name_trie_strips_in_re_procedural_prefix() {
+        let mut trie = CaseNameTrie::new();
+        let case_id = Uuid::new_v4();
+        trie.insert("In re Gault", case_id).unwrap();
+
+        let result = trie.search("Gault").unwrap();
+        assert!(result.exact_matches.iter().any(|d| d.case_id == case_id));
+
+        let result = trie.search("In re Gault").unwrap();
+        assert!(result.exact_matches.iter().any(|d| d.case_id == case_id));
+    }
+
+    #[test]
+    fn test_case_name_trie_strips_ex_parte_procedural_prefix() {
+        let mut trie = CaseNameTrie::new();
+        let case_id = Uuid::new_v4();
+        trie.insert("Ex parte Milligan", case_id).unwrap();
+
+        let result = trie.search("Milligan").unwrap();
+        assert!(result.exact_matches.iter().any(|d| d.case_id == case_id));
+    }
+
+    #[test]
+    fn test_case_name_trie_strips_trailing_et_al_on_both_full_name_and_party() {
+        let mut trie = CaseNameTrie::new();
+        let case_id = Uuid::new_v4();
+        trie.insert("Smith et al. v. Jones", case_id).unwrap();
+
+        for query in ["Smith v. Jones", "Smith", "Jones"] {
+            let result = trie.search(query).unwrap();
+            assert!(
+                result.exact_matches.iter().any(|d| d.case_id == case_id),
+                "expected query {:?} to find the case",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_name_trie_handles_multi_party_names() {
+        let mut trie = CaseNameTrie::new();
+        let case_id = Uuid::new_v4();
+        trie.insert("United States v. Nixon", case_id).unwrap();
+
+        for query in ["United States v. Nixon", "United States", "Nixon", "united states nixon"] {
+            let result = trie.search(query).unwrap();
+            assert!(
+                result.exact_matches.iter().any(|d| d.case_id == case_id),
+                "expected query {:?} to find the case",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_name_trie_remove_name_clears_all_indexed_paths() {
+        let mut trie = CaseNameTrie::new();
+        let case_id = Uuid::new_v4();
+        trie.insert("Brown v. Board of Education", case_id).unwrap();
+
+        assert!(trie.remove_name(case_id, "Brown v. Board of Education"));
+
+        for query in ["Brown v. Board of Education", "Board of Education", "Brown"] {
+            let result = trie.search(query).unwrap();
+            assert!(
+                !result.exact_matches.iter().any(|d| d.case_id == case_id),
+                "expected query {:?} to no longer find the removed case",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn test_content_trie_search_reports_content_provenance() {
+        let mut trie = ContentTrie::new();
+        let tokens = vec!["freedom".to_string(), "of".to_string(), "speech".to_string()];
+        trie.insert(&tokens, doc_ref()).unwrap();
+
+        let result = trie.search_tokens(&tokens).unwrap();
+        assert_eq!(result.source, TrieSource::Content);
+        assert!(result.total_matches >= result.exact_matches.len());
+    }
+
+    #[test]
+    fn test_citation_trie_search_reports_citation_provenance() {
+        let mut trie = CitationTrie::new();
+        trie.insert("410 U.S. 113", doc_ref()).unwrap();
+
+        let result = trie.search("410 U.S. 113").unwrap();
+        assert_eq!(result.source, TrieSource::Citation);
+        assert!(result.total_matches >= result.exact_matches.len());
+    }
+
+    #[test]
+    fn test_citation_trie_normalizes_reporter_variants_onto_the_same_node() {
+        // (indexed spelling, queried spelling) — each pair should land on the same trie node
+        // per `citation::normalize_for_index`, regardless of reporter spacing/punctuation.
+        const VARIANTS: &[(&str, &str)] = &[
+            ("410 U.S. 113", "410 U. S. 113"),
+            ("410 U.S. 113", "410  U.S.   113"),
+            ("98 S.Ct. 2733", "98 S. Ct. 2733"),
+            ("98 S.Ct. 2733", "98 S.Ct 2733"),
+            ("58 L.Ed.2d 466", "58 L. Ed. 2d 466"),
+            ("58 L.Ed.2d 466", "58 L.Ed. 2d 466"),
+            ("58 L.Ed.2d 466", "58 L. Ed.2d 466"),
+            ("410 F.2d 999", "410 F. 2d 999"),
+            ("410 F.3d 999", "410 F. 3d 999"),
+            ("410 F.Supp.2d 999", "410 F. Supp. 2d 999"),
+            ("410 F.Supp. 999", "410 F. Supp. 999"),
+            ("347 U.S. 483", "347 U. S. 483"),
+            ("347 U.S. 483 (1954)", "347 U.S. 483(1954)"),
+            ("347 U.S. 483 (1954)", "347 U.S.  483  (1954)"),
+            ("410 U.S. 113 (1973)", "410 U.S. 113(1973)"),
+            ("98 S.Ct. 2733 (1978)", "98 S. Ct. 2733(1978)"),
+            ("58 L.Ed.2d 466 (1978)", "58 L. Ed. 2d 466 (1978)"),
+            ("  410 U.S. 113", "410 U.S. 113  "),
+            ("410 U.S. 113", "  410   U.S.   113  "),
+            ("5 U.S. 137", "5 U. S. 137"),
+            ("5 U.S. 137 (1803)", "5 U.S. 137(1803)"),
+        ];
+
+        for (indexed, queried) in VARIANTS {
+            let mut trie = CitationTrie::new();
+            trie.insert(indexed, doc_ref()).unwrap();
+
+            let result = trie.search(queried).unwrap();
+            assert!(
+                !result.exact_matches.is_empty(),
+                "expected {queried:?} to match citation indexed as {indexed:?}"
+            );
+        }
+    }
+
+    fn test_trie_config() -> TrieConfig {
+        TrieConfig {
+            use_fst: false,
+            index_case_names: true,
+            index_citations: true,
+            max_prefix_length: 50,
+            index_path: std::path::PathBuf::from("./data/trie_index"),
+            enable_memory_mapping: false,
+            fuzzy_short_token_length_threshold: 6,
+            fuzzy_max_edit_distance_short: 1,
+            fuzzy_max_edit_distance_long: 2,
+            wildcard_max_results: 500,
+            skip_stopword_only_ngrams: false,
+            min_token_length: 0,
+        }
+    }
+
+    async fn index_with_case_names(config: TrieConfig, case_names: &[&str]) -> TrieIndex {
+        let mut trie = TrieIndex::new(config).await.unwrap();
+        for case_name in case_names {
+            trie.insert_case_name(case_name, Uuid::new_v4()).unwrap();
+        }
+        trie
+    }
+
+    #[tokio::test]
+    async fn test_get_completions_ranks_by_frequency() {
+        let mut trie = index_with_case_names(
+            test_trie_config(),
+            &["miranda v arizona", "brown v board of education"],
+        )
+        .await;
+        // Insert "miranda v arizona" again so it outranks the single-insertion "brown" case.
+        trie.insert_case_name("miranda v arizona", Uuid::new_v4()).unwrap();
+
+        let completions = trie.get_completions("m", 10, false).unwrap();
+        assert_eq!(completions.first().map(String::as_str), Some("miranda v arizona"));
+    }
+
+    /// Completions tied on frequency must break the tie lexicographically, deterministically —
+    /// never by however the trie happens to have laid the branches out internally.
+    #[tokio::test]
+    async fn test_get_completions_ties_break_lexicographically() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["roe v zebra", "roe v alpha", "roe v middle"],
+        )
+        .await;
+
+        let completions = trie.get_completions("roe", 10, false).unwrap();
+        assert_eq!(completions, vec!["roe v alpha", "roe v middle", "roe v zebra"]);
+    }
+
+    /// With `bias_shorter` set, a shorter completion of equal frequency outranks a longer one
+    /// even when the longer one would otherwise sort first lexicographically.
+    #[tokio::test]
+    async fn test_get_completions_bias_shorter_prefers_shorter_completion_on_tie() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["roe v aaron event two", "roe v zed"],
+        )
+        .await;
+
+        let completions = trie.get_completions("roe", 10, true).unwrap();
+        assert_eq!(completions.first().map(String::as_str), Some("roe v zed"));
+    }
+
+    /// Without `bias_shorter`, the same tied pair falls back to lexicographic order instead,
+    /// putting the longer-but-alphabetically-earlier completion first.
+    #[tokio::test]
+    async fn test_get_completions_without_bias_ignores_length() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["roe v aaron event two", "roe v zed"],
+        )
+        .await;
+
+        let completions = trie.get_completions("roe", 10, false).unwrap();
+        assert_eq!(completions.first().map(String::as_str), Some("roe v aaron event two"));
+    }
+
+    #[tokio::test]
+    async fn test_get_completions_multi_token_prefix() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["brown v board of education", "brown v allen"],
+        )
+        .await;
+
+        let mut completions = trie.get_completions("brown v", 10, false).unwrap();
+        completions.sort();
+        assert_eq!(completions, vec!["brown v allen", "brown v board of education"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_completions_single_character_prefix() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        let completions = trie.get_completions("m", 10, false).unwrap();
+        assert_eq!(completions, vec!["miranda v arizona"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_completions_respects_limit() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["roe v wade", "roe v smith", "roe v jones"],
+        )
+        .await;
+
+        let completions = trie.get_completions("roe", 2, false).unwrap();
+        assert_eq!(completions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_completions_no_matches_is_empty_not_error() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        let completions = trie.get_completions("nonexistent", 10, false).unwrap();
+        assert!(completions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_round_trip_preserves_search_results() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        for i in 0..3_000usize {
+            let case_id = Uuid::new_v4();
+            trie.insert_case_name(&format!("Term{} v State", i), case_id).unwrap();
+            trie.insert_citation(&format!("{} U.S. {}", 100 + i, i), doc_ref()).unwrap();
+        }
+
+        let path = std::env::temp_dir().join(format!("trie-roundtrip-{}.bin", Uuid::new_v4()));
+        trie.save_to_disk(&path).await.unwrap();
+
+        let reloaded = TrieIndex::load_from_disk(test_trie_config(), &path).await.unwrap();
+
+        for i in [0usize, 1_500, 2_999] {
+            let query = format!("Term{} v State", i);
+            assert_eq!(
+                trie.search(&query).unwrap().exact_matches.len(),
+                reloaded.search(&query).unwrap().exact_matches.len(),
+                "mismatched exact matches for {query} after reload"
+            );
+        }
+        assert_eq!(
+            trie.get_completions("Term1", 10, false).unwrap(),
+            reloaded.get_completions("Term1", 10, false).unwrap()
+        );
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_from_disk_rejects_truncated_file() {
+        let path = std::env::temp_dir().join(format!("trie-truncated-{}.bin", Uuid::new_v4()));
+        tokio::fs::write(&path, b"not a real snapshot").await.unwrap();
+
+        let err = TrieIndex::load_from_disk(test_trie_config(), &path).await.unwrap_err();
+        assert!(matches!(err, SearchError::IndexCorrupted { .. }), "expected IndexCorrupted, got {err:?}");
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_from_disk_rejects_unsupported_version() {
+        let path = std::env::temp_dir().join(format!("trie-badversion-{}.bin", Uuid::new_v4()));
+        let mut bytes = SNAPSHOT_MAGIC.to_vec();
+        bytes.extend_from_slice(&999u32.to_le_bytes());
+        bytes.extend_from_slice(b"irrelevant body");
+        tokio::fs::write(&path, bytes).await.unwrap();
+
+        let err = TrieIndex::load_from_disk(test_trie_config(), &path).await.unwrap_err();
+        assert!(matches!(err, SearchError::IndexCorrupted { .. }), "expected IndexCorrupted, got {err:?}");
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[test]
+    fn test_bounded_levenshtein_matches_known_distances() {
+        assert_eq!(bounded_levenshtein("miranda", "miranda", 2), Some(0));
+        assert_eq!(bounded_levenshtein("miranda", "mirranda", 2), Some(1));
+        assert_eq!(bounded_levenshtein("kitten", "sitting", 3), Some(3));
+        assert_eq!(bounded_levenshtein("miranda", "arizona", 2), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_fuzzy_finds_misspelled_case_name() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        let matches = trie.search_fuzzy("mirranda v arizona", None).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "miranda v arizona");
+        assert_eq!(matches[0].edit_distance, 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_fuzzy_exact_match_has_zero_distance_and_ranks_first() {
+        let mut trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+        trie.insert_case_name("mirinda v arizona", Uuid::new_v4()).unwrap();
+
+        let matches = trie.search_fuzzy("miranda v arizona", None).unwrap();
+        assert_eq!(matches[0].text, "miranda v arizona");
+        assert_eq!(matches[0].edit_distance, 0);
+        assert!(matches.iter().any(|m| m.text == "mirinda v arizona" && m.edit_distance > 0));
+    }
+
+    #[tokio::test]
+    async fn test_search_fuzzy_respects_explicit_max_edit_distance() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        assert!(trie.search_fuzzy("mirranda v arizona", Some(0)).unwrap().is_empty());
+        assert_eq!(trie.search_fuzzy("mirranda v arizona", Some(1)).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_fuzzy_no_matches_within_budget_is_empty_not_error() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        let matches = trie.search_fuzzy("completely different phrase", None).unwrap();
+        assert!(matches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_fuzzy_short_token_uses_tighter_default_budget() {
+        let trie = index_with_case_names(test_trie_config(), &["brown v board"]).await;
+
+        // "board" is 5 characters, under the 6-character short-token threshold, so its
+        // default budget is 1. "bxaxd" is 2 edits away and shouldn't match without an
+        // explicit, larger budget.
+        assert!(trie.search_fuzzy("brown v bxaxd", None).unwrap().is_empty());
+        assert!(!trie.search_fuzzy("brown v bxaxd", Some(2)).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remove_case_drops_exact_matches_and_prunes_unshared_nodes() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+        trie.insert_case_name("miranda v arizona", case_id).unwrap();
+        assert_eq!(trie.search("miranda v arizona").unwrap().exact_matches.len(), 1);
+
+        let removed = trie.remove_case(case_id);
+        assert_eq!(removed, 1);
+        assert!(trie.search("miranda v arizona").unwrap().exact_matches.is_empty());
+        // The whole path was only reachable through this case, so it's pruned entirely and
+        // no longer offered as a completion.
+        assert!(trie.get_completions("miranda", 10, false).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remove_case_leaves_sibling_cases_on_a_shared_prefix_untouched() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let brown_id = Uuid::new_v4();
+        let allen_id = Uuid::new_v4();
+        trie.insert_case_name("brown v board", brown_id).unwrap();
+        trie.insert_case_name("brown v allen", allen_id).unwrap();
+
+        trie.remove_case(brown_id);
+
+        assert!(trie.search("brown v board").unwrap().exact_matches.is_empty());
+        assert_eq!(trie.search("brown v allen").unwrap().exact_matches.len(), 1);
+        assert_eq!(trie.get_completions("brown v", 10, false).unwrap(), vec!["brown v allen"]);
+    }
+
+    #[tokio::test]
+    async fn test_remove_case_also_drops_content_and_citation_entries() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+        let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+        trie.insert_content(&[("separate".to_string(), 0), ("opinion".to_string(), 9)], doc_ref.clone()).unwrap();
+        trie.insert_citation("410 U.S. 113", doc_ref).unwrap();
+
+        let removed = trie.remove_case(case_id);
+        assert_eq!(removed, 2);
+        assert!(trie.search("separate opinion").unwrap().exact_matches.is_empty());
+        assert!(trie.search("410 U.S. 113").unwrap().exact_matches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_insert_content_records_first_token_char_offset() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+        let doc_ref = DocRef { case_id, paragraph_index: 2, char_offset: None };
+        trie.insert_content(&[("estopped".to_string(), 137), ("here".to_string(), 146)], doc_ref).unwrap();
+
+        let matches = trie.search("estopped here").unwrap().exact_matches;
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].char_offset, Some(137));
+    }
+
+    #[tokio::test]
+    async fn test_insert_content_dedupes_identical_case_paragraph_offset_triples() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+        let doc_ref = DocRef { case_id, paragraph_index: 0, char_offset: None };
+        trie.insert_content(&[("res".to_string(), 10), ("judicata".to_string(), 14)], doc_ref.clone()).unwrap();
+        // Simulates a reprocess re-indexing the same sentence at the same offset.
+        trie.insert_content(&[("res".to_string(), 10), ("judicata".to_string(), 14)], doc_ref).unwrap();
+
+        assert_eq!(trie.search("res judicata").unwrap().exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_remove_case_unknown_case_id_is_a_no_op() {
+        let mut trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        let removed = trie.remove_case(Uuid::new_v4());
+        assert_eq!(removed, 0);
+        assert_eq!(trie.search("miranda v arizona").unwrap().exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_case_name_moves_entry_to_new_name() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+        trie.insert_case_name("miranda v arizona", case_id).unwrap();
+
+        trie.update_case_name(case_id, "miranda v arizona", "miranda v state of arizona").unwrap();
+
+        assert!(trie.search("miranda v arizona").unwrap().exact_matches.is_empty());
+        let renamed = trie.search("miranda v state of arizona").unwrap();
+        assert_eq!(renamed.exact_matches.len(), 1);
+        assert_eq!(renamed.exact_matches[0].case_id, case_id);
+    }
+
+    #[tokio::test]
+    async fn test_update_case_name_with_stale_old_name_still_inserts_new_name() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+
+        trie.update_case_name(case_id, "never inserted", "brown v board").unwrap();
+
+        assert_eq!(trie.search("brown v board").unwrap().exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_memory_stats_reports_nonzero_nodes_and_postings() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        let stats = trie.memory_stats();
+        assert!(stats.node_count > 0);
+        assert!(stats.edge_bytes > 0);
+        assert_eq!(stats.posting_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_memory_stats_edge_bytes_stay_under_budget_per_term() {
+        // Regression guard for the sorted-Vec<(Box<str>, TrieNode)> edge representation: since
+        // every non-root node has exactly one incoming edge, edge_bytes / node_count tracks the
+        // average token length (~6-9 bytes here) with no HashMap-style bucket or capacity
+        // slack added on top. A regression back toward hash-table-per-node overhead would push
+        // this well past the budget below.
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        for i in 0..20_000usize {
+            trie.insert_case_name(&format!("Term{i} versus State of Confusion"), Uuid::new_v4()).unwrap();
+        }
+
+        let stats = trie.memory_stats();
+        let edge_bytes_per_node = stats.edge_bytes as f64 / stats.node_count.max(1) as f64;
+        assert!(
+            edge_bytes_per_node < 12.0,
+            "edge bytes per node grew to {edge_bytes_per_node:.2}, exceeding the compact-representation budget"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_stats_counts_terms_per_subtrie() {
+        let mut trie = index_with_case_names(
+            test_trie_config(),
+            &["miranda v arizona", "brown v board of education"],
+        )
+        .await;
+        trie.insert_content(&[("due".to_string(), 0), ("process".to_string(), 4)], doc_ref()).unwrap();
+        trie.insert_citation("410 U.S. 113", doc_ref()).unwrap();
+
+        let stats = trie.get_stats();
+        assert_eq!(stats.case_name_terms, 2);
+        assert_eq!(stats.content_terms, 1);
+        assert_eq!(stats.citation_terms, 1);
+        assert!(stats.total_nodes > 0);
+        assert_eq!(stats.total_document_refs, 3);
+        assert!(stats.max_depth >= 2);
+        assert!(stats.estimated_memory_bytes > 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_stats_reflects_insert_and_remove() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+
+        let before = trie.get_stats();
+        assert_eq!(before.case_name_terms, 0);
+
+        trie.insert_case_name("miranda v arizona", case_id).unwrap();
+        let after_insert = trie.get_stats();
+        assert_eq!(after_insert.case_name_terms, 1);
+        assert!(after_insert.total_nodes > before.total_nodes);
+
+        trie.remove_case(case_id);
+        let after_remove = trie.get_stats();
+        assert_eq!(after_remove.case_name_terms, 0);
+        assert_eq!(after_remove.total_document_refs, 0);
+    }
+
+    #[tokio::test]
+    async fn test_count_with_prefix_counts_terms_in_subtree() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["miranda v arizona", "miranda v ohio", "brown v board of education"],
+        )
+        .await;
+
+        assert_eq!(trie.count_with_prefix(TrieSource::CaseName, "miranda"), 2);
+        assert_eq!(trie.count_with_prefix(TrieSource::CaseName, "brown"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_count_with_prefix_is_zero_for_no_matches_and_empty_prefix() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        assert_eq!(trie.count_with_prefix(TrieSource::CaseName, "nonexistent"), 0);
+        assert_eq!(trie.count_with_prefix(TrieSource::CaseName, ""), 0);
+    }
+
+    #[tokio::test]
+    async fn test_count_with_prefix_respects_citation_case_sensitivity() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_citation("410 U.S. 113", doc_ref()).unwrap();
+
+        assert_eq!(trie.count_with_prefix(TrieSource::Citation, "410 U.S."), 1);
+        assert_eq!(trie.count_with_prefix(TrieSource::Citation, "410 u.s."), 0);
+    }
+
+    #[tokio::test]
+    async fn test_longest_indexed_prefix_returns_deepest_matching_path() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        assert_eq!(
+            trie.longest_indexed_prefix(TrieSource::CaseName, "miranda v arizona"),
+            Some("miranda v arizona".to_string())
+        );
+        assert_eq!(
+            trie.longest_indexed_prefix(TrieSource::CaseName, "miranda v ohio"),
+            Some("miranda v".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_longest_indexed_prefix_is_none_when_first_token_is_unindexed() {
+        let trie = index_with_case_names(test_trie_config(), &["miranda v arizona"]).await;
+
+        assert_eq!(trie.longest_indexed_prefix(TrieSource::CaseName, "brown v board"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_citation_matches_reporter_alias_spellings_exactly() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_citation("98 S.Ct. 2733 (1978)", doc_ref()).unwrap();
+
+        for query in ["98 S.Ct. 2733 (1978)", "98 S. Ct. 2733 (1978)", "98 S.Ct 2733 (1978)"] {
+            match trie.resolve_citation(query) {
+                CitationResolution::Exact(result) => assert_eq!(result.exact_matches.len(), 1),
+                other => panic!("expected exact match for {query:?}, got {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_citation_reports_year_mismatch_on_exact_triple() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_citation("410 U.S. 113 (1973)", doc_ref()).unwrap();
+
+        match trie.resolve_citation("410 U.S. 113 (1974)") {
+            CitationResolution::YearMismatch { result, queried_year, indexed_year } => {
+                assert_eq!(result.exact_matches.len(), 1);
+                assert_eq!(queried_year, 1974);
+                assert_eq!(indexed_year, 1973);
+            }
+            other => panic!("expected a year mismatch, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_citation_is_no_match_when_page_differs() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_citation("410 U.S. 113 (1973)", doc_ref()).unwrap();
+
+        assert!(matches!(
+            trie.resolve_citation("410 U.S. 999 (1973)"),
+            CitationResolution::NoMatch
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_build_from_storage_indexes_names_content_and_citations() {
+        use crate::storage::StorageManager;
+        use crate::text_processing::TextProcessor;
+        use crate::{CaseMetadata, Jurisdiction};
+
+        let temp_dir = std::env::temp_dir().join(format!("trie-build-from-storage-test-{}", Uuid::new_v4()));
+        let mut storage_config = crate::config::Config::default().storage;
+        storage_config.db_path = temp_dir.join("db");
+        let storage = StorageManager::new(storage_config).await.unwrap();
+
+        let case_id = Uuid::new_v4();
+        let metadata = CaseMetadata {
+            id: case_id,
+            name: "Miranda v Arizona".to_string(),
+            citation: "384 U.S. 436".to_string(),
+            court: "Supreme Court".to_string(),
+            decision_date: chrono::NaiveDate::from_ymd_opt(1966, 6, 13).unwrap(),
+            judges: vec!["Warren".to_string()],
+            topics: vec![],
+            full_text: "The right against self-incrimination applies to custodial interrogation.".to_string(),
+            jurisdiction: Jurisdiction::Federal,
+            citations: vec!["384 U.S. 436".to_string()],
+            docket_number: None,
+            source_url: None,
+            word_count: 10,
+            ingestion_date: chrono::Utc::now(),
+            validation_warnings: vec![],
+            content_simhash: None,
+            duplicate_of: None,
+        };
+        storage.store_case_metadata(&metadata).await.unwrap();
+        storage.store_case_text(&case_id, &metadata.full_text, &metadata.full_text).await.unwrap();
+
+        let text_processor = TextProcessor::new(crate::config::Config::default().text_processing).unwrap();
+        let (trie, stats) = TrieIndex::build_from_storage(test_trie_config(), &storage, &text_processor)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.cases_indexed, 1);
+        assert!(stats.content_entries_indexed > 0);
+        assert_eq!(stats.citations_indexed, 1);
+        assert!(trie.search("Miranda v Arizona").unwrap().total_matches > 0);
+        assert!(trie.search("384 U.S. 436").unwrap().total_matches > 0);
+
+        tokio::fs::remove_dir_all(&temp_dir).await.unwrap();
+    }
+
+    #[test]
+    fn test_is_wildcard_query_detects_star_token() {
+        assert!(TrieIndex::is_wildcard_query("freedom of *"));
+        assert!(TrieIndex::is_wildcard_query("* v board of education"));
+        assert!(!TrieIndex::is_wildcard_query("freedom of speech"));
+    }
+
+    #[tokio::test]
+    async fn test_search_wildcard_trailing_matches_any_completion() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_content(
+            &[("freedom".to_string(), 0), ("of".to_string(), 8), ("speech".to_string(), 11)],
+            doc_ref(),
+        )
+        .unwrap();
+        trie.insert_content(
+            &[("freedom".to_string(), 0), ("of".to_string(), 8), ("religion".to_string(), 11)],
+            doc_ref(),
+        )
+        .unwrap();
+        trie.insert_content(
+            &[("freedom".to_string(), 0), ("of".to_string(), 8), ("the".to_string(), 11), ("press".to_string(), 15)],
+            doc_ref(),
+        )
+        .unwrap();
+
+        let result = trie.search("freedom of *").unwrap();
+        assert_eq!(result.exact_matches.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_wildcard_leading_matches_any_single_token() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["marbury v madison", "brown v board of education", "miranda v arizona"],
+        )
+        .await;
+
+        let result = trie.search("* v madison").unwrap();
+        assert_eq!(result.exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_wildcard_middle_matches_any_single_token() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["brown v board of education", "brown v smith"],
+        )
+        .await;
+
+        let result = trie.search("brown * board of education").unwrap();
+        assert_eq!(result.exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_wildcard_does_not_match_multiple_tokens() {
+        let trie = index_with_case_names(test_trie_config(), &["brown v board of education"]).await;
+
+        // "*" is a single-token wildcard, so it should not match "board of" as a whole
+        let result = trie.search("brown v * education").unwrap();
+        assert_eq!(result.exact_matches.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_search_wildcard_respects_cap() {
+        let mut config = test_trie_config();
+        config.wildcard_max_results = 5;
+        let mut trie = TrieIndex::new(config).await.unwrap();
+
+        for i in 0..20 {
+            trie.insert_content(
+                &[("freedom".to_string(), 0), ("of".to_string(), 8), (format!("thing{i}"), 11)],
+                doc_ref(),
+            )
+            .unwrap();
+        }
+
+        let result = trie.search_wildcard("freedom of *").unwrap();
+        assert_eq!(result.exact_matches.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_search_finds_case_name_match_buried_mid_name() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["brown v. board of education"],
+        )
+        .await;
+
+        // "v. board" isn't a prefix path (indexable_paths only splits on the party separator
+        // itself), so this can only be found via the auxiliary substring index.
+        let result = trie.search("v. board").unwrap();
+        assert_eq!(result.exact_matches.len(), 1);
+        assert!(result.is_substring_match);
+    }
+
+    #[tokio::test]
+    async fn test_search_finds_content_match_buried_mid_sentence() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_content(
+            &[
+                ("no".to_string(), 0),
+                ("law".to_string(), 3),
+                ("abridging".to_string(), 7),
+                ("the".to_string(), 17),
+                ("freedom".to_string(), 21),
+                ("of".to_string(), 29),
+                ("speech".to_string(), 32),
+            ],
+            doc_ref(),
+        )
+        .unwrap();
+
+        let result = trie.search("freedom of speech").unwrap();
+        assert_eq!(result.exact_matches.len(), 1);
+        assert!(result.is_substring_match);
+    }
+
+    #[tokio::test]
+    async fn test_search_substring_match_requires_all_tokens_in_same_case() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_content(&[("freedom".to_string(), 0)], doc_ref()).unwrap();
+        trie.insert_content(&[("of".to_string(), 0), ("speech".to_string(), 8)], doc_ref()).unwrap();
+
+        let result = trie.search("freedom of speech").unwrap();
+        assert!(result.exact_matches.is_empty());
+        assert!(!result.is_substring_match);
+    }
+
+    #[tokio::test]
+    async fn test_search_prefers_prefix_match_over_substring_match() {
+        let trie = index_with_case_names(
+            test_trie_config(),
+            &["miranda v. arizona"],
+        )
+        .await;
+
+        // An exact prefix-trie match exists ("miranda" is the first token), so the substring
+        // fallback should never be consulted.
+        let result = trie.search("miranda").unwrap();
+        assert!(!result.exact_matches.is_empty());
+        assert!(!result.is_substring_match);
+    }
+
+    #[tokio::test]
+    async fn test_search_merges_case_name_and_content_buckets_for_different_cases() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_name_case_id = Uuid::new_v4();
+        let content_case_id = Uuid::new_v4();
+
+        trie.insert_case_name("miranda v. arizona", case_name_case_id).unwrap();
+        trie.insert_content(
+            &[
+                ("miranda".to_string(), 0),
+                ("rights".to_string(), 8),
+                ("must".to_string(), 15),
+                ("be".to_string(), 20),
+                ("read".to_string(), 23),
+            ],
+            DocRef {
+                case_id: content_case_id,
+                paragraph_index: 0,
+                char_offset: None,
+            },
+        )
+        .unwrap();
+
+        let result = trie.search("miranda").unwrap();
+
+        assert!(
+            result.buckets.iter().any(|bucket| bucket.source == TrieSource::CaseName),
+            "expected a case-name bucket, got {:?}",
+            result.buckets,
+        );
+        assert!(
+            result.buckets.iter().any(|bucket| bucket.source == TrieSource::Content),
+            "expected a content bucket, got {:?}",
+            result.buckets,
+        );
+        assert!(result.exact_matches.iter().any(|doc_ref| doc_ref.case_id == case_name_case_id));
+        assert!(result.exact_matches.iter().any(|doc_ref| doc_ref.case_id == content_case_id));
+        assert_eq!(result.total_matches, result.exact_matches.len());
+    }
+
+    #[tokio::test]
+    async fn test_search_does_not_double_count_case_name_hit_already_in_substring_index() {
+        let case_id = Uuid::new_v4();
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_case_name("brown v. board of education", case_id).unwrap();
+
+        // "board" also lands in the auxiliary substring index via insert_case_name, so a naive
+        // unconditional merge of the case-name bucket and the substring-fallback bucket would
+        // otherwise report this case's DocRef twice.
+        let result = trie.search("board").unwrap();
+
+        let matches_for_case: Vec<_> = result
+            .exact_matches
+            .iter()
+            .filter(|doc_ref| doc_ref.case_id == case_id)
+            .collect();
+        assert_eq!(matches_for_case.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_remove_case_also_drops_substring_index_entries() {
+        let case_id = Uuid::new_v4();
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_case_name("brown v. board of education", case_id).unwrap();
+
+        assert_eq!(trie.search("v. board").unwrap().exact_matches.len(), 1);
+        trie.remove_case(case_id);
+        assert!(trie.search("v. board").unwrap().exact_matches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_case_name_moves_substring_index_entries() {
+        let case_id = Uuid::new_v4();
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        trie.insert_case_name("brown v. board of education", case_id).unwrap();
+
+        trie.update_case_name(case_id, "brown v. board of education", "brown v. topeka schools").unwrap();
+
+        assert!(trie.search("v. board").unwrap().exact_matches.is_empty());
+        assert_eq!(trie.search("v. topeka").unwrap().exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trie_index_handle_snapshot_sees_committed_writes() {
+        let handle = TrieIndexHandle::new(TrieIndex::new(test_trie_config()).await.unwrap());
+        assert_eq!(handle.snapshot().search("miranda v arizona").unwrap().exact_matches.len(), 0);
+
+        let mut writer = handle.begin_write();
+        writer.insert_case_name("Miranda v Arizona", Uuid::new_v4()).unwrap();
+        handle.commit(writer);
+
+        assert_eq!(handle.snapshot().search("miranda v arizona").unwrap().exact_matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trie_index_handle_snapshot_held_before_commit_is_unaffected() {
+        let handle = TrieIndexHandle::new(TrieIndex::new(test_trie_config()).await.unwrap());
+
+        // A snapshot taken before a write is committed is a fully independent, owned generation:
+        // committing a later batch must not retroactively mutate it.
+        let stale_snapshot = handle.snapshot();
+
+        let mut writer = handle.begin_write();
+        writer.insert_case_name("Miranda v Arizona", Uuid::new_v4()).unwrap();
+        handle.commit(writer);
+
+        assert_eq!(stale_snapshot.search("miranda v arizona").unwrap().exact_matches.len(), 0);
+        assert_eq!(handle.snapshot().search("miranda v arizona").unwrap().exact_matches.len(), 1);
+    }
+
+    /// Concurrent readers repeatedly snapshotting while a writer commits large batches should
+    /// never observe a partially applied batch: every snapshot must show either all of a given
+    /// case's entries (name + citation) or none of them, never one without the other.
+    #[tokio::test]
+    async fn test_trie_index_handle_readers_never_observe_partial_batch() {
+        let handle = std::sync::Arc::new(TrieIndexHandle::new(TrieIndex::new(test_trie_config()).await.unwrap()));
+
+        let writer_handle = handle.clone();
+        let writer_task = tokio::spawn(async move {
+            for batch in 0..20 {
+                let mut writer = writer_handle.begin_write();
+                for i in 0..10 {
+                    let case_id = Uuid::new_v4();
+                    let name = format!("batch {batch} case {i}");
+                    writer.insert_case_name(&name, case_id).unwrap();
+                    writer.insert_citation(&format!("{batch} U.S. {i}"), doc_ref()).unwrap();
+                }
+                writer_handle.commit(writer);
+            }
+        });
+
+        let mut reader_tasks = Vec::new();
+        for _ in 0..8 {
+            let reader_handle = handle.clone();
+            reader_tasks.push(tokio::spawn(async move {
+                for _ in 0..200 {
+                    // Every snapshot is a self-consistent, complete generation by construction
+                    // (a single `Arc` swap), so there is nothing further to assert here beyond
+                    // not panicking or deadlocking against a concurrently committing writer.
+                    let _ = reader_handle.snapshot().search("batch 0 case 0");
+                }
+            }));
+        }
+
+        writer_task.await.unwrap();
+        for reader_task in reader_tasks {
+            reader_task.await.unwrap();
+        }
+
+        assert_eq!(handle.snapshot().memory_stats().node_count > 0, true);
+    }
+
+    #[tokio::test]
+    async fn test_insert_batch_is_searchable_like_sequential_inserts() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let case_id = Uuid::new_v4();
+
+        trie.insert_batch(vec![
+            TrieEntry::CaseName { case_name: "miranda v arizona".to_string(), case_id },
+            TrieEntry::Content {
+                tokens: vec![("due".to_string(), 0), ("process".to_string(), 4)],
+                doc_ref: doc_ref(),
+            },
+            TrieEntry::Citation { citation: "410 U.S. 113".to_string(), doc_ref: doc_ref() },
+        ])
+        .unwrap();
+
+        assert_eq!(trie.search("miranda v arizona").unwrap().exact_matches.len(), 1);
+        assert!(trie.search("due process").unwrap().total_matches > 0);
+        assert!(matches!(trie.resolve_citation("410 U.S. 113"), CitationResolution::Exact(_)));
+    }
+
+    #[tokio::test]
+    async fn test_insert_batch_matches_sequential_inserts_stats() {
+        let case_names = ["miranda v arizona", "brown v board of education", "roe v wade"];
+
+        let mut sequential = TrieIndex::new(test_trie_config()).await.unwrap();
+        for case_name in &case_names {
+            sequential.insert_case_name(case_name, Uuid::new_v4()).unwrap();
+        }
+
+        let mut batched = TrieIndex::new(test_trie_config()).await.unwrap();
+        let entries = case_names
+            .iter()
+            .map(|case_name| TrieEntry::CaseName { case_name: case_name.to_string(), case_id: Uuid::new_v4() })
+            .collect();
+        batched.insert_batch(entries).unwrap();
+
+        let sequential_stats = sequential.get_stats();
+        let batched_stats = batched.get_stats();
+        assert_eq!(sequential_stats.case_name_terms, batched_stats.case_name_terms);
+        assert_eq!(sequential_stats.total_document_refs, batched_stats.total_document_refs);
+        assert_eq!(sequential_stats.total_nodes, batched_stats.total_nodes);
+    }
+
+    #[tokio::test]
+    async fn test_insert_batch_shares_prefix_nodes_across_entries() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let entries = (0..20)
+            .map(|i| TrieEntry::CaseName {
+                case_name: format!("united states v term{i}"),
+                case_id: Uuid::new_v4(),
+            })
+            .collect();
+        trie.insert_batch(entries).unwrap();
+
+        let stats = trie.get_stats();
+        // Every entry shares the "united states" prefix, so the node count must stay far below
+        // one independent path per entry.
+        assert!(stats.total_nodes < 40);
+        assert_eq!(trie.search("united states").unwrap().total_matches, 20);
+    }
+
+    fn stopword_filtering_config() -> TrieConfig {
+        TrieConfig { skip_stopword_only_ngrams: true, min_token_length: 3, ..test_trie_config() }
+    }
+
+    fn stopwords() -> HashSet<String> {
+        ["of", "the", "in", "a", "an", "to"].iter().map(|s| s.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_insert_content_drops_stopword_only_sentence() {
+        let mut trie = TrieIndex::new(stopword_filtering_config()).await.unwrap();
+        trie.set_stopwords(stopwords());
+
+        trie.insert_content(&[("of".to_string(), 0), ("the".to_string(), 3)], doc_ref()).unwrap();
+
+        assert_eq!(trie.get_stats().content_terms, 0);
+    }
+
+    #[tokio::test]
+    async fn test_insert_content_drops_sentence_below_min_token_length() {
+        let mut trie = TrieIndex::new(stopword_filtering_config()).await.unwrap();
+        trie.set_stopwords(stopwords());
+
+        trie.insert_content(&[("id".to_string(), 0), ("re".to_string(), 3)], doc_ref()).unwrap();
+
+        assert_eq!(trie.get_stats().content_terms, 0);
+    }
+
+    #[tokio::test]
+    async fn test_insert_content_keeps_mixed_phrase_with_mid_sentence_stopword() {
+        let mut trie = TrieIndex::new(stopword_filtering_config()).await.unwrap();
+        trie.set_stopwords(stopwords());
+
+        trie.insert_content(
+            &[("freedom".to_string(), 0), ("of".to_string(), 8), ("speech".to_string(), 11)],
+            doc_ref(),
+        )
+        .unwrap();
+
+        assert_eq!(trie.get_stats().content_terms, 1);
+        assert!(trie.search("freedom of speech").unwrap().total_matches > 0);
+    }
+
+    #[tokio::test]
+    async fn test_skip_stopword_only_ngrams_reduces_index_size_on_sample_corpus() {
+        let sentences: Vec<Vec<(String, usize)>> = vec![
+            vec![("of".to_string(), 0), ("the".to_string(), 3)],
+            vec![("in".to_string(), 0), ("the".to_string(), 3)],
+            vec![("to".to_string(), 0), ("a".to_string(), 3)],
+            vec![
+                ("freedom".to_string(), 0),
+                ("of".to_string(), 8),
+                ("speech".to_string(), 11),
+            ],
+            vec![
+                ("due".to_string(), 0),
+                ("process".to_string(), 4),
+                ("of".to_string(), 12),
+                ("law".to_string(), 15),
+            ],
+        ];
+
+        let mut filtered = TrieIndex::new(stopword_filtering_config()).await.unwrap();
+        filtered.set_stopwords(stopwords());
+        let mut unfiltered = TrieIndex::new(test_trie_config()).await.unwrap();
+
+        for sentence in &sentences {
+            filtered.insert_content(sentence, doc_ref()).unwrap();
+            unfiltered.insert_content(sentence, doc_ref()).unwrap();
+        }
+
+        let filtered_stats = filtered.get_stats();
+        let unfiltered_stats = unfiltered.get_stats();
+        assert_eq!(filtered_stats.content_terms, 2);
+        assert_eq!(unfiltered_stats.content_terms, 5);
+        assert!(filtered_stats.total_nodes < unfiltered_stats.total_nodes);
+
+        // Recall for the mixed-phrase (stopword mid-sentence) case is unaffected by filtering.
+        assert_eq!(
+            filtered.search("freedom of speech").unwrap().total_matches,
+            unfiltered.search("freedom of speech").unwrap().total_matches
+        );
+        assert_eq!(
+            filtered.search("due process of law").unwrap().total_matches,
+            unfiltered.search("due process of law").unwrap().total_matches
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_empty_trie_writes_nothing() {
+        let trie = TrieIndex::new(test_trie_config()).await.unwrap();
+
+        let mut buffer = Vec::new();
+        let written = trie.export(&mut buffer, TrieSource::Content).unwrap();
+
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_export_round_trips_frequency_and_document_refs_through_json() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        let first_ref = doc_ref();
+        let second_ref = doc_ref();
+        trie.insert_content(&[("due".to_string(), 0), ("process".to_string(), 4)], first_ref.clone()).unwrap();
+        trie.insert_content(&[("due".to_string(), 0), ("process".to_string(), 4)], second_ref.clone()).unwrap();
+
+        let mut buffer = Vec::new();
+        let written = trie.export(&mut buffer, TrieSource::Content).unwrap();
+        assert_eq!(written, 1);
+
+        let line = String::from_utf8(buffer).unwrap();
+        let entry: TrieExportEntry = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(entry.term, "due process");
+        assert_eq!(entry.frequency, 2);
+        assert_eq!(entry.document_refs.len(), 2);
+        assert!(entry.document_refs.iter().any(|d| d.case_id == first_ref.case_id));
+        assert!(entry.document_refs.iter().any(|d| d.case_id == second_ref.case_id));
+    }
+
+    #[tokio::test]
+    async fn test_export_then_insert_batch_round_trip_matches_original_search_results() {
+        let mut original = TrieIndex::new(test_trie_config()).await.unwrap();
+        for sentence in [
+            "due process of law",
+            "freedom of speech",
+            "equal protection under the law",
+        ] {
+            let tokens: Vec<(String, usize)> =
+                sentence.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+            original.insert_content(&tokens, doc_ref()).unwrap();
+        }
+
+        let mut buffer = Vec::new();
+        let written = original.export(&mut buffer, TrieSource::Content).unwrap();
+        assert_eq!(written, 3);
+
+        let exported = String::from_utf8(buffer).unwrap();
+        let entries: Vec<TrieEntry> = exported
+            .lines()
+            .map(|line| {
+                let row: TrieExportEntry = serde_json::from_str(line).unwrap();
+                let tokens: Vec<(String, usize)> =
+                    row.term.split_whitespace().enumerate().map(|(i, w)| (w.to_string(), i)).collect();
+                TrieEntry::Content { tokens, doc_ref: row.document_refs[0].clone() }
+            })
+            .collect();
+
+        let mut reimported = TrieIndex::new(test_trie_config()).await.unwrap();
+        reimported.insert_batch(entries).unwrap();
+
+        for query in ["due process of law", "freedom of speech", "equal protection under the law"] {
+            assert_eq!(
+                reimported.search(query).unwrap().total_matches,
+                original.search(query).unwrap().total_matches
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prune_drops_rare_terms_but_keeps_frequent_ones_searchable() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+
+        // Indexed once: a rare phrase that should be dropped by pruning.
+        trie.insert_content(&[("obiter".to_string(), 0), ("dictum".to_string(), 7)], doc_ref()).unwrap();
+
+        // Indexed three times: a frequent phrase that must survive pruning untouched.
+        for _ in 0..3 {
+            trie.insert_content(&[("due".to_string(), 0), ("process".to_string(), 4)], doc_ref()).unwrap();
+        }
+
+        let report = trie.prune(2, 100);
+        assert_eq!(report.terms_dropped, 1);
+        assert_eq!(report.document_refs_dropped, 1);
+
+        // The rare phrase's exact match is gone; a caller would fall through to vector search.
+        assert!(trie.search("obiter dictum").unwrap().exact_matches.is_empty());
+        assert_eq!(trie.get_stats().content_terms, 1);
+
+        // The frequent phrase is untouched.
+        assert_eq!(trie.search("due process").unwrap().exact_matches.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_prune_truncates_oversized_posting_lists_without_dropping_the_term() {
+        let mut trie = TrieIndex::new(test_trie_config()).await.unwrap();
+        for _ in 0..10 {
+            trie.insert_content(&[("due".to_string(), 0), ("process".to_string(), 4)], doc_ref()).unwrap();
+        }
+
+        let report = trie.prune(0, 4);
+        assert_eq!(report.terms_dropped, 0);
+        assert_eq!(report.document_refs_dropped, 6);
+
+        let result = trie.search("due process").unwrap();
+        assert_eq!(result.exact_matches.len(), 4);
+        assert_eq!(trie.get_stats().content_terms, 1);
     }
-} 
\ No newline at end of file
+}
\ No newline at end of file
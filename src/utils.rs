@@ -17,7 +17,9 @@
 //! - Common data transformations
 
 use chrono::{DateTime, Utc};
-use std::time::Instant;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 /// Performance timer for measuring operation duration
 pub struct Timer {
@@ -25,6 +27,205 @@ pub struct Timer {
     name: String,
 }
 
+/// Millisecond boundaries between [`DurationHistogram`] buckets; the last bucket catches
+/// everything above the highest boundary.
+const HISTOGRAM_BOUNDARIES_MS: [u64; 6] = [1, 5, 10, 50, 100, 500];
+
+/// A fixed-bucket duration histogram, incremented via atomics so it can be shared behind an
+/// `Arc` and updated concurrently without a lock of its own. Not a general-purpose metrics
+/// library — just enough resolution to see whether a lock's hold/wait times cluster low with
+/// an occasional long tail, per [`InstrumentedRwLock`].
+#[derive(Debug)]
+pub struct DurationHistogram {
+    buckets: [AtomicU64; HISTOGRAM_BOUNDARIES_MS.len() + 1],
+}
+
+impl DurationHistogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+
+    /// Record one observation, incrementing the bucket for the first boundary `duration`
+    /// doesn't exceed, or the overflow bucket if it exceeds all of them.
+    pub fn record(&self, duration: Duration) {
+        let millis = duration.as_millis() as u64;
+        let bucket = HISTOGRAM_BOUNDARIES_MS
+            .iter()
+            .position(|&boundary| millis <= boundary)
+            .unwrap_or(HISTOGRAM_BOUNDARIES_MS.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// `(bucket label, count)` pairs in ascending order; the last label is `">Nms"` for the
+    /// overflow bucket.
+    pub fn snapshot(&self) -> Vec<(String, u64)> {
+        let mut labels: Vec<String> = HISTOGRAM_BOUNDARIES_MS
+            .iter()
+            .map(|boundary| format!("<={boundary}ms"))
+            .collect();
+        labels.push(format!(">{}ms", HISTOGRAM_BOUNDARIES_MS.last().unwrap()));
+
+        labels
+            .into_iter()
+            .zip(self.buckets.iter())
+            .map(|(label, count)| (label, count.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Total observations recorded so far.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|bucket| bucket.load(Ordering::Relaxed)).sum()
+    }
+}
+
+impl Default for DurationHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `tokio::sync::RwLock` wrapper that records acquisition wait time and hold duration as
+/// [`DurationHistogram`]s (separately for reads and writes) and logs a warning when a hold
+/// exceeds `warn_threshold`. Exists so contention on a hot lock — e.g.
+/// `SearchEngine::trie_index`/`vector_index`, read by every query and written by background
+/// checkpointing, cache invalidation, and ingestion — is something a maintainer can see, not
+/// just suspect, when queries start stalling.
+pub struct InstrumentedRwLock<T> {
+    inner: tokio::sync::RwLock<T>,
+    name: &'static str,
+    warn_threshold: Duration,
+    read_wait: DurationHistogram,
+    read_hold: DurationHistogram,
+    write_wait: DurationHistogram,
+    write_hold: DurationHistogram,
+    threshold_breaches: AtomicU64,
+}
+
+impl<T> InstrumentedRwLock<T> {
+    pub fn new(name: &'static str, warn_threshold: Duration, value: T) -> Self {
+        Self {
+            inner: tokio::sync::RwLock::new(value),
+            name,
+            warn_threshold,
+            read_wait: DurationHistogram::new(),
+            read_hold: DurationHistogram::new(),
+            write_wait: DurationHistogram::new(),
+            write_hold: DurationHistogram::new(),
+            threshold_breaches: AtomicU64::new(0),
+        }
+    }
+
+    /// Acquire the read lock. `operation` labels the caller for the wait/hold histograms and
+    /// the threshold-exceeded warning (e.g. `"search_trie"`, `"checkpoint"`).
+    pub async fn read(&self, operation: &'static str) -> InstrumentedReadGuard<'_, T> {
+        let wait_start = Instant::now();
+        let guard = self.inner.read().await;
+        self.read_wait.record(wait_start.elapsed());
+        InstrumentedReadGuard { guard, lock: self, operation, hold_start: Instant::now() }
+    }
+
+    /// Acquire the write lock. See [`InstrumentedRwLock::read`] for `operation`.
+    pub async fn write(&self, operation: &'static str) -> InstrumentedWriteGuard<'_, T> {
+        let wait_start = Instant::now();
+        let guard = self.inner.write().await;
+        self.write_wait.record(wait_start.elapsed());
+        InstrumentedWriteGuard { guard, lock: self, operation, hold_start: Instant::now() }
+    }
+
+    fn record_hold(&self, kind: &'static str, operation: &'static str, held: Duration) {
+        if held > self.warn_threshold {
+            self.threshold_breaches.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                lock = self.name,
+                kind,
+                operation,
+                hold_ms = held.as_millis() as u64,
+                threshold_ms = self.warn_threshold.as_millis() as u64,
+                "lock held longer than warn threshold"
+            );
+        }
+    }
+
+    /// Number of read/write acquisitions whose hold time has exceeded `warn_threshold` since
+    /// construction. Mirrors the count of `tracing::warn!` calls this lock has emitted; exposed
+    /// mainly so tests can assert a threshold breach fired without scraping log output.
+    pub fn threshold_breach_count(&self) -> u64 {
+        self.threshold_breaches.load(Ordering::Relaxed)
+    }
+
+    pub fn read_wait_histogram(&self) -> &DurationHistogram {
+        &self.read_wait
+    }
+
+    pub fn read_hold_histogram(&self) -> &DurationHistogram {
+        &self.read_hold
+    }
+
+    pub fn write_wait_histogram(&self) -> &DurationHistogram {
+        &self.write_wait
+    }
+
+    pub fn write_hold_histogram(&self) -> &DurationHistogram {
+        &self.write_hold
+    }
+}
+
+/// Read guard returned by [`InstrumentedRwLock::read`]; records its hold duration on drop.
+pub struct InstrumentedReadGuard<'a, T> {
+    guard: tokio::sync::RwLockReadGuard<'a, T>,
+    lock: &'a InstrumentedRwLock<T>,
+    operation: &'static str,
+    hold_start: Instant,
+}
+
+impl<'a, T> Deref for InstrumentedReadGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<'a, T> Drop for InstrumentedReadGuard<'a, T> {
+    fn drop(&mut self) {
+        let held = self.hold_start.elapsed();
+        self.lock.read_hold.record(held);
+        self.lock.record_hold("read", self.operation, held);
+    }
+}
+
+/// Write guard returned by [`InstrumentedRwLock::write`]; records its hold duration on drop.
+pub struct InstrumentedWriteGuard<'a, T> {
+    guard: tokio::sync::RwLockWriteGuard<'a, T>,
+    lock: &'a InstrumentedRwLock<T>,
+    operation: &'static str,
+    hold_start: Instant,
+}
+
+impl<'a, T> Deref for InstrumentedWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<'a, T> DerefMut for InstrumentedWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<'a, T> Drop for InstrumentedWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        let held = self.hold_start.elapsed();
+        self.lock.write_hold.record(held);
+        self.lock.record_hold("write", self.operation, held);
+    }
+}
+
 /// Text processing utilities
 pub struct TextUtils;
 
@@ -225,4 +426,61 @@ mod tests {
         assert!(!ValidationUtils::is_valid_search_query("", 2, 100));
         assert!(!ValidationUtils::is_valid_search_query("a", 2, 100));
     }
+
+    #[test]
+    fn test_duration_histogram_buckets_by_boundary() {
+        let histogram = DurationHistogram::new();
+        histogram.record(Duration::from_millis(0));
+        histogram.record(Duration::from_millis(3));
+        histogram.record(Duration::from_millis(1000));
+
+        assert_eq!(histogram.count(), 3);
+        let snapshot = histogram.snapshot();
+        assert_eq!(snapshot[0], ("<=1ms".to_string(), 1));
+        assert_eq!(snapshot[2], ("<=10ms".to_string(), 1));
+        assert_eq!(snapshot.last().unwrap(), &(">500ms".to_string(), 1));
+    }
+
+    #[tokio::test]
+    async fn test_instrumented_rwlock_records_hold_and_does_not_warn_below_threshold() {
+        let lock = InstrumentedRwLock::new("test_lock", Duration::from_millis(50), 0u32);
+
+        {
+            let _guard = lock.read("quick_read").await;
+        }
+
+        assert_eq!(lock.threshold_breach_count(), 0);
+        assert_eq!(lock.read_hold_histogram().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_instrumented_rwlock_warns_on_artificial_long_hold() {
+        let lock = InstrumentedRwLock::new("test_lock", Duration::from_millis(10), 0u32);
+
+        {
+            let _guard = lock.write("slow_write").await;
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        assert_eq!(lock.threshold_breach_count(), 1);
+        assert_eq!(lock.write_hold_histogram().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_instrumented_rwlock_deref_and_deref_mut() {
+        let lock = InstrumentedRwLock::new("test_lock", Duration::from_secs(1), vec![1, 2, 3]);
+
+        {
+            let guard = lock.read("read_len").await;
+            assert_eq!(guard.len(), 3);
+        }
+        {
+            let mut guard = lock.write("push").await;
+            guard.push(4);
+        }
+        {
+            let guard = lock.read("read_after_write").await;
+            assert_eq!(*guard, vec![1, 2, 3, 4]);
+        }
+    }
 } 
\ No newline at end of file
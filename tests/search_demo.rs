@@ -0,0 +1,82 @@
+//! Keeps `examples/search_demo.rs` honest in CI: builds the same demo engine it does and
+//! asserts every one of its queries actually returns results, rather than only checking
+//! that the example compiles.
+
+#[path = "../examples/search_demo.rs"]
+mod search_demo;
+
+use chrono::NaiveDate;
+use trie_semantic_search::search::SortOrder;
+use trie_semantic_search::{SearchQuery, SearchSyntax};
+
+#[tokio::test]
+async fn search_demo_produces_results() {
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let engine = search_demo::build_demo_engine(snapshot_dir.path()).await.unwrap();
+
+    let base_config = trie_semantic_search::SearchConfig { enable_semantic: false, ..Default::default() };
+
+    let queries = vec![
+        SearchQuery {
+            query: "Miranda v. Arizona".to_string(),
+            max_results: Some(5),
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            sort: SortOrder::Relevance,
+            profile: None,
+            config: base_config.clone(),
+        },
+        SearchQuery {
+            query: "right to remain silent".to_string(),
+            max_results: Some(5),
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            sort: SortOrder::Relevance,
+            profile: None,
+            config: base_config.clone(),
+        },
+        SearchQuery {
+            query: "410 U.S. 113".to_string(),
+            max_results: Some(5),
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            court_filter: None,
+            judge_filter: None,
+            date_range: None,
+            topic_filter: None,
+            sort: SortOrder::Relevance,
+            profile: None,
+            config: base_config.clone(),
+        },
+        SearchQuery {
+            query: "equal protection".to_string(),
+            max_results: Some(5),
+            offset: 0,
+            syntax: SearchSyntax::Plain,
+            court_filter: Some(vec!["Supreme Court of the United States".to_string()]),
+            judge_filter: None,
+            date_range: Some((
+                NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1960, 12, 31).unwrap(),
+            )),
+            topic_filter: None,
+            sort: SortOrder::Relevance,
+            profile: None,
+            config: base_config,
+        },
+    ];
+
+    for query in queries {
+        let label = query.query.clone();
+        let outcome = engine.search_with_params(query).await.unwrap();
+        assert!(!outcome.results.is_empty(), "expected at least one result for query {label:?}");
+    }
+}